@@ -1,6 +1,9 @@
 use crate::cli::Args;
+use crate::config::DisplayMode;
+use crate::config::paths::get_session_path;
 use crate::error::AppError;
 use crate::ui;
+use crate::ui::interactive::session::SessionState;
 use crate::version;
 use crossterm::{
     execute,
@@ -9,6 +12,7 @@ use crossterm::{
     },
 };
 use std::io::{Stdout, stdout};
+use std::path::PathBuf;
 
 /// RAII guard for terminal state cleanup.
 ///
@@ -33,10 +37,32 @@ impl Drop for TerminalGuard {
     }
 }
 
+/// The display mode the UI starts in. A display mode given as a flag or set
+/// by any config layer (`display_mode_set`), `normal` included, wins over
+/// the saved one; -c and -w are already exclusive (validate_args).
+fn starting_display_mode(
+    args: &Args,
+    display_mode_set: bool,
+    session: Option<&SessionState>,
+) -> DisplayMode {
+    if args.compact {
+        DisplayMode::Compact
+    } else if args.wide {
+        DisplayMode::Wide
+    } else if args.teletext {
+        DisplayMode::Teletext
+    } else if display_mode_set {
+        DisplayMode::Normal
+    } else {
+        session.map_or(DisplayMode::Normal, |session| session.display_mode)
+    }
+}
+
 /// Run the interactive application flow.
 ///
 /// - Sets up terminal raw mode and alternate screen
-/// - Runs the interactive UI
+/// - Restores the last session (unless `--no-session`)
+/// - Runs the interactive UI and saves its session on exit
 /// - Cleans up terminal state (via RAII guard)
 /// - After exit, prints any timezone warning and version update info
 ///
@@ -45,9 +71,20 @@ impl Drop for TerminalGuard {
 /// UI would be wiped when the alternate screen opens or closes.
 pub async fn run_interactive(
     args: &Args,
+    display_mode_set: bool,
     version_check: tokio::task::JoinHandle<Option<String>>,
     timezone_problem: Option<crate::timezone_check::TimezoneProblem>,
 ) -> Result<(), AppError> {
+    let session_path = get_session_path();
+    let session = if args.no_session {
+        None
+    } else {
+        SessionState::load(&session_path).await
+    };
+    let display_mode = starting_display_mode(args, display_mode_set, session.as_ref());
+    // An explicit --date opens that day's games rather than the saved view
+    let session = session.filter(|_| args.date.is_none());
+
     // Interactive mode
     enable_raw_mode()?;
 
@@ -67,8 +104,10 @@ pub async fn run_interactive(
         args.disable_links,
         args.debug,
         args.min_refresh_interval,
-        args.compact,
-        args.wide,
+        display_mode,
+        session.as_ref(),
+        PathBuf::from(args.export_dir.as_deref().unwrap_or(".")),
+        args.video_player.clone(),
     )
     .await;
 
@@ -88,5 +127,38 @@ pub async fn run_interactive(
         version::print_version_info(&latest_version);
     }
 
-    result
+    let session = result?;
+    if !args.no_session
+        && let Err(e) = session.save(&session_path).await
+    {
+        tracing::warn!("Failed to save session to {}: {e}", session_path.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[test]
+    fn test_configured_normal_mode_wins_over_saved_session() {
+        let args = Args::parse_from(["liiga_teletext"]);
+        let session = SessionState {
+            display_mode: DisplayMode::Wide,
+            ..Default::default()
+        };
+        assert_eq!(
+            starting_display_mode(&args, false, Some(&session)),
+            DisplayMode::Wide
+        );
+        assert_eq!(
+            starting_display_mode(&args, true, Some(&session)),
+            DisplayMode::Normal
+        );
+        assert_eq!(
+            starting_display_mode(&Args::parse_from(["liiga_teletext", "-c"]), true, None),
+            DisplayMode::Compact
+        );
+    }
 }
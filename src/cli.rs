@@ -1,5 +1,8 @@
 use clap::Parser;
 use clap::builder::styling::{AnsiColor, Effects, Styles};
+use toml::{Table, Value};
+
+use crate::config::{Config, DisplayMode};
 
 fn get_styles() -> Styles {
     Styles::styled()
@@ -16,7 +19,8 @@ fn get_styles() -> Styles {
 /// Non-interactive mode is used when any of these conditions are met:
 /// - --once flag is set (run once and exit)
 /// - --compact flag is set (display games in compact format)
-/// - config operations are requested
+/// - config operations are requested (including --check-config)
+/// - --serve runs the web server
 /// - --version flag is set
 /// - --debug mode is enabled (debug mode always runs once and exits)
 pub fn is_noninteractive_mode(args: &Args) -> bool {
@@ -26,6 +30,8 @@ pub fn is_noninteractive_mode(args: &Args) -> bool {
         || args.new_log_file_path.is_some()
         || args.clear_log_file_path
         || args.list_config
+        || args.check_config
+        || args.serve.is_some()
         || args.version
         || args.debug
 }
@@ -42,6 +48,8 @@ pub fn is_noninteractive_mode(args: &Args) -> bool {
 /// - Press 'p' to toggle playoff bracket view
 /// - Press 'l' to toggle live mode in standings
 /// - Press 't' to jump back to today's view
+/// - Press 'v' to cycle the normal, compact, wide and teletext layouts
+/// - Press 'x' to export the page as HTML, SVG, text and TTI
 /// - Press 'r' to refresh data (15s cooldown between refreshes)
 /// - Press 'q' to quit
 ///
@@ -75,6 +83,82 @@ pub struct Args {
     #[arg(short = 'w', long = "wide", help_heading = "Display Options")]
     pub wide: bool,
 
+    /// Display every view on the strict 40x24 grid of a real teletext page,
+    /// with a block-mosaic header and a double-height title where the
+    /// terminal supports it. The grid is centred on larger terminals.
+    #[arg(
+        long = "teletext",
+        conflicts_with_all = ["compact", "wide"],
+        help_heading = "Display Options"
+    )]
+    pub teletext: bool,
+
+    /// Start in the default view instead of the one open when the viewer last
+    /// closed, and don't save the session on exit.
+    #[arg(long = "no-session", help_heading = "Display Options")]
+    pub no_session: bool,
+
+    /// List the games of these teams first, e.g. "Tappara,IFK". Teams are
+    /// given by name or abbreviation. Defaults to the `favourite_teams`
+    /// config setting.
+    #[arg(
+        long = "favourite-teams",
+        value_name = "TEAMS",
+        value_delimiter = ',',
+        help_heading = "Display Options"
+    )]
+    pub favourite_teams: Option<Vec<String>>,
+
+    /// Language of the UI: fi (Finnish), en (English) or sv (Swedish).
+    /// Defaults to the `language` config setting, then to the locale in LANG.
+    #[arg(
+        long = "lang",
+        value_name = "LANG",
+        value_parser = ["fi", "en", "sv"],
+        help_heading = "Display Options"
+    )]
+    pub lang: Option<String>,
+
+    /// With --once, write the page to a file instead of the terminal. The
+    /// format follows the extension: .html, .svg, .txt, or .tti for a
+    /// teletext page file with every sub-page.
+    #[arg(
+        long = "export",
+        value_name = "FILE",
+        requires = "once",
+        help_heading = "Display Options"
+    )]
+    pub export: Option<String>,
+
+    /// Serve pages 221, 222 and 223 as HTML and JSON on ADDR (e.g. 127.0.0.1:8080)
+    /// instead of showing them in the terminal. Open pages update live.
+    #[arg(
+        long = "serve",
+        value_name = "ADDR",
+        conflicts_with = "once",
+        help_heading = "Display Options"
+    )]
+    pub serve: Option<String>,
+
+    /// Directory the `x` key writes page snapshots into in interactive mode.
+    /// Defaults to the `export_dir` config setting, then the current directory.
+    #[arg(
+        long = "export-dir",
+        value_name = "DIR",
+        help_heading = "Display Options"
+    )]
+    pub export_dir: Option<String>,
+
+    /// Command the goal video picker (`g` on a game page) opens clips with,
+    /// e.g. "mpv" or "xdg-open". `{url}` is replaced by the clip URL, which is
+    /// otherwise appended. Without a player the URL is printed on the page.
+    #[arg(
+        long = "video-player",
+        value_name = "CMD",
+        help_heading = "Display Options"
+    )]
+    pub video_player: Option<String>,
+
     /// Update API domain in config. Will prompt for new domain if not provided.
     #[arg(
         long = "config",
@@ -93,10 +177,21 @@ pub struct Args {
     #[arg(long = "clear-log-file", help_heading = "Configuration")]
     pub clear_log_file_path: bool,
 
-    /// List current configuration settings
+    /// List current configuration settings and the layer each one came from
     #[arg(long = "list-config", short = 'l', help_heading = "Configuration")]
     pub list_config: bool,
 
+    /// Check the configuration files strictly and report unknown keys, invalid
+    /// values and malformed URLs with their line and column. Exits non-zero if
+    /// any problems are found.
+    #[arg(long = "check-config", help_heading = "Configuration")]
+    pub check_config: bool,
+
+    /// Apply the named profile, a `[profiles.<NAME>]` table in a config file.
+    /// Defaults to the LIIGA_PROFILE environment variable.
+    #[arg(long = "profile", value_name = "NAME", help_heading = "Configuration")]
+    pub profile: Option<String>,
+
     /// Clear persistent player name cache and start fresh.
     /// Removes cached player names from disk; the app continues running normally after reset.
     #[arg(long = "reset-cache", help_heading = "Configuration")]
@@ -121,8 +216,144 @@ pub struct Args {
     #[arg(long = "log-file", help_heading = "Debug")]
     pub log_file: Option<String>,
 
+    /// Format of the log file: text, or json for one object per line that
+    /// `jq` can read. Defaults to the `log_format` config setting, then text.
+    #[arg(
+        long = "log-format",
+        value_name = "FORMAT",
+        value_parser = ["text", "json"],
+        help_heading = "Debug"
+    )]
+    pub log_format: Option<String>,
+
     /// Set minimum refresh interval in seconds (default: auto-detect based on game count).
     /// Higher values reduce API calls but may miss updates. Use with caution.
     #[arg(long = "min-refresh-interval", help_heading = "Display Options")]
     pub min_refresh_interval: Option<u64>,
 }
+
+impl Args {
+    /// Settings given as command line flags, as config keys. Flags that were
+    /// not given are left out so config files and profiles still apply.
+    pub fn config_overrides(&self) -> Table {
+        let mut overrides = Table::new();
+        if self.compact {
+            overrides.insert("display_mode".to_string(), Value::String("compact".into()));
+        } else if self.wide {
+            overrides.insert("display_mode".to_string(), Value::String("wide".into()));
+        } else if self.teletext {
+            overrides.insert("display_mode".to_string(), Value::String("teletext".into()));
+        }
+        if let Some(seconds) = self
+            .min_refresh_interval
+            .and_then(|s| i64::try_from(s).ok())
+        {
+            overrides.insert("min_refresh_interval".to_string(), Value::Integer(seconds));
+        }
+        if let Some(lang) = &self.lang {
+            overrides.insert("language".to_string(), Value::String(lang.clone()));
+        }
+        if let Some(dir) = &self.export_dir {
+            overrides.insert("export_dir".to_string(), Value::String(dir.clone()));
+        }
+        if let Some(player) = &self.video_player {
+            overrides.insert("video_player".to_string(), Value::String(player.clone()));
+        }
+        if let Some(teams) = &self.favourite_teams {
+            let teams = teams
+                .iter()
+                .map(|team| Value::String(team.trim().to_string()))
+                .collect();
+            overrides.insert("favourite_teams".to_string(), Value::Array(teams));
+        }
+        if let Some(format) = &self.log_format {
+            overrides.insert("log_format".to_string(), Value::String(format.clone()));
+        }
+        if self.disable_links {
+            overrides.insert("plain".to_string(), Value::Boolean(true));
+        }
+        if self.no_session {
+            overrides.insert("restore_session".to_string(), Value::Boolean(false));
+        }
+        overrides
+    }
+
+    /// Takes the display settings from the effective configuration, which
+    /// already includes the command line flags as its last layer.
+    pub fn apply_config(&mut self, config: &Config) {
+        self.compact = config.display_mode == DisplayMode::Compact;
+        self.wide = config.display_mode == DisplayMode::Wide;
+        self.teletext = config.display_mode == DisplayMode::Teletext;
+        self.min_refresh_interval = config.min_refresh_interval;
+        self.disable_links = config.plain;
+        self.no_session = !config.restore_session;
+        self.export_dir = config.export_dir.clone();
+        self.video_player = config.video_player.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_overrides_only_include_given_flags() {
+        let args = Args::parse_from([
+            "liiga_teletext",
+            "-w",
+            "--min-refresh-interval",
+            "30",
+            "--no-session",
+            "--lang",
+            "sv",
+            "--export-dir",
+            "/tmp/pages",
+            "--video-player",
+            "mpv --fs",
+            "--log-format",
+            "json",
+            "--favourite-teams",
+            "Tappara, IFK",
+        ]);
+        let overrides = args.config_overrides();
+        assert_eq!(overrides["display_mode"].as_str(), Some("wide"));
+        assert_eq!(overrides["min_refresh_interval"].as_integer(), Some(30));
+        assert_eq!(overrides["restore_session"].as_bool(), Some(false));
+        assert_eq!(overrides["language"].as_str(), Some("sv"));
+        assert_eq!(overrides["export_dir"].as_str(), Some("/tmp/pages"));
+        assert_eq!(overrides["video_player"].as_str(), Some("mpv --fs"));
+        assert_eq!(overrides["log_format"].as_str(), Some("json"));
+        let teams = overrides["favourite_teams"].as_array().unwrap();
+        assert_eq!(teams[0].as_str(), Some("Tappara"));
+        assert_eq!(teams[1].as_str(), Some("IFK"));
+        assert!(!overrides.contains_key("plain"));
+
+        let none = Args::parse_from(["liiga_teletext"]).config_overrides();
+        assert!(none.is_empty());
+
+        let teletext = Args::parse_from(["liiga_teletext", "--teletext"]).config_overrides();
+        assert_eq!(teletext["display_mode"].as_str(), Some("teletext"));
+        assert!(Args::try_parse_from(["liiga_teletext", "--teletext", "-c"]).is_err());
+    }
+
+    #[test]
+    fn test_apply_config_sets_display_flags() {
+        let mut args = Args::parse_from(["liiga_teletext", "--profile", "office"]);
+        assert_eq!(args.profile.as_deref(), Some("office"));
+        args.apply_config(&Config {
+            display_mode: DisplayMode::Compact,
+            min_refresh_interval: Some(45),
+            plain: true,
+            restore_session: false,
+            export_dir: Some("/tmp/pages".to_string()),
+            video_player: Some("xdg-open".to_string()),
+            ..Default::default()
+        });
+        assert!(args.compact && !args.wide && !args.teletext);
+        assert_eq!(args.min_refresh_interval, Some(45));
+        assert!(args.disable_links);
+        assert!(args.no_session);
+        assert_eq!(args.export_dir.as_deref(), Some("/tmp/pages"));
+        assert_eq!(args.video_player.as_deref(), Some("xdg-open"));
+    }
+}
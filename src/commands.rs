@@ -1,15 +1,23 @@
 use crate::cli::Args;
 use crate::config::Config;
+use crate::config::layers::LayerFiles;
 use crate::config::user_prompts::{prompt_for_api_domain, test_api_with_animation};
+use crate::config::validation::{
+    check_config_source, check_project_config_source, validate_api_domain, validate_log_file_path,
+};
 use crate::data_fetcher::{fetch_liiga_data, is_historical_date};
 use crate::error::AppError;
+use crate::i18n::{t, tf};
 use crate::teletext_ui::TeletextPage;
+use crate::teletext_ui::export::{ExportFormat, export_page_to_file};
 use crate::ui::format_date_for_display;
 use crate::ui::interactive::navigation_manager;
 use crate::version;
 use chrono::{Local, Utc};
 use crossterm::{execute, style::Color, terminal::SetTitle};
 use std::io::stdout;
+use std::path::Path;
+use toml::Value;
 
 /// Validates command line argument combinations.
 ///
@@ -20,6 +28,13 @@ pub fn validate_args(args: &Args) -> Result<(), AppError> {
             "Cannot use both compact (-c) and wide (-w) modes simultaneously",
         ));
     }
+    if let Some(path) = &args.export
+        && ExportFormat::from_path(Path::new(path)).is_none()
+    {
+        return Err(AppError::config_error(format!(
+            "Cannot export to {path}: use a .html, .svg, .txt or .tti file"
+        )));
+    }
     Ok(())
 }
 
@@ -34,7 +49,9 @@ pub async fn handle_version_command() -> Result<(), AppError> {
     version::print_logo();
 
     // Check for updates and show version info
-    if let Some(latest_version) = version::check_latest_version().await {
+    if let Some(latest_version) =
+        version::check_latest_version(Config::load_or_default().await).await
+    {
         let current =
             semver::Version::parse(env!("CARGO_PKG_VERSION")).map_err(AppError::VersionParse)?;
         let latest = semver::Version::parse(&latest_version).map_err(AppError::VersionParse)?;
@@ -72,6 +89,52 @@ pub async fn handle_list_config_command() -> Result<(), AppError> {
     Ok(())
 }
 
+/// Handles the --check-config command.
+///
+/// Checks every configuration file that exists (system, user, project)
+/// and prints each problem as `path:line:column: message`.
+///
+/// # Returns
+/// * `Ok(())` - All files are valid
+/// * `Err(AppError)` - A file has problems or cannot be read
+pub async fn handle_check_config_command() -> Result<(), AppError> {
+    let files = LayerFiles::standard();
+    let paths = files.system.iter().chain([&files.user, &files.project]);
+
+    let mut checked = 0;
+    let mut problem_count = 0;
+    for path in paths {
+        let content = match tokio::fs::read_to_string(path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e.into()),
+        };
+        checked += 1;
+        let problems = if path == &files.project {
+            check_project_config_source(&content)
+        } else {
+            check_config_source(&content)
+        };
+        if problems.is_empty() {
+            println!("{}: OK", path.display());
+        }
+        for problem in &problems {
+            println!("{}:{problem}", path.display());
+        }
+        problem_count += problems.len();
+    }
+
+    if checked == 0 {
+        println!("No configuration files found");
+    }
+    if problem_count > 0 {
+        return Err(AppError::config_error(format!(
+            "{problem_count} problem(s) found in configuration files"
+        )));
+    }
+    Ok(())
+}
+
 /// Handles configuration update commands (--config, --set-log-file, --clear-log-file).
 ///
 /// Updates configuration based on the provided arguments and saves changes.
@@ -81,22 +144,16 @@ pub async fn handle_config_update_command(args: &Args) -> Result<(), AppError> {
     use crate::constants::colors::*;
     use crossterm::style::{Print, ResetColor, SetForegroundColor};
 
-    let mut config = match Config::load().await {
-        Ok(cfg) => cfg,
-        Err(e) => {
-            tracing::warn!("Failed to load config: {e}, using default configuration");
-            Config::default()
-        }
-    };
-
+    let mut new_api_domain = None;
     if let Some(new_domain) = &args.new_api_domain {
+        // The API is tested through the configured proxy, certificates and headers
+        let config = Config::load_or_default().await;
         if new_domain.trim().is_empty() {
             // Prompt interactively (includes API testing with animation)
-            let api_domain = prompt_for_api_domain().await?;
-            config.api_domain = api_domain;
+            new_api_domain = Some(prompt_for_api_domain(&config).await?);
         } else {
             // Test the provided URL before saving (with spinner)
-            match test_api_with_animation(new_domain).await {
+            match test_api_with_animation(new_domain, &config).await {
                 Ok(()) => {
                     let _ = execute!(
                         stdout(),
@@ -105,7 +162,7 @@ pub async fn handle_config_update_command(args: &Args) -> Result<(), AppError> {
                         Print("API connection successful!\n"),
                         ResetColor
                     );
-                    config.api_domain = new_domain.clone();
+                    new_api_domain = Some(new_domain.clone());
                 }
                 Err(e) => {
                     return Err(AppError::config_error(format!(
@@ -116,10 +173,12 @@ pub async fn handle_config_update_command(args: &Args) -> Result<(), AppError> {
         }
     }
 
+    if let Some(api_domain) = &new_api_domain {
+        validate_api_domain(api_domain)?;
+    }
     if let Some(new_log_path) = &args.new_log_file_path {
-        config.log_file_path = Some(new_log_path.clone());
+        validate_log_file_path(new_log_path)?;
     } else if args.clear_log_file_path {
-        config.log_file_path = None;
         let _ = execute!(
             stdout(),
             SetForegroundColor(TELETEXT_YELLOW),
@@ -128,8 +187,22 @@ pub async fn handle_config_update_command(args: &Args) -> Result<(), AppError> {
         );
     }
 
-    config.validate()?;
-    config.save().await?;
+    // Only the user file is edited, so settings from the system and project
+    // files, profiles and the environment are not copied into it
+    Config::update_file(&Config::get_config_path(), |table| {
+        if let Some(api_domain) = new_api_domain {
+            table.insert("api_domain".to_string(), Value::String(api_domain));
+        }
+        if let Some(new_log_path) = &args.new_log_file_path {
+            table.insert(
+                "log_file_path".to_string(),
+                Value::String(new_log_path.clone()),
+            );
+        } else if args.clear_log_file_path {
+            table.remove("log_file_path");
+        }
+    })
+    .await?;
 
     let _ = execute!(
         stdout(),
@@ -148,6 +221,22 @@ pub async fn handle_config_update_command(args: &Args) -> Result<(), AppError> {
     Ok(())
 }
 
+/// Prints a --once page to the terminal, or writes it to the `--export` file.
+async fn show_once_page(page: &mut TeletextPage, args: &Args) -> Result<(), AppError> {
+    if let Some(path) = &args.export {
+        export_page_to_file(page, Path::new(path)).await?;
+        println!("Page {} exported to {path}", page.page_number());
+        return Ok(());
+    }
+
+    // Set terminal title for non-interactive mode
+    execute!(stdout(), SetTitle("SM-LIIGA 221"))?;
+
+    page.render_buffered(&mut stdout())?;
+    println!(); // Add a newline at the end
+    Ok(())
+}
+
 /// Handles the --once command (quick view mode).
 ///
 /// Fetches and displays game data once, then exits.
@@ -158,13 +247,14 @@ pub async fn handle_once_command(
     version_check: tokio::task::JoinHandle<Option<String>>,
 ) -> Result<(), AppError> {
     // In --once mode, don't show loading messages (only show in interactive mode)
+    crate::teletext_ui::teletext_grid::set_grid_mode(args.teletext);
 
     let (games, fetched_date) = match fetch_liiga_data(args.date.clone()).await {
         Ok((games, fetched_date)) => (games, fetched_date),
         Err(e) => {
             let mut error_page = TeletextPage::new(
                 221,
-                "JÄÄKIEKKO".to_string(),
+                t("header.ice_hockey").to_string(),
                 "SM-LIIGA".to_string(),
                 args.disable_links,
                 true,
@@ -172,20 +262,15 @@ pub async fn handle_once_command(
                 args.compact,
                 args.wide,
             );
-            error_page.add_error_message(&format!("Virhe haettaessa otteluita: {e}"));
-            // Set terminal title for non-interactive mode (error case)
-            execute!(stdout(), SetTitle("SM-LIIGA 221"))?;
-
-            error_page.render_buffered(&mut stdout())?;
-            println!();
-            return Ok(());
+            error_page.add_error_message(&tf("games.fetch_failed", &[("error", &e)]));
+            return show_once_page(&mut error_page, args).await;
         }
     };
 
-    let page = if games.is_empty() {
+    let mut page = if games.is_empty() {
         let mut no_games_page = TeletextPage::new(
             221,
-            "JÄÄKIEKKO".to_string(),
+            t("header.ice_hockey").to_string(),
             "SM-LIIGA".to_string(),
             args.disable_links,
             false, // Don't show footer in quick view mode
@@ -199,11 +284,11 @@ pub async fn handle_once_command(
             .format("%Y-%m-%d")
             .to_string();
         if fetched_date == today {
-            no_games_page.add_error_message("Ei otteluita tänään");
+            no_games_page.add_error_message(t("games.no_games_today"));
         } else {
-            no_games_page.add_error_message(&format!(
-                "Ei otteluita {} päivälle",
-                format_date_for_display(&fetched_date)
+            no_games_page.add_error_message(&tf(
+                "games.no_games_on_date",
+                &[("date", &format_date_for_display(&fetched_date))],
             ));
         }
         no_games_page
@@ -252,11 +337,7 @@ pub async fn handle_once_command(
         }
     };
 
-    // Set terminal title for non-interactive mode
-    execute!(stdout(), SetTitle("SM-LIIGA 221"))?;
-
-    page.render_buffered(&mut stdout())?;
-    println!(); // Add a newline at the end
+    show_once_page(&mut page, args).await?;
 
     // Show version info after display if update is available
     if let Ok(Some(latest_version)) = version_check.await {
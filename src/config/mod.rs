@@ -1,16 +1,24 @@
 use crate::error::AppError;
+use crate::i18n::Language;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::Path;
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
+use toml::{Table, Value};
 
+pub mod layers;
 pub mod paths;
 pub mod user_prompts;
 pub mod validation;
 
+use layers::{ConfigLayer, LayerFiles, MergedLayers, merge_layers};
 use paths::{get_config_path, get_log_dir_path};
 use user_prompts::prompt_for_api_domain;
-use validation::validate_config;
+use validation::{
+    validate_api_domain, validate_config, validate_log_levels, validate_log_retention,
+    validate_network_settings, validate_request_limits,
+};
 
 /// Configuration structure for the application.
 /// Handles loading, saving, and managing application settings.
@@ -18,12 +26,121 @@ use validation::validate_config;
 pub struct Config {
     /// API domain for fetching game data. Should include https:// prefix.
     pub api_domain: String,
+    /// Further API base URLs, in the order they are tried when the ones
+    /// before them are failing.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub api_fallback_domains: Vec<String>,
     /// Path to the log file. If not specified, logs will be written to a default location.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub log_file_path: Option<String>,
+    /// Format of the log file: `text` or `json` (one object per line).
+    #[serde(default, skip_serializing_if = "LogFormat::is_text")]
+    pub log_format: LogFormat,
+    /// Rotated log files older than this many days are deleted on start.
+    /// Defaults to 30 days.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_max_age_days: Option<u64>,
+    /// The oldest rotated log files are deleted on start until all of them
+    /// together take at most this many megabytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_max_size_mb: Option<u64>,
+    /// Log level per module, e.g. `"liiga_teletext::data_fetcher" = "debug"`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub log_levels: BTreeMap<String, String>,
     /// HTTP timeout in seconds for API requests. Defaults to 30 seconds if not specified.
     #[serde(default = "default_http_timeout")]
     pub http_timeout_seconds: u64,
+    /// Maximum number of requests in flight to one API host at a time.
+    #[serde(default = "default_max_concurrent_requests")]
+    pub max_concurrent_requests: usize,
+    /// Maximum number of API requests started per second, across all hosts.
+    #[serde(default = "default_max_requests_per_second")]
+    pub max_requests_per_second: u32,
+    /// HTTP(S) proxy URL for all requests, e.g. `http://proxy.example.com:8080`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_proxy: Option<String>,
+    /// PEM file with extra root certificates to trust, e.g. a corporate CA.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_cert_path: Option<String>,
+    /// User-Agent header sent with requests. Defaults to `liiga_teletext/<version>`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+    /// Extra headers sent with every request.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra_headers: BTreeMap<String, String>,
+    /// Game list layout: `normal`, `compact` (`-c`), `wide` (`-w`) or
+    /// `teletext` (`--teletext`).
+    #[serde(default, skip_serializing_if = "DisplayMode::is_normal")]
+    pub display_mode: DisplayMode,
+    /// Minimum auto-refresh interval in seconds (`--min-refresh-interval`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_refresh_interval: Option<u64>,
+    /// Language of the UI: `fi`, `en` or `sv` (`--lang`). When unset the
+    /// locale in `LANG` decides, and Finnish is used for other locales.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+    /// Disable clickable video links (`--plain`).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub plain: bool,
+    /// Teams whose games are listed first, by name (`Tappara`) or
+    /// abbreviation (`TAP`) (`--favourite-teams`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub favourite_teams: Vec<String>,
+    /// Restore the last view, display mode and page on start and save them
+    /// on exit. Disable with `restore_session = false` or `--no-session`.
+    #[serde(
+        default = "default_restore_session",
+        skip_serializing_if = "is_restore_session_default"
+    )]
+    pub restore_session: bool,
+    /// Directory the interactive export key writes page snapshots into.
+    /// Defaults to the current directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub export_dir: Option<String>,
+    /// Command the goal video picker opens clips with (e.g. `mpv` or
+    /// `xdg-open`). The clip URL replaces `{url}` or is appended. Without
+    /// it the picker only prints the URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub video_player: Option<String>,
+    /// Named sets of settings applied over the files with `--profile <name>`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, Table>,
+}
+
+/// Layout of the game list.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DisplayMode {
+    #[default]
+    Normal,
+    /// Team identifiers and scores only
+    Compact,
+    /// Two columns side by side on wide terminals
+    Wide,
+    /// The strict 40x24 grid of a real teletext page
+    Teletext,
+}
+
+impl DisplayMode {
+    fn is_normal(&self) -> bool {
+        *self == DisplayMode::Normal
+    }
+}
+
+/// Format of the log file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    #[default]
+    Text,
+    /// One JSON object per event, for `jq` and log collectors
+    Json,
+}
+
+impl LogFormat {
+    fn is_text(&self) -> bool {
+        *self == LogFormat::Text
+    }
 }
 
 /// Default HTTP timeout in seconds
@@ -31,82 +148,184 @@ fn default_http_timeout() -> u64 {
     crate::constants::DEFAULT_HTTP_TIMEOUT_SECONDS
 }
 
+/// Default limit of concurrent requests per host
+fn default_max_concurrent_requests() -> usize {
+    crate::constants::DEFAULT_MAX_CONCURRENT_REQUESTS_PER_HOST
+}
+
+/// Default request rate limit
+fn default_max_requests_per_second() -> u32 {
+    crate::constants::DEFAULT_MAX_REQUESTS_PER_SECOND
+}
+
+/// Sessions are restored unless disabled
+fn default_restore_session() -> bool {
+    true
+}
+
+fn is_restore_session_default(restore_session: &bool) -> bool {
+    *restore_session
+}
+
 impl Default for Config {
     fn default() -> Self {
         Config {
             api_domain: String::new(),
+            api_fallback_domains: Vec::new(),
             log_file_path: None,
+            log_format: LogFormat::Text,
+            log_max_age_days: None,
+            log_max_size_mb: None,
+            log_levels: BTreeMap::new(),
             http_timeout_seconds: default_http_timeout(),
+            max_concurrent_requests: default_max_concurrent_requests(),
+            max_requests_per_second: default_max_requests_per_second(),
+            http_proxy: None,
+            ca_cert_path: None,
+            user_agent: None,
+            extra_headers: BTreeMap::new(),
+            display_mode: DisplayMode::Normal,
+            min_refresh_interval: None,
+            language: None,
+            plain: false,
+            favourite_teams: Vec::new(),
+            restore_session: default_restore_session(),
+            export_dir: None,
+            video_player: None,
+            profiles: BTreeMap::new(),
         }
     }
 }
 
 impl Config {
-    /// Loads configuration from the default config file location.
-    /// If no config file exists, prompts user for API domain and creates one.
-    /// Environment variables can override config file values.
+    /// Loads the effective configuration from all layers: system file, user
+    /// file, `./liiga_teletext.toml`, the selected profile, environment
+    /// variables and command line flags (see [`layers`]).
+    /// If no layer sets the API domain, prompts for it and saves it to the
+    /// user file.
     ///
     /// # Environment Variables
+    /// - `LIIGA_PROFILE` - Profile to apply when `--profile` is not given
     /// - `LIIGA_API_DOMAIN` - Override API domain
+    /// - `LIIGA_API_FALLBACK_DOMAINS` - Override fallback API domains (comma-separated)
     /// - `LIIGA_LOG_FILE` - Override log file path
     /// - `LIIGA_HTTP_TIMEOUT` - Override HTTP timeout in seconds (default: 30)
+    /// - `LIIGA_MAX_CONCURRENT_REQUESTS` - Override concurrent requests per host (default: 4)
+    /// - `LIIGA_MAX_REQUESTS_PER_SECOND` - Override request rate limit (default: 10)
+    /// - `LIIGA_HTTP_PROXY` - Override proxy URL
+    /// - `LIIGA_CA_CERT` - Override extra CA certificate file
+    /// - `LIIGA_USER_AGENT` - Override User-Agent header
+    /// - `LIIGA_EXTRA_HEADERS` - Extra headers as `Name: value; Name2: value`,
+    ///   added to (and overriding) the configured ones
+    /// - `LIIGA_DISPLAY_MODE` - Override display mode (`normal`, `compact`, `wide`, `teletext`)
+    /// - `LIIGA_MIN_REFRESH_INTERVAL` - Override minimum refresh interval in seconds
     ///
     /// # Returns
     /// * `Ok(Config)` - Successfully loaded or created configuration
     /// * `Err(AppError)` - Error occurred during load/create
-    ///
-    /// # Notes
-    /// - Config file is stored in platform-specific config directory
-    /// - Handles first-time setup with user prompts
-    /// - Environment variables take precedence over config file
     pub async fn load() -> Result<Self, AppError> {
-        let config_path = get_config_path();
+        Ok(Self::load_layered().await?.0)
+    }
 
-        let mut config = if Path::new(&config_path).exists() {
-            let content = fs::read_to_string(&config_path).await?;
-            toml::from_str(&content)?
-        } else {
-            // Check if API domain is provided via environment variable
-            if let Ok(api_domain) = std::env::var("LIIGA_API_DOMAIN") {
-                Config {
-                    api_domain,
-                    log_file_path: None,
-                    http_timeout_seconds: default_http_timeout(),
-                }
-            } else {
-                let api_domain = prompt_for_api_domain().await?;
+    /// Same as [`Config::load`], also returning the merged layers with the
+    /// source of each setting.
+    pub async fn load_layered() -> Result<(Self, MergedLayers), AppError> {
+        let files = LayerFiles::standard();
+        let selection = layers::selection();
+        let mut merged = merge_layers(&files, &selection).await?;
+
+        if !merged.table.contains_key("api_domain") {
+            // The connection test uses the network settings already layered
+            let api_domain = prompt_for_api_domain(&Self::load_or_default().await).await?;
+            Self::update_file(&get_config_path(), |table| {
+                table.insert("api_domain".to_string(), Value::String(api_domain));
+            })
+            .await?;
+            merged = merge_layers(&files, &selection).await?;
+        }
 
-                let config = Config {
-                    api_domain,
-                    log_file_path: None,
-                    http_timeout_seconds: default_http_timeout(),
-                };
+        let config = Self::from_layers(&merged).map_err(|e| {
+            AppError::config_error(format!(
+                "{e}\nRun `liiga_teletext --check-config` to locate the problem"
+            ))
+        })?;
+        config.validate()?;
+        Ok((config, merged))
+    }
+
+    /// Same as [`Config::load`], but never prompts: returns `None` while no
+    /// layer sets `api_domain` yet. For background lookups that must not
+    /// interrupt the screen.
+    pub async fn load_if_configured() -> Result<Option<Self>, AppError> {
+        let merged = merge_layers(&LayerFiles::standard(), &layers::selection()).await?;
+        if !merged.table.contains_key("api_domain") {
+            return Ok(None);
+        }
+        let config = Self::from_layers(&merged)?;
+        config.validate()?;
+        Ok(Some(config))
+    }
 
-                config.save().await?;
-                config
+    /// The configuration described by the merged layers.
+    fn from_layers(merged: &MergedLayers) -> Result<Self, AppError> {
+        Ok(Value::Table(merged.table.clone()).try_into()?)
+    }
+
+    /// The primary API domain followed by the fallbacks, without duplicates.
+    pub fn api_domains(&self) -> Vec<&str> {
+        let mut domains = vec![self.api_domain.as_str()];
+        for domain in &self.api_fallback_domains {
+            if !domains.contains(&domain.as_str()) {
+                domains.push(domain);
             }
-        };
+        }
+        domains
+    }
 
-        // Override with environment variables if present
-        if let Ok(api_domain) = std::env::var("LIIGA_API_DOMAIN") {
-            config.api_domain = api_domain;
+    /// Loads the configuration layers without prompting or validating. Used
+    /// where a missing or broken config must not block, e.g. the update
+    /// check; falls back to the defaults.
+    pub async fn load_or_default() -> Self {
+        match merge_layers(&LayerFiles::standard(), &layers::selection()).await {
+            Ok(mut merged) => {
+                merged
+                    .table
+                    .entry("api_domain")
+                    .or_insert_with(|| Value::String(String::new()));
+                Self::from_layers(&merged).unwrap_or_default()
+            }
+            Err(_) => Config::default(),
         }
+    }
 
-        if let Ok(log_file_path) = std::env::var("LIIGA_LOG_FILE") {
-            config.log_file_path = Some(log_file_path);
+    /// Edits a config file as TOML, keeping every key and profile the edit
+    /// does not touch. Used for the user file so that values from other
+    /// layers are never copied into it.
+    ///
+    /// # Arguments
+    /// * `path` - Config file to edit; created if missing
+    /// * `edit` - Changes to apply to the file's table
+    pub async fn update_file(path: &str, edit: impl FnOnce(&mut Table)) -> Result<(), AppError> {
+        let mut table: Table = match fs::read_to_string(path).await {
+            Ok(content) => toml::from_str(&content)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Table::new(),
+            Err(e) => return Err(e.into()),
+        };
+        edit(&mut table);
+        if let Some(Value::String(api_domain)) = table.get_mut("api_domain") {
+            *api_domain = with_https_prefix(api_domain);
         }
 
-        if let Some(timeout) = std::env::var("LIIGA_HTTP_TIMEOUT")
-            .ok()
-            .and_then(|s| s.parse::<u64>().ok())
+        if let Some(config_dir) = Path::new(path).parent()
+            && !config_dir.exists()
         {
-            config.http_timeout_seconds = timeout;
+            fs::create_dir_all(config_dir).await?;
         }
-
-        // Validate configuration
-        config.validate()?;
-
-        Ok(config)
+        let mut file = fs::File::create(path).await?;
+        file.write_all(toml::to_string_pretty(&table)?.as_bytes())
+            .await?;
+        file.flush().await?;
+        Ok(())
     }
 
     /// Validates the configuration settings
@@ -115,7 +334,19 @@ impl Config {
     /// * `Ok(())` - Configuration is valid
     /// * `Err(AppError)` - Configuration validation failed
     pub fn validate(&self) -> Result<(), AppError> {
-        validate_config(&self.api_domain, &self.log_file_path)
+        validate_config(&self.api_domain, &self.log_file_path)?;
+        for domain in &self.api_fallback_domains {
+            validate_api_domain(domain)?;
+        }
+        validate_request_limits(self.max_concurrent_requests, self.max_requests_per_second)?;
+        validate_log_retention(self.log_max_age_days, self.log_max_size_mb)?;
+        validate_log_levels(&self.log_levels)?;
+        validate_network_settings(
+            self.http_proxy.as_deref(),
+            self.ca_cert_path.as_deref(),
+            self.user_agent.as_deref(),
+            &self.extra_headers,
+        )
     }
 
     /// Saves current configuration to the default config file location.
@@ -128,6 +359,9 @@ impl Config {
     /// - Creates config directory if it doesn't exist
     /// - Ensures api_domain has https:// prefix
     /// - Uses TOML format for storage
+    /// - Writes every setting; the app itself edits the user file with
+    ///   [`Config::update_file`] so other layers' values are not copied into it
+    #[allow(dead_code)] // Library API
     pub async fn save(&self) -> Result<(), AppError> {
         let config_path = get_config_path();
         self.save_to_path(&config_path).await
@@ -165,8 +399,9 @@ impl Config {
     /// * `Err(AppError)` - Error occurred while reading config
     ///
     /// # Notes
-    /// - Shows config file location and current settings
-    /// - Handles case when no config file exists
+    /// - Shows the config files that were read and the selected profile
+    /// - Shows the layer each setting came from (file, profile, env, command line)
+    /// - Handles case when no layer sets the API domain
     pub async fn display() -> Result<(), AppError> {
         use crate::constants::colors::*;
         use crossterm::{
@@ -181,18 +416,11 @@ impl Config {
         // Print header box
         Self::print_header()?;
 
-        if Path::new(&config_path).exists() {
-            let config = Config::load().await?;
-            Self::print_config_details(&config, &config_path, &log_dir)?;
-        } else if std::env::var("LIIGA_API_DOMAIN").is_ok() {
-            let config = Config::load().await?;
-            let _ = execute!(
-                stdout(),
-                SetForegroundColor(TELETEXT_YELLOW),
-                Print("  ⚠ No config file - using environment variables\n\n"),
-                ResetColor
-            );
-            Self::print_config_details(&config, &config_path, &log_dir)?;
+        let merged = merge_layers(&LayerFiles::standard(), &layers::selection()).await?;
+        if merged.table.contains_key("api_domain") {
+            let config = Self::from_layers(&merged)?;
+            config.validate()?;
+            Self::print_config_details(&config, &merged, &log_dir)?;
         } else {
             let _ = execute!(
                 stdout(),
@@ -222,12 +450,8 @@ impl Config {
         Ok(())
     }
 
-    /// Prints detailed configuration information
-    fn print_config_details(
-        config: &Config,
-        config_path: &str,
-        log_dir: &str,
-    ) -> Result<(), AppError> {
+    /// Prints one setting with the layer it came from
+    fn print_setting(title: &str, source: &ConfigLayer, value: &str) {
         use crate::constants::colors::*;
         use crossterm::{
             execute,
@@ -235,81 +459,187 @@ impl Config {
         };
         use std::io::stdout;
 
-        // Config Location
         let _ = execute!(
             stdout(),
             SetForegroundColor(TELETEXT_CYAN),
-            Print("  Config Location\n"),
+            Print(format!("  {title}")),
+            SetForegroundColor(TELETEXT_YELLOW),
+            Print(format!(" ({source})\n")),
             SetForegroundColor(TELETEXT_WHITE),
-            Print(format!("  {config_path}\n\n")),
+            Print(format!("  {value}\n\n")),
             ResetColor
         );
+    }
 
-        // API Domain
-        let api_domain_source = if std::env::var("LIIGA_API_DOMAIN").is_ok() {
-            " (from env)"
-        } else {
-            ""
+    /// Prints detailed configuration information
+    fn print_config_details(
+        config: &Config,
+        merged: &MergedLayers,
+        log_dir: &str,
+    ) -> Result<(), AppError> {
+        use crate::constants::colors::*;
+        use crossterm::{
+            execute,
+            style::{Print, ResetColor, SetForegroundColor},
         };
-        let _ = execute!(
-            stdout(),
-            SetForegroundColor(TELETEXT_CYAN),
-            Print(format!("  API Domain{api_domain_source}\n")),
-            SetForegroundColor(TELETEXT_GREEN),
-            Print(format!("  {}\n\n", config.api_domain)),
-            ResetColor
-        );
+        use std::io::stdout;
 
-        // HTTP Timeout
-        let timeout_source = if std::env::var("LIIGA_HTTP_TIMEOUT").is_ok() {
-            " (from env)"
-        } else {
-            ""
-        };
+        // Config files in layer order, and the profile applied over them
+        let mut files: Vec<String> = merged.files.iter().map(ToString::to_string).collect();
+        if files.is_empty() {
+            files.push("none (environment and command line only)".to_string());
+        }
+        if let Some(profile) = &merged.profile {
+            files.push(format!("profile '{profile}'"));
+        }
         let _ = execute!(
             stdout(),
             SetForegroundColor(TELETEXT_CYAN),
-            Print(format!("  HTTP Timeout{timeout_source}\n")),
+            Print("  Config Layers\n"),
             SetForegroundColor(TELETEXT_WHITE),
-            Print(format!("  {} seconds\n\n", config.http_timeout_seconds)),
+            Print(format!("  {}\n\n", files.join("\n  "))),
             ResetColor
         );
 
-        // Log File Location
-        let _ = execute!(
-            stdout(),
-            SetForegroundColor(TELETEXT_CYAN),
-            Print("  Log File Location\n"),
-            ResetColor
+        let source = |key: &str| merged.source(key);
+        Self::print_setting("API Domain", source("api_domain"), &config.api_domain);
+        if !config.api_fallback_domains.is_empty() {
+            Self::print_setting(
+                "Fallback API Domains",
+                source("api_fallback_domains"),
+                &config.api_fallback_domains.join("\n  "),
+            );
+        }
+        Self::print_setting(
+            "HTTP Timeout",
+            source("http_timeout_seconds"),
+            &format!("{} seconds", config.http_timeout_seconds),
+        );
+        Self::print_setting(
+            "Concurrent Requests per Host",
+            source("max_concurrent_requests"),
+            &config.max_concurrent_requests.to_string(),
+        );
+        Self::print_setting(
+            "Requests per Second",
+            source("max_requests_per_second"),
+            &config.max_requests_per_second.to_string(),
         );
 
-        if let Ok(env_log_path) = std::env::var("LIIGA_LOG_FILE") {
-            let _ = execute!(
-                stdout(),
-                SetForegroundColor(TELETEXT_WHITE),
-                Print(format!("  {env_log_path}\n")),
-                SetForegroundColor(TELETEXT_YELLOW),
-                Print("  (from env)\n"),
-                ResetColor
+        // Display
+        let display_mode = match config.display_mode {
+            DisplayMode::Normal => "normal",
+            DisplayMode::Compact => "compact",
+            DisplayMode::Wide => "wide",
+            DisplayMode::Teletext => "teletext",
+        };
+        Self::print_setting("Display Mode", source("display_mode"), display_mode);
+        let min_refresh = config
+            .min_refresh_interval
+            .map_or_else(|| "auto".to_string(), |secs| format!("{secs} seconds"));
+        Self::print_setting(
+            "Min Refresh Interval",
+            source("min_refresh_interval"),
+            &min_refresh,
+        );
+        let language = config.language.map_or_else(
+            || format!("{} (from locale)", Language::from_env().unwrap_or_default()),
+            |language| language.to_string(),
+        );
+        Self::print_setting("Language", source("language"), &language);
+        Self::print_setting(
+            "Video Links",
+            source("plain"),
+            if config.plain { "disabled" } else { "enabled" },
+        );
+        if !config.favourite_teams.is_empty() {
+            Self::print_setting(
+                "Favourite Teams",
+                source("favourite_teams"),
+                &config.favourite_teams.join(", "),
             );
-        } else if let Some(custom_path) = &config.log_file_path {
-            let _ = execute!(
-                stdout(),
-                SetForegroundColor(TELETEXT_WHITE),
-                Print(format!("  {custom_path}\n")),
-                ResetColor
+        }
+        Self::print_setting(
+            "Restore Session",
+            source("restore_session"),
+            if config.restore_session {
+                "enabled"
+            } else {
+                "disabled"
+            },
+        );
+        Self::print_setting(
+            "Export Directory",
+            source("export_dir"),
+            config.export_dir.as_deref().unwrap_or("current directory"),
+        );
+        Self::print_setting(
+            "Video Player",
+            source("video_player"),
+            config.video_player.as_deref().unwrap_or("print URL"),
+        );
+
+        // Network (only shown when customized)
+        if let Some(proxy) = &config.http_proxy {
+            Self::print_setting("Proxy", source("http_proxy"), proxy);
+        }
+        if let Some(ca_cert_path) = &config.ca_cert_path {
+            Self::print_setting(
+                "Extra CA Certificates",
+                source("ca_cert_path"),
+                ca_cert_path,
             );
-        } else {
-            let _ = execute!(
-                stdout(),
-                SetForegroundColor(TELETEXT_WHITE),
-                Print(format!("  {log_dir}/liiga_teletext.log\n")),
-                SetForegroundColor(TELETEXT_YELLOW),
-                Print("  (default)\n"),
-                ResetColor
+        }
+        if let Some(user_agent) = &config.user_agent {
+            Self::print_setting("User-Agent", source("user_agent"), user_agent);
+        }
+        if !config.extra_headers.is_empty() {
+            let names: Vec<&str> = config.extra_headers.keys().map(String::as_str).collect();
+            Self::print_setting(
+                "Extra Headers",
+                source("extra_headers"),
+                &names.join("\n  "),
             );
         }
 
+        // Log File Location
+        let log_file = config
+            .log_file_path
+            .clone()
+            .unwrap_or_else(|| format!("{log_dir}/liiga_teletext.log"));
+        Self::print_setting("Log File Location", source("log_file_path"), &log_file);
+        Self::print_setting(
+            "Log Format",
+            source("log_format"),
+            match config.log_format {
+                LogFormat::Text => "text",
+                LogFormat::Json => "json",
+            },
+        );
+        let mut retention = format!(
+            "{} days",
+            config
+                .log_max_age_days
+                .unwrap_or(crate::constants::DEFAULT_LOG_MAX_AGE_DAYS)
+        );
+        if let Some(size) = config.log_max_size_mb {
+            retention.push_str(&format!(", at most {size} MB"));
+        }
+        let retention_source = if config.log_max_size_mb.is_some() {
+            source("log_max_size_mb")
+        } else {
+            source("log_max_age_days")
+        };
+        Self::print_setting("Log Retention", retention_source, &retention);
+        if !config.log_levels.is_empty() {
+            let levels: Vec<String> = config
+                .log_levels
+                .iter()
+                .map(|(module, level)| format!("{module}={level}"))
+                .collect();
+            Self::print_setting("Log Levels", source("log_levels"), &levels.join("\n  "));
+        }
+
         Ok(())
     }
 
@@ -338,15 +668,14 @@ impl Config {
         if !config_dir.exists() {
             fs::create_dir_all(config_dir).await?;
         }
-        let api_domain = if !self.api_domain.starts_with("https://") {
-            format!("https://{}", self.api_domain.trim_start_matches("http://"))
-        } else {
-            self.api_domain.clone()
-        };
         let content = toml::to_string_pretty(&Config {
-            api_domain,
-            log_file_path: self.log_file_path.clone(),
-            http_timeout_seconds: self.http_timeout_seconds,
+            api_domain: with_https_prefix(&self.api_domain),
+            api_fallback_domains: self
+                .api_fallback_domains
+                .iter()
+                .map(|domain| with_https_prefix(domain))
+                .collect(),
+            ..self.clone()
         })?;
         let mut file = fs::File::create(path).await?;
         file.write_all(content.as_bytes()).await?;
@@ -363,6 +692,30 @@ impl Config {
     }
 }
 
+/// `domain` with an `https://` prefix, replacing `http://`.
+fn with_https_prefix(domain: &str) -> String {
+    if domain.starts_with("https://") {
+        domain.to_string()
+    } else {
+        format!("https://{}", domain.trim_start_matches("http://"))
+    }
+}
+
+/// Parses `Name: value; Name2: value` into header name/value pairs.
+/// Empty entries are ignored.
+pub fn parse_header_list(list: &str) -> Result<BTreeMap<String, String>, AppError> {
+    list.split(';')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (name, value) = entry.split_once(':').ok_or_else(|| {
+                AppError::config_error(format!("Invalid header '{entry}', expected 'Name: value'"))
+            })?;
+            Ok((name.trim().to_string(), value.trim().to_string()))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -409,6 +762,14 @@ api_domain = "https://api.example.com"
 
         assert_eq!(config.api_domain, "https://api.example.com");
         assert_eq!(config.log_file_path, None);
+        assert_eq!(
+            config.max_concurrent_requests,
+            crate::constants::DEFAULT_MAX_CONCURRENT_REQUESTS_PER_HOST
+        );
+        assert_eq!(
+            config.max_requests_per_second,
+            crate::constants::DEFAULT_MAX_REQUESTS_PER_SECOND
+        );
     }
 
     #[tokio::test]
@@ -420,6 +781,7 @@ api_domain = "https://api.example.com"
             api_domain: "https://api.example.com".to_string(),
             log_file_path: Some("/custom/log/path".to_string()),
             http_timeout_seconds: default_http_timeout(),
+            ..Default::default()
         };
         config.save_to_path(&config_path_str).await.unwrap();
         assert!(config_path.exists());
@@ -451,6 +813,7 @@ api_domain = "https://api.example.com"
             api_domain: "api.example.com".to_string(),
             log_file_path: None,
             http_timeout_seconds: default_http_timeout(),
+            ..Default::default()
         };
         config.save_to_path(&config_path_str).await.unwrap();
         let content = tokio::fs::read_to_string(&config_path).await.unwrap();
@@ -473,6 +836,7 @@ api_domain = "https://api.example.com"
             api_domain: "http://api.example.com".to_string(),
             log_file_path: None,
             http_timeout_seconds: default_http_timeout(),
+            ..Default::default()
         };
         config.save_to_path(&config_path_str).await.unwrap();
         let content = tokio::fs::read_to_string(&config_path).await.unwrap();
@@ -496,6 +860,7 @@ api_domain = "https://api.example.com"
             api_domain: "https://api.example.com".to_string(),
             log_file_path: None,
             http_timeout_seconds: default_http_timeout(),
+            ..Default::default()
         };
         config.save_to_path(&config_path_str).await.unwrap();
         assert!(config_dir.exists());
@@ -511,6 +876,7 @@ api_domain = "https://api.example.com"
             api_domain: "https://api.example.com".to_string(),
             log_file_path: Some("/custom/log/path".to_string()),
             http_timeout_seconds: default_http_timeout(),
+            ..Default::default()
         };
         original_config
             .save_to_path(&config_path_str)
@@ -554,6 +920,7 @@ api_domain = "https://api.example.com"
             api_domain: "https://api.example.com".to_string(),
             log_file_path: Some("/custom/log/path".to_string()),
             http_timeout_seconds: default_http_timeout(),
+            ..Default::default()
         };
         test_config
             .save_to_path(&temp_config_path_str)
@@ -599,6 +966,7 @@ invalid_field = [1, 2, 3, "unclosed_string
             api_domain: "https://api.example.com".to_string(),
             log_file_path: Some("/custom/log/path".to_string()),
             http_timeout_seconds: default_http_timeout(),
+            ..Default::default()
         };
 
         // Test serialization
@@ -618,6 +986,7 @@ invalid_field = [1, 2, 3, "unclosed_string
             api_domain: "https://api.example.com".to_string(),
             log_file_path: None,
             http_timeout_seconds: default_http_timeout(),
+            ..Default::default()
         };
 
         // Test serialization
@@ -737,6 +1106,7 @@ another_extra = 123
                 api_domain: input.to_string(),
                 log_file_path: None,
                 http_timeout_seconds: default_http_timeout(),
+                ..Default::default()
             };
 
             // Save the config
@@ -816,6 +1186,7 @@ another_extra = 123
             api_domain: "https://api.example.com".to_string(),
             log_file_path: None,
             http_timeout_seconds: default_http_timeout(),
+            ..Default::default()
         };
 
         // This should create all the nested directories
@@ -843,6 +1214,7 @@ another_extra = 123
             api_domain: "https://api.example.com/path?param=value&other=123#fragment".to_string(),
             log_file_path: Some("/path/with spaces/and-dashes_underscores.log".to_string()),
             http_timeout_seconds: default_http_timeout(),
+            ..Default::default()
         };
 
         let temp_dir = tempdir().unwrap();
@@ -882,12 +1254,14 @@ another_extra = 123
             api_domain: "https://api.example.com".to_string(),
             log_file_path: None,
             http_timeout_seconds: default_http_timeout(),
+            ..Default::default()
         };
 
         let config_with_some = Config {
             api_domain: "https://api.example.com".to_string(),
             log_file_path: Some("/custom/path.log".to_string()),
             http_timeout_seconds: default_http_timeout(),
+            ..Default::default()
         };
 
         // Test serialization behavior
@@ -907,21 +1281,25 @@ another_extra = 123
                 api_domain: "https://api.example.com".to_string(),
                 log_file_path: None,
                 http_timeout_seconds: default_http_timeout(),
+                ..Default::default()
             },
             Config {
                 api_domain: "http://localhost:8080".to_string(),
                 log_file_path: Some("/tmp/test.log".to_string()),
                 http_timeout_seconds: default_http_timeout(),
+                ..Default::default()
             },
             Config {
                 api_domain: "api.example.com".to_string(),
                 log_file_path: None,
                 http_timeout_seconds: default_http_timeout(),
+                ..Default::default()
             },
             Config {
                 api_domain: "localhost".to_string(),
                 log_file_path: None,
                 http_timeout_seconds: default_http_timeout(),
+                ..Default::default()
             },
         ];
 
@@ -942,18 +1320,63 @@ another_extra = 123
                 api_domain: "".to_string(),
                 log_file_path: None,
                 http_timeout_seconds: default_http_timeout(),
+                ..Default::default()
             },
             // Invalid domain format
             Config {
                 api_domain: "invalid_domain".to_string(),
                 log_file_path: None,
                 http_timeout_seconds: default_http_timeout(),
+                ..Default::default()
             },
             // Empty log file path
             Config {
                 api_domain: "https://api.example.com".to_string(),
                 log_file_path: Some("".to_string()),
                 http_timeout_seconds: default_http_timeout(),
+                ..Default::default()
+            },
+            // Zero concurrent requests would block every fetch
+            Config {
+                api_domain: "https://api.example.com".to_string(),
+                max_concurrent_requests: 0,
+                ..Default::default()
+            },
+            // Zero requests per second would block every fetch
+            Config {
+                api_domain: "https://api.example.com".to_string(),
+                max_requests_per_second: 0,
+                ..Default::default()
+            },
+            // Invalid fallback domain
+            Config {
+                api_domain: "https://api.example.com".to_string(),
+                api_fallback_domains: vec!["not_a_domain".to_string()],
+                ..Default::default()
+            },
+            // Proxy without a supported scheme
+            Config {
+                api_domain: "https://api.example.com".to_string(),
+                http_proxy: Some("socks9://proxy.example.com".to_string()),
+                ..Default::default()
+            },
+            // Missing CA certificate file
+            Config {
+                api_domain: "https://api.example.com".to_string(),
+                ca_cert_path: Some("/nonexistent/ca.pem".to_string()),
+                ..Default::default()
+            },
+            // Header name with a space
+            Config {
+                api_domain: "https://api.example.com".to_string(),
+                extra_headers: BTreeMap::from([("X Bad".to_string(), "1".to_string())]),
+                ..Default::default()
+            },
+            // Header value with a newline
+            Config {
+                api_domain: "https://api.example.com".to_string(),
+                user_agent: Some("agent\nInjected: 1".to_string()),
+                ..Default::default()
             },
         ];
 
@@ -965,6 +1388,147 @@ another_extra = 123
         }
     }
 
+    #[test]
+    fn test_network_settings_roundtrip_and_validate() {
+        let config: Config = toml::from_str(
+            r#"
+api_domain = "https://api.example.com"
+http_proxy = "http://proxy.example.com:8080"
+user_agent = "corporate-agent/1.0"
+
+[extra_headers]
+X-Api-Key = "secret"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.http_proxy.as_deref(),
+            Some("http://proxy.example.com:8080")
+        );
+        assert_eq!(config.extra_headers["X-Api-Key"], "secret");
+        assert!(config.validate().is_ok());
+
+        let toml_string = toml::to_string(&config).unwrap();
+        assert!(toml_string.contains("[extra_headers]"));
+        // Unset network settings are left out of the file
+        assert!(!toml_string.contains("ca_cert_path"));
+    }
+
+    #[test]
+    fn test_api_domains_lists_primary_then_fallbacks() {
+        let config = Config {
+            api_domain: "https://a.example.com".to_string(),
+            api_fallback_domains: vec![
+                "https://b.example.com".to_string(),
+                "https://a.example.com".to_string(),
+                "https://c.example.com".to_string(),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            config.api_domains(),
+            [
+                "https://a.example.com",
+                "https://b.example.com",
+                "https://c.example.com"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_adds_https_prefix_to_fallback_domains() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        let config_path_str = config_path.to_string_lossy();
+        let config = Config {
+            api_domain: "https://api.example.com".to_string(),
+            api_fallback_domains: vec!["http://backup.example.com".to_string()],
+            ..Default::default()
+        };
+        config.save_to_path(&config_path_str).await.unwrap();
+
+        let loaded = Config::load_from_path(&config_path_str).await.unwrap();
+        assert_eq!(
+            loaded.api_fallback_domains,
+            ["https://backup.example.com".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_file_keeps_other_keys_and_profiles() {
+        let temp_dir = tempdir().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        let config_path_str = config_path.to_string_lossy();
+        tokio::fs::write(
+            &config_path,
+            r#"
+api_domain = "https://old.example.com"
+log_file_path = "/tmp/liiga.log"
+
+[profiles.office]
+display_mode = "wide"
+"#,
+        )
+        .await
+        .unwrap();
+
+        Config::update_file(&config_path_str, |table| {
+            table.insert(
+                "api_domain".to_string(),
+                Value::String("new.example.com".to_string()),
+            );
+            table.remove("log_file_path");
+        })
+        .await
+        .unwrap();
+
+        let loaded = Config::load_from_path(&config_path_str).await.unwrap();
+        assert_eq!(loaded.api_domain, "https://new.example.com");
+        assert_eq!(loaded.log_file_path, None);
+        assert_eq!(
+            loaded.profiles["office"]["display_mode"].as_str(),
+            Some("wide")
+        );
+        // Defaults are not written out, so they cannot shadow other layers
+        let content = tokio::fs::read_to_string(&config_path).await.unwrap();
+        assert!(!content.contains("http_timeout_seconds"));
+    }
+
+    #[test]
+    fn test_display_settings_deserialize() {
+        let config: Config = toml::from_str(
+            r#"
+api_domain = "https://api.example.com"
+display_mode = "compact"
+min_refresh_interval = 30
+plain = true
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.display_mode, DisplayMode::Compact);
+        assert_eq!(config.min_refresh_interval, Some(30));
+        assert!(config.plain);
+
+        let invalid: Result<Config, _> = toml::from_str(
+            r#"
+api_domain = "https://api.example.com"
+display_mode = "huge"
+"#,
+        );
+        assert!(invalid.is_err());
+    }
+
+    #[test]
+    fn test_parse_header_list() {
+        let headers = parse_header_list("X-Api-Key: secret; Accept-Language: fi ;").unwrap();
+        assert_eq!(headers.len(), 2);
+        assert_eq!(headers["X-Api-Key"], "secret");
+        assert_eq!(headers["Accept-Language"], "fi");
+
+        assert!(parse_header_list("").unwrap().is_empty());
+        assert!(parse_header_list("no-colon").is_err());
+    }
+
     #[tokio::test]
     async fn test_environment_variable_override() {
         // Set environment variables
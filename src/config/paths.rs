@@ -34,6 +34,18 @@ pub fn get_log_dir_path() -> String {
         .to_string()
 }
 
+/// Returns the platform-specific path for the saved session state.
+///
+/// # Notes
+/// - Lives next to the config file (e.g., ~/.config/liiga_teletext/session.toml on Linux)
+/// - Falls back to current directory if config directory is unavailable
+pub fn get_session_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| Path::new(".").to_path_buf())
+        .join("liiga_teletext")
+        .join("session.toml")
+}
+
 /// Returns the platform-specific path for the cache directory.
 ///
 /// Uses the platform cache directory (e.g., `~/.cache` on Linux, `%LOCALAPPDATA%` on Windows)
@@ -3,7 +3,9 @@
 //! This module handles user prompts and input collection for configuration
 //! initialization when config files don't exist or need user input.
 
+use super::Config;
 use crate::constants::colors::*;
+use crate::data_fetcher::api::http_client::{HttpClientSettings, build_http_client};
 use crate::error::AppError;
 use crossterm::{
     cursor, execute,
@@ -62,8 +64,9 @@ fn println_colored(text: &str, color: Color) {
     );
 }
 
-/// Animated spinner during API test
-pub async fn test_api_with_animation(api_url: &str) -> Result<(), String> {
+/// Animated spinner during API test. The request goes through the proxy,
+/// certificates, User-Agent and extra headers of `config`, like the app's own.
+pub async fn test_api_with_animation(api_url: &str, config: &Config) -> Result<(), String> {
     let url = if api_url.starts_with("http://") || api_url.starts_with("https://") {
         api_url.to_string()
     } else {
@@ -72,9 +75,7 @@ pub async fn test_api_with_animation(api_url: &str) -> Result<(), String> {
 
     let test_url = format!("{}/tournament", url.trim_end_matches('/'));
 
-    let client = reqwest::Client::builder()
-        .timeout(Duration::from_secs(10))
-        .build()
+    let client = build_http_client(&HttpClientSettings::from_config(config))
         .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
 
     // Start spinner animation in background
@@ -155,21 +156,25 @@ async fn read_line() -> Result<String, AppError> {
 /// and tests the API URL before accepting it. If the test fails, the user can
 /// try again or cancel by pressing Enter without input.
 ///
+/// # Arguments
+/// * `config` - Network settings the API test request is made with
+///
 /// # Returns
 /// * `Ok(String)` - The validated API domain
 /// * `Err(AppError)` - Error reading from stdin or user cancelled
 ///
 /// # Example
 /// ```no_run
+/// use liiga_teletext::config::Config;
 /// use liiga_teletext::config::user_prompts::prompt_for_api_domain;
 ///
 /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-/// let api_domain = prompt_for_api_domain().await?;
+/// let api_domain = prompt_for_api_domain(&Config::load_or_default().await).await?;
 /// println!("Got API domain: {}", api_domain);
 /// # Ok(())
 /// # }
 /// ```
-pub async fn prompt_for_api_domain() -> Result<String, AppError> {
+pub async fn prompt_for_api_domain(config: &Config) -> Result<String, AppError> {
     print_header_box("API DOMAIN CONFIGURATION");
 
     println!();
@@ -220,7 +225,7 @@ pub async fn prompt_for_api_domain() -> Result<String, AppError> {
 
         println!();
 
-        match test_api_with_animation(&input).await {
+        match test_api_with_animation(&input, config).await {
             Ok(()) => {
                 print_colored("  ✓ ", TELETEXT_GREEN);
                 println_colored("API connection successful!", TELETEXT_GREEN);
@@ -242,3 +247,38 @@ pub async fn prompt_for_api_domain() -> Result<String, AppError> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_api_test_sends_configured_headers() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/tournament"))
+            .and(header("user-agent", "office-display/1.0"))
+            .and(header("x-api-key", "secret"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        let config = Config {
+            user_agent: Some("office-display/1.0".to_string()),
+            extra_headers: BTreeMap::from([("X-Api-Key".to_string(), "secret".to_string())]),
+            ..Default::default()
+        };
+        assert_eq!(
+            test_api_with_animation(&server.uri(), &config).await,
+            Ok(())
+        );
+        assert!(
+            test_api_with_animation(&server.uri(), &Config::default())
+                .await
+                .is_err()
+        );
+    }
+}
@@ -1,6 +1,10 @@
 use crate::error::AppError;
+use reqwest::header::{HeaderName, HeaderValue};
+use std::collections::BTreeMap;
 use std::path::Path;
 
+use super::layers::PROFILES_KEY;
+
 /// Validates the configuration settings
 ///
 /// # Arguments
@@ -17,7 +21,48 @@ use std::path::Path;
 /// - If log file path is provided, it cannot be empty
 /// - Log file path parent directory must exist or be creatable
 pub fn validate_config(api_domain: &str, log_file_path: &Option<String>) -> Result<(), AppError> {
-    // Validate API domain
+    validate_api_domain(api_domain)?;
+
+    if let Some(log_path) = log_file_path {
+        validate_log_file_path(log_path)?;
+    }
+
+    Ok(())
+}
+
+/// Validates a log file path
+///
+/// # Returns
+/// * `Ok(())` - Path is valid
+/// * `Err(AppError)` - Path is empty or its directory cannot be created
+pub fn validate_log_file_path(log_path: &str) -> Result<(), AppError> {
+    if log_path.is_empty() {
+        return Err(AppError::config_error("Log file path cannot be empty"));
+    }
+
+    // Check if parent directory exists or can be created
+    if let Some(parent) = Path::new(log_path).parent()
+        && !parent.exists()
+    {
+        // Try to create the directory to validate the path
+        std::fs::create_dir_all(parent).map_err(|e| {
+            AppError::config_error(format!(
+                "Cannot create log directory '{}': {}",
+                parent.display(),
+                e
+            ))
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Validates an API domain (primary or fallback)
+///
+/// # Returns
+/// * `Ok(())` - Domain is valid
+/// * `Err(AppError)` - Domain is empty or does not look like a URL or domain name
+pub fn validate_api_domain(api_domain: &str) -> Result<(), AppError> {
     if api_domain.is_empty() {
         return Err(AppError::config_error("API domain cannot be empty"));
     }
@@ -32,26 +77,744 @@ pub fn validate_config(api_domain: &str, log_file_path: &Option<String>) -> Resu
         }
     }
 
-    // Validate log file path if provided
-    if let Some(log_path) = log_file_path {
-        if log_path.is_empty() {
-            return Err(AppError::config_error("Log file path cannot be empty"));
+    Ok(())
+}
+
+/// Validates the HTTP request limits
+///
+/// # Arguments
+/// * `max_concurrent_requests` - Concurrent requests allowed per host
+/// * `max_requests_per_second` - Requests allowed per second
+///
+/// # Returns
+/// * `Ok(())` - Limits are valid
+/// * `Err(AppError)` - A limit is zero, which would block every request
+pub fn validate_request_limits(
+    max_concurrent_requests: usize,
+    max_requests_per_second: u32,
+) -> Result<(), AppError> {
+    if max_concurrent_requests == 0 {
+        return Err(AppError::config_error(
+            "max_concurrent_requests must be at least 1",
+        ));
+    }
+    if max_requests_per_second == 0 {
+        return Err(AppError::config_error(
+            "max_requests_per_second must be at least 1",
+        ));
+    }
+    Ok(())
+}
+
+/// Validates the log retention limits
+///
+/// # Arguments
+/// * `log_max_age_days` - Optional age in days after which rotated logs are deleted
+/// * `log_max_size_mb` - Optional total size in megabytes the rotated logs may take
+///
+/// # Returns
+/// * `Ok(())` - Limits are valid
+/// * `Err(AppError)` - A limit is zero, which would delete every rotated log,
+///   or larger than a config file can hold
+pub fn validate_log_retention(
+    log_max_age_days: Option<u64>,
+    log_max_size_mb: Option<u64>,
+) -> Result<(), AppError> {
+    let max = i64::MAX as u64;
+    for (key, value) in [
+        ("log_max_age_days", log_max_age_days),
+        ("log_max_size_mb", log_max_size_mb),
+    ] {
+        if let Some(value) = value
+            && !(1..=max).contains(&value)
+        {
+            return Err(AppError::config_error(format!(
+                "{key} must be between 1 and {max}, found {value}"
+            )));
         }
+    }
+    Ok(())
+}
 
-        // Check if parent directory exists or can be created
-        if let Some(parent) = Path::new(log_path).parent()
-            && !parent.exists()
+/// Validates the per-module log levels
+///
+/// # Arguments
+/// * `log_levels` - Module paths (e.g. `liiga_teletext::data_fetcher`) and their levels
+///
+/// # Returns
+/// * `Ok(())` - Every entry is a valid filter directive
+/// * `Err(AppError)` - A module path or level is malformed
+///
+/// # Validation Rules
+/// - Module paths must be non-empty and contain no whitespace, `=` or `,`
+/// - Levels must be one of `off`, `error`, `warn`, `info`, `debug` or `trace`
+pub fn validate_log_levels(log_levels: &BTreeMap<String, String>) -> Result<(), AppError> {
+    for (module, level) in log_levels {
+        if module.is_empty()
+            || module
+                .chars()
+                .any(|c| c.is_whitespace() || c == '=' || c == ',')
         {
-            // Try to create the directory to validate the path
-            std::fs::create_dir_all(parent).map_err(|e| {
-                AppError::config_error(format!(
-                    "Cannot create log directory '{}': {}",
-                    parent.display(),
-                    e
-                ))
-            })?;
+            return Err(AppError::config_error(format!(
+                "Invalid module path '{module}' in log_levels"
+            )));
+        }
+        if !LOG_LEVELS.contains(&level.to_ascii_lowercase().as_str()) {
+            return Err(AppError::config_error(format!(
+                "Invalid log level '{level}' for '{module}', expected one of {}",
+                LOG_LEVELS.join(", ")
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Validates the proxy, CA certificate, User-Agent and extra header settings
+///
+/// # Arguments
+/// * `http_proxy` - Optional proxy URL
+/// * `ca_cert_path` - Optional PEM file with extra root certificates
+/// * `user_agent` - Optional User-Agent header value
+/// * `extra_headers` - Headers sent with every request
+///
+/// # Returns
+/// * `Ok(())` - Settings are valid
+/// * `Err(AppError)` - A setting would make every request fail
+///
+/// # Validation Rules
+/// - Proxy URL must be an `http://` or `https://` URL
+/// - CA certificate file must exist
+/// - User-Agent and header values must be valid header values
+/// - Header names must be valid header names
+pub fn validate_network_settings(
+    http_proxy: Option<&str>,
+    ca_cert_path: Option<&str>,
+    user_agent: Option<&str>,
+    extra_headers: &BTreeMap<String, String>,
+) -> Result<(), AppError> {
+    if let Some(proxy) = http_proxy {
+        let valid = reqwest::Url::parse(proxy)
+            .map(|url| matches!(url.scheme(), "http" | "https") && url.host_str().is_some())
+            .unwrap_or(false);
+        if !valid {
+            return Err(AppError::config_error(format!(
+                "Proxy '{proxy}' must be an http:// or https:// URL"
+            )));
+        }
+    }
+
+    if let Some(path) = ca_cert_path
+        && !Path::new(path).is_file()
+    {
+        return Err(AppError::config_error(format!(
+            "CA certificate file '{path}' does not exist"
+        )));
+    }
+
+    if let Some(user_agent) = user_agent
+        && (user_agent.is_empty() || HeaderValue::from_str(user_agent).is_err())
+    {
+        return Err(AppError::config_error(
+            "User-Agent must be non-empty printable text",
+        ));
+    }
+
+    for (name, value) in extra_headers {
+        if HeaderName::from_bytes(name.as_bytes()).is_err() {
+            return Err(AppError::config_error(format!(
+                "Invalid header name '{name}'"
+            )));
+        }
+        if HeaderValue::from_str(value).is_err() {
+            return Err(AppError::config_error(format!(
+                "Invalid value for header '{name}'"
+            )));
         }
     }
 
     Ok(())
 }
+
+/// Top-level keys of the configuration file
+const CONFIG_KEYS: &[&str] = &[
+    "api_domain",
+    "api_fallback_domains",
+    "log_file_path",
+    "log_format",
+    "log_max_age_days",
+    "log_max_size_mb",
+    "log_levels",
+    "http_timeout_seconds",
+    "max_concurrent_requests",
+    "max_requests_per_second",
+    "http_proxy",
+    "ca_cert_path",
+    "user_agent",
+    "extra_headers",
+    "display_mode",
+    "min_refresh_interval",
+    "language",
+    "plain",
+    "favourite_teams",
+    "restore_session",
+    "export_dir",
+    "video_player",
+    PROFILES_KEY,
+];
+
+/// Keys that name a command to run, a file to read or write, where to send
+/// requests or what to send with them. The project file is read from
+/// whatever directory the program starts in, so these are only taken from
+/// the other layers.
+pub const PROJECT_REFUSED_KEYS: &[&str] = &[
+    "video_player",
+    "ca_cert_path",
+    "http_proxy",
+    "log_file_path",
+    "export_dir",
+    "api_domain",
+    "api_fallback_domains",
+    "extra_headers",
+    "user_agent",
+];
+
+const DISPLAY_MODES: &[&str] = &["normal", "compact", "wide", "teletext"];
+
+const LANGUAGES: &[&str] = &["fi", "en", "sv"];
+
+const LOG_FORMATS: &[&str] = &["text", "json"];
+
+const LOG_LEVELS: &[&str] = &["off", "error", "warn", "info", "debug", "trace"];
+
+/// A problem found by [`check_config_source`], at a 1-based line and column
+/// of the checked file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigProblem {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for ConfigProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+/// Checks the text of a configuration file strictly: syntax errors, unknown
+/// keys (with a "did you mean" suggestion), values of the wrong type, values
+/// out of range and malformed URLs. Profiles are checked like the top level.
+///
+/// Loading the file stays lenient; this is what `--check-config` reports.
+///
+/// # Returns
+/// * `Vec<ConfigProblem>` - Problems in file order; empty when the file is valid
+pub fn check_config_source(source: &str) -> Vec<ConfigProblem> {
+    check_source(source, &[])
+}
+
+/// [`check_config_source`] for the project file, which also reports the
+/// [`PROJECT_REFUSED_KEYS`] set in it.
+pub fn check_project_config_source(source: &str) -> Vec<ConfigProblem> {
+    check_source(source, PROJECT_REFUSED_KEYS)
+}
+
+fn check_source(source: &str, refused: &[&str]) -> Vec<ConfigProblem> {
+    let mut checker = SourceChecker {
+        source,
+        refused,
+        problems: Vec::new(),
+    };
+    match toml::de::DeTable::parse(source) {
+        Ok(table) => checker.check_table(table.get_ref(), true),
+        Err(e) => {
+            let offset = e.span().map_or(0, |span| span.start);
+            checker.report(offset, e.message().trim_end().to_string());
+        }
+    }
+    checker
+        .problems
+        .sort_by_key(|problem| (problem.line, problem.column));
+    checker.problems
+}
+
+type Spanned<T> = toml::Spanned<T>;
+type DeValue<'i> = toml::de::DeValue<'i>;
+
+struct SourceChecker<'a> {
+    source: &'a str,
+    /// Known keys that are not allowed in this file
+    refused: &'a [&'a str],
+    problems: Vec<ConfigProblem>,
+}
+
+impl SourceChecker<'_> {
+    /// Records `message` at byte `offset` of the source.
+    fn report(&mut self, offset: usize, message: String) {
+        let before = &self.source[..offset.min(self.source.len())];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        self.problems.push(ConfigProblem {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message,
+        });
+    }
+
+    fn check_table(&mut self, table: &toml::de::DeTable<'_>, top_level: bool) {
+        for (key, value) in table {
+            let name = key.get_ref().as_ref();
+            let offset = key.span().start;
+            if !CONFIG_KEYS.contains(&name) || (!top_level && name == PROFILES_KEY) {
+                let message = match closest_match(name, CONFIG_KEYS) {
+                    Some(suggestion) => {
+                        format!("unknown key `{name}` (did you mean `{suggestion}`?)")
+                    }
+                    None => format!("unknown key `{name}`"),
+                };
+                self.report(offset, message);
+                continue;
+            }
+            if self.refused.contains(&name) {
+                self.report(
+                    offset,
+                    format!("key `{name}` is not allowed in the project file"),
+                );
+                continue;
+            }
+            self.check_value(name, value);
+        }
+    }
+
+    fn check_value(&mut self, key: &str, value: &Spanned<DeValue<'_>>) {
+        let offset = value.span().start;
+        match key {
+            "api_domain" => {
+                if let Some(domain) = self.expect_string(key, value) {
+                    self.check(offset, validate_api_domain(domain));
+                }
+            }
+            "api_fallback_domains" => {
+                let DeValue::Array(domains) = value.get_ref() else {
+                    self.report_type(key, value, "an array of strings");
+                    return;
+                };
+                for domain in domains.iter() {
+                    if let Some(text) = self.expect_string(key, domain) {
+                        self.check(domain.span().start, validate_api_domain(text));
+                    }
+                }
+            }
+            "favourite_teams" => {
+                let DeValue::Array(teams) = value.get_ref() else {
+                    self.report_type(key, value, "an array of strings");
+                    return;
+                };
+                for team in teams.iter() {
+                    if let Some(text) = self.expect_string(key, team)
+                        && text.trim().is_empty()
+                    {
+                        self.check(
+                            team.span().start,
+                            Err(AppError::config_error("Favourite team cannot be empty")),
+                        );
+                    }
+                }
+            }
+            "log_file_path" | "ca_cert_path" | "user_agent" | "export_dir" | "video_player" => {
+                if let Some(text) = self.expect_string(key, value) {
+                    let result = match key {
+                        "log_file_path" if text.is_empty() => {
+                            Err(AppError::config_error("Log file path cannot be empty"))
+                        }
+                        "export_dir" if text.is_empty() => {
+                            Err(AppError::config_error("Export directory cannot be empty"))
+                        }
+                        "video_player" if text.trim().is_empty() => Err(AppError::config_error(
+                            "Video player command cannot be empty",
+                        )),
+                        "ca_cert_path" => {
+                            validate_network_settings(None, Some(text), None, &BTreeMap::new())
+                        }
+                        "user_agent" => {
+                            validate_network_settings(None, None, Some(text), &BTreeMap::new())
+                        }
+                        _ => Ok(()),
+                    };
+                    self.check(offset, result);
+                }
+            }
+            "http_proxy" => {
+                if let Some(proxy) = self.expect_string(key, value) {
+                    self.check(
+                        offset,
+                        validate_network_settings(Some(proxy), None, None, &BTreeMap::new()),
+                    );
+                }
+            }
+            "http_timeout_seconds"
+            | "max_concurrent_requests"
+            | "max_requests_per_second"
+            | "min_refresh_interval"
+            | "log_max_age_days"
+            | "log_max_size_mb" => {
+                let max = if key == "max_requests_per_second" {
+                    i64::from(u32::MAX)
+                } else {
+                    i64::MAX
+                };
+                if let Some(number) = self.expect_integer(key, value)
+                    && !(1..=max).contains(&number)
+                {
+                    self.report(
+                        offset,
+                        format!("`{key}` must be between 1 and {max}, found {number}"),
+                    );
+                }
+            }
+            "display_mode" | "language" | "log_format" => {
+                let allowed = match key {
+                    "language" => LANGUAGES,
+                    "log_format" => LOG_FORMATS,
+                    _ => DISPLAY_MODES,
+                };
+                if let Some(found) = self.expect_string(key, value)
+                    && !allowed.contains(&found)
+                {
+                    let hint = closest_match(found, allowed)
+                        .map(|s| format!(" (did you mean `{s}`?)"))
+                        .unwrap_or_default();
+                    self.report(
+                        offset,
+                        format!(
+                            "`{key}` must be one of {}, found `{found}`{hint}",
+                            allowed.join(", ")
+                        ),
+                    );
+                }
+            }
+            "plain" | "restore_session" if !matches!(value.get_ref(), DeValue::Boolean(_)) => {
+                self.report_type(key, value, "true or false");
+            }
+            "extra_headers" => {
+                let DeValue::Table(headers) = value.get_ref() else {
+                    self.report_type(key, value, "a table of header names and values");
+                    return;
+                };
+                for (name, header_value) in headers {
+                    if let Some(text) = self.expect_string(key, header_value) {
+                        let header =
+                            BTreeMap::from([(name.get_ref().to_string(), text.to_string())]);
+                        self.check(
+                            name.span().start,
+                            validate_network_settings(None, None, None, &header),
+                        );
+                    }
+                }
+            }
+            "log_levels" => {
+                let DeValue::Table(levels) = value.get_ref() else {
+                    self.report_type(key, value, "a table of module paths and levels");
+                    return;
+                };
+                for (module, level) in levels {
+                    if let Some(text) = self.expect_string(key, level) {
+                        let entry =
+                            BTreeMap::from([(module.get_ref().to_string(), text.to_string())]);
+                        self.check(level.span().start, validate_log_levels(&entry));
+                    }
+                }
+            }
+            PROFILES_KEY => {
+                let DeValue::Table(profiles) = value.get_ref() else {
+                    self.report_type(key, value, "a table of profiles");
+                    return;
+                };
+                for (name, profile) in profiles {
+                    match profile.get_ref() {
+                        DeValue::Table(settings) => self.check_table(settings, false),
+                        _ => self.report(
+                            name.span().start,
+                            format!("profile `{}` must be a table", name.get_ref()),
+                        ),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Records a failed validation.
+    fn check(&mut self, offset: usize, result: Result<(), AppError>) {
+        if let Err(e) = result {
+            let message = match e {
+                AppError::Config(message) => message,
+                other => other.to_string(),
+            };
+            self.report(offset, message);
+        }
+    }
+
+    fn report_type(&mut self, key: &str, value: &Spanned<DeValue<'_>>, expected: &str) {
+        let found = value.get_ref().type_str();
+        self.report(
+            value.span().start,
+            format!("`{key}` must be {expected}, found {found}"),
+        );
+    }
+
+    fn expect_string<'v>(&mut self, key: &str, value: &'v Spanned<DeValue<'_>>) -> Option<&'v str> {
+        match value.get_ref() {
+            DeValue::String(text) => Some(text),
+            _ => {
+                self.report_type(key, value, "a string");
+                None
+            }
+        }
+    }
+
+    fn expect_integer(&mut self, key: &str, value: &Spanned<DeValue<'_>>) -> Option<i64> {
+        match value.get_ref() {
+            DeValue::Integer(number) => {
+                let parsed = i64::from_str_radix(number.as_str(), number.radix()).ok();
+                if parsed.is_none() {
+                    self.report(value.span().start, format!("`{key}` is too large"));
+                }
+                parsed
+            }
+            _ => {
+                self.report_type(key, value, "an integer");
+                None
+            }
+        }
+    }
+}
+
+/// The candidate closest to `name` by edit distance, if it is close enough
+/// to be a likely typo.
+fn closest_match<'a>(name: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(name, candidate), *candidate))
+        .filter(|(distance, candidate)| *distance <= (candidate.len() / 3).max(2))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(source: &str) -> Vec<String> {
+        check_config_source(source)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_config_has_no_problems() {
+        let source = r#"
+api_domain = "https://api.example.com"
+api_fallback_domains = ["https://backup.example.com"]
+http_timeout_seconds = 10
+display_mode = "wide"
+language = "en"
+plain = true
+favourite_teams = ["Tappara", "IFK"]
+
+[extra_headers]
+X-Api-Key = "secret"
+
+[profiles.office]
+http_proxy = "http://proxy.example.com:8080"
+restore_session = false
+"#;
+        assert_eq!(messages(source), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_unknown_keys_suggest_the_closest_key() {
+        let source = "api_domian = \"https://api.example.com\"\ncolour = \"red\"\n\n[profiles.office]\ndisplay_mod = \"wide\"\n";
+        assert_eq!(
+            messages(source),
+            [
+                "1:1: unknown key `api_domian` (did you mean `api_domain`?)",
+                "2:1: unknown key `colour`",
+                "5:1: unknown key `display_mod` (did you mean `display_mode`?)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_project_file_refuses_commands_and_paths() {
+        let source = "video_player = \"mpv\"\nplain = true\n\n[profiles.office]\nhttp_proxy = \"http://proxy.example.com:8080\"\n";
+        let messages: Vec<String> = check_project_config_source(source)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "1:1: key `video_player` is not allowed in the project file",
+                "5:1: key `http_proxy` is not allowed in the project file",
+            ]
+        );
+        assert!(check_config_source(source).is_empty());
+    }
+
+    #[test]
+    fn test_project_file_refuses_request_targets_and_headers() {
+        for (key, value) in [
+            ("api_domain", "\"https://evil.example.com\""),
+            ("api_fallback_domains", "[\"https://evil.example.com\"]"),
+            ("extra_headers", "{ X-Debug = \"1\" }"),
+            ("user_agent", "\"curl/8.0\""),
+        ] {
+            let source = format!("{key} = {value}\n\n[profiles.office]\n{key} = {value}\n");
+            let messages: Vec<String> = check_project_config_source(&source)
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            assert_eq!(
+                messages,
+                [
+                    format!("1:1: key `{key}` is not allowed in the project file"),
+                    format!("4:1: key `{key}` is not allowed in the project file"),
+                ]
+            );
+            assert!(check_config_source(&source).is_empty(), "{key}");
+        }
+    }
+
+    #[test]
+    fn test_out_of_range_values_and_wrong_types() {
+        let source = "http_timeout_seconds = 0\nmax_concurrent_requests = \"4\"\ndisplay_mode = \"wdie\"\nplain = 1\nlanguage = \"se\"\n";
+        assert_eq!(
+            messages(source),
+            [
+                "1:24: `http_timeout_seconds` must be between 1 and 9223372036854775807, found 0",
+                "2:27: `max_concurrent_requests` must be an integer, found string",
+                "3:16: `display_mode` must be one of normal, compact, wide, teletext, found `wdie` (did you mean `wide`?)",
+                "4:9: `plain` must be true or false, found integer",
+                "5:12: `language` must be one of fi, en, sv, found `se` (did you mean `sv`?)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_favourite_teams_must_be_team_names() {
+        assert_eq!(
+            messages("favourite_teams = \"Tappara\"\n"),
+            ["1:19: `favourite_teams` must be an array of strings, found string"]
+        );
+        assert_eq!(
+            messages("favourite_teams = [\"Tappara\", 3, \" \"]\n"),
+            [
+                "1:31: `favourite_teams` must be a string, found integer",
+                "1:34: Favourite team cannot be empty",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_log_settings() {
+        let source = "log_format = \"jsno\"\nlog_max_age_days = 0\n\n[log_levels]\n\"liiga_teletext::data_fetcher\" = \"debug\"\nreqwest = \"loud\"\n";
+        assert_eq!(
+            messages(source),
+            [
+                "1:14: `log_format` must be one of text, json, found `jsno` (did you mean `json`?)",
+                "2:20: `log_max_age_days` must be between 1 and 9223372036854775807, found 0",
+                "6:11: Invalid log level 'loud' for 'reqwest', expected one of off, error, warn, info, debug, trace",
+            ]
+        );
+        assert!(
+            validate_log_levels(&BTreeMap::from([(
+                "liiga teletext".to_string(),
+                "info".to_string()
+            )]))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_log_retention_limits() {
+        assert!(validate_log_retention(None, None).is_ok());
+        assert!(validate_log_retention(Some(1), Some(i64::MAX as u64)).is_ok());
+        assert!(validate_log_retention(Some(0), None).is_err());
+        assert!(validate_log_retention(None, Some(0)).is_err());
+        assert!(validate_log_retention(Some(u64::MAX), None).is_err());
+
+        let config = crate::config::Config {
+            api_domain: "https://api.example.com".to_string(),
+            log_max_age_days: Some(0),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_malformed_urls() {
+        let source = "api_domain = \"not a domain\"\nhttp_proxy = \"socks://proxy\"\napi_fallback_domains = [\"ok.example.com\", \"backup\"]\n";
+        assert_eq!(
+            messages(source),
+            [
+                "1:14: API domain must be a valid URL or domain name",
+                "2:14: Proxy 'socks://proxy' must be an http:// or https:// URL",
+                "3:43: API domain must be a valid URL or domain name",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_syntax_error_position() {
+        let problems = check_config_source("api_domain = \"https://api.example.com\"\nplain = \n");
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].line, 2);
+    }
+
+    #[test]
+    fn test_every_config_field_is_a_known_key() {
+        let config = crate::config::Config {
+            api_fallback_domains: vec!["https://backup.example.com".to_string()],
+            log_file_path: Some("/tmp/liiga.log".to_string()),
+            log_format: crate::config::LogFormat::Json,
+            log_max_age_days: Some(7),
+            log_max_size_mb: Some(100),
+            log_levels: BTreeMap::from([("liiga_teletext".to_string(), "debug".to_string())]),
+            http_proxy: Some("http://proxy".to_string()),
+            ca_cert_path: Some("/tmp/ca.pem".to_string()),
+            user_agent: Some("agent".to_string()),
+            extra_headers: BTreeMap::from([("X".to_string(), "y".to_string())]),
+            display_mode: crate::config::DisplayMode::Wide,
+            min_refresh_interval: Some(30),
+            language: Some(crate::i18n::Language::Swedish),
+            plain: true,
+            favourite_teams: vec!["Tappara".to_string()],
+            restore_session: false,
+            export_dir: Some("/tmp/snapshots".to_string()),
+            video_player: Some("mpv".to_string()),
+            profiles: BTreeMap::from([("office".to_string(), toml::Table::new())]),
+            ..Default::default()
+        };
+        let table = toml::Table::try_from(&config).unwrap();
+        for key in table.keys() {
+            assert!(CONFIG_KEYS.contains(&key.as_str()), "{key} is not known");
+        }
+        assert_eq!(table.len(), CONFIG_KEYS.len());
+    }
+}
@@ -10,9 +10,24 @@ pub const DEFAULT_HTTP_TIMEOUT_SECONDS: u64 = 10;
 /// Shorter than the overall request timeout to quickly detect unreachable hosts.
 pub const DEFAULT_HTTP_CONNECT_TIMEOUT_SECONDS: u64 = 5;
 
+/// User-Agent sent with every request unless configured otherwise
+pub const DEFAULT_USER_AGENT: &str =
+    concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
 /// Maximum number of connections per host in the HTTP client pool
 pub const HTTP_POOL_MAX_IDLE_PER_HOST: usize = 100;
 
+/// Default maximum number of requests in flight to one API host.
+/// Bounds the per-game detail fetches on busy nights.
+pub const DEFAULT_MAX_CONCURRENT_REQUESTS_PER_HOST: usize = 4;
+
+/// Default maximum number of API requests started per second.
+/// Also the burst size of the token bucket.
+pub const DEFAULT_MAX_REQUESTS_PER_SECOND: u32 = 10;
+
+/// Default age in days after which rotated log files are deleted
+pub const DEFAULT_LOG_MAX_AGE_DAYS: u64 = 30;
+
 /// Cache TTL (Time To Live) values in seconds
 pub mod cache_ttl {
     /// TTL for live games - set to match auto-refresh interval to prevent cache expiration
@@ -39,6 +54,14 @@ pub mod refresh {
     /// Kept in sync with `cache_ttl::LIVE_GAMES_SECONDS` to prevent cache expiration
     /// between refresh cycles.
     pub const LIVE_GAMES_INTERVAL_SECONDS: u64 = super::cache_ttl::LIVE_GAMES_SECONDS;
+
+    /// Refresh interval of the diagnostics page. It only reads in-memory
+    /// metrics, so it can update much more often than the data views.
+    pub const DIAGNOSTICS_INTERVAL_SECONDS: u64 = 2;
+
+    /// Refresh interval of the off-season page. Its countdowns change daily
+    /// and fixtures are published rarely.
+    pub const OFF_SEASON_INTERVAL_SECONDS: u64 = 3600;
 }
 
 /// Teletext color constants
@@ -89,6 +112,16 @@ pub mod retry {
     pub const SECONDARY_TOURNAMENT_MAX_ATTEMPTS: u32 = 1;
 }
 
+/// API endpoint failover configuration
+pub mod failover {
+    /// How long an endpoint is skipped after its first failure (seconds).
+    /// Doubles with each consecutive failure.
+    pub const INITIAL_COOLDOWN_SECONDS: u64 = 30;
+
+    /// Upper bound for the failure cooldown (seconds)
+    pub const MAX_COOLDOWN_SECONDS: u64 = 300;
+}
+
 /// Playoff bracket visibility configuration
 pub mod bracket {
     /// How many days after the last playoff game the bracket remains
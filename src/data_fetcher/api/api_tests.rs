@@ -58,6 +58,7 @@ mod tests {
             api_domain: "http://localhost:8080".to_string(),
             log_file_path: None,
             http_timeout_seconds: crate::constants::DEFAULT_HTTP_TIMEOUT_SECONDS,
+            ..Default::default()
         }
     }
 
@@ -2343,4 +2344,116 @@ mod tests {
         let (active, _) = second.unwrap();
         assert_eq!(active, vec!["runkosarja"]);
     }
+
+    /// Serves a body with the given validator header, then answers 304 to
+    /// requests that send it back in `conditional_header`.
+    async fn mount_revalidating_endpoint(
+        mock_server: &MockServer,
+        endpoint: &str,
+        validator: (&str, &str),
+        conditional_header: &str,
+    ) {
+        // Exact comparison: the header matcher would split HTTP dates at commas
+        let expected = validator.1.to_string();
+        let conditional_header = conditional_header.to_string();
+        Mock::given(method("GET"))
+            .and(path(endpoint))
+            .and(move |request: &wiremock::Request| {
+                request
+                    .headers
+                    .get(conditional_header.as_str())
+                    .and_then(|value| value.to_str().ok())
+                    == Some(expected.as_str())
+            })
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .mount(mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(endpoint))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(r#"{"value":1}"#)
+                    .insert_header(validator.0, validator.1),
+            )
+            .expect(1)
+            .mount(mock_server)
+            .await;
+    }
+
+    async fn assert_revalidated_with_304(url: &str) {
+        use crate::data_fetcher::cache::{
+            HTTP_RESPONSE_CACHE, get_cached_http_response, get_http_response_for_revalidation,
+        };
+
+        let client = create_test_http_client();
+        let first: serde_json::Value = super::super::fetch_utils::fetch_with_retries(
+            &client,
+            url,
+            crate::constants::retry::MAX_ATTEMPTS,
+        )
+        .await
+        .unwrap();
+        assert_eq!(first["value"], 1);
+
+        // Let the TTL run out; the body and validators stay for revalidation
+        HTTP_RESPONSE_CACHE.expire(&url.to_string()).await;
+        let (_, expired) = get_http_response_for_revalidation(url).await.unwrap();
+        assert!(expired);
+
+        let second: serde_json::Value = super::super::fetch_utils::fetch_with_retries(
+            &client,
+            url,
+            crate::constants::retry::MAX_ATTEMPTS,
+        )
+        .await
+        .unwrap();
+        assert_eq!(second, first);
+        assert!(
+            get_cached_http_response(url).await.is_some(),
+            "304 must extend the cached response's TTL"
+        );
+
+        // Served from the cache until the new expiry: the mocks' expect(1)
+        // fails if this makes a third request
+        let third: serde_json::Value = super::super::fetch_utils::fetch_with_retries(
+            &client,
+            url,
+            crate::constants::retry::MAX_ATTEMPTS,
+        )
+        .await
+        .unwrap();
+        assert_eq!(third, first);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_expired_response_revalidated_with_etag() {
+        let mock_server = MockServer::start().await;
+        mount_revalidating_endpoint(
+            &mock_server,
+            "/conditional/etag",
+            ("ETag", "\"v1\""),
+            "If-None-Match",
+        )
+        .await;
+
+        assert_revalidated_with_304(&format!("{}/conditional/etag", mock_server.uri())).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_expired_response_revalidated_with_last_modified() {
+        let mock_server = MockServer::start().await;
+        mount_revalidating_endpoint(
+            &mock_server,
+            "/conditional/last-modified",
+            ("Last-Modified", "Sat, 18 Oct 2025 15:00:00 GMT"),
+            "If-Modified-Since",
+        )
+        .await;
+
+        assert_revalidated_with_304(&format!("{}/conditional/last-modified", mock_server.uri()))
+            .await;
+    }
 }
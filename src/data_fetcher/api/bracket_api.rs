@@ -1,7 +1,7 @@
 // src/data_fetcher/api/bracket_api.rs
 use crate::config::Config;
 use crate::data_fetcher::api::date_logic::parse_date_and_season;
-use crate::data_fetcher::api::http_client::create_http_client_with_timeout;
+use crate::data_fetcher::api::http_client::http_client_for;
 use crate::data_fetcher::api::tournament_logic::{TournamentType, fetch_tournament_games};
 use crate::data_fetcher::models::ScheduleApiGame;
 use crate::data_fetcher::models::bracket::{PlayoffBracket, build_playoff_bracket};
@@ -44,7 +44,7 @@ fn is_bracket_stale(games: &[ScheduleApiGame], now: DateTime<Utc>, grace_days: i
 /// playoffs are upcoming, ongoing, or recently concluded — an old season's
 /// bracket is not offered during the off-season.
 pub async fn fetch_playoff_bracket(config: &Config) -> Result<PlayoffBracket, AppError> {
-    let client = create_http_client_with_timeout(config.http_timeout_seconds)?;
+    let client = http_client_for(config)?;
 
     // Determine current season from today's date
     let today = Utc::now().format("%Y-%m-%d").to_string();
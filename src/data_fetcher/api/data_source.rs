@@ -0,0 +1,403 @@
+//! Pluggable sources for the raw API data
+//!
+//! Every request the fetch pipeline makes is described by an [`ApiResource`]
+//! and answered by a [`DataSource`]. By default that is an [`HttpDataSource`]
+//! for the configured Liiga API; installing another source with
+//! [`set_data_source`] feeds the same pipeline (caching, processing,
+//! rendering) from fixture files or memory.
+//!
+//! # Example
+//! ```rust,no_run
+//! use std::sync::Arc;
+//! use liiga_teletext::data_fetcher::api::data_source::{
+//!     FixtureDirSource, set_data_source,
+//! };
+//! use liiga_teletext::fetch_liiga_data;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), liiga_teletext::AppError> {
+//!     set_data_source(Arc::new(FixtureDirSource::new("fixtures"))).await;
+//!     let (games, date) = fetch_liiga_data(Some("2025-10-18".to_string())).await?;
+//!     Ok(())
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::{Arc, LazyLock, RwLock};
+use std::time::Instant;
+
+use futures::future::BoxFuture;
+use reqwest::Client;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::endpoint_health::{endpoint_health, is_endpoint_failure};
+use super::fetch_utils::fetch_with_retries;
+use super::urls::{
+    build_game_url, build_standings_url, build_tournament_schedule_url, build_tournament_url,
+};
+use crate::config::Config;
+use crate::error::AppError;
+
+/// A piece of data the fetch pipeline asks for.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ApiResource {
+    /// Games of one tournament on one date (`ScheduleResponse`)
+    Games { tournament: String, date: String },
+    /// Full detail of a single game (`DetailedGameResponse`)
+    GameDetails { season: i32, game_id: i32 },
+    /// Whole-season schedule of one tournament (`Vec<ScheduleApiGame>`)
+    Schedule { tournament: String, season: i32 },
+    /// League table of a season (`StandingsResponse`)
+    Standings { season: i32 },
+}
+
+impl ApiResource {
+    /// Full API URL of the resource.
+    pub fn url(&self, api_domain: &str) -> String {
+        match self {
+            Self::Games { tournament, date } => build_tournament_url(api_domain, tournament, date),
+            Self::GameDetails { season, game_id } => build_game_url(api_domain, *season, *game_id),
+            Self::Schedule { tournament, season } => {
+                build_tournament_schedule_url(api_domain, tournament, *season)
+            }
+            Self::Standings { season } => build_standings_url(api_domain, *season),
+        }
+    }
+
+    /// Path of the resource's file inside a fixture directory, e.g.
+    /// `games/runkosarja/2025-10-18.json` or `game/2026/12345.json`.
+    pub fn fixture_path(&self) -> PathBuf {
+        match self {
+            Self::Games { tournament, date } => ["games", tournament, &format!("{date}.json")]
+                .iter()
+                .collect(),
+            Self::GameDetails { season, game_id } => {
+                ["game", &season.to_string(), &format!("{game_id}.json")]
+                    .iter()
+                    .collect()
+            }
+            Self::Schedule { tournament, season } => {
+                ["schedule", tournament, &format!("{season}.json")]
+                    .iter()
+                    .collect()
+            }
+            Self::Standings { season } => ["standings", &format!("{season}.json")].iter().collect(),
+        }
+    }
+}
+
+impl fmt::Display for ApiResource {
+    /// Domain-less API path, used in error messages of non-HTTP sources
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.url(""))
+    }
+}
+
+/// Answers the fetch pipeline's requests with JSON matching the Liiga API.
+///
+/// Missing resources should be reported as [`AppError::ApiNotFound`] so that
+/// the pipeline's fallbacks (e.g. trying the next tournament) keep working.
+pub trait DataSource: Send + Sync {
+    /// Returns the JSON document for `resource`.
+    fn fetch_json<'a>(
+        &'a self,
+        resource: &'a ApiResource,
+    ) -> BoxFuture<'a, Result<Value, AppError>>;
+
+    /// Same as [`fetch_json`](Self::fetch_json) with a custom retry budget,
+    /// for requests whose failures are expected (e.g. availability checks for
+    /// unpublished tournaments). Sources that don't retry ignore the budget.
+    fn fetch_json_with_retries<'a>(
+        &'a self,
+        resource: &'a ApiResource,
+        _max_retries: u32,
+    ) -> BoxFuture<'a, Result<Value, AppError>> {
+        self.fetch_json(resource)
+    }
+
+    /// Whether the source reads the API domain from the configuration. Only
+    /// then does loading the configuration prompt for a missing domain.
+    fn needs_api_domain(&self) -> bool {
+        false
+    }
+}
+
+/// The Liiga HTTP API, with the usual response caching and retries.
+///
+/// The configured API endpoints are tried in failover order (see
+/// [`endpoint_health`]): a timeout, connection failure or 5xx marks the
+/// endpoint unhealthy and the request moves on to the next one.
+pub struct HttpDataSource {
+    client: Client,
+    api_domains: Vec<String>,
+}
+
+impl HttpDataSource {
+    /// A source for the primary and fallback API domains of `config`.
+    pub fn new(client: Client, config: &Config) -> Self {
+        Self {
+            client,
+            api_domains: config
+                .api_domains()
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+
+    fn failover_order(&self) -> Vec<String> {
+        let domains: Vec<&str> = self.api_domains.iter().map(String::as_str).collect();
+        endpoint_health().failover_order(&domains, Instant::now())
+    }
+}
+
+impl DataSource for HttpDataSource {
+    fn fetch_json<'a>(
+        &'a self,
+        resource: &'a ApiResource,
+    ) -> BoxFuture<'a, Result<Value, AppError>> {
+        Box::pin(async move {
+            let health = endpoint_health();
+            let mut last_error = None;
+            for domain in self.failover_order() {
+                let url = resource.url(&domain);
+                match fetch_with_retries(&self.client, &url, crate::constants::retry::MAX_ATTEMPTS)
+                    .await
+                {
+                    Ok(document) => {
+                        health.record_success(&domain);
+                        return Ok(document);
+                    }
+                    Err(e) if is_endpoint_failure(&e) => {
+                        health.record_failure(&domain, &e, Instant::now());
+                        last_error = Some(e);
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            Err(last_error.expect("at least the primary API endpoint is configured"))
+        })
+    }
+
+    /// Goes to the preferred endpoint only and leaves endpoint health alone,
+    /// since the expected 5xx responses say nothing about the host.
+    fn fetch_json_with_retries<'a>(
+        &'a self,
+        resource: &'a ApiResource,
+        max_retries: u32,
+    ) -> BoxFuture<'a, Result<Value, AppError>> {
+        Box::pin(async move {
+            let preferred = self.failover_order().remove(0);
+            fetch_with_retries(&self.client, &resource.url(&preferred), max_retries).await
+        })
+    }
+
+    fn needs_api_domain(&self) -> bool {
+        true
+    }
+}
+
+/// Serves resources from JSON files under a directory, laid out as
+/// described by [`ApiResource::fixture_path`].
+pub struct FixtureDirSource {
+    root: PathBuf,
+}
+
+impl FixtureDirSource {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl DataSource for FixtureDirSource {
+    fn fetch_json<'a>(
+        &'a self,
+        resource: &'a ApiResource,
+    ) -> BoxFuture<'a, Result<Value, AppError>> {
+        Box::pin(async move {
+            let path = self.root.join(resource.fixture_path());
+            let content = match tokio::fs::read_to_string(&path).await {
+                Ok(content) => content,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                    return Err(AppError::api_not_found(resource.to_string()));
+                }
+                Err(e) => return Err(e.into()),
+            };
+            serde_json::from_str(&content).map_err(|e| {
+                AppError::api_malformed_json(
+                    format!("{}: {e}", path.display()),
+                    resource.to_string(),
+                )
+            })
+        })
+    }
+}
+
+/// Serves resources from memory; handy for tests and embedding.
+#[derive(Default)]
+pub struct InMemorySource {
+    documents: RwLock<HashMap<ApiResource, Value>>,
+}
+
+impl InMemorySource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores (or replaces) the document served for `resource`.
+    ///
+    /// # Errors
+    /// Returns an error if `document` cannot be represented as JSON.
+    pub fn insert(&self, resource: ApiResource, document: &impl Serialize) -> Result<(), AppError> {
+        let value = serde_json::to_value(document)?;
+        self.documents
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(resource, value);
+        Ok(())
+    }
+}
+
+impl DataSource for InMemorySource {
+    fn fetch_json<'a>(
+        &'a self,
+        resource: &'a ApiResource,
+    ) -> BoxFuture<'a, Result<Value, AppError>> {
+        let document = self
+            .documents
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(resource)
+            .cloned();
+        Box::pin(
+            async move { document.ok_or_else(|| AppError::api_not_found(resource.to_string())) },
+        )
+    }
+}
+
+/// Source installed with [`set_data_source`]; `None` uses the HTTP API
+/// configured in `Config`.
+static DATA_SOURCE: LazyLock<RwLock<Option<Arc<dyn DataSource>>>> =
+    LazyLock::new(|| RwLock::new(None));
+
+/// Routes all further fetches to `source` instead of the configured HTTP API.
+/// Clears the data caches so nothing fetched from the previous source leaks
+/// into the new one.
+pub async fn set_data_source(source: Arc<dyn DataSource>) {
+    *DATA_SOURCE.write().unwrap_or_else(|e| e.into_inner()) = Some(source);
+    crate::data_fetcher::cache::clear_all_caches().await;
+}
+
+/// Goes back to fetching from the configured HTTP API.
+pub async fn reset_data_source() {
+    *DATA_SOURCE.write().unwrap_or_else(|e| e.into_inner()) = None;
+    crate::data_fetcher::cache::clear_all_caches().await;
+}
+
+fn installed_data_source() -> Option<Arc<dyn DataSource>> {
+    DATA_SOURCE
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+/// The source fetches go to: the one installed with [`set_data_source`], or
+/// an [`HttpDataSource`] for the API configured in `config`.
+pub(crate) fn data_source(client: &Client, config: &Config) -> Arc<dyn DataSource> {
+    installed_data_source().unwrap_or_else(|| Arc::new(HttpDataSource::new(client.clone(), config)))
+}
+
+/// Whether fetches need the API domain of the configuration, i.e. whether
+/// they go to the HTTP API.
+pub(crate) fn data_source_needs_api_domain() -> bool {
+    installed_data_source().is_none_or(|source| source.needs_api_domain())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn games_resource() -> ApiResource {
+        ApiResource::Games {
+            tournament: "runkosarja".to_string(),
+            date: "2025-10-18".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_resource_urls_match_url_builders() {
+        assert_eq!(
+            games_resource().url("https://api"),
+            "https://api/games?tournament=runkosarja&date=2025-10-18"
+        );
+        assert_eq!(
+            ApiResource::GameDetails {
+                season: 2026,
+                game_id: 42
+            }
+            .to_string(),
+            "/games/2026/42"
+        );
+    }
+
+    #[test]
+    fn test_fixture_paths() {
+        assert_eq!(
+            games_resource().fixture_path(),
+            PathBuf::from("games/runkosarja/2025-10-18.json")
+        );
+        assert_eq!(
+            ApiResource::Standings { season: 2026 }.fixture_path(),
+            PathBuf::from("standings/2026.json")
+        );
+    }
+
+    #[test]
+    fn test_only_http_source_needs_api_domain() {
+        let http = HttpDataSource::new(Client::new(), &Config::default());
+        assert!(http.needs_api_domain());
+        assert!(!InMemorySource::new().needs_api_domain());
+        assert!(!FixtureDirSource::new("fixtures").needs_api_domain());
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_source_serves_inserted_documents() {
+        let source = InMemorySource::new();
+        source
+            .insert(games_resource(), &serde_json::json!({ "games": [] }))
+            .unwrap();
+
+        let document = source.fetch_json(&games_resource()).await.unwrap();
+        assert_eq!(document["games"], serde_json::json!([]));
+
+        let missing = source
+            .fetch_json(&ApiResource::Standings { season: 2026 })
+            .await
+            .unwrap_err();
+        assert!(missing.is_not_found());
+    }
+
+    #[tokio::test]
+    async fn test_fixture_dir_source_reads_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(games_resource().fixture_path());
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, r#"{"games": []}"#).unwrap();
+
+        let source = FixtureDirSource::new(dir.path());
+        let document = source.fetch_json(&games_resource()).await.unwrap();
+        assert_eq!(document["games"], serde_json::json!([]));
+
+        let missing = source
+            .fetch_json(&ApiResource::Standings { season: 2026 })
+            .await
+            .unwrap_err();
+        assert!(missing.is_not_found());
+
+        std::fs::write(&path, "not json").unwrap();
+        assert!(source.fetch_json(&games_resource()).await.is_err());
+    }
+}
@@ -0,0 +1,262 @@
+//! Health tracking and failover order for the configured API endpoints
+//!
+//! A request goes to the first healthy endpoint in the configured order.
+//! Timeouts, connection failures and 5xx responses mark an endpoint
+//! unhealthy for a cooldown that doubles with each consecutive failure; the
+//! next request then fails over to the next endpoint. Unhealthy endpoints
+//! are still tried last, soonest-to-recover first, so a request never fails
+//! just because every endpoint had a bad moment. Any success makes an
+//! endpoint healthy again.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use tracing::{info, warn};
+
+use crate::constants::failover::{INITIAL_COOLDOWN_SECONDS, MAX_COOLDOWN_SECONDS};
+use crate::error::AppError;
+
+/// Whether `error` means the endpoint itself is failing, as opposed to the
+/// requested data being missing or malformed.
+pub fn is_endpoint_failure(error: &AppError) -> bool {
+    matches!(
+        error,
+        AppError::NetworkTimeout { .. }
+            | AppError::NetworkConnection { .. }
+            | AppError::ApiServerError { .. }
+            | AppError::ApiServiceUnavailable { .. }
+    )
+}
+
+#[derive(Debug, Default)]
+struct Health {
+    consecutive_failures: u32,
+    unhealthy_until: Option<Instant>,
+    last_error: Option<String>,
+}
+
+impl Health {
+    fn is_healthy(&self, now: Instant) -> bool {
+        self.unhealthy_until.is_none_or(|until| now >= until)
+    }
+}
+
+/// Health of one endpoint as shown on the diagnostics page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointStatus {
+    pub domain: String,
+    /// The endpoint the last successful request went to
+    pub active: bool,
+    pub healthy: bool,
+    pub consecutive_failures: u32,
+    /// Time until an unhealthy endpoint is preferred again
+    pub retry_in: Option<Duration>,
+    pub last_error: Option<String>,
+}
+
+/// Health of every endpoint, plus the configured order.
+#[derive(Debug, Default)]
+pub struct EndpointHealth {
+    inner: Mutex<Registry>,
+}
+
+#[derive(Debug, Default)]
+struct Registry {
+    configured: Vec<String>,
+    health: HashMap<String, Health>,
+    active: Option<String>,
+}
+
+impl EndpointHealth {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_registry<R>(&self, f: impl FnOnce(&mut Registry) -> R) -> R {
+        f(&mut self.inner.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    /// Remembers the configured endpoints for [`EndpointHealth::statuses`].
+    pub fn configure(&self, domains: &[&str]) {
+        self.with_registry(|registry| {
+            if registry.configured != domains {
+                registry.configured = domains.iter().map(|d| d.to_string()).collect();
+            }
+        });
+    }
+
+    /// `domains` in the order requests should try them.
+    pub fn failover_order(&self, domains: &[&str], now: Instant) -> Vec<String> {
+        self.with_registry(|registry| {
+            let healthy = |domain: &&str| {
+                registry
+                    .health
+                    .get(*domain)
+                    .is_none_or(|health| health.is_healthy(now))
+            };
+            let mut unhealthy: Vec<&str> =
+                domains.iter().copied().filter(|d| !healthy(d)).collect();
+            unhealthy.sort_by_key(|domain| registry.health[*domain].unhealthy_until);
+            domains
+                .iter()
+                .copied()
+                .filter(healthy)
+                .chain(unhealthy)
+                .map(str::to_string)
+                .collect()
+        })
+    }
+
+    /// Marks `domain` healthy and active.
+    pub fn record_success(&self, domain: &str) {
+        self.with_registry(|registry| {
+            let health = registry.health.entry(domain.to_string()).or_default();
+            if health.consecutive_failures > 0 {
+                info!("API endpoint {domain} is healthy again");
+            }
+            *health = Health::default();
+            if registry.active.as_deref() != Some(domain) {
+                if registry.active.is_some() {
+                    info!("Active API endpoint is now {domain}");
+                }
+                registry.active = Some(domain.to_string());
+            }
+        });
+    }
+
+    /// Marks `domain` unhealthy after an endpoint failure.
+    pub fn record_failure(&self, domain: &str, error: &AppError, now: Instant) {
+        self.with_registry(|registry| {
+            let health = registry.health.entry(domain.to_string()).or_default();
+            health.consecutive_failures += 1;
+            let cooldown = INITIAL_COOLDOWN_SECONDS
+                .saturating_mul(1 << (health.consecutive_failures - 1).min(16))
+                .min(MAX_COOLDOWN_SECONDS);
+            health.unhealthy_until = Some(now + Duration::from_secs(cooldown));
+            health.last_error = Some(error.to_string());
+            warn!("API endpoint {domain} marked unhealthy for {cooldown}s: {error}");
+        });
+    }
+
+    /// Health of the configured endpoints, in configured order.
+    pub fn statuses(&self, now: Instant) -> Vec<EndpointStatus> {
+        self.with_registry(|registry| {
+            registry
+                .configured
+                .iter()
+                .map(|domain| {
+                    let health = registry.health.get(domain);
+                    let retry_in = health
+                        .and_then(|h| h.unhealthy_until)
+                        .map(|until| until.saturating_duration_since(now))
+                        .filter(|remaining| !remaining.is_zero());
+                    EndpointStatus {
+                        domain: domain.clone(),
+                        active: registry.active.as_ref() == Some(domain),
+                        healthy: retry_in.is_none(),
+                        consecutive_failures: health.map_or(0, |h| h.consecutive_failures),
+                        retry_in,
+                        last_error: health.and_then(|h| h.last_error.clone()),
+                    }
+                })
+                .collect()
+        })
+    }
+}
+
+static ENDPOINT_HEALTH: LazyLock<EndpointHealth> = LazyLock::new(EndpointHealth::new);
+
+/// The endpoint health shared by all API requests of this process.
+pub fn endpoint_health() -> &'static EndpointHealth {
+    &ENDPOINT_HEALTH
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRIMARY: &str = "https://primary.example.com";
+    const BACKUP: &str = "https://backup.example.com";
+
+    #[test]
+    fn test_endpoint_failures() {
+        assert!(is_endpoint_failure(&AppError::network_timeout("url")));
+        assert!(is_endpoint_failure(&AppError::network_connection(
+            "url", "refused"
+        )));
+        assert!(is_endpoint_failure(&AppError::api_server_error(
+            500, "error", "url"
+        )));
+        assert!(is_endpoint_failure(&AppError::api_service_unavailable(
+            503, "error", "url"
+        )));
+
+        assert!(!is_endpoint_failure(&AppError::api_not_found("url")));
+        assert!(!is_endpoint_failure(&AppError::api_rate_limit(
+            "slow down",
+            "url"
+        )));
+        assert!(!is_endpoint_failure(&AppError::api_malformed_json(
+            "bad", "url"
+        )));
+    }
+
+    #[test]
+    fn test_failover_skips_unhealthy_until_cooldown_ends() {
+        let health = EndpointHealth::new();
+        let now = Instant::now();
+        let domains = [PRIMARY, BACKUP];
+        assert_eq!(health.failover_order(&domains, now), [PRIMARY, BACKUP]);
+
+        health.record_failure(PRIMARY, &AppError::network_timeout(PRIMARY), now);
+        assert_eq!(health.failover_order(&domains, now), [BACKUP, PRIMARY]);
+
+        let after_cooldown = now + Duration::from_secs(INITIAL_COOLDOWN_SECONDS);
+        assert_eq!(
+            health.failover_order(&domains, after_cooldown),
+            [PRIMARY, BACKUP]
+        );
+    }
+
+    #[test]
+    fn test_cooldown_doubles_and_success_resets() {
+        let health = EndpointHealth::new();
+        health.configure(&[PRIMARY, BACKUP]);
+        let now = Instant::now();
+        let error = AppError::api_service_unavailable(503, "down", PRIMARY);
+        health.record_failure(PRIMARY, &error, now);
+        health.record_failure(PRIMARY, &error, now);
+
+        let primary = &health.statuses(now)[0];
+        assert!(!primary.healthy);
+        assert_eq!(primary.consecutive_failures, 2);
+        assert_eq!(
+            primary.retry_in,
+            Some(Duration::from_secs(2 * INITIAL_COOLDOWN_SECONDS))
+        );
+        assert!(primary.last_error.as_deref().unwrap().contains("503"));
+
+        health.record_success(BACKUP);
+        health.record_success(PRIMARY);
+        let statuses = health.statuses(now);
+        assert!(statuses[0].healthy && statuses[0].active);
+        assert_eq!(statuses[0].consecutive_failures, 0);
+        assert!(!statuses[1].active);
+    }
+
+    #[test]
+    fn test_all_unhealthy_tries_soonest_recovery_first() {
+        let health = EndpointHealth::new();
+        let now = Instant::now();
+        let error = AppError::network_timeout("url");
+        health.record_failure(PRIMARY, &error, now);
+        health.record_failure(PRIMARY, &error, now);
+        health.record_failure(BACKUP, &error, now);
+
+        assert_eq!(
+            health.failover_order(&[PRIMARY, BACKUP], now),
+            [BACKUP, PRIMARY]
+        );
+    }
+}
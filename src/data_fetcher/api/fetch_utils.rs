@@ -1,19 +1,70 @@
 //! Generic HTTP fetching utilities with caching, retry logic, and error handling
 
-use reqwest::Client;
+use reqwest::header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::{Client, RequestBuilder, StatusCode};
 use serde::de::DeserializeOwned;
-use std::time::Duration;
+use serde_json::Value;
+use std::time::{Duration, Instant};
 use tracing::{debug, error, info, instrument, warn};
 
-use crate::data_fetcher::cache::{cache_http_response, get_cached_http_response, has_live_games};
+use super::data_source::{ApiResource, data_source};
+use super::metrics::request_metrics;
+use super::rate_limit::acquire as acquire_request_permit;
+use crate::config::Config;
+use crate::data_fetcher::cache::{
+    CachedHttpResponse, cache_http_response_with_validators, extend_http_response_ttl,
+    get_http_response_for_revalidation, has_live_games,
+};
 use crate::data_fetcher::models::ScheduleResponse;
 use crate::error::AppError;
 
-/// Generic fetch function with HTTP caching, retry logic, and comprehensive error handling.
+/// Fetches `resource` from the active [`DataSource`]: the installed one, or
+/// the HTTP API configured in `config`.
+pub(super) async fn fetch_resource<T: DeserializeOwned>(
+    client: &Client,
+    config: &Config,
+    resource: &ApiResource,
+) -> Result<T, AppError> {
+    let document = data_source(client, config).fetch_json(resource).await?;
+    parse_document(document, resource)
+}
+
+/// Same as [`fetch_resource`] but with a custom retry budget, for requests
+/// whose failures are expected (e.g. availability checks for unpublished
+/// tournaments).
+pub(super) async fn fetch_resource_with_retries<T: DeserializeOwned>(
+    client: &Client,
+    config: &Config,
+    resource: &ApiResource,
+    max_retries: u32,
+) -> Result<T, AppError> {
+    let document = data_source(client, config)
+        .fetch_json_with_retries(resource, max_retries)
+        .await?;
+    parse_document(document, resource)
+}
+
+fn parse_document<T: DeserializeOwned>(
+    document: Value,
+    resource: &ApiResource,
+) -> Result<T, AppError> {
+    serde_json::from_value(document)
+        .map_err(|e| AppError::api_unexpected_structure(e.to_string(), resource.to_string()))
+}
+
+/// Fetches a JSON document with HTTP caching, retry logic, and comprehensive error handling.
 ///
 /// This function:
 /// - Checks HTTP response cache first
-/// - Implements retry logic with exponential backoff for transient failures
+/// - Revalidates expired responses with `If-None-Match`/`If-Modified-Since`;
+///   a 304 restarts the cached response's TTL and returns its stored
+///   document, without downloading or parsing it again
+/// - Waits for the process-wide rate limit and a free per-host request slot
+/// - Records latency, status and retries in the request metrics
+/// - Implements retry logic with exponential backoff for transient failures,
+///   up to `max_retries` retries. Use a low budget for endpoints whose
+///   failures are expected and long-lived to avoid wasted requests and rate
+///   limiting
 /// - Respects Retry-After headers for rate limiting
 /// - Caches successful responses with adaptive TTL based on content
 /// - Provides detailed error handling for various HTTP status codes
@@ -21,43 +72,45 @@ use crate::error::AppError;
 /// # Arguments
 /// * `client` - HTTP client for making requests
 /// * `url` - URL to fetch data from
+/// * `max_retries` - Retries allowed after the first attempt
 ///
 /// # Returns
-/// * `Result<T, AppError>` - Parsed response data or error
+/// * `Result<Value, AppError>` - Parsed response document or error
 #[instrument(skip(client))]
-pub(super) async fn fetch<T: DeserializeOwned>(client: &Client, url: &str) -> Result<T, AppError> {
-    fetch_with_retries(client, url, crate::constants::retry::MAX_ATTEMPTS).await
-}
-
-/// Same as [`fetch`] but with a custom retry budget. Use a low budget for
-/// endpoints whose failures are expected and long-lived (e.g. availability
-/// checks for unpublished tournaments) to avoid wasted requests and rate
-/// limiting.
-#[instrument(skip(client))]
-pub(super) async fn fetch_with_retries<T: DeserializeOwned>(
+pub(crate) async fn fetch_with_retries(
     client: &Client,
     url: &str,
     max_retries: u32,
-) -> Result<T, AppError> {
+) -> Result<Value, AppError> {
     info!("Fetching data from URL: {url}");
 
-    // Check HTTP response cache first
-    if let Some(cached_response) = get_cached_http_response(url).await {
-        debug!("Using cached HTTP response for URL: {url}");
-        match serde_json::from_str::<T>(&cached_response) {
-            Ok(parsed) => return Ok(parsed),
-            Err(e) => {
-                warn!("Failed to parse cached response for URL {}: {}", url, e);
-                // Continue with fresh request if cached response is invalid
-            }
+    // Check HTTP response cache first. Expired responses that carry
+    // validators are kept for a conditional request.
+    let mut stale = None;
+    if let Some((cached, expired)) = get_http_response_for_revalidation(url).await {
+        if !expired {
+            debug!("Using cached HTTP response for URL: {url}");
+            return Ok(cached.document);
+        } else if cached.has_validators() {
+            debug!("Revalidating expired cached response for URL: {url}");
+            stale = Some(cached);
         }
     }
 
     // Handle reqwest errors with retries/backoff for transient failures
     let mut attempt = 0u32;
     let mut backoff = Duration::from_millis(crate::constants::retry::INITIAL_BACKOFF_MS);
-    let response = loop {
-        match client.get(url).send().await {
+    // The permit is held until the body has been read
+    let (response, _permit) = loop {
+        let permit = acquire_request_permit(url).await;
+        let started = Instant::now();
+        let result = conditional_get(client, url, stale.as_ref()).send().await;
+        request_metrics().record_response(
+            url,
+            started.elapsed(),
+            result.as_ref().ok().map(|resp| resp.status()),
+        );
+        match result {
             Ok(resp) => {
                 let status = resp.status();
                 if (status.as_u16() == 429 || status.is_server_error()) && attempt < max_retries {
@@ -77,12 +130,14 @@ pub(super) async fn fetch_with_retries<T: DeserializeOwned>(
                         attempt + 1,
                         max_retries
                     );
+                    drop(permit);
+                    request_metrics().record_retry(url);
                     tokio::time::sleep(wait).await;
                     attempt += 1;
                     backoff = backoff.saturating_mul(2);
                     continue;
                 }
-                break resp;
+                break (resp, permit);
             }
             Err(e) => {
                 if (e.is_timeout() || e.is_connect()) && attempt < max_retries {
@@ -94,6 +149,8 @@ pub(super) async fn fetch_with_retries<T: DeserializeOwned>(
                         attempt + 1,
                         max_retries
                     );
+                    drop(permit);
+                    request_metrics().record_retry(url);
                     tokio::time::sleep(backoff).await;
                     attempt += 1;
                     backoff = backoff.saturating_mul(2);
@@ -117,6 +174,15 @@ pub(super) async fn fetch_with_retries<T: DeserializeOwned>(
     debug!("Response status: {status}");
     debug!("Response headers: {:?}", headers);
 
+    if status == StatusCode::NOT_MODIFIED
+        && let Some(cached) = stale
+    {
+        // Unchanged since it was cached: keep the stored document and its TTL
+        info!("Not modified, extending cached response for URL: {url}");
+        extend_http_response_ttl(url).await;
+        return Ok(cached.document);
+    }
+
     if !status.is_success() {
         let status_code = status.as_u16();
         let reason = status.canonical_reason().unwrap_or("Unknown error");
@@ -189,10 +255,21 @@ pub(super) async fn fetch_with_retries<T: DeserializeOwned>(
         };
 
     // Enhanced JSON parsing with more specific error handling
-    match serde_json::from_str::<T>(&response_text) {
+    match serde_json::from_str::<Value>(&response_text) {
         Ok(parsed) => {
-            // Cache only valid/parsable payloads; move the body (no clone)
-            cache_http_response(url.to_string(), response_text, final_ttl).await;
+            // Cache only valid/parsable payloads, already parsed
+            let header_value = |name| {
+                headers
+                    .get(name)
+                    .and_then(|v| v.to_str().ok())
+                    .map(str::to_string)
+            };
+            let cached = CachedHttpResponse {
+                document: parsed.clone(),
+                etag: header_value(ETAG),
+                last_modified: header_value(LAST_MODIFIED),
+            };
+            cache_http_response_with_validators(url.to_string(), cached, final_ttl).await;
             Ok(parsed)
         }
         Err(e) => {
@@ -219,3 +296,22 @@ pub(super) async fn fetch_with_retries<T: DeserializeOwned>(
         }
     }
 }
+
+/// Builds a GET request, made conditional on the validators of a stale
+/// cached response when there is one.
+fn conditional_get(
+    client: &Client,
+    url: &str,
+    stale: Option<&CachedHttpResponse>,
+) -> RequestBuilder {
+    let mut request = client.get(url);
+    if let Some(cached) = stale {
+        if let Some(etag) = &cached.etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &cached.last_modified {
+            request = request.header(IF_MODIFIED_SINCE, last_modified);
+        }
+    }
+    request
+}
@@ -25,12 +25,12 @@ use std::collections::HashMap;
 use tracing::{debug, error, info, warn};
 
 // Import from sibling modules
+use super::data_source::ApiResource;
 use super::date_logic::parse_date_and_season;
-use super::fetch_utils::fetch;
+use super::fetch_utils::fetch_resource;
 use super::tournament_logic::{
     TournamentType, determine_tournaments_for_month, fetch_tournament_games,
 };
-use super::urls::build_game_url;
 
 /// Helper function to extract team name from a ScheduleTeam, with fallback logic.
 /// Returns the team_name if available, otherwise team_placeholder, or "Unknown" as last resort.
@@ -171,7 +171,11 @@ pub(super) async fn process_single_game(
             play_off_pair: game.play_off_pair,
             play_off_req_wins: game.play_off_req_wins,
             series_score: None,
+            game_id: game.id,
+            season: game.season,
             is_placeholder,
+            pre_game: None,
+            annotations: vec![],
         });
     }
 
@@ -231,7 +235,11 @@ pub(super) async fn process_single_game(
         play_off_pair: game.play_off_pair,
         play_off_req_wins: game.play_off_req_wins,
         series_score: None,
+        game_id: game.id,
+        season: game.season,
         is_placeholder,
+        pre_game: None,
+        annotations: vec![],
     })
 }
 
@@ -341,9 +349,12 @@ async fn resolve_goal_events_with_roster(
             tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
         }
 
-        let game_url = build_game_url(&config.api_domain, game.season, game.id);
+        let resource = ApiResource::GameDetails {
+            season: game.season,
+            game_id: game.id,
+        };
 
-        match fetch::<DetailedGameResponse>(client, &game_url).await {
+        match fetch_resource::<DetailedGameResponse>(client, config, &resource).await {
             Ok(detailed_response) => {
                 debug!(
                     "Game ID {}: fetched roster ({} home, {} away players)",
@@ -468,9 +479,10 @@ pub(super) async fn fetch_game_data(
         return Ok(events);
     }
 
-    let url = build_game_url(&config.api_domain, season, game_id);
+    let resource = ApiResource::GameDetails { season, game_id };
 
-    let game_response: DetailedGameResponse = match fetch(client, &url).await {
+    let game_response: DetailedGameResponse = match fetch_resource(client, config, &resource).await
+    {
         Ok(response) => response,
         Err(e) => {
             error!("Failed to fetch game ID {game_id}: {e}");
@@ -884,9 +896,9 @@ async fn fetch_detailed_game_data_for_historical_game(
     season: i32,
     game_id: i32,
 ) -> DetailedGameData {
-    let url = build_game_url(&config.api_domain, season, game_id);
+    let resource = ApiResource::GameDetails { season, game_id };
 
-    match fetch::<DetailedGameResponse>(client, &url).await {
+    match fetch_resource::<DetailedGameResponse>(client, config, &resource).await {
         Ok(response) => {
             // Process goal events to get scorer information with player lookup
             let goal_events = process_goal_events_for_historical_game_with_players(
@@ -1137,6 +1149,7 @@ mod tests {
             api_domain: "http://localhost:0".to_string(),
             log_file_path: None,
             http_timeout_seconds: 1,
+            ..Default::default()
         }
     }
 
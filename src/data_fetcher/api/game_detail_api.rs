@@ -0,0 +1,38 @@
+// src/data_fetcher/api/game_detail_api.rs
+use crate::config::Config;
+use crate::data_fetcher::api::data_source::ApiResource;
+use crate::data_fetcher::api::fetch_utils::fetch_resource;
+use crate::data_fetcher::api::http_client::http_client_for;
+use crate::data_fetcher::cache::{cache_detailed_game_data, get_cached_detailed_game_data};
+use crate::data_fetcher::models::DetailedGameResponse;
+use crate::error::AppError;
+use tracing::info;
+
+/// Fetches the full detail of a single game (events, rosters) for the game
+/// detail page. Shares the detailed game cache with the game list, so opening
+/// a game that was just listed usually costs no extra request.
+pub async fn fetch_game_details(
+    config: &Config,
+    season: i32,
+    game_id: i32,
+) -> Result<DetailedGameResponse, AppError> {
+    if let Some(cached) = get_cached_detailed_game_data(season, game_id).await {
+        return Ok(cached);
+    }
+
+    info!("Fetching details for game {game_id} (season {season})");
+    let client = http_client_for(config)?;
+    let resource = ApiResource::GameDetails { season, game_id };
+
+    let response: DetailedGameResponse =
+        fetch_resource(&client, config, &resource)
+            .await
+            .map_err(|e| match e {
+                AppError::ApiNotFound { .. } => AppError::api_game_not_found(game_id, season),
+                other => other,
+            })?;
+
+    let is_live = response.game.started && !response.game.ended;
+    cache_detailed_game_data(season, game_id, response.clone(), is_live).await;
+    Ok(response)
+}
@@ -1,28 +1,162 @@
 //! HTTP client creation and configuration utilities
 
-use reqwest::Client;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Certificate, Client, Proxy};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{LazyLock, Mutex};
 use std::time::Duration;
 
+use crate::config::Config;
+use crate::error::AppError;
+
+/// Everything a client is built from. Clients are shared per distinct
+/// settings, so changing e.g. the proxy builds a fresh client.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HttpClientSettings {
+    /// Overall request timeout in seconds
+    pub timeout_seconds: u64,
+    /// Proxy URL for all requests
+    pub proxy: Option<String>,
+    /// PEM file with extra root certificates to trust
+    pub ca_cert_path: Option<String>,
+    /// User-Agent header; defaults to `liiga_teletext/<version>`
+    pub user_agent: Option<String>,
+    /// Headers sent with every request
+    pub extra_headers: BTreeMap<String, String>,
+}
+
+impl HttpClientSettings {
+    /// Default network settings with the given timeout.
+    pub fn with_timeout(timeout_seconds: u64) -> Self {
+        Self {
+            timeout_seconds,
+            proxy: None,
+            ca_cert_path: None,
+            user_agent: None,
+            extra_headers: BTreeMap::new(),
+        }
+    }
+
+    /// The network settings of `config`.
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            timeout_seconds: config.http_timeout_seconds,
+            proxy: config.http_proxy.clone(),
+            ca_cert_path: config.ca_cert_path.clone(),
+            user_agent: config.user_agent.clone(),
+            extra_headers: config.extra_headers.clone(),
+        }
+    }
+
+    fn proxy(&self) -> Result<Option<Proxy>, AppError> {
+        self.proxy
+            .as_deref()
+            .map(|url| {
+                Proxy::all(url)
+                    .map_err(|e| AppError::config_error(format!("Invalid proxy URL '{url}': {e}")))
+            })
+            .transpose()
+    }
+
+    fn root_certificates(&self) -> Result<Vec<Certificate>, AppError> {
+        let Some(path) = &self.ca_cert_path else {
+            return Ok(Vec::new());
+        };
+        let pem = std::fs::read(path).map_err(|e| {
+            AppError::config_error(format!("Cannot read CA certificate file '{path}': {e}"))
+        })?;
+        Certificate::from_pem_bundle(&pem).map_err(|e| {
+            AppError::config_error(format!("Invalid CA certificate file '{path}': {e}"))
+        })
+    }
+
+    fn default_headers(&self) -> Result<HeaderMap, AppError> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.extra_headers {
+            let header_name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| AppError::config_error(format!("Invalid header name '{name}'")))?;
+            let header_value = HeaderValue::from_str(value).map_err(|_| {
+                AppError::config_error(format!("Invalid value for header '{name}'"))
+            })?;
+            headers.insert(header_name, header_value);
+        }
+        Ok(headers)
+    }
+}
+
+/// Clients shared by the whole process, keyed by their settings.
+/// Cloning a `Client` shares its connection pool.
+static HTTP_CLIENTS: LazyLock<Mutex<HashMap<HttpClientSettings, Client>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
 /// Creates a properly configured HTTP client with connection pooling and timeout handling.
 /// This follows the coding guidelines for HTTP client usage with proper timeout handling,
 /// connection pooling, and HTTP/2 multiplexing when available.
 ///
 /// # Returns
-/// * `Result<Client, reqwest::Error>` - A configured reqwest HTTP client or error
+/// * `Result<Client, AppError>` - A configured reqwest HTTP client or error
 ///
 /// # Features
 /// * Configurable timeout for requests (default: 30 seconds, configurable via config/env)
 /// * Connection pooling with centralized pool size configuration
 /// * HTTP/2 multiplexing when available
+/// * Optional proxy, extra root certificates, User-Agent and extra headers
 /// * Automatic retry logic for transient failures (implemented in fetch function)
-pub fn create_http_client_with_timeout(timeout_seconds: u64) -> Result<Client, reqwest::Error> {
-    Client::builder()
+pub fn build_http_client(settings: &HttpClientSettings) -> Result<Client, AppError> {
+    let user_agent = settings
+        .user_agent
+        .as_deref()
+        .unwrap_or(crate::constants::DEFAULT_USER_AGENT);
+    let mut builder = Client::builder()
         .connect_timeout(Duration::from_secs(
             crate::constants::DEFAULT_HTTP_CONNECT_TIMEOUT_SECONDS,
         ))
-        .timeout(Duration::from_secs(timeout_seconds))
+        .timeout(Duration::from_secs(settings.timeout_seconds))
         .pool_max_idle_per_host(crate::constants::HTTP_POOL_MAX_IDLE_PER_HOST)
-        .build()
+        .user_agent(user_agent)
+        .default_headers(settings.default_headers()?)
+        .tls_certs_merge(settings.root_certificates()?);
+    if let Some(proxy) = settings.proxy()? {
+        builder = builder.proxy(proxy);
+    }
+    Ok(builder.build()?)
+}
+
+/// Creates an HTTP client with default network settings and the given timeout.
+#[allow(dead_code)] // Used in tests
+pub fn create_http_client_with_timeout(timeout_seconds: u64) -> Result<Client, AppError> {
+    build_http_client(&HttpClientSettings::with_timeout(timeout_seconds))
+}
+
+/// Returns the process-wide HTTP client for `settings`, creating it on first
+/// use. Reusing the client keeps its connection pool warm between refreshes
+/// instead of reconnecting on every fetch.
+///
+/// # Arguments
+/// * `settings` - Timeout and network settings of the client
+///
+/// # Returns
+/// * `Result<Client, AppError>` - A shared client or a build error
+pub fn shared_http_client(settings: &HttpClientSettings) -> Result<Client, AppError> {
+    let mut clients = HTTP_CLIENTS.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(client) = clients.get(settings) {
+        return Ok(client.clone());
+    }
+    let client = build_http_client(settings)?;
+    clients.insert(settings.clone(), client.clone());
+    Ok(client)
+}
+
+/// Returns the shared HTTP client for `config` and applies its request
+/// limits (concurrent requests per host, requests per second) and API
+/// endpoint list.
+pub fn http_client_for(config: &Config) -> Result<Client, AppError> {
+    super::rate_limit::configure(
+        config.max_concurrent_requests,
+        config.max_requests_per_second,
+    );
+    super::endpoint_health::endpoint_health().configure(&config.api_domains());
+    shared_http_client(&HttpClientSettings::from_config(config))
 }
 
 /// Creates an HTTP client for testing with default timeout
@@ -31,3 +165,96 @@ pub fn create_test_http_client() -> Client {
     create_http_client_with_timeout(crate::constants::DEFAULT_HTTP_TIMEOUT_SECONDS)
         .expect("Failed to create test HTTP client")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn test_shared_clients_are_registered_per_settings() {
+        let short = HttpClientSettings::with_timeout(41);
+        let long = HttpClientSettings::with_timeout(42);
+        shared_http_client(&short).unwrap();
+        shared_http_client(&short).unwrap();
+        shared_http_client(&long).unwrap();
+
+        let clients = HTTP_CLIENTS.lock().unwrap();
+        assert!(clients.contains_key(&short));
+        assert!(clients.contains_key(&long));
+    }
+
+    #[tokio::test]
+    async fn test_user_agent_and_extra_headers_are_sent() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/headers"))
+            .and(header("user-agent", "corporate-agent/1.0"))
+            .and(header("x-api-key", "secret"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let settings = HttpClientSettings {
+            user_agent: Some("corporate-agent/1.0".to_string()),
+            extra_headers: BTreeMap::from([("X-Api-Key".to_string(), "secret".to_string())]),
+            ..HttpClientSettings::with_timeout(5)
+        };
+        let client = build_http_client(&settings).unwrap();
+        let response = client
+            .get(format!("{}/headers", server.uri()))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn test_requests_go_through_proxy() {
+        // A plain-HTTP proxy receives the absolute target URL
+        let proxy = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("via proxy"))
+            .expect(1)
+            .mount(&proxy)
+            .await;
+
+        let settings = HttpClientSettings {
+            proxy: Some(proxy.uri()),
+            ..HttpClientSettings::with_timeout(5)
+        };
+        let client = build_http_client(&settings).unwrap();
+        let body = client
+            .get("http://liiga.invalid/games")
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        assert_eq!(body, "via proxy");
+    }
+
+    #[test]
+    fn test_invalid_settings_are_config_errors() {
+        let bad_header = HttpClientSettings {
+            extra_headers: BTreeMap::from([("Bad Header".to_string(), "x".to_string())]),
+            ..HttpClientSettings::with_timeout(5)
+        };
+        assert!(matches!(
+            build_http_client(&bad_header),
+            Err(AppError::Config(_))
+        ));
+
+        let missing_ca = HttpClientSettings {
+            ca_cert_path: Some("/nonexistent/ca.pem".to_string()),
+            ..HttpClientSettings::with_timeout(5)
+        };
+        assert!(matches!(
+            build_http_client(&missing_ca),
+            Err(AppError::Config(_))
+        ));
+    }
+}
@@ -0,0 +1,222 @@
+//! Process-wide request metrics for the diagnostics page
+//!
+//! `fetch_with_retries` records every HTTP attempt here: its latency up to
+//! the response headers, its status, and whether it was retried. Metrics are
+//! grouped by API endpoint (see [`endpoint_label`]) and kept in memory only.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+/// How many recent latencies per endpoint the percentiles are computed from
+const LATENCY_SAMPLES: usize = 256;
+
+/// Short name of the API endpoint `url` belongs to: `games` (a day's games),
+/// `game` (one game's details), `schedule`, `standings` or `other`.
+pub fn endpoint_label(url: &str) -> &'static str {
+    let path = url.split('?').next().unwrap_or(url);
+    if path.contains("/schedule") {
+        "schedule"
+    } else if path.contains("/standings") {
+        "standings"
+    } else if path.contains("/games/") {
+        "game"
+    } else if path.ends_with("/games") {
+        "games"
+    } else {
+        "other"
+    }
+}
+
+/// Counters of one endpoint.
+#[derive(Debug, Default)]
+struct EndpointCounters {
+    requests: u64,
+    errors: u64,
+    retries: u64,
+    rate_limited: u64,
+    not_modified: u64,
+    latencies: VecDeque<Duration>,
+}
+
+/// Summary of one endpoint's requests since startup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointMetrics {
+    pub endpoint: &'static str,
+    /// HTTP attempts, retries included
+    pub requests: u64,
+    /// Attempts that failed to connect or got an error status
+    pub errors: u64,
+    /// Attempts repeated after a transient failure
+    pub retries: u64,
+    /// 429 Too Many Requests responses
+    pub rate_limited: u64,
+    /// 304 Not Modified responses to revalidations
+    pub not_modified: u64,
+    pub p50: Option<Duration>,
+    pub p90: Option<Duration>,
+    pub p99: Option<Duration>,
+}
+
+/// Request counters and latencies grouped by endpoint.
+#[derive(Debug, Default)]
+pub struct RequestMetrics {
+    endpoints: Mutex<BTreeMap<&'static str, EndpointCounters>>,
+}
+
+impl RequestMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_endpoint(&self, url: &str, update: impl FnOnce(&mut EndpointCounters)) {
+        let mut endpoints = self.endpoints.lock().unwrap_or_else(|e| e.into_inner());
+        update(endpoints.entry(endpoint_label(url)).or_default());
+    }
+
+    /// Records one HTTP attempt; `status` is `None` if no response arrived.
+    pub fn record_response(&self, url: &str, latency: Duration, status: Option<StatusCode>) {
+        self.with_endpoint(url, |counters| {
+            counters.requests += 1;
+            match status {
+                Some(StatusCode::NOT_MODIFIED) => counters.not_modified += 1,
+                Some(status) if status.is_success() => {}
+                Some(status) => {
+                    counters.errors += 1;
+                    if status == StatusCode::TOO_MANY_REQUESTS {
+                        counters.rate_limited += 1;
+                    }
+                }
+                None => counters.errors += 1,
+            }
+            if counters.latencies.len() == LATENCY_SAMPLES {
+                counters.latencies.pop_front();
+            }
+            counters.latencies.push_back(latency);
+        });
+    }
+
+    /// Records that the last attempt for `url` is being retried.
+    pub fn record_retry(&self, url: &str) {
+        self.with_endpoint(url, |counters| counters.retries += 1);
+    }
+
+    /// Per-endpoint summaries in endpoint name order.
+    pub fn snapshot(&self) -> Vec<EndpointMetrics> {
+        let endpoints = self.endpoints.lock().unwrap_or_else(|e| e.into_inner());
+        endpoints
+            .iter()
+            .map(|(endpoint, counters)| {
+                let mut sorted: Vec<Duration> = counters.latencies.iter().copied().collect();
+                sorted.sort_unstable();
+                EndpointMetrics {
+                    endpoint,
+                    requests: counters.requests,
+                    errors: counters.errors,
+                    retries: counters.retries,
+                    rate_limited: counters.rate_limited,
+                    not_modified: counters.not_modified,
+                    p50: percentile(&sorted, 50),
+                    p90: percentile(&sorted, 90),
+                    p99: percentile(&sorted, 99),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Nearest-rank percentile of already sorted samples.
+fn percentile(sorted: &[Duration], pct: usize) -> Option<Duration> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = (pct * sorted.len()).div_ceil(100).max(1);
+    sorted.get(rank - 1).copied()
+}
+
+static METRICS: LazyLock<RequestMetrics> = LazyLock::new(RequestMetrics::new);
+
+/// The metrics recorded by all API requests of this process.
+pub fn request_metrics() -> &'static RequestMetrics {
+    &METRICS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint_labels() {
+        assert_eq!(
+            endpoint_label("https://api/games?tournament=runkosarja&date=2025-10-18"),
+            "games"
+        );
+        assert_eq!(endpoint_label("https://api/games/2026/42"), "game");
+        assert_eq!(
+            endpoint_label("https://api/schedule?tournament=playoffs&week=1&season=2026"),
+            "schedule"
+        );
+        assert_eq!(
+            endpoint_label("https://api/standings/?season=2026"),
+            "standings"
+        );
+        assert_eq!(endpoint_label("https://api/teams"), "other");
+    }
+
+    #[test]
+    fn test_counts_by_status() {
+        let metrics = RequestMetrics::new();
+        let url = "https://api/games/2026/42";
+        let ms = Duration::from_millis;
+        metrics.record_response(url, ms(10), Some(StatusCode::OK));
+        metrics.record_response(url, ms(10), Some(StatusCode::NOT_MODIFIED));
+        metrics.record_response(url, ms(10), Some(StatusCode::TOO_MANY_REQUESTS));
+        metrics.record_retry(url);
+        metrics.record_response(url, ms(10), None);
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.len(), 1);
+        let game = &snapshot[0];
+        assert_eq!(game.endpoint, "game");
+        assert_eq!(game.requests, 4);
+        assert_eq!(game.errors, 2);
+        assert_eq!(game.rate_limited, 1);
+        assert_eq!(game.not_modified, 1);
+        assert_eq!(game.retries, 1);
+    }
+
+    #[test]
+    fn test_latency_percentiles() {
+        let metrics = RequestMetrics::new();
+        for ms in (1..=100).rev() {
+            metrics.record_response(
+                "https://api/standings/?season=2026",
+                Duration::from_millis(ms),
+                Some(StatusCode::OK),
+            );
+        }
+
+        let standings = &metrics.snapshot()[0];
+        assert_eq!(standings.p50, Some(Duration::from_millis(50)));
+        assert_eq!(standings.p90, Some(Duration::from_millis(90)));
+        assert_eq!(standings.p99, Some(Duration::from_millis(99)));
+    }
+
+    #[test]
+    fn test_latency_window_keeps_recent_samples() {
+        let metrics = RequestMetrics::new();
+        let url = "https://api/teams";
+        for _ in 0..LATENCY_SAMPLES {
+            metrics.record_response(url, Duration::from_secs(5), Some(StatusCode::OK));
+        }
+        for _ in 0..LATENCY_SAMPLES {
+            metrics.record_response(url, Duration::from_millis(5), Some(StatusCode::OK));
+        }
+
+        let other = &metrics.snapshot()[0];
+        assert_eq!(other.requests, 2 * LATENCY_SAMPLES as u64);
+        assert_eq!(other.p99, Some(Duration::from_millis(5)));
+    }
+}
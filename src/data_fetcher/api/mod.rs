@@ -1,11 +1,19 @@
 #[cfg(test)]
 mod api_tests;
 pub mod bracket_api;
+// Library API for embedders; the binary itself always fetches over HTTP
+#[allow(dead_code)]
+pub mod data_source;
 pub mod date_logic;
+pub mod endpoint_health;
 mod fetch_utils;
 mod game_api;
+pub mod game_detail_api;
 pub mod http_client;
+pub mod metrics;
+pub mod off_season_api;
 pub mod orchestrator;
+pub mod rate_limit;
 pub mod season_schedule;
 pub mod season_utils;
 pub mod standings_api;
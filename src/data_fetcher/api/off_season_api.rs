@@ -0,0 +1,42 @@
+use crate::config::Config;
+use crate::data_fetcher::api::http_client::http_client_for;
+use crate::data_fetcher::api::standings_api::fetch_season_standings;
+use crate::data_fetcher::api::tournament_logic::{TournamentType, fetch_tournament_games};
+use crate::data_fetcher::models::bracket::build_playoff_bracket;
+use crate::data_fetcher::processors::off_season::{
+    OffSeasonInfo, build_off_season_info, finished_season,
+};
+use crate::error::AppError;
+use chrono::Local;
+use tracing::{info, warn};
+
+/// Fetches the off-season page data: the coming season's regular season and
+/// preseason schedules, and the finished season's playoffs and final table.
+/// Schedules or standings the API doesn't have yet are left empty.
+pub async fn fetch_off_season_info(config: &Config) -> Result<OffSeasonInfo, AppError> {
+    let client = http_client_for(config)?;
+    let today = Local::now().date_naive();
+    let finished = finished_season(today);
+    info!("Fetching off-season data: season {finished} and the next");
+
+    let (upcoming, playoffs, standings) = tokio::join!(
+        fetch_tournament_games(
+            &client,
+            config,
+            &[
+                TournamentType::Runkosarja,
+                TournamentType::ValmistavatOttelut
+            ],
+            finished + 1,
+        ),
+        fetch_tournament_games(&client, config, &[TournamentType::Playoffs], finished),
+        fetch_season_standings(config, finished),
+    );
+    let standings = standings.unwrap_or_else(|e| {
+        warn!("Failed to fetch final standings of season {finished}: {e}");
+        Vec::new()
+    });
+    let bracket = build_playoff_bracket(&playoffs, &format!("{}-{finished}", finished - 1));
+
+    Ok(build_off_season_info(today, &upcoming, &bracket, standings))
+}
@@ -2,12 +2,14 @@
 
 use crate::config::Config;
 use crate::data_fetcher::cache::persistence::PLAYER_NAME_STORE;
-use crate::data_fetcher::models::GameData;
+use crate::data_fetcher::models::{GameData, ScheduleApiGame};
 use crate::error::AppError;
 use tracing::{info, instrument, warn};
 
 // HTTP client utilities available from sibling http_client module
-use super::http_client::create_http_client_with_timeout;
+use super::http_client::http_client_for;
+// The active data source decides whether the API domain is needed
+use super::data_source::data_source_needs_api_domain;
 // Date and season logic available from sibling date_logic module
 use super::date_logic::{determine_fetch_date, parse_date_and_season};
 // Season utilities available from sibling season_utils module
@@ -15,10 +17,52 @@ use super::season_utils::{is_historical_date, should_use_schedule_for_playoffs};
 // Game-specific API operations available from sibling game_api module
 use super::game_api::{fetch_historical_games, process_games};
 // Tournament logic available from sibling tournament_logic module
-use super::tournament_logic::build_tournament_list;
+use super::tournament_logic::{
+    TournamentType, build_tournament_list, determine_tournaments_for_month, fetch_tournament_games,
+};
+// Pre-game standings for upcoming games available from sibling standings_api module
+use super::standings_api::fetch_standings;
+use crate::data_fetcher::cache::{
+    cache_pre_game_standings, cache_season_schedule, create_pre_game_standings_key,
+    create_season_schedule_key, get_cached_goal_events_data, get_cached_pre_game_standings,
+    get_cached_season_schedule,
+};
+use crate::data_fetcher::models::standings::StandingsEntry;
+use crate::data_fetcher::processors::annotations::{ScorerHistory, annotate_games};
+use crate::data_fetcher::processors::playoff_series::calculate_series_scores;
+use crate::data_fetcher::processors::pre_game::add_pre_game_info;
+use crate::teletext_ui::ScoreType;
+use reqwest::Client;
 // Tournament-specific API operations available from sibling tournament_api module
 use super::tournament_api::{determine_return_date, fetch_day_data, handle_no_games_found};
 
+/// `LIIGA_API_DOMAIN` when it is set to a value CI uses to block network
+/// calls ("", "placeholder", "test" or "unset").
+fn placeholder_api_domain() -> Option<String> {
+    std::env::var("LIIGA_API_DOMAIN")
+        .ok()
+        .filter(|domain| ["", "placeholder", "test", "unset"].contains(&domain.as_str()))
+}
+
+/// The configuration for lookups made while a page is being built (e.g. the
+/// season countdown), or `None` when making them would need the API domain
+/// prompt or is blocked by a placeholder `LIIGA_API_DOMAIN`.
+pub async fn load_background_config() -> Option<Config> {
+    if !data_source_needs_api_domain() {
+        return Some(Config::load_or_default().await);
+    }
+    if placeholder_api_domain().is_some() {
+        return None;
+    }
+    match Config::load_if_configured().await {
+        Ok(config) => config,
+        Err(e) => {
+            warn!("Skipping background lookups, config failed to load: {e}");
+            None
+        }
+    }
+}
+
 /// Main API entry point that orchestrates the fetching of Liiga game data.
 ///
 /// This function coordinates between multiple specialized modules to:
@@ -54,14 +98,13 @@ pub async fn fetch_liiga_data(
 ) -> Result<(Vec<GameData>, String), AppError> {
     info!("Starting to fetch Liiga data");
 
+    // Only the HTTP API needs the API domain; other sources use the rest of
+    // the configuration without prompting for it
+    let needs_api_domain = data_source_needs_api_domain();
+
     // Early check: prevent network calls if API domain is not properly configured
     // This prevents CI hangs when LIIGA_API_DOMAIN is unset or invalid
-    if let Ok(api_domain) = std::env::var("LIIGA_API_DOMAIN")
-        && (api_domain.is_empty()
-            || api_domain == "placeholder"
-            || api_domain == "test"
-            || api_domain == "unset")
-    {
+    if needs_api_domain && let Some(api_domain) = placeholder_api_domain() {
         warn!(
             "LIIGA_API_DOMAIN is set to '{}' - skipping network calls to prevent CI hangs",
             api_domain
@@ -71,9 +114,13 @@ pub async fn fetch_liiga_data(
         ));
     }
 
-    let config = Config::load().await?;
+    let config = if needs_api_domain {
+        Config::load().await?
+    } else {
+        Config::load_or_default().await
+    };
     info!("Config loaded successfully");
-    let client = create_http_client_with_timeout(config.http_timeout_seconds)?;
+    let client = http_client_for(&config)?;
 
     // Determine the date to fetch data for
     let (date, is_pre_noon_cutoff) = determine_fetch_date(custom_date);
@@ -100,7 +147,10 @@ pub async fn fetch_liiga_data(
             },
             date
         );
-        let historical_games = fetch_historical_games(&client, &config, &date).await?;
+        let mut historical_games = fetch_historical_games(&client, &config, &date).await?;
+        let schedule = fetch_season_schedule(&client, &config, &historical_games, &date).await;
+        add_pre_game_data(&config, &mut historical_games, &schedule, &date).await;
+        add_annotations(&mut historical_games, &schedule).await;
         PLAYER_NAME_STORE.save_to_disk().await;
         return Ok((historical_games, date));
     }
@@ -142,10 +192,13 @@ pub async fn fetch_liiga_data(
     };
 
     // Process games if we found any
-    let all_games = process_games(&client, &config, response_data).await?;
+    let mut all_games = process_games(&client, &config, response_data).await?;
 
     // Determine the appropriate date to return
     let return_date = determine_return_date(&all_games, earliest_date.clone(), &date);
+    let schedule = fetch_season_schedule(&client, &config, &all_games, &return_date).await;
+    add_pre_game_data(&config, &mut all_games, &schedule, &return_date).await;
+    add_annotations(&mut all_games, &schedule).await;
 
     if all_games.is_empty() {
         info!("No games found after processing all data");
@@ -170,3 +223,252 @@ pub async fn fetch_liiga_data(
 
     Ok((all_games, return_date))
 }
+
+fn is_upcoming(game: &GameData) -> bool {
+    matches!(game.score_type, ScoreType::Scheduled) && !game.is_placeholder
+}
+
+fn is_finished(game: &GameData) -> bool {
+    matches!(game.score_type, ScoreType::Final)
+}
+
+/// Whether `game` needs the season schedule: upcoming games for their
+/// pre-game info, finished games with goals for first goals of the season.
+fn needs_schedule(game: &GameData) -> bool {
+    is_upcoming(game) || (is_finished(game) && !game.goal_events.is_empty())
+}
+
+/// Whether `schedule` lists every upcoming and finished game of `games` in
+/// its current state, i.e. none of them has ended since it was fetched.
+fn schedule_is_current(schedule: &[ScheduleApiGame], games: &[GameData]) -> bool {
+    games.iter().filter(|g| needs_schedule(g)).all(|g| {
+        schedule
+            .iter()
+            .any(|s| s.id == g.game_id && s.ended == is_finished(g))
+    })
+}
+
+/// The season schedule of `date`'s tournaments, shared by the pre-game info
+/// and the annotations. Empty without fetching when no game of `games` needs
+/// it, or when the schedule cannot be fetched.
+///
+/// The schedule is kept per season, so refreshes reuse it until one of
+/// `games` ends or the cached copy expires.
+async fn fetch_season_schedule(
+    client: &Client,
+    config: &Config,
+    games: &[GameData],
+    date: &str,
+) -> Vec<ScheduleApiGame> {
+    if !games.iter().any(needs_schedule) {
+        return Vec::new();
+    }
+    let (_, month, season) = parse_date_and_season(date);
+    let tournaments = determine_tournaments_for_month(month);
+    let names: Vec<&str> = tournaments.iter().map(TournamentType::as_str).collect();
+    let key = create_season_schedule_key(&config.api_domain, season, &names);
+    if let Some(schedule) = get_cached_season_schedule(&key).await
+        && schedule_is_current(&schedule, games)
+    {
+        return schedule;
+    }
+
+    let schedule = fetch_tournament_games(client, config, &tournaments, season).await;
+    // A failed fetch is retried on the next refresh
+    if !schedule.is_empty() {
+        cache_season_schedule(key, schedule.clone()).await;
+    }
+    schedule
+}
+
+/// Adds standings, form, head-to-head and playoff series state to the
+/// scheduled games of `date`, using the season `schedule` fetched once by
+/// [`fetch_season_schedule`] for both this and the annotations.
+///
+/// Pre-game info is decoration: when the standings cannot be fetched, or
+/// `schedule` is empty because it could not be, the games are returned
+/// without the parts that need them.
+async fn add_pre_game_data(
+    config: &Config,
+    games: &mut [GameData],
+    schedule: &[ScheduleApiGame],
+    date: &str,
+) {
+    if !games.iter().any(is_upcoming) {
+        return;
+    }
+
+    let standings = pre_game_standings(config, schedule).await;
+
+    if games
+        .iter()
+        .any(|g| g.play_off_phase.is_some() && g.series_score.is_none())
+    {
+        calculate_series_scores(schedule, games, date);
+    }
+    add_pre_game_info(games, &standings, schedule);
+}
+
+/// The standings shown with upcoming games. The table only moves when a
+/// game ends, so it is fetched again only once `schedule` lists more ended
+/// games (or after an hour); without a schedule it is fetched every time.
+async fn pre_game_standings(config: &Config, schedule: &[ScheduleApiGame]) -> Vec<StandingsEntry> {
+    let key = (!schedule.is_empty()).then(|| {
+        let ended = schedule.iter().filter(|s| s.ended).count();
+        create_pre_game_standings_key(&config.api_domain, ended)
+    });
+    if let Some(key) = &key
+        && let Some(standings) = get_cached_pre_game_standings(key).await
+    {
+        return standings;
+    }
+
+    match fetch_standings(config, false).await {
+        Ok((standings, _)) => {
+            if let Some(key) = key {
+                cache_pre_game_standings(key, standings.clone()).await;
+            }
+            standings
+        }
+        Err(e) => {
+            warn!("Failed to fetch standings for pre-game info: {e}");
+            Vec::new()
+        }
+    }
+}
+
+/// Runs the annotation rules on the finished games.
+///
+/// First goals of the season are only noted for teams whose every earlier
+/// game this season still has its goal events in the cache.
+async fn add_annotations(games: &mut [GameData], schedule: &[ScheduleApiGame]) {
+    if !games.iter().any(is_finished) {
+        return;
+    }
+
+    let mut history = ScorerHistory::default();
+    // Without a schedule a season opener can't be told from a failed fetch
+    if !schedule.is_empty() {
+        for game in games.iter().filter(|g| is_finished(g)) {
+            for team in [&game.home_team, &game.away_team] {
+                let earlier = schedule.iter().filter(|s| {
+                    s.ended
+                        && s.start < game.start
+                        && (&s.home_team_name == team || &s.away_team_name == team)
+                });
+                let mut complete = true;
+                for earlier_game in earlier {
+                    match get_cached_goal_events_data(earlier_game.season, earlier_game.id).await {
+                        Some(events) => history.add_earlier_game(&events),
+                        None => complete = false,
+                    }
+                }
+                if complete {
+                    history.mark_complete(team);
+                }
+            }
+        }
+    }
+
+    annotate_games(games, &history);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_fetcher::cache::{clear_http_response_cache, clear_pre_game_standings_cache};
+    use crate::testing_utils::TestDataBuilder;
+    use serial_test::serial;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn scheduled(id: i32, ended: bool) -> ScheduleApiGame {
+        ScheduleApiGame {
+            id,
+            season: 2025,
+            start: "2025-01-15T18:30:00Z".to_string(),
+            home_team_name: "Tappara".to_string(),
+            away_team_name: "HIFK".to_string(),
+            serie: 1,
+            finished_type: None,
+            started: ended,
+            ended,
+            game_time: None,
+            play_off_phase: None,
+            play_off_pair: None,
+            play_off_req_wins: None,
+            home_team_goals: 0,
+            away_team_goals: 0,
+        }
+    }
+
+    #[test]
+    fn test_schedule_is_stale_once_a_game_ends() {
+        let mut game = TestDataBuilder::create_basic_game("Tappara", "HIFK");
+        game.game_id = 7;
+        game.score_type = ScoreType::Scheduled;
+        let schedule = vec![scheduled(7, false)];
+        assert!(schedule_is_current(&schedule, std::slice::from_ref(&game)));
+
+        // A final without goals needs no schedule for its annotations
+        game.score_type = ScoreType::Final;
+        assert!(!needs_schedule(&game));
+        game.goal_events = vec![TestDataBuilder::create_goal_event("Koivu", 12, 1, 0, true)];
+        assert!(needs_schedule(&game));
+        assert!(!schedule_is_current(&schedule, std::slice::from_ref(&game)));
+        assert!(schedule_is_current(&[scheduled(7, true)], &[game.clone()]));
+
+        // A game the cached schedule does not list yet
+        game.game_id = 8;
+        assert!(!schedule_is_current(&[scheduled(7, true)], &[game]));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_pre_game_standings_fetched_again_only_when_a_game_ends() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/standings/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "season": [{
+                    "teamId": "t1",
+                    "teamName": "Tappara",
+                    "ranking": 1,
+                    "liveRanking": 1,
+                    "games": 0,
+                    "wins": 0,
+                    "overtimeWins": 0,
+                    "losses": 0,
+                    "overtimeLosses": 0,
+                    "points": 0,
+                    "livePoints": 0,
+                    "goals": 0,
+                    "goalsAgainst": 0,
+                    "liveGoals": 0,
+                    "liveGoalsAgainst": 0
+                }],
+                "playoffsLines": [4, 12]
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+        let config = Config {
+            api_domain: server.uri(),
+            http_timeout_seconds: 1,
+            ..Default::default()
+        };
+        clear_pre_game_standings_cache().await;
+
+        // The HTTP cache is cleared between calls so only the memo can
+        // spare a request
+        let one_ended = [scheduled(1, true), scheduled(2, false)];
+        for _ in 0..2 {
+            clear_http_response_cache().await;
+            assert_eq!(pre_game_standings(&config, &one_ended).await.len(), 1);
+        }
+
+        clear_http_response_cache().await;
+        let two_ended = [scheduled(1, true), scheduled(2, true)];
+        assert_eq!(pre_game_standings(&config, &two_ended).await.len(), 1);
+    }
+}
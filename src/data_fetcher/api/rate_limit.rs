@@ -0,0 +1,231 @@
+//! Process-wide limits for outgoing API requests
+//!
+//! Every HTTP request first takes a token from a shared token bucket, which
+//! caps the request rate, and then a permit from its host's semaphore, which
+//! caps how many requests to one host are in flight at once. The limits come
+//! from `Config` and are applied by [`configure`].
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex, RwLock};
+use std::time::Duration;
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::time::Instant;
+use tracing::debug;
+
+/// Token bucket holding up to `capacity` tokens, refilled at `rate` tokens
+/// per second. Tokens may go negative: each taker reserves its token and
+/// waits until the bucket would have refilled it.
+#[derive(Debug)]
+struct TokenBucket {
+    capacity: f64,
+    rate: f64,
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate: u32, now: Instant) -> Self {
+        let rate = f64::from(rate.max(1));
+        Self {
+            capacity: rate,
+            rate,
+            tokens: rate,
+            updated_at: now,
+        }
+    }
+
+    /// Takes one token and returns how long the caller must wait before
+    /// using it.
+    fn reserve(&mut self, now: Instant) -> Duration {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+        self.updated_at = now;
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.rate)
+        }
+    }
+}
+
+/// Rate and per-host concurrency limits shared by all requests.
+#[derive(Debug)]
+pub struct RequestLimiter {
+    max_per_host: usize,
+    max_per_second: u32,
+    bucket: Mutex<TokenBucket>,
+    hosts: Mutex<HashMap<String, Arc<Semaphore>>>,
+}
+
+/// Held while a request is in flight; dropping it frees the host's slot.
+#[derive(Debug)]
+pub struct RequestPermit {
+    _permit: OwnedSemaphorePermit,
+}
+
+impl RequestLimiter {
+    pub fn new(max_per_host: usize, max_per_second: u32) -> Self {
+        Self {
+            max_per_host: max_per_host.max(1),
+            max_per_second: max_per_second.max(1),
+            bucket: Mutex::new(TokenBucket::new(max_per_second, Instant::now())),
+            hosts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn has_limits(&self, max_per_host: usize, max_per_second: u32) -> bool {
+        self.max_per_host == max_per_host.max(1) && self.max_per_second == max_per_second.max(1)
+    }
+
+    /// Number of requests currently holding a host slot.
+    pub fn in_flight(&self) -> usize {
+        let hosts = self.hosts.lock().unwrap_or_else(|e| e.into_inner());
+        hosts
+            .values()
+            .map(|semaphore| self.max_per_host - semaphore.available_permits())
+            .sum()
+    }
+
+    /// Waits for a rate-limit token and a free slot for the host of `url`.
+    pub async fn acquire(&self, url: &str) -> RequestPermit {
+        let wait = self
+            .bucket
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .reserve(Instant::now());
+        if !wait.is_zero() {
+            debug!("Rate limit reached, delaying request to {url} by {wait:?}");
+            tokio::time::sleep(wait).await;
+        }
+
+        let semaphore = {
+            let mut hosts = self.hosts.lock().unwrap_or_else(|e| e.into_inner());
+            Arc::clone(
+                hosts
+                    .entry(host_key(url))
+                    .or_insert_with(|| Arc::new(Semaphore::new(self.max_per_host))),
+            )
+        };
+        let permit = semaphore
+            .acquire_owned()
+            .await
+            .expect("request semaphores are never closed");
+        RequestPermit { _permit: permit }
+    }
+}
+
+/// `host[:port]` of `url`, or the whole string if it does not parse.
+fn host_key(url: &str) -> String {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) => match (parsed.host_str(), parsed.port()) {
+            (Some(host), Some(port)) => format!("{host}:{port}"),
+            (Some(host), None) => host.to_string(),
+            (None, _) => url.to_string(),
+        },
+        Err(_) => url.to_string(),
+    }
+}
+
+static LIMITER: LazyLock<RwLock<Arc<RequestLimiter>>> = LazyLock::new(|| {
+    RwLock::new(Arc::new(RequestLimiter::new(
+        crate::constants::DEFAULT_MAX_CONCURRENT_REQUESTS_PER_HOST,
+        crate::constants::DEFAULT_MAX_REQUESTS_PER_SECOND,
+    )))
+});
+
+/// Applies new limits to subsequent requests. Requests already holding a
+/// permit finish under the limits they started with.
+pub fn configure(max_per_host: usize, max_per_second: u32) {
+    let mut limiter = LIMITER.write().unwrap_or_else(|e| e.into_inner());
+    if !limiter.has_limits(max_per_host, max_per_second) {
+        debug!("Request limits: {max_per_host} per host, {max_per_second} per second");
+        *limiter = Arc::new(RequestLimiter::new(max_per_host, max_per_second));
+    }
+}
+
+/// Waits until a request to `url` is allowed by the process-wide limits.
+pub(super) async fn acquire(url: &str) -> RequestPermit {
+    let limiter = Arc::clone(&LIMITER.read().unwrap_or_else(|e| e.into_inner()));
+    limiter.acquire(url).await
+}
+
+/// Number of API requests in flight right now.
+pub fn in_flight_requests() -> usize {
+    LIMITER
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .in_flight()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_bucket_allows_burst_then_spaces_requests() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(2, start);
+
+        assert_eq!(bucket.reserve(start), Duration::ZERO);
+        assert_eq!(bucket.reserve(start), Duration::ZERO);
+        assert_eq!(bucket.reserve(start), Duration::from_millis(500));
+        assert_eq!(bucket.reserve(start), Duration::from_secs(1));
+
+        // Two seconds later the debt is paid off and the bucket is full again
+        let later = start + Duration::from_secs(2);
+        assert_eq!(bucket.reserve(later), Duration::ZERO);
+        assert_eq!(bucket.reserve(later), Duration::ZERO);
+        assert_eq!(bucket.reserve(later), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_token_bucket_does_not_exceed_capacity() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(1, start);
+        let much_later = start + Duration::from_secs(60);
+
+        assert_eq!(bucket.reserve(much_later), Duration::ZERO);
+        assert_eq!(bucket.reserve(much_later), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_host_key() {
+        assert_eq!(
+            host_key("https://api.example.com/games/1"),
+            "api.example.com"
+        );
+        assert_eq!(host_key("http://127.0.0.1:8080/x"), "127.0.0.1:8080");
+        assert_eq!(host_key("not a url"), "not a url");
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_requests_limited_per_host() {
+        let limiter = RequestLimiter::new(1, 100);
+        let first = limiter.acquire("https://a.example.com/1").await;
+
+        let same_host = tokio::time::timeout(
+            Duration::from_millis(50),
+            limiter.acquire("https://a.example.com/2"),
+        )
+        .await;
+        assert!(same_host.is_err(), "second request to the host should wait");
+        assert_eq!(limiter.in_flight(), 1);
+
+        let other_host = tokio::time::timeout(
+            Duration::from_millis(50),
+            limiter.acquire("https://b.example.com/1"),
+        )
+        .await;
+        assert!(other_host.is_ok(), "other hosts have their own slots");
+
+        drop(first);
+        let after_release = tokio::time::timeout(
+            Duration::from_millis(50),
+            limiter.acquire("https://a.example.com/2"),
+        )
+        .await;
+        assert!(after_release.is_ok());
+    }
+}
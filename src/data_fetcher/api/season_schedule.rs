@@ -5,9 +5,8 @@ use reqwest::Client;
 use tracing::{error, info, instrument};
 
 // Generic fetch utility available from sibling fetch_utils module
-use super::fetch_utils::fetch;
-// URL builders available from sibling urls module
-use super::urls::build_schedule_url;
+use super::data_source::ApiResource;
+use super::fetch_utils::fetch_resource;
 
 /// Fetches the regular season schedule to determine the season start date.
 /// Returns the start date of the first regular season game.
@@ -18,9 +17,12 @@ pub async fn fetch_regular_season_start_date(
     season: i32,
 ) -> Result<Option<String>, AppError> {
     info!("Fetching regular season schedule for season: {season}");
-    let url = build_schedule_url(&config.api_domain, season);
+    let resource = ApiResource::Schedule {
+        tournament: "runkosarja".to_string(),
+        season,
+    };
 
-    match fetch::<Vec<ScheduleApiGame>>(client, &url).await {
+    match fetch_resource::<Vec<ScheduleApiGame>>(client, config, &resource).await {
         Ok(games) => {
             if games.is_empty() {
                 info!("No regular season games found for season: {season}");
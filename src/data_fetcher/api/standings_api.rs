@@ -5,9 +5,9 @@ use chrono::{Datelike, Utc};
 use std::collections::HashMap;
 use tracing::{info, instrument};
 
-use super::fetch_utils::fetch;
-use super::http_client::create_http_client_with_timeout;
-use super::urls::build_standings_url;
+use super::data_source::ApiResource;
+use super::fetch_utils::fetch_resource;
+use super::http_client::http_client_for;
 
 /// Fetches standings from the Liiga standings API.
 ///
@@ -26,6 +26,15 @@ pub async fn fetch_standings(
     fetch_standings_for_season(config, live_mode, season, is_lookahead_month(now.month())).await
 }
 
+/// Fetches the final (non-live) table of `season`, sorted by ranking.
+pub async fn fetch_season_standings(
+    config: &Config,
+    season: i32,
+) -> Result<Vec<StandingsEntry>, AppError> {
+    let (entries, _) = fetch_standings_for_season(config, false, season, false).await?;
+    Ok(entries)
+}
+
 /// True during the off-season look-ahead window, when the upcoming season's
 /// standings may not exist in the API yet and falling back to the previous
 /// season's final table is preferable to an empty page.
@@ -40,9 +49,9 @@ async fn fetch_previous_season(
     season: i32,
 ) -> Result<StandingsResponse, AppError> {
     let previous = season - 1;
-    let url = build_standings_url(&config.api_domain, previous);
-    info!("No standings for season {season} yet, falling back to {previous}: {url}");
-    fetch(client, &url).await
+    let resource = ApiResource::Standings { season: previous };
+    info!("No standings for season {season} yet, falling back to {previous}: {resource}");
+    fetch_resource(client, config, &resource).await
 }
 
 async fn fetch_standings_for_season(
@@ -51,15 +60,15 @@ async fn fetch_standings_for_season(
     season: i32,
     allow_previous_fallback: bool,
 ) -> Result<(Vec<StandingsEntry>, Vec<u16>), AppError> {
-    let client = create_http_client_with_timeout(config.http_timeout_seconds)?;
+    let client = http_client_for(config)?;
 
-    let url = build_standings_url(&config.api_domain, season);
-    info!("Fetching standings from: {url}");
+    let resource = ApiResource::Standings { season };
+    info!("Fetching standings: {resource}");
 
     // During the off-season look-ahead the upcoming season's standings may
     // not exist in the API yet (empty team list or 404); fall back to the
     // previous season's final table instead of showing an empty page.
-    let response: StandingsResponse = match fetch(&client, &url).await {
+    let response: StandingsResponse = match fetch_resource(&client, config, &resource).await {
         Ok(StandingsResponse { season: teams, .. })
             if teams.is_empty() && allow_previous_fallback =>
         {
@@ -122,6 +131,7 @@ fn season_for_date(year: i32, month: u32) -> i32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::Instant;
 
     #[test]
     fn test_season_during_regular_season_and_playoffs() {
@@ -153,6 +163,7 @@ mod tests {
             api_domain,
             log_file_path: None,
             http_timeout_seconds: 1,
+            ..Default::default()
         }
     }
 
@@ -179,6 +190,23 @@ mod tests {
         })
     }
 
+    /// Starts a mock API without cached standings of `seasons`: wiremock
+    /// reuses the addresses of dropped servers and the HTTP response cache is
+    /// keyed by URL. It also keeps expired responses for revalidation, so an
+    /// earlier test's responses could be served or revalidated instead.
+    async fn start_mock_server(seasons: &[i32]) -> wiremock::MockServer {
+        use crate::data_fetcher::api::urls::build_standings_url;
+        use crate::data_fetcher::cache::HTTP_RESPONSE_CACHE;
+
+        let server = wiremock::MockServer::start().await;
+        for season in seasons {
+            HTTP_RESPONSE_CACHE
+                .remove(&build_standings_url(&server.uri(), *season))
+                .await;
+        }
+        server
+    }
+
     async fn mount_standings(
         server: &wiremock::MockServer,
         season: i32,
@@ -195,7 +223,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_lookahead_falls_back_to_previous_season_when_next_empty() {
-        let server = wiremock::MockServer::start().await;
+        let server = start_mock_server(&[2027, 2026]).await;
         let empty = serde_json::json!({"season": [], "playoffsLines": []});
         mount_standings(
             &server,
@@ -220,7 +248,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_lookahead_falls_back_to_previous_season_when_next_missing() {
-        let server = wiremock::MockServer::start().await;
+        let server = start_mock_server(&[2027, 2026]).await;
         mount_standings(&server, 2027, wiremock::ResponseTemplate::new(404)).await;
         mount_standings(
             &server,
@@ -239,7 +267,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_no_fallback_outside_lookahead_window() {
-        let server = wiremock::MockServer::start().await;
+        let server = start_mock_server(&[2027]).await;
         let empty = serde_json::json!({"season": [], "playoffsLines": []});
         mount_standings(
             &server,
@@ -257,4 +285,32 @@ mod tests {
             "in-season an empty response must not be papered over with old data"
         );
     }
+
+    #[tokio::test]
+    async fn test_fails_over_to_next_endpoint_when_primary_is_down() {
+        use crate::data_fetcher::api::endpoint_health::endpoint_health;
+
+        let primary = wiremock::MockServer::start().await;
+        let backup = wiremock::MockServer::start().await;
+        mount_standings(&primary, 2041, wiremock::ResponseTemplate::new(503)).await;
+        mount_standings(
+            &backup,
+            2041,
+            wiremock::ResponseTemplate::new(200).set_body_json(standings_json("Ilves")),
+        )
+        .await;
+
+        let config = Config {
+            api_fallback_domains: vec![backup.uri()],
+            ..make_test_config(primary.uri())
+        };
+        let (entries, _) = fetch_standings_for_season(&config, false, 2041, false)
+            .await
+            .unwrap();
+        assert_eq!(entries[0].team_name, "Ilves");
+
+        // The next request goes straight to the healthy backup
+        let order = endpoint_health().failover_order(&config.api_domains(), Instant::now());
+        assert_eq!(order, [backup.uri(), primary.uri()]);
+    }
 }
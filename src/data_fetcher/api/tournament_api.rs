@@ -14,8 +14,9 @@ use std::collections::HashMap;
 use tracing::{debug, error, info, instrument, warn};
 
 // Import from sibling modules
-use super::fetch_utils::fetch;
-use super::urls::{build_tournament_url, create_tournament_key};
+use super::data_source::ApiResource;
+use super::fetch_utils::fetch_resource;
+use super::urls::create_tournament_key;
 
 /// Determines if a candidate date should be used as the best date for showing games.
 /// Prioritizes future games over past games, and regular season over preseason when close to season start.
@@ -132,9 +133,12 @@ pub(super) async fn fetch_tournament_data_with_cache_check(
         "Cache miss, fetching from API for {} on {}",
         tournament, date
     );
-    let url = build_tournament_url(&config.api_domain, tournament, date);
+    let resource = ApiResource::Games {
+        tournament: tournament.to_string(),
+        date: date.to_string(),
+    };
 
-    match fetch::<ScheduleResponse>(client, &url).await {
+    match fetch_resource::<ScheduleResponse>(client, config, &resource).await {
         Ok(response) => {
             info!(
                 "Successfully fetched tournament data for {} on {}",
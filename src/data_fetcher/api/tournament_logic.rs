@@ -15,7 +15,7 @@ use tracing::{info, warn};
 use super::date_logic::{
     PLAYOFFS_END_MONTH, PLAYOFFS_START_MONTH, PRESEASON_END_MONTH, PRESEASON_START_MONTH,
 };
-use super::urls::{build_tournament_schedule_url, build_tournament_url, create_tournament_key};
+use super::urls::create_tournament_key;
 
 /// Represents a tournament type with its string identifier
 #[derive(Debug, Clone, PartialEq)]
@@ -101,6 +101,25 @@ async fn mark_unavailable(api_domain: &str, tournament: &str) {
     map.insert(unavailable_key(api_domain, tournament), expiry);
 }
 
+/// Tournaments currently skipped as unavailable, with the time left until
+/// they are checked again, soonest first.
+pub async fn unavailable_tournaments() -> Vec<(String, Duration)> {
+    let now = Instant::now();
+    let mut entries: Vec<(String, Duration)> = UNAVAILABLE_TOURNAMENTS
+        .read()
+        .await
+        .iter()
+        .filter(|(_, expiry)| **expiry > now)
+        .map(|(key, expiry)| {
+            // Keys are "{api_domain}:{tournament}"; the domain has colons too
+            let tournament = key.rsplit_once(':').map_or(key.as_str(), |(_, t)| t);
+            (tournament.to_string(), *expiry - now)
+        })
+        .collect();
+    entries.sort_by_key(|(_, remaining)| *remaining);
+    entries
+}
+
 /// Clears the tournament unavailability cache. Test isolation only: mock
 /// server ports are reused across tests, so entries from one test could
 /// otherwise leak into another test's domain.
@@ -160,8 +179,8 @@ pub async fn fetch_tournament_games(
     tournaments: &[TournamentType],
     season: i32,
 ) -> Vec<ScheduleApiGame> {
-    // Import fetch function from core module
-    use super::fetch_utils::fetch;
+    use super::data_source::ApiResource;
+    use super::fetch_utils::fetch_resource;
 
     info!(
         "Fetching games from {} tournaments for season {}",
@@ -173,14 +192,16 @@ pub async fn fetch_tournament_games(
     let fetch_futures: Vec<_> = tournaments
         .iter()
         .map(|tournament| {
-            let url =
-                build_tournament_schedule_url(&config.api_domain, tournament.as_str(), season);
+            let resource = ApiResource::Schedule {
+                tournament: tournament.as_str().to_string(),
+                season,
+            };
             let tournament_name = tournament.as_str();
 
             async move {
-                info!("Fetching {} schedule from: {}", tournament_name, url);
+                info!("Fetching {} schedule: {}", tournament_name, resource);
 
-                match fetch::<Vec<ScheduleApiGame>>(client, &url).await {
+                match fetch_resource::<Vec<ScheduleApiGame>>(client, config, &resource).await {
                     Ok(games) => {
                         info!(
                             "Successfully fetched {} games for {} tournament in season {}",
@@ -297,8 +318,8 @@ pub async fn determine_active_tournaments(
     config: &Config,
     date: &str,
 ) -> Result<(Vec<&'static str>, HashMap<String, ScheduleResponse>), AppError> {
-    // Import fetch function from core module
-    use super::fetch_utils::fetch_with_retries;
+    use super::data_source::ApiResource;
+    use super::fetch_utils::fetch_resource_with_retries;
 
     info!(
         "Determining active tournaments for date: {} using API nextGameDate logic",
@@ -372,7 +393,10 @@ pub async fn determine_active_tournaments(
     let fetch_futures: Vec<_> = checkable_candidates
         .iter()
         .map(|&tournament| {
-            let url = build_tournament_url(&config.api_domain, tournament, date);
+            let resource = ApiResource::Games {
+                tournament: tournament.to_string(),
+                date: date.to_string(),
+            };
             let tournament_name = tournament;
             let api_domain = config.api_domain.clone();
 
@@ -386,7 +410,14 @@ pub async fn determine_active_tournaments(
 
             async move {
                 info!("Checking tournament: {tournament_name}");
-                match fetch_with_retries::<ScheduleResponse>(client, &url, max_retries).await {
+                match fetch_resource_with_retries::<ScheduleResponse>(
+                    client,
+                    config,
+                    &resource,
+                    max_retries,
+                )
+                .await
+                {
                     Ok(response) => Ok((tournament_name, response)),
                     Err(e) => {
                         if tournament_name != "runkosarja" && is_unavailability_error(&e) {
@@ -505,6 +536,13 @@ mod tests {
         assert!(!is_marked_unavailable("https://domain-b.test", "valmistavat_ottelut").await);
         // Different tournament on the same domain is unaffected
         assert!(!is_marked_unavailable("https://domain-a.test", "playoffs").await);
+
+        let listed = unavailable_tournaments().await;
+        assert!(listed.iter().any(|(tournament, remaining)| {
+            tournament == "valmistavat_ottelut"
+                && remaining.as_secs()
+                    <= crate::constants::cache_ttl::TOURNAMENT_UNAVAILABLE_SECONDS
+        }));
     }
 
     #[test]
@@ -61,6 +61,7 @@ pub fn build_game_url(api_domain: &str, season: i32, game_id: i32) -> String {
 /// let url = build_schedule_url("https://api.example.com", 2024);
 /// assert_eq!(url, "https://api.example.com/schedule?tournament=runkosarja&week=1&season=2024");
 /// ```
+#[allow(dead_code)]
 pub fn build_schedule_url(api_domain: &str, season: i32) -> String {
     format!("{api_domain}/schedule?tournament=runkosarja&week=1&season={season}")
 }
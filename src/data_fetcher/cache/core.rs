@@ -2,7 +2,7 @@
 use super::{
     DETAILED_GAME_CACHE, GOAL_EVENTS_CACHE, HTTP_RESPONSE_CACHE, PLAYER_CACHE, TOURNAMENT_CACHE,
     clear_cache, clear_detailed_game_cache, clear_goal_events_cache, clear_http_response_cache,
-    clear_tournament_cache,
+    clear_pre_game_standings_cache, clear_season_schedule_cache, clear_tournament_cache,
 };
 
 // Combined Cache Management Functions
@@ -18,26 +18,11 @@ pub async fn get_all_cache_stats() -> CacheStats {
     );
 
     CacheStats {
-        player_cache: CacheInfo {
-            size: player.0,
-            capacity: player.1,
-        },
-        tournament_cache: CacheInfo {
-            size: tournament.0,
-            capacity: tournament.1,
-        },
-        detailed_game_cache: CacheInfo {
-            size: detailed_game.0,
-            capacity: detailed_game.1,
-        },
-        goal_events_cache: CacheInfo {
-            size: goal_events.0,
-            capacity: goal_events.1,
-        },
-        http_response_cache: CacheInfo {
-            size: http_response.0,
-            capacity: http_response.1,
-        },
+        player_cache: CacheInfo::new(player, PLAYER_CACHE.hit_counts()),
+        tournament_cache: CacheInfo::new(tournament, TOURNAMENT_CACHE.hit_counts()),
+        detailed_game_cache: CacheInfo::new(detailed_game, DETAILED_GAME_CACHE.hit_counts()),
+        goal_events_cache: CacheInfo::new(goal_events, GOAL_EVENTS_CACHE.hit_counts()),
+        http_response_cache: CacheInfo::new(http_response, HTTP_RESPONSE_CACHE.hit_counts()),
     }
 }
 
@@ -46,6 +31,27 @@ pub async fn get_all_cache_stats() -> CacheStats {
 pub struct CacheInfo {
     pub size: usize,
     pub capacity: usize,
+    /// Lookups that found a fresh entry
+    pub hits: u64,
+    /// Lookups that found nothing or an expired entry
+    pub misses: u64,
+}
+
+impl CacheInfo {
+    fn new((size, capacity): (usize, usize), (hits, misses): (u64, u64)) -> Self {
+        Self {
+            size,
+            capacity,
+            hits,
+            misses,
+        }
+    }
+
+    /// Share of lookups that were hits, or `None` before the first lookup.
+    pub fn hit_ratio(&self) -> Option<f64> {
+        let lookups = self.hits + self.misses;
+        (lookups > 0).then(|| self.hits as f64 / lookups as f64)
+    }
 }
 
 /// Combined cache statistics
@@ -66,6 +72,8 @@ pub async fn clear_all_caches() {
     clear_detailed_game_cache().await;
     clear_goal_events_cache().await;
     clear_http_response_cache().await;
+    clear_season_schedule_cache().await;
+    clear_pre_game_standings_cache().await;
 }
 
 /// Gets detailed cache debugging information including individual cache entries
@@ -662,7 +670,7 @@ mod tests {
         tokio::time::sleep(Duration::from_millis(10)).await;
 
         let url = format!("https://api.example.com/test-{test_id}");
-        let response_data = format!(r#"{{"test": "data-{test_id}"}}"#);
+        let response_data = serde_json::json!({"test": format!("data-{test_id}")});
 
         cache_http_response(url.clone(), response_data.clone(), 60).await;
 
@@ -788,7 +796,12 @@ mod tests {
         );
 
         let http_url = format!("https://api.example.com/test-stats-{test_id}");
-        cache_http_response(http_url.clone(), format!("test data {test_id}"), 60).await;
+        cache_http_response(
+            http_url.clone(),
+            serde_json::json!(format!("test data {test_id}")),
+            60,
+        )
+        .await;
 
         // Verify HTTP response cache entry
         assert!(
@@ -981,7 +994,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
             GameData {
                 home_team: "Kärpät".to_string(),
@@ -999,7 +1016,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
             GameData {
                 home_team: "JYP".to_string(),
@@ -1017,7 +1038,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
         ];
 
@@ -1046,7 +1071,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
             GameData {
                 home_team: "Kärpät".to_string(),
@@ -1064,7 +1093,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
             GameData {
                 home_team: "JYP".to_string(),
@@ -1082,7 +1115,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
         ];
 
@@ -1111,7 +1148,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
             GameData {
                 home_team: "Kärpät".to_string(),
@@ -1129,7 +1170,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
         ];
 
@@ -1158,7 +1203,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
             GameData {
                 home_team: "Kärpät".to_string(),
@@ -1176,7 +1225,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
             GameData {
                 home_team: "JYP".to_string(),
@@ -1194,7 +1247,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
         ];
 
@@ -1226,7 +1283,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
             GameData {
                 home_team: "Kärpät".to_string(),
@@ -1244,7 +1305,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
             GameData {
                 home_team: "JYP".to_string(),
@@ -1262,7 +1327,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
         ];
 
@@ -1300,7 +1369,11 @@ mod tests {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         }];
 
         let result = has_live_games_from_game_data(&single_ongoing);
@@ -1327,7 +1400,11 @@ mod tests {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         }];
 
         let result = has_live_games_from_game_data(&single_completed);
@@ -1354,7 +1431,11 @@ mod tests {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         }];
 
         let result = has_live_games_from_game_data(&single_scheduled);
@@ -1382,7 +1463,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
             GameData {
                 home_team: "Kärpät".to_string(),
@@ -1400,7 +1485,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
             GameData {
                 home_team: "JYP".to_string(),
@@ -1418,7 +1507,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
             GameData {
                 home_team: "TPS".to_string(),
@@ -1436,7 +1529,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
         ];
 
@@ -1465,7 +1562,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
             GameData {
                 home_team: "Kärpät".to_string(),
@@ -1483,7 +1584,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
         ];
 
@@ -1513,7 +1618,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
             // Ongoing regular season game
             GameData {
@@ -1532,7 +1641,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
             // Scheduled playoff game
             GameData {
@@ -1551,7 +1664,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
             // Completed playoff game with shootout
             GameData {
@@ -1570,7 +1687,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
             // Another ongoing game
             GameData {
@@ -1589,7 +1710,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             },
         ];
 
@@ -10,8 +10,9 @@ use tracing::{debug, info, warn};
 use ttl_cache::TtlCache;
 
 use crate::constants::cache_ttl;
+use crate::data_fetcher::models::standings::StandingsEntry;
 use crate::data_fetcher::models::{
-    DetailedGameResponse, GameData, GoalEventData, ScheduleResponse,
+    DetailedGameResponse, GameData, GoalEventData, ScheduleApiGame, ScheduleResponse,
 };
 #[cfg(test)]
 use crate::data_fetcher::player_names::format_for_display;
@@ -114,19 +115,71 @@ pub async fn clear_cache() {
 
 // --- HTTP response cache (backed by generic TtlCache) ---
 
-pub(crate) static HTTP_RESPONSE_CACHE: LazyLock<TtlCache<String, String>> =
+/// A cached response, parsed once when it was downloaded, with the
+/// validators needed to revalidate it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CachedHttpResponse {
+    pub document: serde_json::Value,
+    /// `ETag` response header, sent back as `If-None-Match`
+    pub etag: Option<String>,
+    /// `Last-Modified` response header, sent back as `If-Modified-Since`
+    pub last_modified: Option<String>,
+}
+
+impl CachedHttpResponse {
+    /// Whether the response can be revalidated with a conditional request.
+    pub fn has_validators(&self) -> bool {
+        self.etag.is_some() || self.last_modified.is_some()
+    }
+}
+
+pub(crate) static HTTP_RESPONSE_CACHE: LazyLock<TtlCache<String, CachedHttpResponse>> =
     LazyLock::new(|| TtlCache::new("http_response", 100));
 
 /// Cache an HTTP response with a specific TTL.
-pub async fn cache_http_response(url: String, data: String, ttl_seconds: u64) {
+#[cfg(test)]
+pub async fn cache_http_response(url: String, data: serde_json::Value, ttl_seconds: u64) {
+    cache_http_response_with_validators(
+        url,
+        CachedHttpResponse {
+            document: data,
+            ..Default::default()
+        },
+        ttl_seconds,
+    )
+    .await;
+}
+
+/// Cache an HTTP response together with its ETag/Last-Modified validators.
+pub async fn cache_http_response_with_validators(
+    url: String,
+    response: CachedHttpResponse,
+    ttl_seconds: u64,
+) {
     HTTP_RESPONSE_CACHE
-        .insert(url, data, Duration::from_secs(ttl_seconds))
+        .insert(url, response, Duration::from_secs(ttl_seconds))
         .await;
 }
 
 /// Get a cached HTTP response if not expired.
-pub async fn get_cached_http_response(url: &str) -> Option<String> {
-    HTTP_RESPONSE_CACHE.get(&url.to_string()).await
+#[cfg(test)]
+pub async fn get_cached_http_response(url: &str) -> Option<serde_json::Value> {
+    HTTP_RESPONSE_CACHE
+        .get(&url.to_string())
+        .await
+        .map(|response| response.document)
+}
+
+/// Get a cached HTTP response even if expired, with whether it has expired.
+/// Expired responses are only useful for revalidation.
+pub async fn get_http_response_for_revalidation(url: &str) -> Option<(CachedHttpResponse, bool)> {
+    HTTP_RESPONSE_CACHE.get_allow_stale(&url.to_string()).await
+}
+
+/// Restart the TTL of a cached HTTP response after the server confirmed
+/// it is unchanged (304 Not Modified).
+pub async fn extend_http_response_ttl(url: &str) -> bool {
+    HTTP_RESPONSE_CACHE.touch(&url.to_string()).await
 }
 
 /// Clear all HTTP response cache entries.
@@ -231,6 +284,75 @@ pub async fn clear_goal_events_cache() {
     GOAL_EVENTS_CACHE.clear().await;
 }
 
+// --- Season schedule cache (backed by generic TtlCache) ---
+
+pub(crate) static SEASON_SCHEDULE_CACHE: LazyLock<TtlCache<String, Vec<ScheduleApiGame>>> =
+    LazyLock::new(|| TtlCache::new("season_schedule", 10));
+
+/// Creates a cache key for the season schedule of `tournaments` served by
+/// `api_domain`.
+pub fn create_season_schedule_key(api_domain: &str, season: i32, tournaments: &[&str]) -> String {
+    format!(
+        "season_schedule_{api_domain}_{season}_{}",
+        tournaments.join("+")
+    )
+}
+
+/// Caches a season schedule. Ending games change it, so it is kept for an
+/// hour at most.
+pub async fn cache_season_schedule(key: String, schedule: Vec<ScheduleApiGame>) {
+    SEASON_SCHEDULE_CACHE
+        .insert(
+            key,
+            schedule,
+            Duration::from_secs(cache_ttl::COMPLETED_GAMES_SECONDS),
+        )
+        .await;
+}
+
+/// Retrieves a cached season schedule if it has not expired.
+pub async fn get_cached_season_schedule(key: &str) -> Option<Vec<ScheduleApiGame>> {
+    SEASON_SCHEDULE_CACHE.get(&key.to_string()).await
+}
+
+/// Clears all season schedule cache entries.
+pub async fn clear_season_schedule_cache() {
+    SEASON_SCHEDULE_CACHE.clear().await;
+}
+
+// --- Pre-game standings cache (backed by generic TtlCache) ---
+
+pub(crate) static PRE_GAME_STANDINGS_CACHE: LazyLock<TtlCache<String, Vec<StandingsEntry>>> =
+    LazyLock::new(|| TtlCache::new("pre_game_standings", 4));
+
+/// Creates a cache key for the standings shown with upcoming games, taken
+/// from `api_domain` when `ended_games` games of the season had ended.
+pub fn create_pre_game_standings_key(api_domain: &str, ended_games: usize) -> String {
+    format!("pre_game_standings_{api_domain}_{ended_games}")
+}
+
+/// Caches the standings shown with upcoming games for at most an hour.
+pub async fn cache_pre_game_standings(key: String, standings: Vec<StandingsEntry>) {
+    PRE_GAME_STANDINGS_CACHE
+        .insert(
+            key,
+            standings,
+            Duration::from_secs(cache_ttl::COMPLETED_GAMES_SECONDS),
+        )
+        .await;
+}
+
+/// Retrieves the cached standings shown with upcoming games if they have
+/// not expired.
+pub async fn get_cached_pre_game_standings(key: &str) -> Option<Vec<StandingsEntry>> {
+    PRE_GAME_STANDINGS_CACHE.get(&key.to_string()).await
+}
+
+/// Clears all pre-game standings cache entries.
+pub async fn clear_pre_game_standings_cache() {
+    PRE_GAME_STANDINGS_CACHE.clear().await;
+}
+
 // --- Tournament cache (backed by generic TtlCache) ---
 
 pub(crate) static TOURNAMENT_CACHE: LazyLock<TtlCache<String, ScheduleResponse>> =
@@ -6,6 +6,7 @@
 
 use std::hash::Hash;
 use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 
 use lru::LruCache;
@@ -31,9 +32,12 @@ impl<V> CacheEntry<V> {
 /// - Expired entries are lazily removed on access (`get`, `get_if`).
 /// - LRU eviction kicks in when the cache exceeds its capacity.
 /// - All public methods are `async` and use `tokio::sync::RwLock` internally.
+/// - Lookups are counted as hits or misses for the diagnostics page.
 pub struct TtlCache<K: Eq + Hash, V> {
     inner: RwLock<LruCache<K, CacheEntry<V>>>,
     name: &'static str,
+    hits: AtomicU64,
+    misses: AtomicU64,
 }
 
 impl<K: Eq + Hash + Clone, V: Clone> TtlCache<K, V> {
@@ -47,15 +51,22 @@ impl<K: Eq + Hash + Clone, V: Clone> TtlCache<K, V> {
                 NonZeroUsize::new(capacity).expect("cache capacity must be > 0"),
             )),
             name,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
         }
     }
 
+    fn count_lookup(&self, hit: bool) {
+        let counter = if hit { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
     /// Retrieve a value if it exists and has not expired.
     ///
     /// Takes a write lock so that expired entries can be evicted immediately.
     pub async fn get(&self, key: &K) -> Option<V> {
         let mut cache = self.inner.write().await;
-        if let Some(entry) = cache.get(key) {
+        let value = if let Some(entry) = cache.get(key) {
             if entry.is_expired() {
                 debug!(
                     "{}: evicting expired entry (age={:?}, ttl={:?})",
@@ -70,7 +81,9 @@ impl<K: Eq + Hash + Clone, V: Clone> TtlCache<K, V> {
             }
         } else {
             None
-        }
+        };
+        self.count_lookup(value.is_some());
+        value
     }
 
     /// Insert a value with a specific TTL.
@@ -92,7 +105,7 @@ impl<K: Eq + Hash + Clone, V: Clone> TtlCache<K, V> {
     /// This supports use-cases like aggressive TTL for games about to start.
     pub async fn get_if(&self, key: &K, predicate: impl FnOnce(Instant) -> bool) -> Option<V> {
         let mut cache = self.inner.write().await;
-        if let Some(entry) = cache.get(key) {
+        let value = if let Some(entry) = cache.get(key) {
             if entry.is_expired() || !predicate(entry.cached_at) {
                 debug!(
                     "{}: evicting entry (expired={}, age={:?}, ttl={:?})",
@@ -108,6 +121,34 @@ impl<K: Eq + Hash + Clone, V: Clone> TtlCache<K, V> {
             }
         } else {
             None
+        };
+        self.count_lookup(value.is_some());
+        value
+    }
+
+    /// Retrieve a value even if it has expired, together with whether it has.
+    ///
+    /// Expired entries are kept so callers can revalidate them (e.g. HTTP
+    /// conditional requests) and then [`touch`](Self::touch) them back to life.
+    pub async fn get_allow_stale(&self, key: &K) -> Option<(V, bool)> {
+        let mut cache = self.inner.write().await;
+        let value = cache
+            .get(key)
+            .map(|entry| (entry.data.clone(), entry.is_expired()));
+        self.count_lookup(matches!(value, Some((_, false))));
+        value
+    }
+
+    /// Restart an entry's TTL from now, keeping its value and TTL length.
+    /// Returns `true` if the key was present.
+    pub async fn touch(&self, key: &K) -> bool {
+        let mut cache = self.inner.write().await;
+        match cache.get_mut(key) {
+            Some(entry) => {
+                entry.cached_at = Instant::now();
+                true
+            }
+            None => false,
         }
     }
 
@@ -123,6 +164,15 @@ impl<K: Eq + Hash + Clone, V: Clone> TtlCache<K, V> {
         (cache.len(), cache.cap().into())
     }
 
+    /// Returns `(hits, misses)` of all lookups so far. A lookup of an expired
+    /// entry is a miss.
+    pub fn hit_counts(&self) -> (u64, u64) {
+        (
+            self.hits.load(Ordering::Relaxed),
+            self.misses.load(Ordering::Relaxed),
+        )
+    }
+
     /// Number of entries currently stored (including possibly expired ones).
     #[cfg(test)]
     #[allow(clippy::len_without_is_empty)]
@@ -138,6 +188,17 @@ impl<K: Eq + Hash + Clone, V: Clone> TtlCache<K, V> {
         cache.pop(key).is_some()
     }
 
+    /// Backdate an entry so that its TTL has run out, without removing it.
+    #[cfg(test)]
+    pub async fn expire(&self, key: &K) {
+        let mut cache = self.inner.write().await;
+        if let Some(entry) = cache.get_mut(key)
+            && let Some(past) = Instant::now().checked_sub(entry.ttl + Duration::from_millis(1))
+        {
+            entry.cached_at = past;
+        }
+    }
+
     /// The maximum number of entries the cache can hold before LRU eviction.
     #[cfg(test)]
     pub async fn capacity(&self) -> usize {
@@ -343,4 +404,51 @@ mod tests {
             .await;
         assert_eq!(cache.get(&"final".to_string()).await, Some(999));
     }
+
+    #[tokio::test]
+    async fn test_stale_entry_survives_and_touch_revives_it() {
+        let cache = test_cache(10);
+        cache
+            .insert("key1".to_string(), 42, Duration::from_secs(60))
+            .await;
+        cache.expire(&"key1".to_string()).await;
+
+        assert_eq!(
+            cache.get_allow_stale(&"key1".to_string()).await,
+            Some((42, true))
+        );
+        assert_eq!(cache.len().await, 1);
+        assert!(!cache.touch(&"missing".to_string()).await);
+    }
+
+    #[tokio::test]
+    async fn test_touch_restarts_ttl() {
+        let cache = test_cache(10);
+        cache
+            .insert("key1".to_string(), 42, Duration::from_millis(20))
+            .await;
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(
+            cache.get_allow_stale(&"key1".to_string()).await,
+            Some((42, true))
+        );
+
+        assert!(cache.touch(&"key1".to_string()).await);
+        assert_eq!(cache.get(&"key1".to_string()).await, Some(42));
+    }
+
+    #[tokio::test]
+    async fn test_hit_counts() {
+        let cache = test_cache(10);
+        cache
+            .insert("key1".to_string(), 42, Duration::from_secs(60))
+            .await;
+
+        cache.get(&"key1".to_string()).await;
+        cache.get(&"key1".to_string()).await;
+        cache.get(&"missing".to_string()).await;
+        cache.get_if(&"key1".to_string(), |_| false).await;
+
+        assert_eq!(cache.hit_counts(), (2, 2));
+    }
 }
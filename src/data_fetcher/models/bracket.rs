@@ -1,7 +1,8 @@
+use serde::Serialize;
 use std::hash::Hash;
 
 /// A single playoff series between two teams
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, Serialize)]
 pub struct BracketMatchup {
     pub phase: i32,
     pub pair: i32,
@@ -17,7 +18,7 @@ pub struct BracketMatchup {
 }
 
 /// All matchups in a single playoff round
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, Serialize)]
 pub struct BracketPhase {
     pub phase_number: i32,
     pub name: String,
@@ -25,7 +26,7 @@ pub struct BracketPhase {
 }
 
 /// The complete playoff bracket for a season
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, Serialize)]
 pub struct PlayoffBracket {
     pub season: String,
     pub phases: Vec<BracketPhase>,
@@ -2,15 +2,62 @@ use super::detailed::{DetailedGame, DetailedTeam};
 use super::goals::{GoalEvent, GoalEventData};
 use super::schedule::{ScheduleGame, ScheduleTeam};
 use crate::teletext_ui::ScoreType;
+use serde::Serialize;
 
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, Serialize)]
 pub struct PlayoffSeriesScore {
     pub home_team_wins: u8,
     pub away_team_wins: u8,
     pub req_wins: u8,
 }
 
-#[derive(Debug, Clone, Hash)]
+/// Standing and recent results of one team before a scheduled game.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize)]
+pub struct TeamPreGame {
+    /// Current position in the league table
+    pub position: Option<u16>,
+    pub points: Option<u16>,
+    /// Results of the last (at most five) games, oldest first; `true` is a win
+    pub form: Vec<bool>,
+}
+
+/// What is known about a matchup before it is played, shown under
+/// upcoming games.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize)]
+pub struct PreGameInfo {
+    pub home: TeamPreGame,
+    pub away: TeamPreGame,
+    /// Season head-to-head wins so far (home team, away team)
+    pub head_to_head: (u8, u8),
+}
+
+/// Note about a finished game, drawn as an extra line under it; produced by
+/// the rules in `processors::annotations`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum GameAnnotation {
+    HatTrick {
+        scorer: String,
+        goals: u8,
+    },
+    /// The winner trailed by `deficit` goals during the third period
+    Comeback {
+        team: String,
+        deficit: u8,
+    },
+    /// The winner kept the opponent off the scoresheet
+    Shutout {
+        team: String,
+    },
+    FirstSeasonGoal {
+        scorer: String,
+    },
+    EmptyNetGoal {
+        scorer: String,
+        minute: i32,
+    },
+}
+
+#[derive(Debug, Clone, Hash, Serialize)]
 pub struct GameData {
     pub home_team: String,
     pub away_team: String,
@@ -27,9 +74,17 @@ pub struct GameData {
     pub play_off_pair: Option<i32>,
     pub play_off_req_wins: Option<i32>,
     pub series_score: Option<PlayoffSeriesScore>,
+    /// Liiga API game id; with `season` identifies the game for detail fetches
+    pub game_id: i32,
+    pub season: i32,
     /// True for games with unresolved team names (e.g. "QF1" vs "SF2");
     /// filtered out in `create_base_page` (navigation_manager) before rendering.
     pub is_placeholder: bool,
+    /// Standings, form and head-to-head for scheduled games; see
+    /// `processors::pre_game`
+    pub pre_game: Option<PreGameInfo>,
+    /// Hat tricks, comebacks and other notes on finished games
+    pub annotations: Vec<GameAnnotation>,
 }
 
 pub trait HasTeams {
@@ -218,7 +273,11 @@ mod tests {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         };
 
         assert_eq!(game_data.home_team, "HIFK");
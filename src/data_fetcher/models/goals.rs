@@ -37,7 +37,7 @@ pub struct EmbeddedPlayer {
     pub first_name: String,
 }
 
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, Serialize)]
 pub struct GoalEventData {
     pub scorer_player_id: i64,
     pub scorer_name: String,
@@ -54,81 +54,87 @@ impl GoalEventData {
     /// Gets the display string for goal types with safe fallbacks for missing data
     /// Ensures rendering continues even with problematic goal type data (Requirement 4.1)
     pub fn get_goal_type_display(&self) -> String {
-        // Handle missing or empty goal types safely
-        if self.goal_types.is_empty() {
-            return String::new();
-        }
+        format_goal_types(&self.goal_types)
+    }
+}
 
-        let mut indicators = Vec::new();
-        let valid_goal_types = ["EV", "YV", "YV2", "IM", "VT", "AV", "TM", "VL", "MV", "RV"];
+/// Formats raw API goal types ("YV", "TM", ...) for display in fixed priority
+/// order, dropping unknown types. Shared by the game list and the timeline.
+pub fn format_goal_types(goal_types: &[String]) -> String {
+    // Handle missing or empty goal types safely
+    if goal_types.is_empty() {
+        return String::new();
+    }
 
-        // Process goal types in fixed priority order to maintain consistent display
-        // This preserves the original behavior while adding safe fallbacks
+    let mut indicators = Vec::new();
+    let valid_goal_types = ["EV", "YV", "YV2", "IM", "VT", "AV", "TM", "VL", "MV", "RV"];
 
-        // First, validate and clean the goal types
-        let mut valid_goal_types_set = std::collections::HashSet::new();
-        for goal_type in &self.goal_types {
-            let goal_type_str = goal_type.trim();
-            if !goal_type_str.is_empty() && valid_goal_types.contains(&goal_type_str) {
-                valid_goal_types_set.insert(goal_type_str);
-            } else if !goal_type_str.is_empty() {
-                tracing::debug!(
-                    "Invalid goal type '{}' found, excluding from display",
-                    goal_type_str
-                );
-            }
-        }
+    // Process goal types in fixed priority order to maintain consistent display
+    // This preserves the original behavior while adding safe fallbacks
 
-        // Add goal types in fixed priority order (maintains original behavior)
-        // EV (even strength) is only shown when it's the only type
-        if valid_goal_types_set.len() == 1 && valid_goal_types_set.contains("EV") {
-            indicators.push("EV");
-        } else {
-            // For all other cases, show special goal types in priority order
-            if valid_goal_types_set.contains("YV") {
-                indicators.push("YV");
-            }
-            if valid_goal_types_set.contains("YV2") {
-                indicators.push("YV2");
-            }
-            if valid_goal_types_set.contains("IM") {
-                indicators.push("IM");
-            }
-            if valid_goal_types_set.contains("VT") {
-                indicators.push("VT");
-            }
-            if valid_goal_types_set.contains("AV") {
-                indicators.push("AV");
-            }
-            if valid_goal_types_set.contains("TM") {
-                indicators.push("TM");
-            }
-            if valid_goal_types_set.contains("VL") {
-                indicators.push("VL");
-            }
-            if valid_goal_types_set.contains("MV") {
-                indicators.push("MV");
-            }
-            if valid_goal_types_set.contains("RV") {
-                indicators.push("RV");
-            }
+    // First, validate and clean the goal types
+    let mut valid_goal_types_set = std::collections::HashSet::new();
+    for goal_type in goal_types {
+        let goal_type_str = goal_type.trim();
+        if !goal_type_str.is_empty() && valid_goal_types.contains(&goal_type_str) {
+            valid_goal_types_set.insert(goal_type_str);
+        } else if !goal_type_str.is_empty() {
+            tracing::debug!(
+                "Invalid goal type '{}' found, excluding from display",
+                goal_type_str
+            );
         }
+    }
 
-        // Join with space separator, ensuring safe string operations
-        let result = indicators.join(" ");
-
-        // Validate result length to prevent layout issues
-        if result.len() > 20 {
-            // Reasonable maximum for goal type display
-            tracing::warn!(
-                "Goal type display '{}' is unusually long ({}), may cause layout issues",
-                result,
-                result.len()
-            );
+    // Add goal types in fixed priority order (maintains original behavior)
+    // EV (even strength) is only shown when it's the only type
+    if valid_goal_types_set.len() == 1 && valid_goal_types_set.contains("EV") {
+        indicators.push("EV");
+    } else {
+        // For all other cases, show special goal types in priority order
+        if valid_goal_types_set.contains("YV") {
+            indicators.push("YV");
+        }
+        if valid_goal_types_set.contains("YV2") {
+            indicators.push("YV2");
+        }
+        if valid_goal_types_set.contains("IM") {
+            indicators.push("IM");
         }
+        if valid_goal_types_set.contains("VT") {
+            indicators.push("VT");
+        }
+        if valid_goal_types_set.contains("AV") {
+            indicators.push("AV");
+        }
+        if valid_goal_types_set.contains("TM") {
+            indicators.push("TM");
+        }
+        if valid_goal_types_set.contains("VL") {
+            indicators.push("VL");
+        }
+        if valid_goal_types_set.contains("MV") {
+            indicators.push("MV");
+        }
+        if valid_goal_types_set.contains("RV") {
+            indicators.push("RV");
+        }
+    }
+
+    // Join with space separator, ensuring safe string operations
+    let result = indicators.join(" ");
 
-        result
+    // Validate result length to prevent layout issues
+    if result.len() > 20 {
+        // Reasonable maximum for goal type display
+        tracing::warn!(
+            "Goal type display '{}' is unusually long ({}), may cause layout issues",
+            result,
+            result.len()
+        );
     }
+
+    result
 }
 
 #[cfg(test)]
@@ -7,7 +7,9 @@ pub mod schedule;
 pub mod standings;
 
 // Re-export all public types for backward compatibility
-pub use common::{GameData, HasGoalEvents, HasTeams, PlayoffSeriesScore};
+pub use common::{
+    GameAnnotation, GameData, HasGoalEvents, HasTeams, PlayoffSeriesScore, PreGameInfo, TeamPreGame,
+};
 pub use detailed::{DetailedGame, DetailedGameResponse, DetailedTeam};
 pub use goals::{GoalEvent, GoalEventData};
 pub use players::Player;
@@ -1,4 +1,4 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// API response from /standings/?season={season}
 #[derive(Debug, Deserialize)]
@@ -31,7 +31,7 @@ pub struct ApiStandingsTeam {
 }
 
 /// Internal standings entry used for rendering and change detection
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 pub struct StandingsEntry {
     pub team_name: String,
     pub team_id: String,
@@ -0,0 +1,358 @@
+use crate::data_fetcher::models::{GameAnnotation, GameData, GoalEventData};
+use crate::teletext_ui::ScoreType;
+use std::collections::HashSet;
+
+/// A rule looks at one finished game and returns the annotations it finds.
+type Rule = fn(&GameData, &ScorerHistory) -> Vec<GameAnnotation>;
+
+/// Rules in the order their annotations are drawn under the game.
+const RULES: &[Rule] = &[
+    hat_tricks,
+    comeback,
+    shutout,
+    first_season_goals,
+    empty_net_goals,
+];
+
+/// Start of the third period in game minutes
+const THIRD_PERIOD_START: i32 = 40;
+/// End of regulation time in game minutes
+const REGULATION_END: i32 = 60;
+
+/// Goal scorers of the season's earlier games, as far as the cache knows them.
+///
+/// A first goal of the season can only be told apart from a gap in the
+/// cache when every earlier game of the scorer's team has been seen, so
+/// teams are marked complete one at a time.
+#[derive(Debug, Default)]
+pub struct ScorerHistory {
+    earlier_scorers: HashSet<i64>,
+    complete_teams: HashSet<String>,
+}
+
+impl ScorerHistory {
+    /// Records the goals of a game played earlier in the season.
+    pub fn add_earlier_game(&mut self, goal_events: &[GoalEventData]) {
+        self.earlier_scorers
+            .extend(goal_events.iter().map(|e| e.scorer_player_id));
+    }
+
+    /// Marks every earlier game of `team` this season as recorded.
+    pub fn mark_complete(&mut self, team: &str) {
+        self.complete_teams.insert(team.to_string());
+    }
+
+    fn is_first_season_goal(&self, team: &str, scorer_player_id: i64) -> bool {
+        self.complete_teams.contains(team) && !self.earlier_scorers.contains(&scorer_player_id)
+    }
+}
+
+/// Runs every rule on the finished games and stores the results in
+/// `annotations`; other games are left without annotations.
+pub fn annotate_games(games: &mut [GameData], history: &ScorerHistory) {
+    for game in games.iter_mut() {
+        game.annotations = if matches!(game.score_type, ScoreType::Final) {
+            annotate_game(game, history)
+        } else {
+            Vec::new()
+        };
+    }
+}
+
+/// Runs every rule on one game.
+pub fn annotate_game(game: &GameData, history: &ScorerHistory) -> Vec<GameAnnotation> {
+    RULES.iter().flat_map(|rule| rule(game, history)).collect()
+}
+
+/// The shootout winner is recorded as a goal (`VL`) but is not one in the
+/// statistics.
+fn is_shootout_goal(event: &GoalEventData) -> bool {
+    event.goal_types.iter().any(|t| t == "VL")
+}
+
+fn team_name(game: &GameData, is_home_team: bool) -> &str {
+    if is_home_team {
+        &game.home_team
+    } else {
+        &game.away_team
+    }
+}
+
+/// Goal events in the order they were scored. Events are stored home team
+/// first, but the running score grows by one with every goal.
+fn chronological(game: &GameData) -> Vec<&GoalEventData> {
+    let mut events: Vec<&GoalEventData> = game.goal_events.iter().collect();
+    events.sort_by_key(|e| e.home_team_score + e.away_team_score);
+    events
+}
+
+/// Whether the home team won, from the score after the last goal.
+fn home_team_won(game: &GameData) -> Option<bool> {
+    let last = chronological(game).pop()?;
+    match last.home_team_score.cmp(&last.away_team_score) {
+        std::cmp::Ordering::Greater => Some(true),
+        std::cmp::Ordering::Less => Some(false),
+        std::cmp::Ordering::Equal => None,
+    }
+}
+
+fn hat_tricks(game: &GameData, _: &ScorerHistory) -> Vec<GameAnnotation> {
+    let mut goals: Vec<(&GoalEventData, u8)> = Vec::new();
+    for event in game.goal_events.iter().filter(|e| !is_shootout_goal(e)) {
+        match goals
+            .iter_mut()
+            .find(|(first, _)| first.scorer_player_id == event.scorer_player_id)
+        {
+            Some((_, count)) => *count += 1,
+            None => goals.push((event, 1)),
+        }
+    }
+    goals
+        .into_iter()
+        .filter(|(_, count)| *count >= 3)
+        .map(|(event, count)| GameAnnotation::HatTrick {
+            scorer: event.scorer_name.clone(),
+            goals: count,
+        })
+        .collect()
+}
+
+fn comeback(game: &GameData, _: &ScorerHistory) -> Vec<GameAnnotation> {
+    let Some(home_won) = home_team_won(game) else {
+        return Vec::new();
+    };
+    let winner_margin = |home: i32, away: i32| {
+        if home_won { home - away } else { away - home }
+    };
+
+    // Scores held during the third period: the one it started with and the
+    // one after each goal scored in it
+    let mut margin_at_third = 0;
+    let mut worst_margin = i32::MAX;
+    for event in chronological(game) {
+        let margin = winner_margin(event.home_team_score, event.away_team_score);
+        if event.minute < THIRD_PERIOD_START {
+            margin_at_third = margin;
+        } else if event.minute < REGULATION_END {
+            worst_margin = worst_margin.min(margin);
+        }
+    }
+    let worst_margin = worst_margin.min(margin_at_third);
+
+    if worst_margin <= -2 {
+        vec![GameAnnotation::Comeback {
+            team: team_name(game, home_won).to_string(),
+            deficit: worst_margin.unsigned_abs() as u8,
+        }]
+    } else {
+        Vec::new()
+    }
+}
+
+fn shutout(game: &GameData, _: &ScorerHistory) -> Vec<GameAnnotation> {
+    let Some(home_won) = home_team_won(game) else {
+        return Vec::new();
+    };
+    let loser_scored = game
+        .goal_events
+        .iter()
+        .any(|e| e.is_home_team != home_won && !is_shootout_goal(e));
+    if loser_scored {
+        Vec::new()
+    } else {
+        vec![GameAnnotation::Shutout {
+            team: team_name(game, home_won).to_string(),
+        }]
+    }
+}
+
+fn first_season_goals(game: &GameData, history: &ScorerHistory) -> Vec<GameAnnotation> {
+    let mut seen = HashSet::new();
+    game.goal_events
+        .iter()
+        .filter(|e| !is_shootout_goal(e) && e.scorer_player_id > 0)
+        .filter(|e| seen.insert(e.scorer_player_id))
+        .filter(|e| {
+            history.is_first_season_goal(team_name(game, e.is_home_team), e.scorer_player_id)
+        })
+        .map(|e| GameAnnotation::FirstSeasonGoal {
+            scorer: e.scorer_name.clone(),
+        })
+        .collect()
+}
+
+fn empty_net_goals(game: &GameData, _: &ScorerHistory) -> Vec<GameAnnotation> {
+    game.goal_events
+        .iter()
+        .filter(|e| e.goal_types.iter().any(|t| t == "TM"))
+        .map(|e| GameAnnotation::EmptyNetGoal {
+            scorer: e.scorer_name.clone(),
+            minute: e.minute,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing_utils::TestDataBuilder;
+
+    /// A goal by `scorer` (id from the name's length and first letter) that
+    /// left the score at `score`.
+    fn goal(scorer: &str, minute: i32, score: (i32, i32), is_home_team: bool) -> GoalEventData {
+        GoalEventData {
+            scorer_player_id: scorer.len() as i64 * 1000 + scorer.as_bytes()[0] as i64,
+            scorer_name: scorer.to_string(),
+            minute,
+            home_team_score: score.0,
+            away_team_score: score.1,
+            is_winning_goal: false,
+            goal_types: vec![],
+            is_home_team,
+            video_clip_url: None,
+        }
+    }
+
+    fn typed(mut event: GoalEventData, goal_type: &str) -> GoalEventData {
+        event.goal_types = vec![goal_type.to_string()];
+        event
+    }
+
+    fn finished(goal_events: Vec<GoalEventData>) -> GameData {
+        let mut game = TestDataBuilder::create_custom_game(0, "TPS", "HIFK", "", "runkosarja");
+        game.goal_events = goal_events;
+        game
+    }
+
+    #[test]
+    fn test_hat_trick_ignores_shootout_winner() {
+        let game = finished(vec![
+            goal("Aho", 5, (1, 0), true),
+            goal("Aho", 25, (2, 1), true),
+            goal("Lehtonen", 12, (1, 1), false),
+            goal("Lehtonen", 50, (2, 2), false),
+            goal("Aho", 52, (3, 2), true),
+            goal("Ranta", 58, (3, 3), false),
+            typed(goal("Lehtonen", 65, (3, 4), false), "VL"),
+        ]);
+
+        let annotations = annotate_game(&game, &ScorerHistory::default());
+
+        assert_eq!(
+            annotations
+                .iter()
+                .filter(|a| matches!(a, GameAnnotation::HatTrick { .. }))
+                .collect::<Vec<_>>(),
+            vec![&GameAnnotation::HatTrick {
+                scorer: "Aho".to_string(),
+                goals: 3
+            }]
+        );
+    }
+
+    #[test]
+    fn test_comeback_from_two_down_in_third_period() {
+        // HIFK leads 3-1 early in the third; TPS wins 4-3
+        let game = finished(vec![
+            goal("Aho", 10, (1, 0), true),
+            goal("Kivi", 57, (4, 3), true),
+            goal("Aho", 52, (3, 3), true),
+            goal("Lehto", 15, (1, 1), false),
+            goal("Lehto", 30, (1, 2), false),
+            goal("Ranta", 41, (1, 3), false),
+            goal("Aho", 45, (2, 3), true),
+        ]);
+
+        assert!(annotate_game(&game, &ScorerHistory::default()).contains(
+            &GameAnnotation::Comeback {
+                team: "TPS".to_string(),
+                deficit: 2
+            }
+        ));
+    }
+
+    #[test]
+    fn test_no_comeback_when_deficit_was_before_third_period() {
+        let game = finished(vec![
+            goal("Lehto", 5, (0, 1), false),
+            goal("Lehto", 8, (0, 2), false),
+            goal("Aho", 15, (1, 2), true),
+            goal("Aho", 30, (2, 2), true),
+            goal("Kivi", 55, (3, 2), true),
+        ]);
+
+        assert!(
+            !annotate_game(&game, &ScorerHistory::default())
+                .iter()
+                .any(|a| matches!(a, GameAnnotation::Comeback { .. }))
+        );
+    }
+
+    #[test]
+    fn test_shutout_and_empty_net_goal() {
+        let game = finished(vec![
+            goal("Lehto", 20, (0, 1), false),
+            typed(goal("Ranta", 59, (0, 2), false), "TM"),
+        ]);
+
+        assert_eq!(
+            annotate_game(&game, &ScorerHistory::default()),
+            vec![
+                GameAnnotation::Shutout {
+                    team: "HIFK".to_string()
+                },
+                GameAnnotation::EmptyNetGoal {
+                    scorer: "Ranta".to_string(),
+                    minute: 59
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_first_season_goal_needs_complete_team_history() {
+        let game = finished(vec![
+            goal("Aho", 5, (1, 0), true),
+            goal("Kivi", 10, (2, 0), true),
+            goal("Lehto", 20, (2, 1), false),
+        ]);
+        let mut history = ScorerHistory::default();
+        history.add_earlier_game(&[goal("Aho", 30, (1, 0), true)]);
+
+        // Nothing is known to be a first goal until the history covers a team
+        assert!(
+            !annotate_game(&game, &history)
+                .iter()
+                .any(|a| matches!(a, GameAnnotation::FirstSeasonGoal { .. }))
+        );
+
+        history.mark_complete("TPS");
+        let firsts: Vec<_> = annotate_game(&game, &history)
+            .into_iter()
+            .filter(|a| matches!(a, GameAnnotation::FirstSeasonGoal { .. }))
+            .collect();
+        assert_eq!(
+            firsts,
+            vec![GameAnnotation::FirstSeasonGoal {
+                scorer: "Kivi".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_only_finished_games_are_annotated() {
+        let mut ongoing = finished(vec![goal("Aho", 5, (1, 0), true)]);
+        ongoing.score_type = ScoreType::Ongoing;
+        let mut games = vec![ongoing, finished(vec![goal("Aho", 5, (1, 0), true)])];
+
+        annotate_games(&mut games, &ScorerHistory::default());
+
+        assert!(games[0].annotations.is_empty());
+        assert_eq!(
+            games[1].annotations,
+            vec![GameAnnotation::Shutout {
+                team: "TPS".to_string()
+            }]
+        );
+    }
+}
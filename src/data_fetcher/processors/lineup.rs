@@ -0,0 +1,168 @@
+//! Team lineups and sidelined players for a single game.
+//!
+//! Groups each roster by line number and collects the injured and suspended
+//! players of both teams. Pure data processing: rendering lives in
+//! `teletext_ui::game_detail_display`.
+
+use super::timeline::roster_names;
+use crate::data_fetcher::models::{DetailedGameResponse, Player};
+use crate::data_fetcher::player_names::create_fallback_name;
+use std::collections::BTreeMap;
+
+/// Players dressed on one line, in roster order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LineGroup {
+    pub line: i32,
+    pub players: Vec<String>,
+}
+
+/// Why a player is out of the lineup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SidelineReason {
+    Injured,
+    Suspended,
+}
+
+/// A player missing the game.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SidelinedPlayer {
+    pub name: String,
+    pub reason: SidelineReason,
+}
+
+/// One team's lineup for the game.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct TeamLineup {
+    /// Lines in ascending line number order
+    pub lines: Vec<LineGroup>,
+    pub sidelined: Vec<SidelinedPlayer>,
+}
+
+/// Lineups of both teams.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct GameLineups {
+    pub home: TeamLineup,
+    pub away: TeamLineup,
+}
+
+/// Builds both teams' lineups from the rosters in the detailed game data.
+///
+/// # Arguments
+/// * `details` - Detailed game response including both rosters
+///
+/// # Returns
+/// * `GameLineups` - Players grouped by line plus the sidelined players
+pub fn build_game_lineups(details: &DetailedGameResponse) -> GameLineups {
+    GameLineups {
+        home: build_team_lineup(&details.home_team_players),
+        away: build_team_lineup(&details.away_team_players),
+    }
+}
+
+/// Groups one roster by line. Removed players are left out entirely;
+/// injured and suspended players go to the sidelined list even if the
+/// roster still has a line number for them.
+fn build_team_lineup(players: &[Player]) -> TeamLineup {
+    let names = roster_names(players);
+    let name_of = |player: &Player| {
+        names
+            .get(&player.id)
+            .cloned()
+            .unwrap_or_else(|| create_fallback_name(player.id))
+    };
+
+    let mut lines: BTreeMap<i32, Vec<String>> = BTreeMap::new();
+    let mut sidelined = Vec::new();
+    for player in players.iter().filter(|p| !p.removed) {
+        let reason = if player.suspended {
+            Some(SidelineReason::Suspended)
+        } else if player.injured {
+            Some(SidelineReason::Injured)
+        } else {
+            None
+        };
+        match (reason, player.line) {
+            (Some(reason), _) => sidelined.push(SidelinedPlayer {
+                name: name_of(player),
+                reason,
+            }),
+            (None, Some(line)) => lines.entry(line).or_default().push(name_of(player)),
+            (None, None) => {}
+        }
+    }
+
+    TeamLineup {
+        lines: lines
+            .into_iter()
+            .map(|(line, players)| LineGroup { line, players })
+            .collect(),
+        sidelined,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player(id: i64, last: &str, line: Option<i32>) -> Player {
+        Player {
+            id,
+            first_name: "Testi".to_string(),
+            last_name: last.to_string(),
+            line,
+            ..Player::default()
+        }
+    }
+
+    #[test]
+    fn test_players_grouped_by_line_in_order() {
+        let lineup = build_team_lineup(&[
+            player(1, "Kolmonen", Some(3)),
+            player(2, "Ykkönen", Some(1)),
+            player(3, "Toinen", Some(1)),
+            player(4, "Katsomo", None),
+        ]);
+        let lines: Vec<_> = lineup.lines.iter().map(|l| l.line).collect();
+        assert_eq!(lines, vec![1, 3]);
+        assert_eq!(lineup.lines[0].players, vec!["Ykkönen", "Toinen"]);
+        assert!(lineup.sidelined.is_empty());
+    }
+
+    #[test]
+    fn test_injured_and_suspended_are_sidelined() {
+        let lineup = build_team_lineup(&[
+            Player {
+                injured: true,
+                ..player(1, "Loukkaantunut", Some(2))
+            },
+            Player {
+                suspended: true,
+                ..player(2, "Pelikielto", None)
+            },
+            Player {
+                removed: true,
+                injured: true,
+                ..player(3, "Poistettu", Some(1))
+            },
+        ]);
+        assert!(lineup.lines.is_empty());
+        assert_eq!(
+            lineup.sidelined,
+            vec![
+                SidelinedPlayer {
+                    name: "Loukkaantunut".to_string(),
+                    reason: SidelineReason::Injured,
+                },
+                SidelinedPlayer {
+                    name: "Pelikielto".to_string(),
+                    reason: SidelineReason::Suspended,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty_roster_gives_empty_lineup() {
+        assert_eq!(build_team_lineup(&[]), TeamLineup::default());
+    }
+}
@@ -1,9 +1,14 @@
+pub mod annotations;
 pub mod core;
 pub mod game_status;
 pub mod goal_events;
+pub mod lineup;
+pub mod off_season;
 pub mod player_fetching;
 pub mod playoff_series;
+pub mod pre_game;
 pub mod time_formatting;
+pub mod timeline;
 
 // Re-export all public items from core for backward compatibility
 
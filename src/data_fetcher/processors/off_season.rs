@@ -0,0 +1,225 @@
+use crate::data_fetcher::api::tournament_logic::TournamentType;
+use crate::data_fetcher::models::ScheduleApiGame;
+use crate::data_fetcher::models::bracket::PlayoffBracket;
+use crate::data_fetcher::models::standings::StandingsEntry;
+use chrono::{DateTime, Datelike, Local, NaiveDate};
+
+/// Playoff phase of the final series
+const FINAL_PHASE: i32 = 5;
+
+/// First month after the playoffs have ended
+const OFF_SEASON_START_MONTH: u32 = 5;
+
+/// What the off-season page shows: the countdowns and fixtures of the coming
+/// season and the outcome of the one that ended.
+#[derive(Debug, Clone, Default)]
+pub struct OffSeasonInfo {
+    /// The season that ended, by its ending year as in the API
+    pub finished_season: i32,
+    /// Days until the first preseason game; 0 once the preseason is underway
+    pub days_until_preseason: Option<i64>,
+    /// Days until the first regular season game; 0 once the season is underway
+    pub days_until_regular_season: Option<i64>,
+    /// Regular season games of the opening day, by start time
+    pub opening_fixtures: Vec<ScheduleApiGame>,
+    /// Preseason games from today on, by start time
+    pub preseason_games: Vec<ScheduleApiGame>,
+    pub champion: Option<String>,
+    /// Final table of the finished season, sorted by ranking
+    pub final_standings: Vec<StandingsEntry>,
+}
+
+/// The last season whose playoffs have been played by `today`. From May on
+/// it is the season ending this year.
+pub fn finished_season(today: NaiveDate) -> i32 {
+    if today.month() >= OFF_SEASON_START_MONTH {
+        today.year()
+    } else {
+        today.year() - 1
+    }
+}
+
+/// Local calendar date of an API start time.
+pub fn local_date(start: &str) -> Option<NaiveDate> {
+    DateTime::parse_from_rfc3339(start)
+        .ok()
+        .map(|dt| dt.with_timezone(&Local).date_naive())
+}
+
+/// Builds the off-season page data.
+///
+/// `upcoming` holds the coming season's regular season and preseason
+/// schedule games (told apart by `serie`), `bracket` the finished season's
+/// playoffs and `final_standings` its league table.
+pub fn build_off_season_info(
+    today: NaiveDate,
+    upcoming: &[ScheduleApiGame],
+    bracket: &PlayoffBracket,
+    final_standings: Vec<StandingsEntry>,
+) -> OffSeasonInfo {
+    let mut games: Vec<(NaiveDate, &ScheduleApiGame)> = upcoming
+        .iter()
+        .filter_map(|game| local_date(&game.start).map(|date| (date, game)))
+        .collect();
+    games.sort_by(|a, b| a.1.start.cmp(&b.1.start));
+
+    let regular_serie = TournamentType::Runkosarja.to_serie();
+    let preseason_serie = TournamentType::ValmistavatOttelut.to_serie();
+    let first_date = |serie: i32| {
+        games
+            .iter()
+            .find(|(_, game)| game.serie == serie)
+            .map(|(date, _)| *date)
+    };
+    let days_until = |date: NaiveDate| (date - today).num_days().max(0);
+
+    let opening_day = first_date(regular_serie);
+    let opening_fixtures = games
+        .iter()
+        .filter(|(date, game)| game.serie == regular_serie && Some(*date) == opening_day)
+        .map(|(_, game)| (*game).clone())
+        .collect();
+    let preseason_games = games
+        .iter()
+        .filter(|(date, game)| game.serie == preseason_serie && *date >= today)
+        .map(|(_, game)| (*game).clone())
+        .collect();
+
+    OffSeasonInfo {
+        finished_season: finished_season(today),
+        days_until_preseason: first_date(preseason_serie).map(days_until),
+        days_until_regular_season: opening_day.map(days_until),
+        opening_fixtures,
+        preseason_games,
+        champion: champion(bracket),
+        final_standings,
+    }
+}
+
+/// Winner of the final series, once it has been decided.
+fn champion(bracket: &PlayoffBracket) -> Option<String> {
+    bracket
+        .phases
+        .iter()
+        .filter(|phase| phase.phase_number == FINAL_PHASE)
+        .flat_map(|phase| &phase.matchups)
+        .find_map(|matchup| matchup.winner.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_fetcher::models::bracket::build_playoff_bracket;
+
+    fn game(serie: TournamentType, start: &str, home: &str, away: &str) -> ScheduleApiGame {
+        ScheduleApiGame {
+            id: 0,
+            season: 2027,
+            start: start.to_string(),
+            home_team_name: home.to_string(),
+            away_team_name: away.to_string(),
+            serie: serie.to_serie(),
+            finished_type: None,
+            started: false,
+            ended: false,
+            game_time: None,
+            play_off_phase: None,
+            play_off_pair: None,
+            play_off_req_wins: None,
+            home_team_goals: 0,
+            away_team_goals: 0,
+        }
+    }
+
+    fn final_game(home: &str, away: &str, score: (i32, i32)) -> ScheduleApiGame {
+        let mut game = game(TournamentType::Playoffs, "2026-04-25T15:00:00Z", home, away);
+        game.play_off_phase = Some(FINAL_PHASE);
+        game.play_off_pair = Some(1);
+        game.play_off_req_wins = Some(1);
+        game.ended = true;
+        game.finished_type = Some("ENDED_DURING_REGULAR_GAME_TIME".to_string());
+        game.home_team_goals = score.0;
+        game.away_team_goals = score.1;
+        game
+    }
+
+    fn date(text: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(text, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_finished_season_turns_in_may() {
+        assert_eq!(finished_season(date("2026-04-30")), 2025);
+        assert_eq!(finished_season(date("2026-05-01")), 2026);
+        assert_eq!(finished_season(date("2026-10-18")), 2026);
+    }
+
+    #[test]
+    fn test_countdowns_and_opening_day() {
+        let upcoming = vec![
+            game(
+                TournamentType::Runkosarja,
+                "2026-09-12T15:00:00Z",
+                "TPS",
+                "HIFK",
+            ),
+            game(
+                TournamentType::Runkosarja,
+                "2026-09-11T15:30:00Z",
+                "Ilves",
+                "Lukko",
+            ),
+            game(
+                TournamentType::Runkosarja,
+                "2026-09-11T15:30:00Z",
+                "Tappara",
+                "KalPa",
+            ),
+            game(
+                TournamentType::ValmistavatOttelut,
+                "2026-07-31T15:00:00Z",
+                "Ässät",
+                "TPS",
+            ),
+            game(
+                TournamentType::ValmistavatOttelut,
+                "2026-08-14T15:00:00Z",
+                "HIFK",
+                "Jukurit",
+            ),
+        ];
+
+        let info = build_off_season_info(
+            date("2026-08-01"),
+            &upcoming,
+            &build_playoff_bracket(&[], "2025-2026"),
+            vec![],
+        );
+
+        assert_eq!(info.finished_season, 2026);
+        // The preseason has started: only the games still to come are listed
+        assert_eq!(info.days_until_preseason, Some(0));
+        assert_eq!(info.preseason_games.len(), 1);
+        assert_eq!(info.preseason_games[0].home_team_name, "HIFK");
+        assert_eq!(info.days_until_regular_season, Some(41));
+        assert_eq!(
+            info.opening_fixtures
+                .iter()
+                .map(|g| g.home_team_name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["Ilves", "Tappara"]
+        );
+        assert_eq!(info.champion, None);
+    }
+
+    #[test]
+    fn test_champion_is_final_winner() {
+        let bracket = build_playoff_bracket(&[final_game("Tappara", "KalPa", (3, 1))], "2025-2026");
+
+        let info = build_off_season_info(date("2026-06-01"), &[], &bracket, vec![]);
+
+        assert_eq!(info.champion.as_deref(), Some("Tappara"));
+        assert_eq!(info.days_until_preseason, None);
+        assert_eq!(info.days_until_regular_season, None);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::data_fetcher::models::standings::StandingsEntry;
+use crate::data_fetcher::models::{GameData, PreGameInfo, ScheduleApiGame, TeamPreGame};
+use crate::teletext_ui::ScoreType;
+
+/// Number of recent games shown as a team's form
+pub const FORM_GAMES: usize = 5;
+
+/// Fills in `pre_game` for every scheduled game from the league table and
+/// the season schedule.
+///
+/// Positions and points come from `standings` (sorted by ranking). Form and
+/// head-to-head count only schedule games that ended before the game starts;
+/// games with equal scores are skipped as incomplete data, as in
+/// `calculate_series_scores`.
+pub fn add_pre_game_info(
+    games: &mut [GameData],
+    standings: &[StandingsEntry],
+    schedule: &[ScheduleApiGame],
+) {
+    let mut decided: Vec<&ScheduleApiGame> = schedule
+        .iter()
+        .filter(|g| g.ended && g.home_team_goals != g.away_team_goals)
+        .collect();
+    decided.sort_by(|a, b| a.start.cmp(&b.start));
+
+    for game in games.iter_mut() {
+        if !matches!(game.score_type, ScoreType::Scheduled) || game.is_placeholder {
+            continue;
+        }
+        let played: Vec<&ScheduleApiGame> = decided
+            .iter()
+            .copied()
+            .filter(|g| g.start < game.start)
+            .collect();
+
+        game.pre_game = Some(PreGameInfo {
+            home: team_pre_game(&game.home_team, standings, &played),
+            away: team_pre_game(&game.away_team, standings, &played),
+            head_to_head: head_to_head(&game.home_team, &game.away_team, &played),
+        });
+    }
+}
+
+fn team_pre_game(
+    team: &str,
+    standings: &[StandingsEntry],
+    played: &[&ScheduleApiGame],
+) -> TeamPreGame {
+    let standing = standings
+        .iter()
+        .enumerate()
+        .find(|(_, entry)| entry.team_name == team);
+
+    let results: Vec<bool> = played
+        .iter()
+        .filter_map(|g| winner(g).map(|winner| (g, winner)))
+        .filter(|(g, _)| g.home_team_name == team || g.away_team_name == team)
+        .map(|(_, winner)| winner == team)
+        .collect();
+    let form = results[results.len().saturating_sub(FORM_GAMES)..].to_vec();
+
+    TeamPreGame {
+        position: standing.map(|(index, _)| index as u16 + 1),
+        points: standing.map(|(_, entry)| entry.points),
+        form,
+    }
+}
+
+fn head_to_head(home: &str, away: &str, played: &[&ScheduleApiGame]) -> (u8, u8) {
+    played
+        .iter()
+        .filter(|g| {
+            (g.home_team_name == home && g.away_team_name == away)
+                || (g.home_team_name == away && g.away_team_name == home)
+        })
+        .filter_map(|g| winner(g))
+        .fold((0, 0), |(home_wins, away_wins), winner| {
+            if winner == home {
+                (home_wins + 1, away_wins)
+            } else {
+                (home_wins, away_wins + 1)
+            }
+        })
+}
+
+fn winner(game: &ScheduleApiGame) -> Option<&str> {
+    match game.home_team_goals.cmp(&game.away_team_goals) {
+        std::cmp::Ordering::Greater => Some(&game.home_team_name),
+        std::cmp::Ordering::Less => Some(&game.away_team_name),
+        std::cmp::Ordering::Equal => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing_utils::TestDataBuilder;
+
+    fn ended(home: &str, away: &str, start: &str, score: (i32, i32)) -> ScheduleApiGame {
+        ScheduleApiGame {
+            id: 0,
+            season: 2025,
+            start: start.to_string(),
+            home_team_name: home.to_string(),
+            away_team_name: away.to_string(),
+            serie: 1,
+            finished_type: Some("ENDED_DURING_REGULAR_GAME_TIME".to_string()),
+            started: true,
+            ended: true,
+            game_time: Some(3600),
+            play_off_phase: None,
+            play_off_pair: None,
+            play_off_req_wins: None,
+            home_team_goals: score.0,
+            away_team_goals: score.1,
+        }
+    }
+
+    fn standing(team: &str, points: u16) -> StandingsEntry {
+        StandingsEntry {
+            team_name: team.to_string(),
+            team_id: String::new(),
+            games_played: 0,
+            wins: 0,
+            ot_wins: 0,
+            ot_losses: 0,
+            losses: 0,
+            goals_for: 0,
+            goals_against: 0,
+            points,
+            live_goals_for: 0,
+            live_goals_against: 0,
+            live_points_delta: None,
+            live_position_change: None,
+            live_game_active: false,
+        }
+    }
+
+    fn scheduled(home: &str, away: &str, start: &str) -> GameData {
+        let mut game = TestDataBuilder::create_custom_game(0, home, away, "", "runkosarja");
+        game.score_type = ScoreType::Scheduled;
+        game.start = start.to_string();
+        game
+    }
+
+    #[test]
+    fn test_positions_and_points_from_standings() {
+        let standings = vec![
+            standing("Tappara", 40),
+            standing("HIFK", 35),
+            standing("TPS", 30),
+        ];
+        let mut games = vec![scheduled("TPS", "Tappara", "2025-11-01T15:00:00Z")];
+
+        add_pre_game_info(&mut games, &standings, &[]);
+
+        let info = games[0].pre_game.as_ref().unwrap();
+        assert_eq!(info.home.position, Some(3));
+        assert_eq!(info.home.points, Some(30));
+        assert_eq!(info.away.position, Some(1));
+        assert_eq!(info.away.points, Some(40));
+        assert_eq!(info.head_to_head, (0, 0));
+    }
+
+    #[test]
+    fn test_form_keeps_last_five_games_before_start() {
+        let schedule = vec![
+            ended("TPS", "HIFK", "2025-10-01T18:30:00Z", (1, 2)),
+            ended("Ilves", "TPS", "2025-10-03T18:30:00Z", (1, 4)),
+            ended("TPS", "Lukko", "2025-10-05T18:30:00Z", (3, 2)),
+            ended("KalPa", "TPS", "2025-10-08T18:30:00Z", (5, 0)),
+            ended("TPS", "Pelicans", "2025-10-10T18:30:00Z", (2, 1)),
+            ended("Kärpät", "TPS", "2025-10-12T18:30:00Z", (1, 3)),
+            // After the game, not part of the form
+            ended("TPS", "Ässät", "2025-11-05T18:30:00Z", (0, 6)),
+        ];
+        let mut games = vec![scheduled("TPS", "Tappara", "2025-11-01T15:00:00Z")];
+
+        add_pre_game_info(&mut games, &[], &schedule);
+
+        let info = games[0].pre_game.as_ref().unwrap();
+        assert_eq!(info.home.form, vec![true, true, false, true, true]);
+        assert!(info.away.form.is_empty());
+        assert_eq!(info.home.position, None);
+    }
+
+    #[test]
+    fn test_head_to_head_counts_both_venues_and_skips_ties() {
+        let schedule = vec![
+            ended("TPS", "HIFK", "2025-09-20T18:30:00Z", (3, 1)),
+            ended("HIFK", "TPS", "2025-10-04T18:30:00Z", (2, 1)),
+            ended("HIFK", "TPS", "2025-10-18T18:30:00Z", (0, 4)),
+            ended("TPS", "HIFK", "2025-10-25T18:30:00Z", (2, 2)),
+            ended("TPS", "Lukko", "2025-10-26T18:30:00Z", (2, 0)),
+        ];
+        let mut games = vec![scheduled("TPS", "HIFK", "2025-11-01T15:00:00Z")];
+
+        add_pre_game_info(&mut games, &[], &schedule);
+
+        assert_eq!(games[0].pre_game.as_ref().unwrap().head_to_head, (2, 1));
+    }
+
+    #[test]
+    fn test_only_scheduled_games_get_pre_game_info() {
+        let mut finished = scheduled("TPS", "HIFK", "2025-11-01T15:00:00Z");
+        finished.score_type = ScoreType::Final;
+        let mut placeholder = scheduled("QF1", "QF4", "2025-11-01T18:30:00Z");
+        placeholder.is_placeholder = true;
+        let mut games = vec![finished, placeholder];
+
+        add_pre_game_info(&mut games, &[standing("TPS", 30)], &[]);
+
+        assert!(games.iter().all(|game| game.pre_game.is_none()));
+    }
+}
@@ -0,0 +1,479 @@
+//! Chronological event timeline for a single game.
+//!
+//! Interleaves both teams' goals and penalties from the detailed game data,
+//! derives the power-play windows the penalties create and tracks the scoring
+//! team's lead after every goal. Pure data processing: rendering lives in
+//! `teletext_ui::game_detail_display`.
+
+use crate::data_fetcher::models::{DetailedGameResponse, DetailedTeam, Player};
+use crate::data_fetcher::player_names::{
+    build_full_name, create_fallback_name, format_for_display, format_with_disambiguation,
+};
+use std::collections::HashMap;
+
+/// Penalty lengths (minutes) that put the team a skater short. Misconducts
+/// (10 min) and game misconducts (20 min) are served without a power play.
+const MANPOWER_PENALTY_MINUTES: [i32; 3] = [2, 4, 5];
+
+/// Skaters a team has on the ice at full strength.
+const FULL_STRENGTH: i32 = 5;
+
+/// Fewest skaters a team can be reduced to; further penalties stack up.
+const MIN_SKATERS: i32 = 3;
+
+/// What happened at a point of the timeline.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TimelineEventKind {
+    Goal {
+        scorer: String,
+        home_score: i32,
+        away_score: i32,
+        goal_types: Vec<String>,
+        is_winning_goal: bool,
+        /// Scoring team's lead after the goal (0 for an equalizer)
+        lead: i32,
+    },
+    Penalty {
+        player: String,
+        minutes: i32,
+        reason: String,
+    },
+    /// A team gains a skater advantage
+    PowerPlayStart {
+        /// Game time the advantage ends
+        ends_at: i32,
+        /// Skaters on ice (team with the advantage, opponent)
+        skaters: (i32, i32),
+    },
+}
+
+/// One row of the timeline.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TimelineEntry {
+    /// Game time in seconds
+    pub game_time: i32,
+    /// Period number (4+ for overtime)
+    pub period: i32,
+    /// Team the event belongs to (for power plays: the team with the advantage)
+    pub is_home_team: bool,
+    /// Whether the event happened while a power play was running
+    pub in_power_play: bool,
+    pub kind: TimelineEventKind,
+}
+
+/// A stretch of game time during which one team had more skaters on the ice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PowerPlayWindow {
+    /// Team with the advantage
+    pub is_home_team: bool,
+    pub start: i32,
+    pub end: i32,
+    /// Skaters on ice (team with the advantage, opponent) when the window opened
+    pub skaters: (i32, i32),
+}
+
+impl PowerPlayWindow {
+    /// Whether an event at `game_time` falls inside the window. The opening
+    /// second belongs to the penalty that caused it; a power-play goal that
+    /// ends the window early still counts as inside.
+    pub fn contains(&self, game_time: i32) -> bool {
+        game_time > self.start && game_time <= self.end
+    }
+}
+
+/// Timeline of a game: entries in chronological order plus power-play windows.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct GameTimeline {
+    pub entries: Vec<TimelineEntry>,
+    pub power_plays: Vec<PowerPlayWindow>,
+}
+
+/// Builds the timeline for a game from its detailed data.
+///
+/// # Arguments
+/// * `details` - Detailed game response including both rosters
+///
+/// # Returns
+/// * `GameTimeline` - Goals, penalties and power-play starts sorted by game time
+pub fn build_game_timeline(details: &DetailedGameResponse) -> GameTimeline {
+    let home_names = roster_names(&details.home_team_players);
+    let away_names = roster_names(&details.away_team_players);
+    let game = &details.game;
+
+    let power_plays = power_play_windows(&game.home_team, &game.away_team);
+    let in_power_play = |t: i32| power_plays.iter().any(|w| w.contains(t));
+
+    let mut entries = Vec::new();
+    for (team, names, is_home_team) in [
+        (&game.home_team, &home_names, true),
+        (&game.away_team, &away_names, false),
+    ] {
+        for goal in &team.goal_events {
+            let scorer = names
+                .get(&goal.scorer_player_id)
+                .cloned()
+                .unwrap_or_else(|| {
+                    goal.scorer_player.as_ref().map_or_else(
+                        || create_fallback_name(goal.scorer_player_id),
+                        |p| format_for_display(&build_full_name("", &p.last_name)),
+                    )
+                });
+            let lead = if is_home_team {
+                goal.home_team_score - goal.away_team_score
+            } else {
+                goal.away_team_score - goal.home_team_score
+            };
+            entries.push(TimelineEntry {
+                game_time: goal.game_time,
+                period: goal.period,
+                is_home_team,
+                in_power_play: in_power_play(goal.game_time),
+                kind: TimelineEventKind::Goal {
+                    scorer,
+                    home_score: goal.home_team_score,
+                    away_score: goal.away_team_score,
+                    goal_types: goal.goal_types.clone(),
+                    is_winning_goal: goal.winning_goal,
+                    lead,
+                },
+            });
+        }
+
+        for penalty in &team.penalty_events {
+            let player_id = i64::from(penalty.player_id);
+            let player = names
+                .get(&player_id)
+                .cloned()
+                .unwrap_or_else(|| create_fallback_name(player_id));
+            entries.push(TimelineEntry {
+                game_time: penalty.game_time,
+                period: penalty.period,
+                is_home_team,
+                in_power_play: in_power_play(penalty.game_time),
+                kind: TimelineEventKind::Penalty {
+                    player,
+                    minutes: penalty.penalty_minutes,
+                    reason: penalty.penalty_fault_name.clone(),
+                },
+            });
+        }
+    }
+
+    for window in &power_plays {
+        entries.push(TimelineEntry {
+            game_time: window.start,
+            period: period_for_time(window.start),
+            is_home_team: window.is_home_team,
+            in_power_play: true,
+            kind: TimelineEventKind::PowerPlayStart {
+                ends_at: window.end,
+                skaters: window.skaters,
+            },
+        });
+    }
+
+    // Same second: goal first, then the penalty, then the power play it opens
+    entries.sort_by_key(|e| {
+        let order = match e.kind {
+            TimelineEventKind::Goal { .. } => 0,
+            TimelineEventKind::Penalty { .. } => 1,
+            TimelineEventKind::PowerPlayStart { .. } => 2,
+        };
+        (e.game_time, order)
+    });
+
+    GameTimeline {
+        entries,
+        power_plays,
+    }
+}
+
+/// Period number a game time falls into (20 minute periods).
+fn period_for_time(game_time: i32) -> i32 {
+    (game_time.max(0) / 1200) + 1
+}
+
+/// Display names for a roster, disambiguated within the team.
+pub(crate) fn roster_names(players: &[Player]) -> HashMap<i64, String> {
+    let tuples: Vec<(i64, String, String)> = players
+        .iter()
+        .map(|p| (p.id, p.first_name.clone(), p.last_name.clone()))
+        .collect();
+    format_with_disambiguation(&tuples)
+}
+
+/// Derives the power-play windows from both teams' penalties.
+///
+/// Walks the game in segments between penalty start and end times, counting
+/// the skaters each team has on the ice. Coincidental penalties cancel out;
+/// consecutive segments with the same advantage merge into one window.
+fn power_play_windows(home: &DetailedTeam, away: &DetailedTeam) -> Vec<PowerPlayWindow> {
+    let intervals = |team: &DetailedTeam| -> Vec<(i32, i32)> {
+        team.penalty_events
+            .iter()
+            .filter(|p| MANPOWER_PENALTY_MINUTES.contains(&p.penalty_minutes))
+            .filter(|p| p.penalty_endtime > p.penalty_begintime)
+            .map(|p| (p.penalty_begintime, p.penalty_endtime))
+            .collect()
+    };
+    let home_penalties = intervals(home);
+    let away_penalties = intervals(away);
+
+    let mut boundaries: Vec<i32> = home_penalties
+        .iter()
+        .chain(&away_penalties)
+        .flat_map(|&(begin, end)| [begin, end])
+        .collect();
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    let skaters_at = |penalties: &[(i32, i32)], t: i32| {
+        let serving = penalties
+            .iter()
+            .filter(|&&(begin, end)| begin <= t && t < end)
+            .count() as i32;
+        (FULL_STRENGTH - serving).max(MIN_SKATERS)
+    };
+
+    let mut windows: Vec<PowerPlayWindow> = Vec::new();
+    for segment in boundaries.windows(2) {
+        let (start, end) = (segment[0], segment[1]);
+        let home_skaters = skaters_at(&home_penalties, start);
+        let away_skaters = skaters_at(&away_penalties, start);
+        if home_skaters == away_skaters {
+            continue;
+        }
+
+        let is_home_team = home_skaters > away_skaters;
+        if let Some(last) = windows.last_mut()
+            && last.is_home_team == is_home_team
+            && last.end == start
+        {
+            last.end = end;
+            continue;
+        }
+
+        let skaters = if is_home_team {
+            (home_skaters, away_skaters)
+        } else {
+            (away_skaters, home_skaters)
+        };
+        windows.push(PowerPlayWindow {
+            is_home_team,
+            start,
+            end,
+            skaters,
+        });
+    }
+
+    windows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_fetcher::models::detailed::{DetailedGame, PenaltyEvent};
+    use crate::data_fetcher::models::goals::GoalEvent;
+
+    fn goal(scorer: i64, time: i32, home: i32, away: i32, types: &[&str]) -> GoalEvent {
+        GoalEvent {
+            scorer_player_id: scorer,
+            log_time: String::new(),
+            game_time: time,
+            period: time / 1200 + 1,
+            event_id: time,
+            home_team_score: home,
+            away_team_score: away,
+            winning_goal: false,
+            goal_types: types.iter().map(|t| t.to_string()).collect(),
+            assistant_player_ids: vec![],
+            video_clip_url: None,
+            scorer_player: None,
+        }
+    }
+
+    fn penalty(player: i32, begin: i32, minutes: i32) -> PenaltyEvent {
+        PenaltyEvent {
+            player_id: player,
+            sufferer_player_id: 0,
+            log_time: String::new(),
+            game_time: begin,
+            period: begin / 1200 + 1,
+            penalty_begintime: begin,
+            penalty_endtime: begin + minutes * 60,
+            penalty_fault_name: "Koukkaaminen".to_string(),
+            penalty_fault_type: String::new(),
+            penalty_minutes: minutes,
+        }
+    }
+
+    fn team(id: &str, goals: Vec<GoalEvent>, penalties: Vec<PenaltyEvent>) -> DetailedTeam {
+        DetailedTeam {
+            team_id: id.to_string(),
+            team_name: id.to_string(),
+            goals: goals.len() as i32,
+            goal_events: goals,
+            penalty_events: penalties,
+        }
+    }
+
+    fn player(id: i64, first: &str, last: &str) -> Player {
+        Player {
+            id,
+            first_name: first.to_string(),
+            last_name: last.to_string(),
+            ..Player::default()
+        }
+    }
+
+    fn details(home: DetailedTeam, away: DetailedTeam) -> DetailedGameResponse {
+        DetailedGameResponse {
+            game: DetailedGame {
+                id: 1,
+                season: 2026,
+                start: "2025-10-18T15:00:00Z".to_string(),
+                end: None,
+                home_team: home,
+                away_team: away,
+                periods: vec![],
+                finished_type: None,
+                started: true,
+                ended: false,
+                game_time: 3600,
+                serie: "runkosarja".to_string(),
+            },
+            awards: vec![],
+            home_team_players: vec![player(10, "Mikko", "Virtanen")],
+            away_team_players: vec![player(20, "Jani", "Lahtinen")],
+        }
+    }
+
+    #[test]
+    fn test_events_are_interleaved_chronologically() {
+        let timeline = build_game_timeline(&details(
+            team("HIFK", vec![goal(10, 300, 1, 0, &["EV"])], vec![]),
+            team(
+                "TPS",
+                vec![goal(20, 1500, 1, 1, &["EV"])],
+                vec![penalty(20, 900, 2)],
+            ),
+        ));
+
+        let times: Vec<i32> = timeline.entries.iter().map(|e| e.game_time).collect();
+        assert_eq!(times, vec![300, 900, 900, 1500]);
+        assert!(matches!(
+            timeline.entries[2].kind,
+            TimelineEventKind::PowerPlayStart { .. }
+        ));
+    }
+
+    #[test]
+    fn test_running_lead_is_from_scoring_team_perspective() {
+        let timeline = build_game_timeline(&details(
+            team(
+                "HIFK",
+                vec![goal(10, 300, 1, 0, &[]), goal(10, 700, 2, 0, &[])],
+                vec![],
+            ),
+            team("TPS", vec![goal(20, 1500, 2, 1, &[])], vec![]),
+        ));
+
+        let leads: Vec<i32> = timeline
+            .entries
+            .iter()
+            .filter_map(|e| match e.kind {
+                TimelineEventKind::Goal { lead, .. } => Some(lead),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(leads, vec![1, 2, -1]);
+    }
+
+    #[test]
+    fn test_minor_penalty_opens_power_play_for_opponent() {
+        let timeline = build_game_timeline(&details(
+            team("HIFK", vec![], vec![]),
+            team("TPS", vec![], vec![penalty(20, 600, 2)]),
+        ));
+
+        assert_eq!(
+            timeline.power_plays,
+            vec![PowerPlayWindow {
+                is_home_team: true,
+                start: 600,
+                end: 720,
+                skaters: (5, 4),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_coincidental_penalties_cancel_out() {
+        let timeline = build_game_timeline(&details(
+            team("HIFK", vec![], vec![penalty(10, 600, 2)]),
+            team("TPS", vec![], vec![penalty(20, 600, 2)]),
+        ));
+        assert!(timeline.power_plays.is_empty());
+    }
+
+    #[test]
+    fn test_overlapping_penalties_merge_into_one_window() {
+        // Two TPS minors 60s apart: 5-on-4, then 5-on-3, then 5-on-4 again
+        let timeline = build_game_timeline(&details(
+            team("HIFK", vec![], vec![]),
+            team(
+                "TPS",
+                vec![],
+                vec![penalty(20, 600, 2), penalty(20, 660, 2)],
+            ),
+        ));
+        assert_eq!(timeline.power_plays.len(), 1);
+        assert_eq!(timeline.power_plays[0].start, 600);
+        assert_eq!(timeline.power_plays[0].end, 780);
+    }
+
+    #[test]
+    fn test_misconduct_does_not_create_power_play() {
+        let timeline = build_game_timeline(&details(
+            team("HIFK", vec![], vec![]),
+            team("TPS", vec![], vec![penalty(20, 600, 10)]),
+        ));
+        assert!(timeline.power_plays.is_empty());
+    }
+
+    #[test]
+    fn test_power_play_goal_is_inside_window() {
+        let mut pen = penalty(20, 600, 2);
+        // Power-play goal at 11:00 ends the penalty early
+        pen.penalty_endtime = 660;
+        let timeline = build_game_timeline(&details(
+            team("HIFK", vec![goal(10, 660, 1, 0, &["YV"])], vec![]),
+            team("TPS", vec![], vec![pen]),
+        ));
+
+        let goal_entry = timeline
+            .entries
+            .iter()
+            .find(|e| matches!(e.kind, TimelineEventKind::Goal { .. }))
+            .unwrap();
+        assert!(goal_entry.in_power_play);
+    }
+
+    #[test]
+    fn test_names_come_from_rosters_with_fallback() {
+        let timeline = build_game_timeline(&details(
+            team("HIFK", vec![goal(10, 300, 1, 0, &[])], vec![]),
+            team("TPS", vec![], vec![penalty(99, 900, 2)]),
+        ));
+        match &timeline.entries[0].kind {
+            TimelineEventKind::Goal { scorer, .. } => assert_eq!(scorer, "Virtanen"),
+            other => panic!("expected goal, got {other:?}"),
+        }
+        match &timeline.entries[1].kind {
+            TimelineEventKind::Penalty { player, .. } => {
+                assert_eq!(player, &create_fallback_name(99))
+            }
+            other => panic!("expected penalty, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,854 @@
+//! UI strings in Finnish, English and Swedish
+//!
+//! Every text drawn on the teletext pages is looked up by key from the
+//! catalogue of the active language. The language is chosen once at startup
+//! (`--lang`, the `language` config setting or the locale in `LANG`) and
+//! defaults to Finnish. Team names, goal types and other data from the API
+//! are shown as they come.
+//!
+//! Texts may contain `{name}` placeholders, filled in with [`tf`].
+
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Language of the UI strings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    #[serde(rename = "fi")]
+    Finnish,
+    #[serde(rename = "en")]
+    English,
+    #[serde(rename = "sv")]
+    Swedish,
+}
+
+impl Language {
+    /// All languages, Finnish first
+    pub const ALL: [Language; 3] = [Language::Finnish, Language::English, Language::Swedish];
+
+    /// ISO 639-1 code used in the config file and with `--lang`
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::Finnish => "fi",
+            Language::English => "en",
+            Language::Swedish => "sv",
+        }
+    }
+
+    /// The language with the given code, e.g. `sv`.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|language| language.code().eq_ignore_ascii_case(code))
+    }
+
+    /// The language of a POSIX locale such as `sv_FI.UTF-8`, if it is one
+    /// we have strings for.
+    pub fn from_locale(locale: &str) -> Option<Self> {
+        let code = locale.split(['_', '.', '@', '-']).next().unwrap_or("");
+        Self::from_code(code)
+    }
+
+    /// The language of the user's locale: the first of `LC_ALL`,
+    /// `LC_MESSAGES` and `LANG` that is set.
+    pub fn from_env() -> Option<Self> {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|locale| !locale.is_empty())
+            .and_then(|locale| Self::from_locale(&locale))
+    }
+
+    fn catalogue(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Language::Finnish => FINNISH,
+            Language::English => ENGLISH,
+            Language::Swedish => SWEDISH,
+        }
+    }
+
+    /// The text for `key` in this language. Falls back to Finnish, and to
+    /// the key itself if no catalogue has it.
+    pub fn text(self, key: &'static str) -> &'static str {
+        lookup(self.catalogue(), key)
+            .or_else(|| lookup(FINNISH, key))
+            .unwrap_or(key)
+    }
+}
+
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+fn lookup(catalogue: &'static [(&'static str, &'static str)], key: &str) -> Option<&'static str> {
+    catalogue
+        .iter()
+        .find(|(entry, _)| *entry == key)
+        .map(|(_, text)| *text)
+}
+
+static LANGUAGE: AtomicU8 = AtomicU8::new(0);
+
+/// Sets the language of every string looked up from now on.
+pub fn set_language(language: Language) {
+    let index = Language::ALL
+        .iter()
+        .position(|l| *l == language)
+        .unwrap_or(0);
+    LANGUAGE.store(index as u8, Ordering::Relaxed);
+}
+
+/// The active language.
+pub fn language() -> Language {
+    Language::ALL
+        .get(usize::from(LANGUAGE.load(Ordering::Relaxed)))
+        .copied()
+        .unwrap_or_default()
+}
+
+/// The text for `key` in the active language.
+pub fn t(key: &'static str) -> &'static str {
+    language().text(key)
+}
+
+/// The text for `key` in the active language with its `{name}`
+/// placeholders replaced by the given values.
+pub fn tf(key: &'static str, args: &[(&str, &dyn Display)]) -> String {
+    fill(t(key), args)
+}
+
+fn fill(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    args.iter()
+        .fold(template.to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{name}}}"), &value.to_string())
+        })
+}
+
+const FINNISH: &[(&str, &str)] = &[
+    ("header.ice_hockey", "JÄÄKIEKKO"),
+    // Series names (page subheaders)
+    ("series.playoffs", "PLAYOFFS"),
+    ("series.playout", "PLAYOUT-OTTELUT"),
+    ("series.qualifications", "LIIGAKARSINTA"),
+    ("series.practice", "HARJOITUSOTTELUT"),
+    ("series.regular_season", "RUNKOSARJA"),
+    // Playoff phases
+    ("phase.first_round", "1. KIERROS"),
+    ("phase.quarter_finals", "PUOLIVÄLIERÄT"),
+    ("phase.semi_finals", "VÄLIERÄT"),
+    ("phase.bronze_game", "PRONSSIOTTELU"),
+    ("phase.final", "FINAALI"),
+    ("phase.playout", "PLAYOUT"),
+    ("phase.games", "OTTELUT"),
+    // Page subheaders
+    ("page.standings", "SARJATAULUKKO"),
+    ("page.standings_live", "SARJATAULUKKO (LIVE)"),
+    ("page.playoffs", "PUDOTUSPELIT"),
+    ("page.game_events", "OTTELUTAPAHTUMAT"),
+    ("page.lineups", "KOKOONPANOT"),
+    ("page.goal_videos", "MAALIVIDEOT"),
+    ("page.off_season", "KESÄTAUKO"),
+    ("page.diagnostics", "DIAGNOSTIIKKA"),
+    // Games list
+    ("games.next_games", "Seuraavat ottelut"),
+    ("games.next_games_short", "Seur. ottelut"),
+    ("games.no_games_today", "Ei otteluita tänään"),
+    ("games.no_games_on_date", "Ei otteluita päivälle {date}"),
+    ("games.fetch_failed", "Virhe haettaessa otteluita: {error}"),
+    (
+        "games.navigate_hint",
+        "Käytä Shift + nuolia siirtyäksesi toiselle päivälle",
+    ),
+    (
+        "games.restart_hint",
+        "tai käynnistä sovellus uudelleen (-d parametrilla)",
+    ),
+    ("games.restart_hint_end", "nähdäksesi päivän ottelut."),
+    ("games.refresh_hint", "tai paina 'r' päivittääksesi tiedot."),
+    ("games.season_countdown", "Runkosarjan alkuun {days} päivää"),
+    ("offseason.season", "KAUSI {season}"),
+    (
+        "offseason.preseason_countdown",
+        "Harjoitusotteluihin {days} päivää",
+    ),
+    (
+        "offseason.preseason_underway",
+        "Harjoitusottelut ovat käynnissä",
+    ),
+    ("offseason.season_underway", "Runkosarja on käynnissä"),
+    (
+        "offseason.start_unknown",
+        "Runkosarjan alkamispäivä ei ole vielä tiedossa",
+    ),
+    ("offseason.opening", "AVAUSKIERROS {date}"),
+    ("offseason.preseason", "HARJOITUSOTTELUT {date}"),
+    ("offseason.champion", "Suomen mestari: {team}"),
+    ("offseason.no_standings", "Sarjataulukko ei ole saatavilla"),
+    ("diagnostics.requests", "PYYNNÖT"),
+    ("diagnostics.in_flight", "Käynnissä nyt: {count}"),
+    ("diagnostics.no_requests", "Ei pyyntöjä vielä"),
+    ("diagnostics.endpoint", "Pääte"),
+    ("diagnostics.request_count", "Pyynn."),
+    ("diagnostics.errors", "Virh."),
+    ("diagnostics.retries", "Uus."),
+    ("diagnostics.response_times", "VASTEAJAT"),
+    ("diagnostics.api_endpoints", "RAJAPINNAT"),
+    ("diagnostics.paused", "tauolla {seconds}s"),
+    ("diagnostics.caches", "VÄLIMUISTIT"),
+    ("diagnostics.cache_name", "Nimi"),
+    ("diagnostics.cache_size", "Koko"),
+    ("diagnostics.cache_hits", "Osumat"),
+    ("diagnostics.cache_http", "http"),
+    ("diagnostics.cache_games", "ottelut"),
+    ("diagnostics.cache_tournament", "turnaus"),
+    ("diagnostics.cache_goals", "maalit"),
+    ("diagnostics.cache_players", "pelaajat"),
+    ("diagnostics.refresh", "PÄIVITYS"),
+    ("diagnostics.backoff_off", "Uusintaviive: ei käytössä"),
+    (
+        "diagnostics.backoff",
+        "Uusintaviive: {backoff} (jäljellä {remaining})",
+    ),
+    ("diagnostics.unavailable", "EI SAATAVILLA"),
+    ("diagnostics.all_available", "Kaikki turnaukset saatavilla"),
+    ("diagnostics.next_check", "uusi haku {minutes} min"),
+    ("pregame.points", "{points}p"),
+    ("pregame.form_win", "V"),
+    ("pregame.form_loss", "H"),
+    ("pregame.head_to_head", "Kesk. {home}–{away}"),
+    (
+        "annotation.hat_trick",
+        "Hattutemppu: {player} ({goals} maalia)",
+    ),
+    (
+        "annotation.comeback",
+        "Nousu: {team} {deficit} maalin takaa",
+    ),
+    ("annotation.shutout", "Nollapeli: {team}"),
+    ("annotation.first_season_goal", "Kauden 1. maali: {player}"),
+    (
+        "annotation.empty_net",
+        "Tyhjään maaliin: {player} {minute}.",
+    ),
+    ("result.shootout", "rl"),
+    ("result.overtime", "ja"),
+    ("clock.overtime", "JA"),
+    // Loading and error messages
+    (
+        "loading.historical",
+        "Haetaan historiallista dataa päivälle {date}...",
+    ),
+    (
+        "loading.historical_wait",
+        "Tämä voi kestää hetken, odotathan...",
+    ),
+    (
+        "loading.games_on_date",
+        "Haetaan otteluita päivälle {date}...",
+    ),
+    ("loading.todays_games", "Haetaan päivän otteluita..."),
+    ("loading.previous_games", "Etsitään edellisiä otteluita..."),
+    ("loading.next_games", "Etsitään seuraavia otteluita..."),
+    ("loading.standings", "Haetaan sarjataulukkoa..."),
+    ("loading.playoffs", "Haetaan pudotuspelejä..."),
+    ("loading.lineups", "Haetaan kokoonpanoja..."),
+    ("loading.off_season", "Haetaan kauden tietoja..."),
+    ("loading.game_events", "Haetaan ottelun tapahtumia..."),
+    ("loading.refreshing", "Päivitetään..."),
+    ("error.standings", "Sarjataulukon lataus epäonnistui."),
+    ("error.standings_back", "Paina 's' palataksesi otteluihin."),
+    ("error.playoffs", "Pudotuspelien lataus epäonnistui."),
+    ("error.playoffs_back", "Paina 'p' palataksesi."),
+    ("error.off_season", "Kauden tietojen lataus epäonnistui."),
+    ("error.lineups", "Kokoonpanojen lataus epäonnistui."),
+    (
+        "error.game_events",
+        "Ottelun tapahtumien lataus epäonnistui.",
+    ),
+    ("error.detail_back", "Paina Esc palataksesi."),
+    // Page not found (block graphics support the letters used here)
+    ("not_found.art_top", "SIVUA"),
+    ("not_found.art_bottom", "EI LÖYDY"),
+    ("not_found.message", "Sivu {page} ei ole käytössä"),
+    (
+        "not_found.pages",
+        "221 Ottelut  222 Taulukko  223 Pudotuspelit",
+    ),
+    // Footer
+    ("footer.quit", "q=Lopeta"),
+    ("footer.standings_games", "s=Ottelut"),
+    ("footer.live", "l=Live"),
+    ("footer.live_on", "l=Live ✓"),
+    ("footer.bracket_games", "p=Ottelut"),
+    ("footer.standings", "s=Taulukko"),
+    ("footer.detail_games", "Esc=Ottelut"),
+    ("footer.detail_game", "↑↓=Ottelu"),
+    ("footer.events", "k=Tapahtumat"),
+    ("footer.lineups", "k=Kokoonpanot"),
+    ("footer.videos", "g=Videot"),
+    ("footer.videos_back", "Esc=Tapahtumat"),
+    ("footer.videos_select", "↑↓=Maali"),
+    ("footer.videos_open", "Enter=Avaa"),
+    ("footer.refresh", "r=Päivitä"),
+    ("footer.previous", "⇧←Edellinen"),
+    ("footer.next", "⇧→Seuraava"),
+    ("footer.playoffs", "p=Pudotuspelit"),
+    ("footer.today", "t=Tänään"),
+    ("footer.game_page", "231=Ottelu"),
+    ("footer.off_season", "224=Kesätauko"),
+    ("footer.not_updating", "(Ei päivity)"),
+    // Game details
+    ("detail.period", "{period}. ERÄ"),
+    ("detail.shootout", "VOITTOLAUKAUKSET"),
+    ("detail.overtime", "JATKOAIKA"),
+    ("detail.power_play", "YV"),
+    ("detail.winning_goal", "VM"),
+    ("detail.shootout_win", "vl"),
+    ("detail.starts_at", "Ottelu alkaa klo {time}"),
+    ("detail.no_events", "Ei tapahtumia"),
+    ("lineup.unknown", "Kokoonpano ei ole vielä tiedossa"),
+    ("videos.none", "Ottelusta ei ole maalivideoita"),
+    ("videos.url", "VIDEON OSOITE"),
+    ("videos.opened", "AVATTU: {scorer} {minute}'"),
+    (
+        "videos.no_player",
+        "Ei videosoitinta – osoite näkyy sivulla",
+    ),
+    (
+        "videos.failed",
+        "Soitin ei käynnistynyt – osoite näkyy sivulla",
+    ),
+    ("lineup.sidelined", "Poissa:"),
+    ("lineup.injured", "loukk."),
+    ("lineup.suspended", "pelikielto"),
+    // Standings column labels
+    ("standings.team", "Joukkue"),
+    ("standings.games", "O"),
+    ("standings.wins", "V"),
+    ("standings.ot_wins", "JV"),
+    ("standings.ot_losses", "JH"),
+    ("standings.losses", "H"),
+    ("standings.goals_for", "TM"),
+    ("standings.goals_against", "PM"),
+    ("standings.points", "P"),
+    // Playoff bracket
+    ("bracket.not_running", "PUDOTUSPELIT EIVÄT OLE KÄYNNISSÄ"),
+    ("bracket.champion", "MESTARI"),
+    // Display modes
+    ("display_mode.normal", "NORMAALI"),
+    ("display_mode.compact", "TIIVIS"),
+    ("display_mode.wide", "LEVEÄ"),
+    ("display_mode.teletext", "TEKSTI-TV"),
+    (
+        "display_mode.too_narrow",
+        "{mode} VAATII {required} MERKKIÄ, NYT {current}",
+    ),
+    ("display_mode.incompatible", "{mode} EI KÄY: {issue}"),
+    (
+        "display_mode.too_short",
+        "{mode} VAATII {required} RIVIÄ, NYT {current}",
+    ),
+    ("display_mode.fallback", "{reason} - {mode} TILA"),
+    ("export.saved", "TALLENNETTU {name}"),
+    ("export.failed", "TALLENNUS EPÄONNISTUI"),
+];
+
+const ENGLISH: &[(&str, &str)] = &[
+    ("header.ice_hockey", "ICE HOCKEY"),
+    ("series.playoffs", "PLAYOFFS"),
+    ("series.playout", "PLAYOUT GAMES"),
+    ("series.qualifications", "LIIGA QUALIFIERS"),
+    ("series.practice", "PRESEASON GAMES"),
+    ("series.regular_season", "REGULAR SEASON"),
+    ("phase.first_round", "1ST ROUND"),
+    ("phase.quarter_finals", "QUARTERFINALS"),
+    ("phase.semi_finals", "SEMIFINALS"),
+    ("phase.bronze_game", "BRONZE GAME"),
+    ("phase.final", "FINAL"),
+    ("phase.playout", "PLAYOUT"),
+    ("phase.games", "GAMES"),
+    ("page.standings", "STANDINGS"),
+    ("page.standings_live", "STANDINGS (LIVE)"),
+    ("page.playoffs", "PLAYOFFS"),
+    ("page.game_events", "GAME EVENTS"),
+    ("page.lineups", "LINEUPS"),
+    ("page.goal_videos", "GOAL VIDEOS"),
+    ("page.off_season", "OFF-SEASON"),
+    ("page.diagnostics", "DIAGNOSTICS"),
+    ("games.next_games", "Upcoming games"),
+    ("games.next_games_short", "Upcoming"),
+    ("games.no_games_today", "No games today"),
+    ("games.no_games_on_date", "No games on {date}"),
+    ("games.fetch_failed", "Error fetching games: {error}"),
+    (
+        "games.navigate_hint",
+        "Use Shift + arrows to move to another day",
+    ),
+    (
+        "games.restart_hint",
+        "or restart the app (with the -d option)",
+    ),
+    ("games.restart_hint_end", "to see that day's games."),
+    ("games.refresh_hint", "or press 'r' to refresh."),
+    (
+        "games.season_countdown",
+        "Regular season starts in {days} days",
+    ),
+    ("offseason.season", "SEASON {season}"),
+    (
+        "offseason.preseason_countdown",
+        "Preseason starts in {days} days",
+    ),
+    ("offseason.preseason_underway", "Preseason is underway"),
+    ("offseason.season_underway", "Regular season is underway"),
+    (
+        "offseason.start_unknown",
+        "Regular season start date not yet known",
+    ),
+    ("offseason.opening", "OPENING ROUND {date}"),
+    ("offseason.preseason", "PRESEASON {date}"),
+    ("offseason.champion", "Finnish champion: {team}"),
+    ("offseason.no_standings", "Standings not available"),
+    ("diagnostics.requests", "REQUESTS"),
+    ("diagnostics.in_flight", "In flight: {count}"),
+    ("diagnostics.no_requests", "No requests yet"),
+    ("diagnostics.endpoint", "Endpoint"),
+    ("diagnostics.request_count", "Reqs"),
+    ("diagnostics.errors", "Errs"),
+    ("diagnostics.retries", "Retry"),
+    ("diagnostics.response_times", "RESPONSE TIMES"),
+    ("diagnostics.api_endpoints", "API ENDPOINTS"),
+    ("diagnostics.paused", "paused {seconds}s"),
+    ("diagnostics.caches", "CACHES"),
+    ("diagnostics.cache_name", "Name"),
+    ("diagnostics.cache_size", "Size"),
+    ("diagnostics.cache_hits", "Hits"),
+    ("diagnostics.cache_http", "http"),
+    ("diagnostics.cache_games", "games"),
+    ("diagnostics.cache_tournament", "schedule"),
+    ("diagnostics.cache_goals", "goals"),
+    ("diagnostics.cache_players", "players"),
+    ("diagnostics.refresh", "REFRESH"),
+    ("diagnostics.backoff_off", "Retry backoff: off"),
+    (
+        "diagnostics.backoff",
+        "Retry backoff: {backoff} ({remaining} left)",
+    ),
+    ("diagnostics.unavailable", "UNAVAILABLE"),
+    ("diagnostics.all_available", "All tournaments available"),
+    ("diagnostics.next_check", "retry in {minutes} min"),
+    ("pregame.points", "{points}pt"),
+    ("pregame.form_win", "W"),
+    ("pregame.form_loss", "L"),
+    ("pregame.head_to_head", "H2H {home}–{away}"),
+    (
+        "annotation.hat_trick",
+        "Hat trick: {player} ({goals} goals)",
+    ),
+    (
+        "annotation.comeback",
+        "Comeback: {team} from {deficit} down",
+    ),
+    ("annotation.shutout", "Shutout: {team}"),
+    (
+        "annotation.first_season_goal",
+        "First goal of the season: {player}",
+    ),
+    (
+        "annotation.empty_net",
+        "Empty-net goal: {player}, {minute} min",
+    ),
+    ("result.shootout", "so"),
+    ("result.overtime", "ot"),
+    ("clock.overtime", "OT"),
+    (
+        "loading.historical",
+        "Fetching historical data for {date}...",
+    ),
+    (
+        "loading.historical_wait",
+        "This may take a moment, please wait...",
+    ),
+    ("loading.games_on_date", "Fetching games for {date}..."),
+    ("loading.todays_games", "Fetching today's games..."),
+    ("loading.previous_games", "Looking for earlier games..."),
+    ("loading.next_games", "Looking for later games..."),
+    ("loading.standings", "Fetching standings..."),
+    ("loading.playoffs", "Fetching playoffs..."),
+    ("loading.lineups", "Fetching lineups..."),
+    ("loading.off_season", "Fetching season info..."),
+    ("loading.game_events", "Fetching game events..."),
+    ("loading.refreshing", "Refreshing..."),
+    ("error.standings", "Failed to load standings."),
+    ("error.standings_back", "Press 's' to return to the games."),
+    ("error.playoffs", "Failed to load playoffs."),
+    ("error.playoffs_back", "Press 'p' to return."),
+    ("error.off_season", "Failed to load season info."),
+    ("error.lineups", "Failed to load lineups."),
+    ("error.game_events", "Failed to load game events."),
+    ("error.detail_back", "Press Esc to return."),
+    ("not_found.art_top", "PAGE"),
+    ("not_found.art_bottom", "NOT FOUND"),
+    ("not_found.message", "Page {page} is not in use"),
+    ("not_found.pages", "221 Games  222 Standings  223 Playoffs"),
+    ("footer.quit", "q=Quit"),
+    ("footer.standings_games", "s=Games"),
+    ("footer.live", "l=Live"),
+    ("footer.live_on", "l=Live ✓"),
+    ("footer.bracket_games", "p=Games"),
+    ("footer.standings", "s=Standings"),
+    ("footer.detail_games", "Esc=Games"),
+    ("footer.detail_game", "↑↓=Game"),
+    ("footer.events", "k=Events"),
+    ("footer.lineups", "k=Lineups"),
+    ("footer.videos", "g=Videos"),
+    ("footer.videos_back", "Esc=Events"),
+    ("footer.videos_select", "↑↓=Goal"),
+    ("footer.videos_open", "Enter=Open"),
+    ("footer.refresh", "r=Refresh"),
+    ("footer.previous", "⇧←Previous"),
+    ("footer.next", "⇧→Next"),
+    ("footer.playoffs", "p=Playoffs"),
+    ("footer.today", "t=Today"),
+    ("footer.game_page", "231=Game"),
+    ("footer.off_season", "224=Off-season"),
+    ("footer.not_updating", "(Not updating)"),
+    ("detail.period", "{period}. PERIOD"),
+    ("detail.shootout", "SHOOTOUT"),
+    ("detail.overtime", "OVERTIME"),
+    ("detail.power_play", "PP"),
+    ("detail.winning_goal", "GW"),
+    ("detail.shootout_win", "so"),
+    ("detail.starts_at", "Game starts at {time}"),
+    ("detail.no_events", "No events"),
+    ("lineup.unknown", "Lineup not yet known"),
+    ("videos.none", "No goal videos for this game"),
+    ("videos.url", "VIDEO URL"),
+    ("videos.opened", "OPENED: {scorer} {minute}'"),
+    ("videos.no_player", "No video player – URL shown on page"),
+    (
+        "videos.failed",
+        "Player failed to start – URL shown on page",
+    ),
+    ("lineup.sidelined", "Out:"),
+    ("lineup.injured", "inj."),
+    ("lineup.suspended", "susp."),
+    ("standings.team", "Team"),
+    ("standings.games", "GP"),
+    ("standings.wins", "W"),
+    ("standings.ot_wins", "OW"),
+    ("standings.ot_losses", "OL"),
+    ("standings.losses", "L"),
+    ("standings.goals_for", "GF"),
+    ("standings.goals_against", "GA"),
+    ("standings.points", "P"),
+    ("bracket.not_running", "PLAYOFFS ARE NOT UNDERWAY"),
+    ("bracket.champion", "CHAMPION"),
+    ("display_mode.normal", "NORMAL"),
+    ("display_mode.compact", "COMPACT"),
+    ("display_mode.wide", "WIDE"),
+    ("display_mode.teletext", "TELETEXT"),
+    (
+        "display_mode.too_narrow",
+        "{mode} NEEDS {required} COLUMNS, HAVE {current}",
+    ),
+    ("display_mode.incompatible", "{mode} NOT POSSIBLE: {issue}"),
+    (
+        "display_mode.too_short",
+        "{mode} NEEDS {required} ROWS, HAVE {current}",
+    ),
+    ("display_mode.fallback", "{reason} - {mode} MODE"),
+    ("export.saved", "SAVED {name}"),
+    ("export.failed", "EXPORT FAILED"),
+];
+
+const SWEDISH: &[(&str, &str)] = &[
+    ("header.ice_hockey", "ISHOCKEY"),
+    ("series.playoffs", "SLUTSPEL"),
+    ("series.playout", "PLAYOUT-MATCHER"),
+    ("series.qualifications", "LIIGAKVAL"),
+    ("series.practice", "TRÄNINGSMATCHER"),
+    ("series.regular_season", "GRUNDSERIEN"),
+    ("phase.first_round", "1:A OMGÅNGEN"),
+    ("phase.quarter_finals", "KVARTSFINALER"),
+    ("phase.semi_finals", "SEMIFINALER"),
+    ("phase.bronze_game", "BRONSMATCH"),
+    ("phase.final", "FINAL"),
+    ("phase.playout", "PLAYOUT"),
+    ("phase.games", "MATCHER"),
+    ("page.standings", "SERIETABELL"),
+    ("page.standings_live", "SERIETABELL (LIVE)"),
+    ("page.playoffs", "SLUTSPEL"),
+    ("page.game_events", "MATCHHÄNDELSER"),
+    ("page.lineups", "LAGUPPSTÄLLNINGAR"),
+    ("page.goal_videos", "MÅLVIDEOR"),
+    ("page.off_season", "SOMMARUPPEHÅLL"),
+    ("page.diagnostics", "DIAGNOSTIK"),
+    ("games.next_games", "Kommande matcher"),
+    ("games.next_games_short", "Kommande"),
+    ("games.no_games_today", "Inga matcher i dag"),
+    ("games.no_games_on_date", "Inga matcher {date}"),
+    ("games.fetch_failed", "Fel vid hämtning av matcher: {error}"),
+    (
+        "games.navigate_hint",
+        "Använd Shift + pilarna för att byta dag",
+    ),
+    ("games.restart_hint", "eller starta om programmet (med -d)"),
+    ("games.restart_hint_end", "för att se dagens matcher."),
+    ("games.refresh_hint", "eller tryck 'r' för att uppdatera."),
+    (
+        "games.season_countdown",
+        "Grundserien börjar om {days} dagar",
+    ),
+    ("offseason.season", "SÄSONG {season}"),
+    (
+        "offseason.preseason_countdown",
+        "Försäsongen börjar om {days} dagar",
+    ),
+    ("offseason.preseason_underway", "Försäsongen pågår"),
+    ("offseason.season_underway", "Grundserien pågår"),
+    (
+        "offseason.start_unknown",
+        "Grundseriens startdatum är inte känt än",
+    ),
+    ("offseason.opening", "PREMIÄRRUNDA {date}"),
+    ("offseason.preseason", "TRÄNINGSMATCHER {date}"),
+    ("offseason.champion", "Finska mästare: {team}"),
+    ("offseason.no_standings", "Tabellen är inte tillgänglig"),
+    ("diagnostics.requests", "FÖRFRÅGNINGAR"),
+    ("diagnostics.in_flight", "Pågående: {count}"),
+    ("diagnostics.no_requests", "Inga förfrågningar än"),
+    ("diagnostics.endpoint", "Ändpunkt"),
+    ("diagnostics.request_count", "Förfr."),
+    ("diagnostics.errors", "Fel"),
+    ("diagnostics.retries", "Oms."),
+    ("diagnostics.response_times", "SVARSTIDER"),
+    ("diagnostics.api_endpoints", "API-ÄNDPUNKTER"),
+    ("diagnostics.paused", "paus {seconds}s"),
+    ("diagnostics.caches", "CACHAR"),
+    ("diagnostics.cache_name", "Namn"),
+    ("diagnostics.cache_size", "Storlek"),
+    ("diagnostics.cache_hits", "Träffar"),
+    ("diagnostics.cache_http", "http"),
+    ("diagnostics.cache_games", "matcher"),
+    ("diagnostics.cache_tournament", "turnering"),
+    ("diagnostics.cache_goals", "mål"),
+    ("diagnostics.cache_players", "spelare"),
+    ("diagnostics.refresh", "UPPDATERING"),
+    ("diagnostics.backoff_off", "Fördröjning: av"),
+    (
+        "diagnostics.backoff",
+        "Fördröjning: {backoff} ({remaining} kvar)",
+    ),
+    ("diagnostics.unavailable", "EJ TILLGÄNGLIGA"),
+    ("diagnostics.all_available", "Alla turneringar tillgängliga"),
+    ("diagnostics.next_check", "ny sökning {minutes} min"),
+    ("pregame.points", "{points}p"),
+    ("pregame.form_win", "V"),
+    ("pregame.form_loss", "F"),
+    ("pregame.head_to_head", "Inb. {home}–{away}"),
+    ("annotation.hat_trick", "Hattrick: {player} ({goals} mål)"),
+    (
+        "annotation.comeback",
+        "Vändning: {team} från {deficit} mål under",
+    ),
+    ("annotation.shutout", "Nolla: {team}"),
+    (
+        "annotation.first_season_goal",
+        "Säsongens första mål: {player}",
+    ),
+    ("annotation.empty_net", "Mål i tom bur: {player} {minute}."),
+    ("result.shootout", "sl"),
+    ("result.overtime", "fö"),
+    ("clock.overtime", "FÖ"),
+    ("loading.historical", "Hämtar historiska data för {date}..."),
+    ("loading.historical_wait", "Det kan ta en stund, vänta..."),
+    ("loading.games_on_date", "Hämtar matcher för {date}..."),
+    ("loading.todays_games", "Hämtar dagens matcher..."),
+    ("loading.previous_games", "Söker tidigare matcher..."),
+    ("loading.next_games", "Söker senare matcher..."),
+    ("loading.standings", "Hämtar serietabellen..."),
+    ("loading.playoffs", "Hämtar slutspelet..."),
+    ("loading.lineups", "Hämtar laguppställningar..."),
+    ("loading.off_season", "Hämtar säsongsinformation..."),
+    ("loading.game_events", "Hämtar matchhändelser..."),
+    ("loading.refreshing", "Uppdaterar..."),
+    ("error.standings", "Serietabellen kunde inte laddas."),
+    (
+        "error.standings_back",
+        "Tryck 's' för att återgå till matcherna.",
+    ),
+    ("error.playoffs", "Slutspelet kunde inte laddas."),
+    ("error.playoffs_back", "Tryck 'p' för att återgå."),
+    (
+        "error.off_season",
+        "Säsongsinformationen kunde inte laddas.",
+    ),
+    ("error.lineups", "Laguppställningarna kunde inte laddas."),
+    ("error.game_events", "Matchhändelserna kunde inte laddas."),
+    ("error.detail_back", "Tryck Esc för att återgå."),
+    ("not_found.art_top", "SIDAN"),
+    ("not_found.art_bottom", "SAKNAS"),
+    ("not_found.message", "Sidan {page} används inte"),
+    ("not_found.pages", "221 Matcher  222 Tabell  223 Slutspel"),
+    ("footer.quit", "q=Avsluta"),
+    ("footer.standings_games", "s=Matcher"),
+    ("footer.live", "l=Live"),
+    ("footer.live_on", "l=Live ✓"),
+    ("footer.bracket_games", "p=Matcher"),
+    ("footer.standings", "s=Tabell"),
+    ("footer.detail_games", "Esc=Matcher"),
+    ("footer.detail_game", "↑↓=Match"),
+    ("footer.events", "k=Händelser"),
+    ("footer.lineups", "k=Uppställningar"),
+    ("footer.videos", "g=Videor"),
+    ("footer.videos_back", "Esc=Händelser"),
+    ("footer.videos_select", "↑↓=Mål"),
+    ("footer.videos_open", "Enter=Öppna"),
+    ("footer.refresh", "r=Uppdatera"),
+    ("footer.previous", "⇧←Föregående"),
+    ("footer.next", "⇧→Nästa"),
+    ("footer.playoffs", "p=Slutspel"),
+    ("footer.today", "t=I dag"),
+    ("footer.game_page", "231=Match"),
+    ("footer.off_season", "224=Sommaruppehåll"),
+    ("footer.not_updating", "(Uppdateras inte)"),
+    ("detail.period", "{period}. PERIODEN"),
+    ("detail.shootout", "STRAFFLÄGGNING"),
+    ("detail.overtime", "FÖRLÄNGNING"),
+    ("detail.power_play", "PP"),
+    ("detail.winning_goal", "AM"),
+    ("detail.shootout_win", "sl"),
+    ("detail.starts_at", "Matchen börjar kl. {time}"),
+    ("detail.no_events", "Inga händelser"),
+    ("lineup.unknown", "Laguppställningen är inte känd än"),
+    ("videos.none", "Inga målvideor från matchen"),
+    ("videos.url", "VIDEONS ADRESS"),
+    ("videos.opened", "ÖPPNAD: {scorer} {minute}'"),
+    (
+        "videos.no_player",
+        "Ingen videospelare – adressen visas på sidan",
+    ),
+    (
+        "videos.failed",
+        "Spelaren startade inte – adressen visas på sidan",
+    ),
+    ("lineup.sidelined", "Borta:"),
+    ("lineup.injured", "skadad"),
+    ("lineup.suspended", "avstängd"),
+    ("standings.team", "Lag"),
+    ("standings.games", "M"),
+    ("standings.wins", "V"),
+    ("standings.ot_wins", "ÖV"),
+    ("standings.ot_losses", "ÖF"),
+    ("standings.losses", "F"),
+    ("standings.goals_for", "GM"),
+    ("standings.goals_against", "IM"),
+    ("standings.points", "P"),
+    ("bracket.not_running", "SLUTSPELET PÅGÅR INTE"),
+    ("bracket.champion", "MÄSTARE"),
+    ("display_mode.normal", "NORMAL"),
+    ("display_mode.compact", "KOMPAKT"),
+    ("display_mode.wide", "BRED"),
+    ("display_mode.teletext", "TEXT-TV"),
+    (
+        "display_mode.too_narrow",
+        "{mode} KRÄVER {required} TECKEN, NU {current}",
+    ),
+    ("display_mode.incompatible", "{mode} GÅR INTE: {issue}"),
+    (
+        "display_mode.too_short",
+        "{mode} KRÄVER {required} RADER, NU {current}",
+    ),
+    ("display_mode.fallback", "{reason} - {mode} LÄGE"),
+    ("export.saved", "SPARAD {name}"),
+    ("export.failed", "EXPORT MISSLYCKADES"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    fn keys(language: Language) -> BTreeSet<&'static str> {
+        language.catalogue().iter().map(|(key, _)| *key).collect()
+    }
+
+    #[test]
+    fn test_every_key_exists_in_every_catalogue() {
+        let finnish = keys(Language::Finnish);
+        for language in Language::ALL {
+            let keys = keys(language);
+            let missing: Vec<_> = finnish.difference(&keys).collect();
+            let extra: Vec<_> = keys.difference(&finnish).collect();
+            assert!(missing.is_empty(), "{language} is missing {missing:?}");
+            assert!(extra.is_empty(), "{language} has unknown keys {extra:?}");
+        }
+    }
+
+    #[test]
+    fn test_catalogues_have_no_duplicate_keys() {
+        for language in Language::ALL {
+            assert_eq!(
+                keys(language).len(),
+                language.catalogue().len(),
+                "{language} defines a key twice"
+            );
+        }
+    }
+
+    #[test]
+    fn test_placeholders_match_finnish() {
+        let placeholders = |text: &str| -> BTreeSet<String> {
+            text.split('{')
+                .skip(1)
+                .filter_map(|rest| rest.split_once('}'))
+                .map(|(name, _)| name.to_string())
+                .collect()
+        };
+        for (key, finnish) in FINNISH {
+            for language in Language::ALL {
+                assert_eq!(
+                    placeholders(language.text(key)),
+                    placeholders(finnish),
+                    "{language} {key}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_text_falls_back_to_the_key() {
+        assert_eq!(Language::English.text("footer.quit"), "q=Quit");
+        assert_eq!(Language::Swedish.text("no.such.key"), "no.such.key");
+    }
+
+    #[test]
+    fn test_fill_placeholders() {
+        assert_eq!(
+            fill(
+                Language::English.text("games.season_countdown"),
+                &[("days", &12)]
+            ),
+            "Regular season starts in 12 days"
+        );
+    }
+
+    #[test]
+    fn test_language_from_code_and_locale() {
+        assert_eq!(Language::from_code("EN"), Some(Language::English));
+        assert_eq!(Language::from_code("de"), None);
+        assert_eq!(
+            Language::from_locale("sv_FI.UTF-8"),
+            Some(Language::Swedish)
+        );
+        assert_eq!(Language::from_locale("fi_FI"), Some(Language::Finnish));
+        assert_eq!(Language::from_locale("en-US"), Some(Language::English));
+        assert_eq!(Language::from_locale("C.UTF-8"), None);
+        assert_eq!(Language::from_locale(""), None);
+    }
+}
@@ -44,6 +44,7 @@ pub mod config;
 pub mod constants;
 pub mod data_fetcher;
 pub mod error;
+pub mod i18n;
 pub mod teletext_ui;
 pub mod testing_utils;
 pub mod timezone_check;
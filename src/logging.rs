@@ -1,11 +1,16 @@
 use crate::cli::Args;
-use crate::config::Config;
+use crate::config::{Config, LogFormat};
 use crate::error::AppError;
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
 use std::io::stdout;
-use std::path::Path;
-use tracing_appender::non_blocking::WorkerGuard;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use tracing::Subscriber;
+use tracing_appender::non_blocking::{NonBlocking, WorkerGuard};
 use tracing_appender::rolling::{RollingFileAppender, Rotation};
-use tracing_subscriber::{EnvFilter, fmt, prelude::*};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::{EnvFilter, Layer, fmt, prelude::*};
 
 /// Sets up logging configuration for the application.
 ///
@@ -14,19 +19,19 @@ use tracing_subscriber::{EnvFilter, fmt, prelude::*};
 /// - Once mode without debug: logs only to file
 /// - Other non-interactive modes: logs to both stdout and file
 /// - Creates log directory if it doesn't exist
-/// - Uses daily rolling file appender
+/// - Uses daily rolling file appender, deleting rotated files past the
+///   configured age or total size
+/// - Writes the log file as text or JSON lines (`log_format`) with the
+///   per-module levels of `log_levels`
 ///
 /// Returns the path to the log file and the guard that must be kept alive
 /// for the duration of the program to ensure proper log flushing.
 pub async fn setup_logging(args: &Args) -> Result<(String, WorkerGuard), AppError> {
-    // Try to load config to get log file path if specified
-    let config_log_path = Config::load()
-        .await
-        .ok()
-        .and_then(|config| config.log_file_path);
+    // Try to load config to get the log settings
+    let config = Config::load().await.unwrap_or_default();
 
     // Set up logging to both console and file
-    let custom_log_path = args.log_file.as_ref().or(config_log_path.as_ref());
+    let custom_log_path = args.log_file.as_ref().or(config.log_file_path.as_ref());
     let (log_dir, log_file_name) = match custom_log_path {
         Some(custom_path) => {
             let path = Path::new(custom_path);
@@ -47,6 +52,17 @@ pub async fn setup_logging(args: &Args) -> Result<(String, WorkerGuard), AppErro
         })?;
     }
 
+    // Clean up before the appender opens today's file
+    let (max_age, max_bytes) = retention_limits(&config);
+    let pruned = prune_rotated_logs(
+        Path::new(&log_dir),
+        &log_file_name,
+        max_age,
+        max_bytes,
+        SystemTime::now(),
+    )
+    .await;
+
     // Set up a rolling file appender that creates a new log file each day
     let file_appender = RollingFileAppender::new(Rotation::DAILY, &log_dir, &log_file_name);
 
@@ -55,63 +71,243 @@ pub async fn setup_logging(args: &Args) -> Result<(String, WorkerGuard), AppErro
     // to ensure logs are flushed properly
     let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
 
-    // Set up the subscriber with appropriate outputs based on mode
-    let registry = tracing_subscriber::registry();
+    // Interactive mode and once mode without debug log only to the file;
+    // other non-interactive modes also log to stdout, always as text
     let is_noninteractive = crate::cli::is_noninteractive_mode(args);
+    let stdout_layer = (is_noninteractive && (!args.once || args.debug)).then(|| {
+        fmt::Layer::new()
+            .with_writer(stdout)
+            .with_ansi(true)
+            .with_filter(log_filter(&config.log_levels))
+    });
 
-    if is_noninteractive {
-        if args.once && !args.debug {
-            // Once mode without debug: log only to file, not to stdout
-            registry
-                .with(
-                    fmt::Layer::new()
-                        .with_writer(non_blocking)
-                        .with_ansi(false)
-                        .with_filter(
-                            EnvFilter::from_default_env()
-                                .add_directive("liiga_teletext=info".parse().unwrap()),
-                        ),
-                )
-                .init();
-        } else {
-            // Other non-interactive modes: log to both stdout and file
-            registry
-                .with(
-                    fmt::Layer::new()
-                        .with_writer(stdout)
-                        .with_ansi(true)
-                        .with_filter(
-                            EnvFilter::from_default_env()
-                                .add_directive("liiga_teletext=info".parse().unwrap()),
-                        ),
-                )
-                .with(
-                    fmt::Layer::new()
-                        .with_writer(non_blocking)
-                        .with_ansi(false)
-                        .with_filter(
-                            EnvFilter::from_default_env()
-                                .add_directive("liiga_teletext=info".parse().unwrap()),
-                        ),
-                )
-                .init();
+    tracing_subscriber::registry()
+        .with(file_layer(
+            non_blocking,
+            config.log_format,
+            log_filter(&config.log_levels),
+        ))
+        .with(stdout_layer)
+        .init();
+
+    for (path, result) in pruned {
+        match result {
+            Ok(()) => tracing::info!("Deleted old log file {}", path.display()),
+            Err(e) => tracing::warn!("Failed to delete old log file {}: {e}", path.display()),
         }
-    } else {
-        // Interactive: log only to file
-        registry
-            .with(
-                fmt::Layer::new()
-                    .with_writer(non_blocking)
-                    .with_ansi(false)
-                    .with_filter(
-                        EnvFilter::from_default_env()
-                            .add_directive("liiga_teletext=info".parse().unwrap()),
-                    ),
-            )
-            .init();
     }
 
     // Return the log file path and guard
     let log_file_path = format!("{log_dir}/{log_file_name}");
     Ok((log_file_path, guard))
 }
+
+/// The age and total size limits of the rotated logs. Huge configured values
+/// saturate rather than wrap around to a small limit.
+fn retention_limits(config: &Config) -> (Duration, Option<u64>) {
+    let max_age_days = config
+        .log_max_age_days
+        .unwrap_or(crate::constants::DEFAULT_LOG_MAX_AGE_DAYS);
+    let max_age = Duration::from_secs(max_age_days.saturating_mul(24 * 60 * 60));
+    let max_bytes = config
+        .log_max_size_mb
+        .map(|mb| mb.saturating_mul(1024 * 1024));
+    (max_age, max_bytes)
+}
+
+/// The level filter of a log output: `RUST_LOG`, then `liiga_teletext=info`,
+/// then the per-module levels of the config.
+fn log_filter(log_levels: &BTreeMap<String, String>) -> EnvFilter {
+    let mut filter =
+        EnvFilter::from_default_env().add_directive("liiga_teletext=info".parse().unwrap());
+    for (module, level) in log_levels {
+        // Config::load has validated the entries
+        if let Ok(directive) = format!("{module}={level}").parse() {
+            filter = filter.add_directive(directive);
+        }
+    }
+    filter
+}
+
+/// The log file output, as text or as one JSON object per event with the
+/// event's fields at the top level (`jq 'select(.level == "WARN")'`).
+fn file_layer<S>(
+    writer: NonBlocking,
+    format: LogFormat,
+    filter: EnvFilter,
+) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    let layer = fmt::Layer::new().with_writer(writer).with_ansi(false);
+    match format {
+        LogFormat::Text => layer.with_filter(filter).boxed(),
+        LogFormat::Json => layer.json().flatten_event(true).with_filter(filter).boxed(),
+    }
+}
+
+/// Deletes the rotated `<file_name>.YYYY-MM-DD` files in `dir` that were
+/// last written more than `max_age` before `now` or, counting from the
+/// newest, would take the files over `max_bytes` together. The newest file
+/// is always kept, as the appender may still be writing to it.
+///
+/// Returns the deleted files with the outcome of each deletion.
+async fn prune_rotated_logs(
+    dir: &Path,
+    file_name: &str,
+    max_age: Duration,
+    max_bytes: Option<u64>,
+    now: SystemTime,
+) -> Vec<(PathBuf, std::io::Result<()>)> {
+    let prefix = format!("{file_name}.");
+    let mut files = Vec::new();
+    let Ok(mut entries) = tokio::fs::read_dir(dir).await else {
+        return Vec::new();
+    };
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let is_rotated = name
+            .strip_prefix(&prefix)
+            .is_some_and(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok());
+        if !is_rotated {
+            continue;
+        }
+        if let Ok(metadata) = entry.metadata().await
+            && metadata.is_file()
+        {
+            let modified = metadata.modified().unwrap_or(now);
+            files.push((modified, metadata.len(), entry.path()));
+        }
+    }
+
+    // Newest first
+    files.sort_by_key(|(modified, ..)| std::cmp::Reverse(*modified));
+
+    let mut kept_bytes = 0;
+    let mut pruned = Vec::new();
+    for (i, (modified, size, path)) in files.into_iter().enumerate() {
+        let too_old = now.duration_since(modified).is_ok_and(|age| age > max_age);
+        let too_big = max_bytes.is_some_and(|max| kept_bytes + size > max);
+        if i > 0 && (too_old || too_big) {
+            let result = tokio::fs::remove_file(&path).await;
+            pruned.push((path, result));
+        } else {
+            kept_bytes += size;
+        }
+    }
+    pruned
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::filter::LevelFilter;
+
+    /// Writes a log file of `size` bytes last modified `age_days` before `now`.
+    fn write_log(dir: &Path, name: &str, size: usize, now: SystemTime, age_days: u64) {
+        let path = dir.join(name);
+        std::fs::write(&path, vec![b'x'; size]).unwrap();
+        let file = std::fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(now - Duration::from_secs(age_days * 24 * 60 * 60))
+            .unwrap();
+    }
+
+    fn remaining(dir: &Path) -> Vec<String> {
+        let mut names: Vec<String> = std::fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[tokio::test]
+    async fn test_prune_deletes_old_rotated_files_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let now = SystemTime::now();
+        write_log(dir.path(), "liiga_teletext.log.2026-10-18", 10, now, 0);
+        write_log(dir.path(), "liiga_teletext.log.2026-10-01", 10, now, 17);
+        write_log(dir.path(), "liiga_teletext.log.2026-08-01", 10, now, 78);
+        // Not rotated logs: left alone however old
+        write_log(dir.path(), "liiga_teletext.log.bak", 10, now, 90);
+        write_log(dir.path(), "other.log.2026-08-01", 10, now, 78);
+
+        let pruned = prune_rotated_logs(
+            dir.path(),
+            "liiga_teletext.log",
+            Duration::from_secs(30 * 24 * 60 * 60),
+            None,
+            now,
+        )
+        .await;
+
+        assert_eq!(pruned.len(), 1);
+        assert!(pruned[0].1.is_ok());
+        assert_eq!(
+            remaining(dir.path()),
+            [
+                "liiga_teletext.log.2026-10-01",
+                "liiga_teletext.log.2026-10-18",
+                "liiga_teletext.log.bak",
+                "other.log.2026-08-01",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_prune_keeps_newest_files_within_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let now = SystemTime::now();
+        write_log(dir.path(), "app.log.2026-10-18", 600, now, 0);
+        write_log(dir.path(), "app.log.2026-10-17", 300, now, 1);
+        write_log(dir.path(), "app.log.2026-10-16", 300, now, 2);
+        write_log(dir.path(), "app.log.2026-10-15", 50, now, 3);
+
+        let max_age = Duration::from_secs(30 * 24 * 60 * 60);
+        prune_rotated_logs(dir.path(), "app.log", max_age, Some(1000), now).await;
+        assert_eq!(
+            remaining(dir.path()),
+            [
+                "app.log.2026-10-15",
+                "app.log.2026-10-17",
+                "app.log.2026-10-18"
+            ]
+        );
+
+        // The newest file stays even when it alone is over the limit
+        prune_rotated_logs(dir.path(), "app.log", max_age, Some(100), now).await;
+        assert_eq!(remaining(dir.path()), ["app.log.2026-10-18"]);
+    }
+
+    #[test]
+    fn test_retention_limits_saturate() {
+        let config = Config {
+            log_max_age_days: Some(i64::MAX as u64),
+            log_max_size_mb: Some(i64::MAX as u64),
+            ..Default::default()
+        };
+        assert_eq!(
+            retention_limits(&config),
+            (Duration::from_secs(u64::MAX), Some(u64::MAX))
+        );
+
+        let defaults = retention_limits(&Config::default());
+        assert_eq!(defaults, (Duration::from_secs(30 * 24 * 60 * 60), None));
+    }
+
+    #[test]
+    fn test_log_filter_applies_module_levels() {
+        assert_eq!(
+            log_filter(&BTreeMap::new()).max_level_hint(),
+            Some(LevelFilter::INFO)
+        );
+        let levels = BTreeMap::from([(
+            "liiga_teletext::data_fetcher".to_string(),
+            "trace".to_string(),
+        )]);
+        assert_eq!(
+            log_filter(&levels).max_level_hint(),
+            Some(LevelFilter::TRACE)
+        );
+    }
+}
@@ -6,7 +6,9 @@ mod config;
 mod constants;
 mod data_fetcher;
 mod error;
+mod i18n;
 mod logging;
+mod server;
 mod teletext_ui;
 mod timezone_check;
 mod ui;
@@ -19,15 +21,22 @@ mod testing_utils;
 use clap::Parser;
 use cli::Args;
 use config::Config;
+use config::layers::ConfigLayer;
 use error::AppError;
 
 #[tokio::main]
 async fn main() -> Result<(), AppError> {
-    let args = Args::parse();
+    let mut args = Args::parse();
 
     // Validate argument combinations
     commands::validate_args(&args)?;
 
+    // Every config load from here on applies the profile and the flags
+    config::layers::select(config::layers::LayerSelection {
+        profile: args.profile.clone(),
+        cli: args.config_overrides(),
+    });
+
     // Set up logging configuration
     let (log_file_path, _guard) = logging::setup_logging(&args).await?;
     tracing::info!("Logs are being written to: {log_file_path}");
@@ -41,6 +50,9 @@ async fn main() -> Result<(), AppError> {
     if args.list_config {
         return commands::handle_list_config_command().await;
     }
+    if args.check_config {
+        return commands::handle_check_config_command().await;
+    }
 
     // Handle configuration updates
     if args.new_api_domain.is_some() || args.new_log_file_path.is_some() || args.clear_log_file_path
@@ -57,11 +69,23 @@ async fn main() -> Result<(), AppError> {
         tracing::warn!("Local timezone resolution failed: {}", problem.message());
     }
 
-    // Check for new version in the background for non-config operations
-    let version_check = tokio::spawn(version::check_latest_version());
-
     // Load config first to fail early if there's an issue
-    let _config = Config::load().await?;
+    let (config, layers) = Config::load_layered().await?;
+    // Display settings may come from config files and profiles
+    args.apply_config(&config);
+    // A display mode set by any layer, even `normal`, wins over the session
+    let display_mode_set = *layers.source("display_mode") != ConfigLayer::Default;
+    ui::components::favourites::set_favourite_teams(&config.favourite_teams);
+    i18n::set_language(
+        config
+            .language
+            .or_else(i18n::Language::from_env)
+            .unwrap_or_default(),
+    );
+
+    // Check for new version in the background for non-config operations,
+    // through the same proxy and headers as the API requests
+    let version_check = tokio::spawn(version::check_latest_version(config));
 
     if args.reset_cache {
         let count = data_fetcher::cache::clear_all_cache_files().await;
@@ -76,7 +100,14 @@ async fn main() -> Result<(), AppError> {
         return commands::handle_once_command(&args, version_check).await;
     }
 
+    if let Some(addr) = &args.serve {
+        if let Some(problem) = &timezone_problem {
+            eprintln!("WARNING: {}", problem.message());
+        }
+        return server::run(addr, &args).await;
+    }
+
     // Interactive mode. The warning is handed over rather than printed here
     // because the alternate screen would wipe anything written before it opens.
-    app::run_interactive(&args, version_check, timezone_problem).await
+    app::run_interactive(&args, display_mode_set, version_check, timezone_problem).await
 }
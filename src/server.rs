@@ -0,0 +1,517 @@
+//! Local web server for `--serve`.
+//!
+//! Serves the teletext pages 221 (games), 222 (standings) and 223 (playoff
+//! bracket) as HTML, the data behind them as JSON, and pushes every page
+//! update to open browsers with Server-Sent Events. Each page is kept fresh by
+//! a [`PageFeed`], which runs the interactive viewer's refresh logic, so a
+//! wall-mounted display follows live games like the terminal does.
+//!
+//! The HTTP handling is deliberately small: GET and HEAD only, one request per
+//! connection and no TLS. It is meant for a display on the local network.
+
+use crate::cli::Args;
+use crate::error::AppError;
+use crate::i18n::t;
+use crate::teletext_ui::export::{PageSnapshot, html_document};
+use crate::ui::interactive::page_feed::PageFeed;
+use crate::ui::interactive::state_manager::ViewMode;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::watch;
+
+/// Pages served, with the view that builds each
+const PAGES: [(u16, ViewMode); 3] = [
+    (221, ViewMode::Games),
+    (222, ViewMode::Standings { live_mode: false }),
+    (223, ViewMode::Bracket),
+];
+
+/// How often the feeds are checked; each refreshes on its own schedule
+const TICK: Duration = Duration::from_secs(1);
+
+/// Comment sent on an idle event stream so proxies keep it open
+const KEEPALIVE: Duration = Duration::from_secs(30);
+
+/// Largest request head read before the request is rejected
+const MAX_REQUEST_BYTES: usize = 8192;
+
+/// Time a client has to send its request head before the connection is closed
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Replaces the page content when the server pushes an update, and jumps to
+/// a page when three digits are typed, like on a teletext remote.
+const PAGE_SCRIPT: &str = r#"<script>
+const source = new EventSource("/events/{page}");
+source.onmessage = (event) => {
+  document.querySelector("pre").innerHTML = event.data;
+};
+let digits = "";
+document.addEventListener("keydown", (event) => {
+  digits = /^[0-9]$/.test(event.key) ? digits + event.key : "";
+  if (digits.length === 3) {
+    location.href = "/" + digits;
+  }
+});
+</script>
+"#;
+
+/// One page as served, rendered once per change.
+#[derive(Debug, Clone, PartialEq)]
+struct ServedPage {
+    /// Increases with every published change, across all pages
+    revision: u64,
+    /// Content of the page's `<pre>` block
+    fragment: String,
+    /// JSON API document, without the `updated` timestamp
+    data: serde_json::Value,
+    /// When the page was last published, RFC 3339
+    updated: String,
+}
+
+type Pages = BTreeMap<u16, Arc<ServedPage>>;
+
+/// Runs the server on `addr` (e.g. `127.0.0.1:8080`) until the process is stopped.
+pub async fn run(addr: &str, args: &Args) -> Result<(), AppError> {
+    let listener = TcpListener::bind(addr)
+        .await
+        .map_err(|e| AppError::config_error(format!("Cannot listen on {addr}: {e}")))?;
+    let local_addr = listener.local_addr()?;
+    println!("Serving pages 221, 222 and 223 at http://{local_addr}/ (Ctrl+C to stop)");
+    tracing::info!("Web server listening on {local_addr}");
+
+    let (sender, receiver) = watch::channel(Pages::new());
+    tokio::spawn(accept_connections(listener, receiver));
+
+    let mut feeds: Vec<(u16, PageFeed)> = PAGES
+        .iter()
+        .map(|&(number, view)| {
+            let feed = PageFeed::new(view, args.disable_links, args.min_refresh_interval);
+            (number, feed)
+        })
+        .collect();
+    let mut revision = 0;
+    let mut last_clock_minute = chrono::Local::now().format("%H:%M").to_string();
+
+    loop {
+        // Republish every page when the header clock's minute changes
+        let clock_minute = chrono::Local::now().format("%H:%M").to_string();
+        let clock_changed = clock_minute != last_clock_minute;
+        last_clock_minute = clock_minute;
+
+        for (number, feed) in &mut feeds {
+            let changed = match feed.tick().await {
+                Ok(changed) => changed,
+                Err(e) => {
+                    tracing::warn!("Refreshing page {number} failed: {e}");
+                    false
+                }
+            };
+            if !(changed || clock_changed) {
+                continue;
+            }
+            match render_page(*number, feed) {
+                Ok(Some((fragment, data))) => sender.send_if_modified(|pages| {
+                    let unchanged = pages
+                        .get(number)
+                        .is_some_and(|page| page.fragment == fragment && page.data == data);
+                    if unchanged {
+                        return false;
+                    }
+                    revision += 1;
+                    tracing::debug!("Publishing page {number} revision {revision}");
+                    pages.insert(
+                        *number,
+                        Arc::new(ServedPage {
+                            revision,
+                            fragment,
+                            data,
+                            updated: chrono::Local::now().to_rfc3339(),
+                        }),
+                    );
+                    true
+                }),
+                Ok(None) => false,
+                Err(e) => {
+                    tracing::warn!("Rendering page {number} failed: {e}");
+                    false
+                }
+            };
+        }
+
+        tokio::time::sleep(TICK).await;
+    }
+}
+
+/// The `<pre>` content and JSON data of a feed's page, once it has one.
+fn render_page(
+    number: u16,
+    feed: &PageFeed,
+) -> Result<Option<(String, serde_json::Value)>, AppError> {
+    let Some(page) = feed.page() else {
+        return Ok(None);
+    };
+    let snapshot = PageSnapshot::capture(page)?;
+    let text = snapshot.text();
+    let links: Vec<serde_json::Value> = snapshot
+        .links()
+        .into_iter()
+        .map(|(text, url)| serde_json::json!({ "text": text, "url": url }))
+        .collect();
+    let mut data = serde_json::json!({
+        "page": number,
+        "date": feed.date(),
+        "lines": text.lines().collect::<Vec<_>>(),
+        "links": links,
+    });
+    // The model behind each page, next to its text
+    match number {
+        222 => {
+            let (standings, playoff_lines) = feed.standings();
+            data["standings"] = serde_json::to_value(standings)?;
+            data["playoff_lines"] = serde_json::to_value(playoff_lines)?;
+        }
+        223 => data["bracket"] = serde_json::to_value(feed.bracket())?,
+        _ => data["games"] = serde_json::to_value(feed.games())?,
+    }
+    Ok(Some((snapshot.html(), data)))
+}
+
+async fn accept_connections(listener: TcpListener, pages: watch::Receiver<Pages>) {
+    loop {
+        match listener.accept().await {
+            Ok((stream, peer)) => {
+                let pages = pages.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = handle_connection(stream, pages).await {
+                        tracing::debug!("Connection from {peer} closed: {e}");
+                    }
+                });
+            }
+            Err(e) => {
+                tracing::warn!("Accepting a connection failed: {e}");
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+        }
+    }
+}
+
+/// What a request asks for.
+#[derive(Debug, PartialEq, Eq)]
+enum Route {
+    Index,
+    Page(u16),
+    Json(u16),
+    Events(u16),
+    NotFound,
+    MethodNotAllowed,
+}
+
+fn route(method: &str, target: &str) -> Route {
+    if method != "GET" && method != "HEAD" {
+        return Route::MethodNotAllowed;
+    }
+    let path = target.split(['?', '#']).next().unwrap_or_default();
+    let page = |number: &str| {
+        number
+            .parse::<u16>()
+            .ok()
+            .filter(|number| PAGES.iter().any(|(page, _)| page == number))
+    };
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    match segments.as_slice() {
+        [""] => Route::Index,
+        [number] => page(number).map_or(Route::NotFound, Route::Page),
+        ["api", number] => {
+            page(number.trim_end_matches(".json")).map_or(Route::NotFound, Route::Json)
+        }
+        ["events", number] => page(number).map_or(Route::NotFound, Route::Events),
+        _ => Route::NotFound,
+    }
+}
+
+/// Reads the request head and returns its method and target.
+async fn read_request(stream: &mut TcpStream) -> std::io::Result<Option<(String, String)>> {
+    let mut head = Vec::new();
+    let mut buffer = [0u8; 1024];
+    while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+        if head.len() > MAX_REQUEST_BYTES {
+            return Ok(None);
+        }
+        let read = stream.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        head.extend_from_slice(&buffer[..read]);
+    }
+    let head = String::from_utf8_lossy(&head);
+    let mut parts = head.lines().next().unwrap_or_default().split_whitespace();
+    Ok(match (parts.next(), parts.next()) {
+        (Some(method), Some(target)) => Some((method.to_string(), target.to_string())),
+        _ => None,
+    })
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    mut pages: watch::Receiver<Pages>,
+) -> std::io::Result<()> {
+    let request = match tokio::time::timeout(REQUEST_TIMEOUT, read_request(&mut stream)).await {
+        Ok(request) => request?,
+        Err(_) => {
+            let response = http_response(
+                "408 Request Timeout",
+                "text/plain",
+                "Request timeout\n",
+                true,
+            );
+            return stream.write_all(&response).await;
+        }
+    };
+    let Some((method, target)) = request else {
+        let response = http_response("400 Bad Request", "text/plain", "Bad request\n", true);
+        return stream.write_all(&response).await;
+    };
+    let route = route(&method, &target);
+    tracing::debug!("{method} {target} -> {route:?}");
+
+    if let Route::Events(number) = route {
+        return stream_events(stream, number, &mut pages).await;
+    }
+
+    let with_body = method != "HEAD";
+    let response = match route {
+        Route::Index => [
+            "HTTP/1.1 302 Found\r\nLocation: /221\r\nContent-Length: 0\r\n",
+            "Connection: close\r\n\r\n",
+        ]
+        .concat()
+        .into_bytes(),
+        Route::Page(number) => {
+            let page = pages.borrow().get(&number).cloned();
+            let fragment = page.map_or_else(
+                || loading_text(number).to_string(),
+                |page| page.fragment.clone(),
+            );
+            let body = html_document(
+                &format!("SM-LIIGA {number}"),
+                &fragment,
+                &PAGE_SCRIPT.replace("{page}", &number.to_string()),
+            );
+            http_response("200 OK", "text/html; charset=utf-8", &body, with_body)
+        }
+        Route::Json(number) => match pages.borrow().get(&number) {
+            Some(page) => {
+                let mut data = page.data.clone();
+                data["updated"] = serde_json::Value::String(page.updated.clone());
+                let body = format!("{data:#}\n");
+                http_response("200 OK", "application/json", &body, with_body)
+            }
+            None => http_response(
+                "503 Service Unavailable",
+                "application/json",
+                "{\"error\": \"page not loaded yet\"}\n",
+                with_body,
+            ),
+        },
+        Route::NotFound => http_response("404 Not Found", "text/plain", "Not found\n", with_body),
+        Route::MethodNotAllowed => http_response(
+            "405 Method Not Allowed",
+            "text/plain",
+            "Only GET and HEAD are supported\n",
+            with_body,
+        ),
+        Route::Events(_) => unreachable!("event streams are handled above"),
+    };
+    stream.write_all(&response).await?;
+    stream.shutdown().await
+}
+
+/// Shown until a page's first refresh completes
+fn loading_text(number: u16) -> &'static str {
+    match number {
+        222 => t("loading.standings"),
+        223 => t("loading.playoffs"),
+        _ => t("loading.todays_games"),
+    }
+}
+
+fn http_response(status: &str, content_type: &str, body: &str, with_body: bool) -> Vec<u8> {
+    let mut response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\n\
+         Cache-Control: no-cache\r\nConnection: close\r\n\r\n",
+        body.len()
+    );
+    if with_body {
+        response.push_str(body);
+    }
+    response.into_bytes()
+}
+
+/// Sends the page's content whenever a new revision is published.
+async fn stream_events(
+    mut stream: TcpStream,
+    number: u16,
+    pages: &mut watch::Receiver<Pages>,
+) -> std::io::Result<()> {
+    stream
+        .write_all(
+            b"HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\n\
+              Cache-Control: no-cache\r\nConnection: keep-alive\r\n\r\n",
+        )
+        .await?;
+
+    let mut sent_revision = 0;
+    loop {
+        let page = pages.borrow_and_update().get(&number).cloned();
+        if let Some(page) = page
+            && page.revision != sent_revision
+        {
+            stream
+                .write_all(sse_event(&page.fragment).as_bytes())
+                .await?;
+            sent_revision = page.revision;
+        }
+
+        match tokio::time::timeout(KEEPALIVE, pages.changed()).await {
+            Ok(Ok(())) => {}
+            // The refresh loop is gone; nothing more will be published
+            Ok(Err(_)) => return Ok(()),
+            Err(_) => stream.write_all(b": keepalive\n\n").await?,
+        }
+    }
+}
+
+/// Formats `data` as one Server-Sent Event, one `data:` field per line.
+fn sse_event(data: &str) -> String {
+    let mut event = String::with_capacity(data.len() + 16);
+    for line in data.split('\n') {
+        event.push_str("data: ");
+        event.push_str(line);
+        event.push('\n');
+    }
+    event.push('\n');
+    event
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn served(revision: u64, fragment: &str) -> Arc<ServedPage> {
+        Arc::new(ServedPage {
+            revision,
+            fragment: fragment.to_string(),
+            data: serde_json::json!({ "page": 221, "lines": [fragment] }),
+            updated: "2026-10-18T18:30:00+03:00".to_string(),
+        })
+    }
+
+    /// Serves `pages` on a local port and returns its address.
+    async fn start(pages: watch::Receiver<Pages>) -> std::net::SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(accept_connections(listener, pages));
+        addr
+    }
+
+    async fn get(addr: std::net::SocketAddr, target: &str) -> String {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(format!("GET {target} HTTP/1.1\r\nHost: test\r\n\r\n").as_bytes())
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    async fn read_until(stream: &mut TcpStream, received: &mut String, needle: &str) {
+        let mut buffer = [0u8; 1024];
+        while !received.contains(needle) {
+            let read = stream.read(&mut buffer).await.unwrap();
+            assert!(read > 0, "stream closed before {needle:?}");
+            received.push_str(&String::from_utf8_lossy(&buffer[..read]));
+        }
+    }
+
+    #[test]
+    fn test_route() {
+        assert_eq!(route("GET", "/"), Route::Index);
+        assert_eq!(route("GET", "/221"), Route::Page(221));
+        assert_eq!(route("HEAD", "/222/"), Route::Page(222));
+        assert_eq!(route("GET", "/223?refresh=1"), Route::Page(223));
+        assert_eq!(route("GET", "/api/221"), Route::Json(221));
+        assert_eq!(route("GET", "/api/222.json"), Route::Json(222));
+        assert_eq!(route("GET", "/events/223"), Route::Events(223));
+        assert_eq!(route("GET", "/231"), Route::NotFound);
+        assert_eq!(route("GET", "/api/abc"), Route::NotFound);
+        assert_eq!(route("GET", "/favicon.ico"), Route::NotFound);
+        assert_eq!(route("POST", "/221"), Route::MethodNotAllowed);
+    }
+
+    #[test]
+    fn test_sse_event_splits_lines() {
+        assert_eq!(sse_event("one"), "data: one\n\n");
+        assert_eq!(sse_event("one\ntwo\n"), "data: one\ndata: two\ndata: \n\n");
+    }
+
+    #[tokio::test]
+    async fn test_serves_html_json_and_redirect() {
+        let (_sender, receiver) = watch::channel(Pages::from([(221, served(1, "TPS 3-2 HIFK"))]));
+        let addr = start(receiver).await;
+
+        let html = get(addr, "/221").await;
+        assert!(html.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(html.contains("<pre>TPS 3-2 HIFK</pre>"));
+        assert!(html.contains("new EventSource(\"/events/221\")"));
+
+        let json = get(addr, "/api/221").await;
+        let body = json.split("\r\n\r\n").nth(1).unwrap();
+        let data: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert_eq!(data["page"], 221);
+        assert_eq!(data["updated"], "2026-10-18T18:30:00+03:00");
+
+        let loading = get(addr, "/api/222").await;
+        assert!(loading.starts_with("HTTP/1.1 503"));
+
+        let index = get(addr, "/").await;
+        assert!(index.starts_with("HTTP/1.1 302 Found\r\nLocation: /221\r\n"));
+        assert!(get(addr, "/999").await.starts_with("HTTP/1.1 404"));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_silent_client_times_out() {
+        let (_sender, receiver) = watch::channel(Pages::new());
+        let addr = start(receiver).await;
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream.write_all(b"GET /221 HTTP/1.1\r\n").await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 408 Request Timeout\r\n"));
+    }
+
+    #[tokio::test]
+    async fn test_event_stream_pushes_new_revisions() {
+        let (sender, receiver) = watch::channel(Pages::from([(221, served(1, "0-0"))]));
+        let addr = start(receiver).await;
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(b"GET /events/221 HTTP/1.1\r\n\r\n")
+            .await
+            .unwrap();
+
+        let mut received = String::new();
+        read_until(&mut stream, &mut received, "data: 0-0\n\n").await;
+        assert!(received.contains("Content-Type: text/event-stream"));
+
+        sender.send_modify(|pages| {
+            pages.insert(221, served(2, "1-0"));
+        });
+        read_until(&mut stream, &mut received, "data: 1-0\n\n").await;
+    }
+}
@@ -1,4 +1,5 @@
 use crate::data_fetcher::models::bracket::{BracketMatchup, BracketPhase, PlayoffBracket};
+use crate::i18n::t;
 use crate::teletext_ui::core::TeletextRow;
 
 // ANSI 256 color codes for bracket display
@@ -51,8 +52,9 @@ pub fn render_bracket(
         return vec![
             TeletextRow::BracketLine(String::new()),
             TeletextRow::BracketLine(format!(
-                "{} PUDOTUSPELIT EIV\u{00C4}T OLE K\u{00C4}YNNISS\u{00C4} {}",
+                "{} {} {}",
                 color(WHITE),
+                t("bracket.not_running"),
                 RESET
             )),
         ];
@@ -454,11 +456,11 @@ fn render_full_path(
     };
 
     if has_r1 {
-        draw_column(&mut canvas, &r1_slots, "1. KIERROS");
+        draw_column(&mut canvas, &r1_slots, t("phase.first_round"));
     }
-    draw_column(&mut canvas, &qf_slots, "PUOLIV\u{00C4}LIER\u{00C4}T");
-    draw_column(&mut canvas, &sf_slots, "V\u{00C4}LIER\u{00C4}T");
-    let fin_x = draw_column(&mut canvas, &fin_slots, "FINAALI");
+    draw_column(&mut canvas, &qf_slots, t("phase.quarter_finals"));
+    draw_column(&mut canvas, &sf_slots, t("phase.semi_finals"));
+    let fin_x = draw_column(&mut canvas, &fin_slots, t("phase.final"));
 
     // --- Champion label after the final's connector arm ---
     let champion = advancing_label(fin_slots.first(), name_max);
@@ -485,7 +487,7 @@ fn render_full_path(
         canvas.put_str(
             fin_x,
             header_y,
-            &truncate_team_name("PRONSSIOTTELU", pitch - 1),
+            &truncate_team_name(t("phase.bronze_game"), pitch - 1),
             CYAN,
         );
         let (top, bottom) = matchup_labels(m, name_max, false);
@@ -817,8 +819,9 @@ fn append_champion(phases: &[BracketPhase], rows: &mut Vec<TeletextRow>) {
     {
         rows.push(TeletextRow::BracketLine(String::new()));
         rows.push(TeletextRow::BracketLine(format!(
-            "{}MESTARI: {}{}",
+            "{}{}: {}{}",
             bold_color(GREEN),
+            t("bracket.champion"),
             winner,
             RESET,
         )));
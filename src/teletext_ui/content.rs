@@ -42,7 +42,11 @@ impl TeletextPage {
     ///     play_off_pair: None,
     ///     play_off_req_wins: None,
     ///     series_score: None,
+    ///     game_id: 0,
+    ///     season: 0,
     ///     is_placeholder: false,
+    ///     pre_game: None,
+    ///     annotations: vec![],
     /// });
     ///
     /// page.add_game_result(game);
@@ -59,6 +63,8 @@ impl TeletextPage {
             goal_events: game_data.goal_events,
             played_time: game_data.played_time,
             series_score: game_data.series_score,
+            pre_game: game_data.pre_game,
+            annotations: game_data.annotations,
         });
     }
 
@@ -158,6 +164,11 @@ impl TeletextPage {
         self.content_rows.push(TeletextRow::BracketLine(line));
     }
 
+    /// Adds a pre-formatted game timeline row to the page content.
+    pub fn add_detail_line(&mut self, line: String) {
+        self.content_rows.push(TeletextRow::DetailLine(line));
+    }
+
     /// Adds a forced page break marker for bracket pagination.
     pub fn add_bracket_page_break(&mut self) {
         self.content_rows.push(TeletextRow::BracketPageBreak);
@@ -1,14 +1,17 @@
 // src/teletext_ui.rs - Updated with better display formatting
 
-use crate::config::Config;
 use crate::data_fetcher::GoalEventData;
-use crate::data_fetcher::models::PlayoffSeriesScore;
+use crate::data_fetcher::api::http_client::http_client_for;
+use crate::data_fetcher::api::orchestrator::load_background_config;
+use crate::data_fetcher::models::{GameAnnotation, PlayoffSeriesScore, PreGameInfo};
 use crate::data_fetcher::processors::should_show_todays_games;
 use crate::error::AppError;
+use crate::i18n::tf;
 use chrono::Local;
 use crossterm::{execute, style::Print};
-use std::cell::Cell;
+use std::cell::RefCell;
 use std::io::{Stdout, Write};
+use std::time::Instant;
 use tracing::debug;
 
 use crate::ui::teletext::colors::*;
@@ -30,8 +33,8 @@ use super::season_utils::calculate_days_until_regular_season;
 #[derive(Debug)]
 pub struct TeletextPage {
     page_number: u16,
-    title: String,
-    subheader: String,
+    pub(super) title: String,
+    pub(super) subheader: String,
     pub(super) content_rows: Vec<TeletextRow>,
     pub(super) current_page: usize,
     pub(super) screen_height: u16,
@@ -46,16 +49,24 @@ pub struct TeletextPage {
     pub(super) error_warning_active: bool,                       // Show footer warning when true
     pub(super) compact_mode: bool,                               // Enable compact display mode
     pub(super) wide_mode: bool,                                  // Enable wide display mode
+    pub(super) teletext_mode: bool, // Render into the strict 40x24 teletext grid
     pub(super) layout_manager: ColumnLayoutManager, // Layout management for dynamic column calculations
     pub(super) is_standings_page: bool,             // Whether this is a standings page
     pub(super) standings_live_mode: bool,           // Whether live mode is active in standings
     pub(super) playoffs_lines: Vec<u16>, // Positions after which to draw playoff separator lines
-    pub(super) skip_screen_clear: Cell<bool>, // Interior mutability needed because render_buffered takes &self. When true, skips screen clear to avoid flicker.
-    pub(super) is_loading_page: bool,         // Whether this is a loading/fetching page
-    pub(super) is_bracket_page: bool,         // Whether this is a bracket display page
-    pub(super) has_bracket_data: bool,        // Whether bracket data is available
+    pub(super) is_loading_page: bool,    // Whether this is a loading/fetching page
+    pub(super) is_bracket_page: bool,    // Whether this is a bracket display page
+    pub(super) game_detail_index: Option<usize>, // Game shown on a game detail page
+    pub(super) is_game_lineup_page: bool, // Whether the game detail page shows lineups
+    pub(super) is_diagnostics_page: bool, // Whether this is the hidden diagnostics page
+    pub(super) is_off_season_page: bool, // Whether this is the off-season page
+    pub(super) goal_videos: Option<(usize, usize)>, // Game index and selected clip on a goal video picker page
+    pub(super) has_bracket_data: bool,              // Whether bracket data is available
     pub(super) initial_fetched_date: Option<String>, // The date originally fetched on startup
     pub(super) page_input_display: Option<String>, // Digits typed for teletext-style page entry, shown in header
+    pub(super) notice: Option<String>, // Short notice shown above the footer (e.g. a display mode fallback)
+    pub(super) live_clock_synced_at: Instant, // When row played times were last synced with the server
+    pub(super) live_clock_cells: RefCell<Vec<super::live_clock::LiveClockCell>>, // Clock positions drawn by the last render
 }
 
 #[derive(Debug)]
@@ -71,6 +82,10 @@ pub enum TeletextRow {
         goal_events: Vec<GoalEventData>,
         played_time: i32,
         series_score: Option<PlayoffSeriesScore>,
+        /// Standings, form and head-to-head, shown under scheduled games
+        pre_game: Option<PreGameInfo>,
+        /// Hat tricks, comebacks and other notes, shown under finished games
+        annotations: Vec<GameAnnotation>,
     },
     ErrorMessage(String),
     FutureGamesHeader(String), // For "Seuraavat ottelut {date}" line
@@ -97,6 +112,8 @@ pub enum TeletextRow {
         live_game_active: bool,
     },
     BracketLine(String),
+    /// Pre-formatted game timeline row (at most 40 visible columns).
+    DetailLine(String),
     /// Forced page break marker for bracket display.
     /// Inserted between phase groups to prevent mid-matchup pagination.
     BracketPageBreak,
@@ -176,16 +193,24 @@ impl TeletextPage {
             error_warning_active: false,
             compact_mode,
             wide_mode,
+            teletext_mode: super::teletext_grid::grid_mode(),
             layout_manager,
             is_standings_page: false,
             standings_live_mode: false,
             playoffs_lines: Vec::new(),
-            skip_screen_clear: Cell::new(false),
             is_loading_page: false,
             is_bracket_page: false,
+            game_detail_index: None,
+            is_game_lineup_page: false,
+            is_diagnostics_page: false,
+            is_off_season_page: false,
+            goal_videos: None,
             has_bracket_data: false,
             initial_fetched_date: None,
             page_input_display: None,
+            notice: None,
+            live_clock_synced_at: Instant::now(),
+            live_clock_cells: RefCell::new(Vec::new()),
         }
     }
 
@@ -198,7 +223,7 @@ impl TeletextPage {
 
     /// Formats the page number slot of the header: either the page number
     /// itself or an in-progress page entry padded with dashes ("22-").
-    fn format_header_page_str(&self) -> String {
+    pub(super) fn format_header_page_str(&self) -> String {
         match &self.page_input_display {
             Some(digits) => format!("{digits:-<3}"),
             None => self.page_number.to_string(),
@@ -394,33 +419,25 @@ impl TeletextPage {
             return;
         }
 
-        // Find the earliest regular season game by fetching future regular season games
-        // Only attempt countdown calculation if we can load config without user interaction
-        match tokio::task::spawn_blocking(|| std::env::var("LIIGA_API_DOMAIN")).await {
-            Ok(Ok(api_domain))
-                if !api_domain.is_empty()
-                    && api_domain != "placeholder"
-                    && api_domain != "test"
-                    && api_domain != "unset" =>
-            {
-                // Config is available via environment variable, safe to calculate countdown
-                let client = reqwest::Client::new();
-                let config = match Config::load().await {
-                    Ok(config) => config,
-                    Err(_) => return, // Skip countdown if config loading fails
-                };
-
-                if let Some(days_until_season) =
-                    calculate_days_until_regular_season(&client, &config, None).await
-                {
-                    let countdown_text = format!("Runkosarjan alkuun {days_until_season} päivää");
-                    self.season_countdown = Some(countdown_text);
-                }
-            }
-            _ => {
-                // No valid API domain available, skip countdown to prevent interactive config reads
-                debug!("Skipping season countdown due to missing or invalid LIIGA_API_DOMAIN");
+        // Find the earliest regular season game by fetching future regular season games.
+        // The config is only used if it can be loaded without prompting.
+        let Some(config) = load_background_config().await else {
+            debug!("Skipping season countdown, no API domain configured");
+            return;
+        };
+        let client = match http_client_for(&config) {
+            Ok(client) => client,
+            Err(e) => {
+                debug!("Skipping season countdown, HTTP client failed: {e}");
+                return;
             }
+        };
+
+        if let Some(days_until_season) =
+            calculate_days_until_regular_season(&client, &config, None).await
+        {
+            let countdown_text = tf("games.season_countdown", &[("days", &days_until_season)]);
+            self.season_countdown = Some(countdown_text);
         }
     }
 
@@ -465,25 +482,58 @@ impl TeletextPage {
     /// ```
     /// Renders the page content using double buffering for reduced flickering.
     /// This method builds all terminal escape sequences and content in a buffer first,
-    /// then writes everything in a single operation.
+    /// then writes everything in a single operation. In interactive mode the buffer
+    /// is diffed against the frame already on screen and only changed cells are written.
     pub fn render_buffered(&self, stdout: &mut Stdout) -> Result<(), AppError> {
-        // Get terminal dimensions - use appropriate width in non-interactive mode
-        let width = if self.ignore_height_limit {
+        if !self.ignore_height_limit {
+            // Hide cursor to prevent visual artifacts during rendering
+            execute!(stdout, crossterm::cursor::Hide)?;
+        }
+        let (width, height) = self.frame_size()?;
+        let buffer = self.compose_frame(stdout, width)?;
+
+        // In interactive mode only the cells that differ from the frame on
+        // screen are written; --once output goes out as rendered
+        let output = if self.ignore_height_limit {
+            buffer
+        } else {
+            super::screen_diff::present_frame(&buffer, width, height)
+        };
+
+        // Write everything in one operation (minimizes flicker)
+        execute!(stdout, Print(output))?;
+
+        // Show cursor again
+        execute!(stdout, crossterm::cursor::Show)?;
+
+        stdout.flush()?;
+        Ok(())
+    }
+
+    /// Size of the frame the page is rendered into: the terminal in
+    /// interactive mode, a fixed width with unlimited height in `--once` mode.
+    pub(crate) fn frame_size(&self) -> Result<(u16, u16), AppError> {
+        if self.ignore_height_limit && self.teletext_mode {
+            Ok((super::teletext_grid::GRID_WIDTH, 0))
+        } else if self.ignore_height_limit {
             // Use wider default width for non-interactive mode when wide mode is enabled
             if self.wide_mode {
-                136u16 // Wide enough to accommodate wide mode (128+ required)
+                Ok((136, 0)) // Wide enough to accommodate wide mode (128+ required)
             } else {
-                80u16 // Standard width for normal mode
+                Ok((80, 0)) // Standard width for normal mode
             }
         } else {
-            // Hide cursor to prevent visual artifacts during rendering
-            execute!(stdout, crossterm::cursor::Hide)?;
-
-            // Get terminal dimensions
-            let (width, _) = crossterm::terminal::size()?;
-            width
-        };
+            Ok(crossterm::terminal::size()?)
+        }
+    }
 
+    /// Composes the whole frame (header, content and footer) as one string of
+    /// absolutely positioned ANSI output, `width` columns wide.
+    pub(crate) fn compose_frame(
+        &self,
+        stdout: &mut Stdout,
+        width: u16,
+    ) -> Result<String, AppError> {
         // Get content for current page to calculate buffer size
         let (visible_rows, _) = self.get_page_content();
 
@@ -493,27 +543,19 @@ impl TeletextPage {
         // Build the entire screen content in a string buffer (double buffering)
         let mut buffer = String::with_capacity(expected_size);
 
-        // Only clear the screen in interactive mode using more efficient method
-        if !self.ignore_height_limit {
-            buffer.push_str("\x1b[H"); // Move to home position
-            if self.skip_screen_clear.replace(false) {
-                tracing::trace!("Skipped screen clear to avoid flicker");
-            } else {
-                buffer.push_str("\x1b[0J"); // Clear from cursor down
-            }
+        // Clock cells are re-recorded by the content renderers below
+        self.live_clock_cells.borrow_mut().clear();
+
+        if self.teletext_mode {
+            return self.compose_grid_frame(stdout, width);
         }
 
         // Format the header text with date if available, plus a teletext-style clock
         let page_str = self.format_header_page_str();
         let clock = Local::now().format("%H:%M").to_string();
-        let header_text = if let Some(ref date) = self.fetched_date {
-            let formatted_date = match chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d") {
-                Ok(date) => date.format("%d.%m.%Y").to_string(),
-                Err(_) => date.clone(),
-            };
-            format!("SM-LIIGA {page_str} {formatted_date} {clock}")
-        } else {
-            format!("SM-LIIGA {page_str} {clock}")
+        let header_text = match self.header_date() {
+            Some(date) => format!("SM-LIIGA {page_str} {date} {clock}"),
+            None => format!("SM-LIIGA {page_str} {clock}"),
         };
 
         // Use optimized ANSI code generation for headers (requirement 4.3)
@@ -613,65 +655,91 @@ impl TeletextPage {
                 current_line,
                 self.screen_height,
             );
-
-            let view_mode = if self.is_standings_page {
-                Some(crate::ui::interactive::state_manager::ViewMode::Standings {
-                    live_mode: self.standings_live_mode,
-                })
-            } else if self.is_bracket_page {
-                Some(crate::ui::interactive::state_manager::ViewMode::Bracket)
-            } else {
-                Some(crate::ui::interactive::state_manager::ViewMode::Games)
-            };
-            let show_today_shortcut = self.fetched_date.as_ref().is_some_and(|date| {
-                let default_date = if should_show_todays_games() {
-                    Local::now().format("%Y-%m-%d").to_string()
-                } else {
-                    Local::now()
-                        .date_naive()
-                        .pred_opt()
-                        .expect("Date underflow cannot happen")
-                        .format("%Y-%m-%d")
-                        .to_string()
-                };
-                // Hide 't' when on the default date (today/yesterday)
-                if date == &default_date {
-                    return false;
-                }
-                // Hide 't' when still viewing the initial auto-forwarded date
-                if let Some(ref initial) = self.initial_fetched_date
-                    && date == initial
-                {
-                    return false;
-                }
-                true
-            });
-
-            super::footer::render_footer_with_view(
-                stdout,
-                &mut buffer,
-                &super::footer::FooterContext {
-                    footer_y,
-                    width: width as usize,
-                    auto_refresh_indicator: &self.auto_refresh_indicator,
-                    auto_refresh_disabled: self.auto_refresh_disabled,
-                    error_warning_active: self.error_warning_active,
-                    season_countdown: &self.season_countdown,
-                    view_mode: view_mode.as_ref(),
-                    show_today_shortcut,
-                    has_bracket_data: self.has_bracket_data,
-                },
-            )?;
+            self.render_footer(stdout, &mut buffer, footer_y, width as usize)?;
         }
 
-        // Write entire buffer in one operation (minimizes flicker)
-        execute!(stdout, Print(buffer))?;
+        Ok(buffer)
+    }
 
-        // Show cursor again
-        execute!(stdout, crossterm::cursor::Show)?;
+    /// Date of the games shown, formatted for the header ("18.10.2026").
+    pub(super) fn header_date(&self) -> Option<String> {
+        let date = self.fetched_date.as_ref()?;
+        Some(match chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+            Ok(date) => date.format("%d.%m.%Y").to_string(),
+            Err(_) => date.clone(),
+        })
+    }
 
-        stdout.flush()?;
-        Ok(())
+    /// Renders the Fastext footer on row `footer_y` (0-based), `width` columns
+    /// wide, with a notice or the season countdown on the row above it.
+    pub(super) fn render_footer(
+        &self,
+        stdout: &mut Stdout,
+        buffer: &mut String,
+        footer_y: usize,
+        width: usize,
+    ) -> Result<(), AppError> {
+        let view_mode = if self.is_standings_page {
+            Some(crate::ui::interactive::state_manager::ViewMode::Standings {
+                live_mode: self.standings_live_mode,
+            })
+        } else if self.is_bracket_page {
+            Some(crate::ui::interactive::state_manager::ViewMode::Bracket)
+        } else if self.is_diagnostics_page {
+            Some(crate::ui::interactive::state_manager::ViewMode::Diagnostics)
+        } else if self.is_off_season_page {
+            Some(crate::ui::interactive::state_manager::ViewMode::OffSeason)
+        } else if let Some((index, selected)) = self.goal_videos {
+            Some(crate::ui::interactive::state_manager::ViewMode::GoalVideos { index, selected })
+        } else if let Some(index) = self.game_detail_index {
+            if self.is_game_lineup_page {
+                Some(crate::ui::interactive::state_manager::ViewMode::GameLineup { index })
+            } else {
+                Some(crate::ui::interactive::state_manager::ViewMode::GameDetail { index })
+            }
+        } else {
+            Some(crate::ui::interactive::state_manager::ViewMode::Games)
+        };
+        let show_today_shortcut = self.fetched_date.as_ref().is_some_and(|date| {
+            let default_date = if should_show_todays_games() {
+                Local::now().format("%Y-%m-%d").to_string()
+            } else {
+                Local::now()
+                    .date_naive()
+                    .pred_opt()
+                    .expect("Date underflow cannot happen")
+                    .format("%Y-%m-%d")
+                    .to_string()
+            };
+            // Hide 't' when on the default date (today/yesterday)
+            if date == &default_date {
+                return false;
+            }
+            // Hide 't' when still viewing the initial auto-forwarded date
+            if let Some(ref initial) = self.initial_fetched_date
+                && date == initial
+            {
+                return false;
+            }
+            true
+        });
+
+        super::footer::render_footer_with_view(
+            stdout,
+            buffer,
+            &super::footer::FooterContext {
+                footer_y,
+                width,
+                auto_refresh_indicator: &self.auto_refresh_indicator,
+                auto_refresh_disabled: self.auto_refresh_disabled,
+                error_warning_active: self.error_warning_active,
+                season_countdown: &self.season_countdown,
+                notice: &self.notice,
+                view_mode: view_mode.as_ref(),
+                show_today_shortcut,
+                has_bracket_data: self.has_bracket_data,
+            },
+        )
     }
 
     // --- Mode utility methods (moved from mode_utils.rs) ---
@@ -684,11 +752,13 @@ impl TeletextPage {
 
     /// Sets the compact mode state.
     /// Compact mode and wide mode are mutually exclusive.
-    #[allow(dead_code)]
     pub fn set_compact_mode(&mut self, compact: bool) -> Result<(), &'static str> {
         if compact && self.wide_mode {
             self.wide_mode = false;
         }
+        if compact {
+            self.teletext_mode = false;
+        }
         self.compact_mode = compact;
         Ok(())
     }
@@ -701,15 +771,31 @@ impl TeletextPage {
 
     /// Sets the wide mode state.
     /// Compact mode and wide mode are mutually exclusive.
-    #[allow(dead_code)]
     pub fn set_wide_mode(&mut self, wide: bool) -> Result<(), &'static str> {
         if wide && self.compact_mode {
             self.compact_mode = false;
         }
+        if wide {
+            self.teletext_mode = false;
+        }
         self.wide_mode = wide;
         Ok(())
     }
 
+    /// Returns whether the page renders into the strict 40×24 teletext grid.
+    pub fn is_teletext_mode(&self) -> bool {
+        self.teletext_mode
+    }
+
+    /// Sets the strict teletext grid mode. It replaces compact and wide mode.
+    pub fn set_teletext_mode(&mut self, teletext: bool) {
+        if teletext {
+            self.compact_mode = false;
+            self.wide_mode = false;
+        }
+        self.teletext_mode = teletext;
+    }
+
     /// Validates that compact mode and wide mode are not both enabled.
     #[allow(dead_code)]
     pub fn validate_mode_exclusivity(&self) -> Result<(), &'static str> {
@@ -746,6 +832,21 @@ impl TeletextPage {
             .any(|row| matches!(row, TeletextRow::ErrorMessage(_)))
     }
 
+    /// Shows all content on one page at the fixed `--once` width, without the
+    /// footer. For pages shown somewhere other than the terminal.
+    pub fn set_full_page(&mut self) {
+        self.ignore_height_limit = true;
+        self.show_footer = false;
+        self.current_page = 0;
+        let width = if self.wide_mode { 136 } else { 80 };
+        self.layout_manager = ColumnLayoutManager::new(width, CONTENT_MARGIN);
+    }
+
+    /// Teletext page number shown in the header (221, 222, ...).
+    pub fn page_number(&self) -> u16 {
+        self.page_number
+    }
+
     /// Checks if this page is a loading page (created by `create_loading_page`).
     /// Unlike `has_error_messages()`, this distinguishes loading pages from real
     /// error/no-data pages, preventing stale game resurrection during restoration.
@@ -770,7 +871,7 @@ impl TeletextPage {
 
 /// Helper function to extract ANSI color code from crossterm Color enum.
 /// Provides a fallback value for non-ANSI colors.
-pub(super) fn get_ansi_code(color: crossterm::style::Color, fallback: u8) -> u8 {
+pub(super) const fn get_ansi_code(color: crossterm::style::Color, fallback: u8) -> u8 {
     match color {
         crossterm::style::Color::AnsiValue(val) => val,
         _ => fallback,
@@ -1095,7 +1196,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             }));
         }
 
@@ -1167,7 +1272,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             }));
         }
 
@@ -1219,7 +1328,11 @@ mod tests {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         }));
 
         // Test game with goals
@@ -1251,7 +1364,11 @@ mod tests {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         }));
 
         let (content, _) = page.get_page_content();
@@ -1311,7 +1428,11 @@ mod tests {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         }));
 
         // Test ongoing game with goals
@@ -1356,7 +1477,11 @@ mod tests {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         }));
 
         // Test finished game with overtime
@@ -1376,7 +1501,11 @@ mod tests {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         }));
 
         // Test finished game with shootout
@@ -1396,7 +1525,11 @@ mod tests {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         }));
 
         let (content, _) = page.get_page_content();
@@ -1477,7 +1610,11 @@ mod tests {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         }));
 
         // Create another page with video links disabled
@@ -1508,7 +1645,11 @@ mod tests {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         }));
 
         let (content, _) = page.get_page_content();
@@ -1579,6 +1720,8 @@ mod tests {
             goal_events,
             played_time: 3600,
             series_score: None,
+            pre_game: None,
+            annotations: Vec::new(),
         };
 
         let game_rows = vec![&game_row];
@@ -1733,6 +1876,8 @@ mod tests {
             goal_events: vec![],
             played_time: 0,
             series_score: None,
+            pre_game: None,
+            annotations: Vec::new(),
         };
 
         let formatted = page.format_compact_game(&scheduled_game, &config);
@@ -1751,6 +1896,8 @@ mod tests {
             goal_events: vec![],
             played_time: 3900,
             series_score: None,
+            pre_game: None,
+            annotations: Vec::new(),
         };
 
         let formatted = page.format_compact_game(&final_game, &config);
@@ -1785,6 +1932,8 @@ mod tests {
                 goal_events: vec![],
                 played_time: 3900,
                 series_score: None,
+                pre_game: None,
+                annotations: Vec::new(),
             },
             TeletextRow::GameResult {
                 home_team: "HIFK".to_string(),
@@ -1797,6 +1946,8 @@ mod tests {
                 goal_events: vec![],
                 played_time: 3900,
                 series_score: None,
+                pre_game: None,
+                annotations: Vec::new(),
             },
         ];
 
@@ -1944,7 +2095,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             };
             let game_data = GameResultData::new(&game);
             many_games_page.add_game_result(game_data);
@@ -2098,6 +2253,8 @@ mod tests {
             goal_events: Vec::new(),
             played_time: 0,
             series_score: None,
+            pre_game: None,
+            annotations: Vec::new(),
         };
 
         let game2 = TeletextRow::GameResult {
@@ -2111,6 +2268,8 @@ mod tests {
             goal_events: Vec::new(),
             played_time: 0,
             series_score: None,
+            pre_game: None,
+            annotations: Vec::new(),
         };
 
         let game3 = TeletextRow::GameResult {
@@ -2124,6 +2283,8 @@ mod tests {
             goal_events: Vec::new(),
             played_time: 0,
             series_score: None,
+            pre_game: None,
+            annotations: Vec::new(),
         };
 
         let rows = vec![&game1, &game2, &game3];
@@ -2172,6 +2333,8 @@ mod tests {
             goal_events: vec![],
             played_time: 0,
             series_score: None,
+            pre_game: None,
+            annotations: Vec::new(),
         };
 
         let formatted = page.format_compact_game(&scheduled_game, &config);
@@ -2190,6 +2353,8 @@ mod tests {
             goal_events: vec![],
             played_time: 2400, // 40 minutes
             series_score: None,
+            pre_game: None,
+            annotations: Vec::new(),
         };
 
         let formatted = page.format_compact_game(&ongoing_game, &config);
@@ -2208,6 +2373,8 @@ mod tests {
             goal_events: vec![],
             played_time: 3900,
             series_score: None,
+            pre_game: None,
+            annotations: Vec::new(),
         };
 
         let formatted = page.format_compact_game(&overtime_game, &config);
@@ -2226,6 +2393,8 @@ mod tests {
             goal_events: vec![],
             played_time: 3900,
             series_score: None,
+            pre_game: None,
+            annotations: Vec::new(),
         };
 
         let formatted = page.format_compact_game(&shootout_game, &config);
@@ -2357,6 +2526,34 @@ mod tests {
         assert!(page.can_fit_two_pages());
     }
 
+    #[test]
+    fn test_set_full_page_shows_everything_without_footer() {
+        let mut page = TeletextPage::new(
+            221,
+            "JÄÄKIEKKO".to_string(),
+            "RUNKOSARJA".to_string(),
+            false,
+            true,  // show_footer
+            false, // interactive page
+            false,
+            true, // wide_mode
+        );
+        page.set_screen_height(10);
+        for i in 0..20 {
+            page.add_error_message(&format!("Rivi {i}"));
+        }
+        page.current_page = 1;
+
+        page.set_full_page();
+
+        assert_eq!(page.frame_size().unwrap(), (136, 0));
+        assert_eq!(page.get_current_page(), 0);
+        assert!(page.can_fit_two_pages());
+        let frame = page.compose_frame(&mut std::io::stdout(), 136).unwrap();
+        assert!(frame.contains("Rivi 19"));
+        assert!(!frame.contains(crate::i18n::t("footer.quit")));
+    }
+
     #[test]
     fn test_can_fit_two_pages_with_insufficient_width() {
         // This test simulates a narrow terminal by using interactive mode
@@ -2408,7 +2605,11 @@ mod tests {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         };
         let test_game_data = GameResultData::new(&test_game);
         page.add_game_result(test_game_data);
@@ -2450,7 +2651,11 @@ mod tests {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         };
         let test_game_data = GameResultData::new(&test_game);
         page.add_game_result(test_game_data);
@@ -2493,7 +2698,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             };
             let test_game_data = GameResultData::new(&test_game);
             page.add_game_result(test_game_data);
@@ -2542,7 +2751,11 @@ mod tests {
                 play_off_pair: None,
                 play_off_req_wins: None,
                 series_score: None,
+                game_id: 0,
+                season: 0,
                 is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
             };
             let test_game_data = GameResultData::new(&test_game);
             page.add_game_result(test_game_data);
@@ -2591,7 +2804,11 @@ mod tests {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         };
 
         let test_game2 = GameData {
@@ -2610,7 +2827,11 @@ mod tests {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         };
 
         page.add_game_result(GameResultData::new(&test_game1));
@@ -2919,7 +3140,11 @@ fn test_video_link_functionality_with_dynamic_layout() {
         play_off_pair: None,
         play_off_req_wins: None,
         series_score: None,
+        game_id: 0,
+        season: 0,
         is_placeholder: false,
+        pre_game: None,
+        annotations: vec![],
     }));
 
     // Test with video links disabled
@@ -2950,7 +3175,11 @@ fn test_video_link_functionality_with_dynamic_layout() {
         play_off_pair: None,
         play_off_req_wins: None,
         series_score: None,
+        game_id: 0,
+        season: 0,
         is_placeholder: false,
+        pre_game: None,
+        annotations: vec![],
     }));
 
     let (content, _) = page.get_page_content();
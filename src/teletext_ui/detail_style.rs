@@ -0,0 +1,46 @@
+//! Colors and text helpers shared by the 40-column detail pages: the game
+//! timeline, lineups and goal videos, the diagnostics page and the
+//! off-season page.
+
+use crate::teletext_ui::core::get_ansi_code;
+use crate::ui::teletext::colors::{
+    goal_type_fg, home_scorer_fg, result_fg, text_fg, winning_goal_fg,
+};
+
+// ANSI 256 color codes of the game list's palette
+pub(super) const WHITE: u8 = get_ansi_code(text_fg(), 231);
+pub(super) const CYAN: u8 = get_ansi_code(home_scorer_fg(), 51);
+pub(super) const GREEN: u8 = get_ansi_code(result_fg(), 46);
+pub(super) const YELLOW: u8 = get_ansi_code(goal_type_fg(), 226);
+pub(super) const MAGENTA: u8 = get_ansi_code(winning_goal_fg(), 201);
+
+pub(super) const RESET: &str = "\x1b[0m";
+
+/// Foreground escape sequence of the 256-color `code`.
+pub(super) fn color(code: u8) -> String {
+    format!("\x1b[38;5;{code}m")
+}
+
+/// Truncates `text` to at most `max` characters (char-safe).
+pub(super) fn fit(text: &str, max: usize) -> String {
+    text.chars().take(max).collect()
+}
+
+/// Strips ANSI escape sequences to recover the visible text of a row.
+#[cfg(test)]
+pub(super) fn visible(text: &str) -> String {
+    let mut out = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            for next in chars.by_ref() {
+                if next.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
@@ -0,0 +1,314 @@
+//! Hidden diagnostics page (888): request metrics, API endpoint health,
+//! cache hit ratios, refresh backoff and the tournament-unavailable memo.
+
+use std::time::Duration;
+
+use crate::data_fetcher::api::endpoint_health::EndpointStatus;
+use crate::data_fetcher::api::metrics::EndpointMetrics;
+use crate::data_fetcher::cache::CacheInfo;
+use crate::i18n::{t, tf};
+use crate::teletext_ui::core::TeletextRow;
+use crate::teletext_ui::detail_style::{CYAN, RESET, WHITE, YELLOW, color};
+
+/// Visible width of a diagnostics row (the 40-column teletext grid)
+const COLUMN_WIDTH: usize = 40;
+
+/// Width of the row label column of the tables
+const LABEL_WIDTH: usize = 9;
+
+/// Everything shown on the diagnostics page, collected at refresh time.
+#[derive(Debug, Clone, Default)]
+pub struct DiagnosticsSnapshot {
+    /// API requests holding a connection slot right now
+    pub in_flight: usize,
+    pub endpoints: Vec<EndpointMetrics>,
+    /// Configured API endpoints in failover order
+    pub api_endpoints: Vec<EndpointStatus>,
+    /// Cache name and its statistics
+    pub caches: Vec<(&'static str, CacheInfo)>,
+    /// Current auto-refresh retry backoff (zero when not backing off)
+    pub retry_backoff: Duration,
+    pub backoff_remaining: Duration,
+    /// Tournaments skipped as unavailable and the time until the next check
+    pub unavailable_tournaments: Vec<(String, Duration)>,
+}
+
+/// "850ms" below ten seconds, otherwise "12s"; "-" without samples.
+fn format_latency(latency: Option<Duration>) -> String {
+    match latency {
+        None => "-".to_string(),
+        Some(d) if d < Duration::from_secs(10) => format!("{}ms", d.as_millis()),
+        Some(d) => format!("{}s", d.as_secs()),
+    }
+}
+
+/// "4.0 s" style duration with one decimal.
+fn format_seconds(duration: Duration) -> String {
+    format!("{:.1} s", duration.as_secs_f64())
+}
+
+fn table_header(columns: &[(&str, usize)]) -> TeletextRow {
+    let mut line = color(CYAN);
+    for (i, (title, width)) in columns.iter().enumerate() {
+        if i == 0 {
+            line.push_str(&format!("{title:<width$}"));
+        } else {
+            line.push_str(&format!("{title:>width$}"));
+        }
+    }
+    line.push_str(RESET);
+    TeletextRow::DetailLine(line)
+}
+
+fn table_row(label: &str, cells: &[(String, usize)]) -> TeletextRow {
+    let mut line = format!("{}{label:<LABEL_WIDTH$}{}", color(YELLOW), color(WHITE));
+    for (cell, width) in cells {
+        line.push_str(&format!("{cell:>width$}"));
+    }
+    line.push_str(RESET);
+    TeletextRow::DetailLine(line)
+}
+
+fn text_row(text: &str) -> TeletextRow {
+    let text: String = text.chars().take(COLUMN_WIDTH).collect();
+    TeletextRow::DetailLine(format!("{}{text}{RESET}", color(WHITE)))
+}
+
+/// One API endpoint: `>` marks the active one; an unhealthy endpoint shows
+/// how long it is skipped and its last error.
+fn endpoint_rows(endpoint: &EndpointStatus) -> Vec<TeletextRow> {
+    let marker = if endpoint.active { '>' } else { ' ' };
+    let host = endpoint
+        .domain
+        .trim_start_matches("https://")
+        .trim_start_matches("http://");
+    let host: String = host.chars().take(26).collect();
+    let status = match endpoint.retry_in {
+        None => "OK".to_string(),
+        Some(remaining) => tf("diagnostics.paused", &[("seconds", &remaining.as_secs())]),
+    };
+    let mut rows = vec![text_row(&format!("{marker} {host:<26}{status:>12}"))];
+    if let (false, Some(error)) = (endpoint.healthy, &endpoint.last_error) {
+        rows.push(text_row(&format!("  {error}")));
+    }
+    rows
+}
+
+/// Builds the diagnostics page rows.
+///
+/// # Arguments
+/// * `snapshot` - Metrics and state collected for this refresh
+///
+/// # Returns
+/// * `Vec<TeletextRow>` - Section headers and 40-column detail lines
+pub fn render_diagnostics(snapshot: &DiagnosticsSnapshot) -> Vec<TeletextRow> {
+    let mut rows = vec![TeletextRow::SeriesHeader(
+        t("diagnostics.requests").to_string(),
+    )];
+    rows.push(text_row(&tf(
+        "diagnostics.in_flight",
+        &[("count", &snapshot.in_flight)],
+    )));
+    if snapshot.endpoints.is_empty() {
+        rows.push(text_row(t("diagnostics.no_requests")));
+    } else {
+        rows.push(table_header(&[
+            (t("diagnostics.endpoint"), LABEL_WIDTH),
+            (t("diagnostics.request_count"), 6),
+            (t("diagnostics.errors"), 6),
+            (t("diagnostics.retries"), 6),
+            ("429", 6),
+            ("304", 6),
+        ]));
+        for endpoint in &snapshot.endpoints {
+            rows.push(table_row(
+                endpoint.endpoint,
+                &[
+                    (endpoint.requests.to_string(), 6),
+                    (endpoint.errors.to_string(), 6),
+                    (endpoint.retries.to_string(), 6),
+                    (endpoint.rate_limited.to_string(), 6),
+                    (endpoint.not_modified.to_string(), 6),
+                ],
+            ));
+        }
+
+        rows.push(TeletextRow::SeriesHeader(
+            t("diagnostics.response_times").to_string(),
+        ));
+        rows.push(table_header(&[
+            (t("diagnostics.endpoint"), LABEL_WIDTH),
+            ("p50", 10),
+            ("p90", 10),
+            ("p99", 10),
+        ]));
+        for endpoint in &snapshot.endpoints {
+            rows.push(table_row(
+                endpoint.endpoint,
+                &[
+                    (format_latency(endpoint.p50), 10),
+                    (format_latency(endpoint.p90), 10),
+                    (format_latency(endpoint.p99), 10),
+                ],
+            ));
+        }
+    }
+
+    if !snapshot.api_endpoints.is_empty() {
+        rows.push(TeletextRow::SeriesHeader(
+            t("diagnostics.api_endpoints").to_string(),
+        ));
+        for endpoint in &snapshot.api_endpoints {
+            rows.extend(endpoint_rows(endpoint));
+        }
+    }
+
+    rows.push(TeletextRow::SeriesHeader(
+        t("diagnostics.caches").to_string(),
+    ));
+    rows.push(table_header(&[
+        (t("diagnostics.cache_name"), LABEL_WIDTH),
+        (t("diagnostics.cache_size"), 11),
+        (t("diagnostics.cache_hits"), 10),
+    ]));
+    for (name, info) in &snapshot.caches {
+        let ratio = info
+            .hit_ratio()
+            .map_or_else(|| "-".to_string(), |r| format!("{:.0}%", r * 100.0));
+        rows.push(table_row(
+            name,
+            &[
+                (format!("{}/{}", info.size, info.capacity), 11),
+                (ratio, 10),
+            ],
+        ));
+    }
+
+    rows.push(TeletextRow::SeriesHeader(
+        t("diagnostics.refresh").to_string(),
+    ));
+    if snapshot.retry_backoff.is_zero() {
+        rows.push(text_row(t("diagnostics.backoff_off")));
+    } else {
+        rows.push(text_row(&tf(
+            "diagnostics.backoff",
+            &[
+                ("backoff", &format_seconds(snapshot.retry_backoff)),
+                ("remaining", &format_seconds(snapshot.backoff_remaining)),
+            ],
+        )));
+    }
+
+    rows.push(TeletextRow::SeriesHeader(
+        t("diagnostics.unavailable").to_string(),
+    ));
+    if snapshot.unavailable_tournaments.is_empty() {
+        rows.push(text_row(t("diagnostics.all_available")));
+    }
+    for (tournament, remaining) in &snapshot.unavailable_tournaments {
+        let minutes = format!("{:>2}", remaining.as_secs().div_ceil(60));
+        let next_check = tf("diagnostics.next_check", &[("minutes", &minutes)]);
+        rows.push(text_row(&format!("{tournament:<22}{next_check}")));
+    }
+
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Visible text of a row
+    fn visible(row: &TeletextRow) -> String {
+        match row {
+            TeletextRow::DetailLine(line) | TeletextRow::SeriesHeader(line) => {
+                crate::teletext_ui::detail_style::visible(line)
+            }
+            _ => String::new(),
+        }
+    }
+
+    fn snapshot() -> DiagnosticsSnapshot {
+        DiagnosticsSnapshot {
+            in_flight: 2,
+            endpoints: vec![EndpointMetrics {
+                endpoint: "games",
+                requests: 120,
+                errors: 3,
+                retries: 2,
+                rate_limited: 1,
+                not_modified: 40,
+                p50: Some(Duration::from_millis(85)),
+                p90: Some(Duration::from_millis(430)),
+                p99: Some(Duration::from_secs(12)),
+            }],
+            api_endpoints: vec![
+                EndpointStatus {
+                    domain: "https://primary.example.com".to_string(),
+                    active: false,
+                    healthy: false,
+                    consecutive_failures: 1,
+                    retry_in: Some(Duration::from_secs(25)),
+                    last_error: Some("Network timeout".to_string()),
+                },
+                EndpointStatus {
+                    domain: "https://backup.example.com".to_string(),
+                    active: true,
+                    healthy: true,
+                    consecutive_failures: 0,
+                    retry_in: None,
+                    last_error: None,
+                },
+            ],
+            caches: vec![(
+                "http",
+                CacheInfo {
+                    size: 12,
+                    capacity: 100,
+                    hits: 3,
+                    misses: 1,
+                },
+            )],
+            retry_backoff: Duration::from_secs(4),
+            backoff_remaining: Duration::from_millis(1500),
+            unavailable_tournaments: vec![("playoffs".to_string(), Duration::from_secs(61))],
+        }
+    }
+
+    #[test]
+    fn test_render_diagnostics() {
+        let lines: Vec<String> = render_diagnostics(&snapshot())
+            .iter()
+            .map(visible)
+            .collect();
+
+        assert!(lines.contains(&"Käynnissä nyt: 2".to_string()));
+        assert!(lines.contains(&"games       120     3     2     1    40".to_string()));
+        assert!(lines.contains(&"games          85ms     430ms       12s".to_string()));
+        assert!(lines.contains(&"  primary.example.com        tauolla 25s".to_string()));
+        assert!(lines.contains(&"  Network timeout".to_string()));
+        assert!(lines.contains(&"> backup.example.com                  OK".to_string()));
+        assert!(lines.contains(&"http          12/100       75%".to_string()));
+        assert!(lines.contains(&"Uusintaviive: 4.0 s (jäljellä 1.5 s)".to_string()));
+        assert!(lines.contains(&"playoffs              uusi haku  2 min".to_string()));
+        assert!(
+            lines
+                .iter()
+                .all(|line| line.chars().count() <= COLUMN_WIDTH)
+        );
+    }
+
+    #[test]
+    fn test_render_diagnostics_before_any_requests() {
+        let lines: Vec<String> = render_diagnostics(&DiagnosticsSnapshot::default())
+            .iter()
+            .map(visible)
+            .collect();
+
+        assert!(lines.contains(&"Ei pyyntöjä vielä".to_string()));
+        assert!(lines.contains(&"Uusintaviive: ei käytössä".to_string()));
+        assert!(lines.contains(&"Kaikki turnaukset saatavilla".to_string()));
+        assert!(!lines.contains(&"VASTEAJAT".to_string()));
+        assert!(!lines.contains(&"RAJAPINNAT".to_string()));
+    }
+}
@@ -0,0 +1,466 @@
+// src/teletext_ui/export.rs - Page snapshots as HTML, SVG and plain text
+//
+// A snapshot is taken from the same ANSI frame `render_buffered` writes to
+// the terminal: it is replayed into a `ScreenFrame` and every run of equally
+// styled cells becomes a span (HTML) or a rect and text element (SVG). The
+// colors are the terminal's 256-color codes resolved through the xterm
+// palette, so the exported page looks like the one on screen, and OSC 8 video
+// links become ordinary `<a href>` links. TTI files for teletext inserters
+// are written by the `tti` module.
+
+use super::core::TeletextPage;
+use super::screen_diff::{CellStyle, ScreenCell, ScreenFrame};
+use crate::error::AppError;
+use crate::ui::teletext::colors::ansi_hex;
+use std::path::{Path, PathBuf};
+
+/// Teletext fonts when installed, then common monospace fallbacks. The
+/// exports stay self-contained, so no font is embedded or linked.
+const FONT_STACK: &str =
+    "'Bedstead', 'MODE7GX3', 'Teletext50', 'DejaVu Sans Mono', 'Courier New', monospace";
+
+/// Foreground of cells without an explicit color (the terminal default)
+const DEFAULT_FG: u8 = 231;
+
+/// Background of cells without an explicit color
+const DEFAULT_BG: u8 = 16;
+
+/// Rows a `--once` page, which has no height limit, is replayed into
+const MAX_SNAPSHOT_ROWS: u16 = 1000;
+
+/// SVG cell size in pixels; text runs are stretched to exactly fill their cells
+const SVG_CELL_WIDTH: usize = 10;
+const SVG_CELL_HEIGHT: usize = 20;
+const SVG_FONT_SIZE: usize = 16;
+
+/// Format of an exported page snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Html,
+    Svg,
+    Text,
+    /// Teletext page file with every sub-page (see `tti`)
+    Tti,
+}
+
+impl ExportFormat {
+    /// Every format, in the order the interactive export writes them
+    pub const ALL: [ExportFormat; 4] = [
+        ExportFormat::Html,
+        ExportFormat::Svg,
+        ExportFormat::Text,
+        ExportFormat::Tti,
+    ];
+
+    /// Picks the format from a file extension (`.html`/`.htm`, `.svg`, `.txt`, `.tti`).
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "html" | "htm" => Some(ExportFormat::Html),
+            "svg" => Some(ExportFormat::Svg),
+            "txt" => Some(ExportFormat::Text),
+            "tti" => Some(ExportFormat::Tti),
+            _ => None,
+        }
+    }
+
+    /// File extension written for this format
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Html => "html",
+            ExportFormat::Svg => "svg",
+            ExportFormat::Text => "txt",
+            ExportFormat::Tti => "tti",
+        }
+    }
+}
+
+/// Renders the page as it would appear on screen in the given format. A
+/// TTI file holds every sub-page instead, laid out on the teletext grid.
+pub fn export_page(page: &mut TeletextPage, format: ExportFormat) -> Result<String, AppError> {
+    let title = format!("SM-LIIGA {}", page.page_number());
+    Ok(match format {
+        ExportFormat::Html => render_html(&snapshot(page)?, &title),
+        ExportFormat::Svg => render_svg(&snapshot(page)?, &title),
+        ExportFormat::Text => render_text(&snapshot(page)?),
+        ExportFormat::Tti => super::tti::render_tti(page)?,
+    })
+}
+
+/// Writes the page to `path` in the format its extension names.
+pub async fn export_page_to_file(page: &mut TeletextPage, path: &Path) -> Result<(), AppError> {
+    let format = ExportFormat::from_path(path).ok_or_else(|| {
+        AppError::config_error(format!(
+            "Cannot export to {}: use a .html, .svg, .txt or .tti file",
+            path.display()
+        ))
+    })?;
+    let content = export_page(page, format)?;
+    tokio::fs::write(path, content).await?;
+    Ok(())
+}
+
+/// Writes the page in every format into `dir`, creating it if needed, as
+/// `liiga-<page>-<timestamp>.<ext>`. Returns the written files.
+pub async fn export_page_to_dir(
+    page: &mut TeletextPage,
+    dir: &Path,
+) -> Result<Vec<PathBuf>, AppError> {
+    tokio::fs::create_dir_all(dir).await?;
+    let stem = format!(
+        "liiga-{}-{}",
+        page.page_number(),
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    );
+    let mut written = Vec::with_capacity(ExportFormat::ALL.len());
+    for format in ExportFormat::ALL {
+        let path = dir.join(format!("{stem}.{}", format.extension()));
+        tokio::fs::write(&path, export_page(page, format)?).await?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// A page replayed once and rendered into several formats, as the web
+/// server needs for every refresh.
+pub struct PageSnapshot {
+    rows: Vec<Vec<ScreenCell>>,
+}
+
+impl PageSnapshot {
+    pub fn capture(page: &TeletextPage) -> Result<Self, AppError> {
+        Ok(Self {
+            rows: snapshot(page)?,
+        })
+    }
+
+    /// The page as plain text lines
+    pub fn text(&self) -> String {
+        render_text(&self.rows)
+    }
+
+    /// Content for the `<pre>` block of [`html_document`]
+    pub fn html(&self) -> String {
+        html_rows(&self.rows)
+    }
+
+    /// Video links on the page: the linked text and the URL
+    pub fn links(&self) -> Vec<(String, String)> {
+        links(&self.rows)
+    }
+}
+
+/// Replays the page's frame into a cell grid without the trailing blank rows.
+fn snapshot(page: &TeletextPage) -> Result<Vec<Vec<ScreenCell>>, AppError> {
+    let (width, height) = page.frame_size()?;
+    let height = if height == 0 {
+        MAX_SNAPSHOT_ROWS
+    } else {
+        height
+    };
+    // The teletext grid is exported as the grid itself, without the
+    // centring and double-size lines that fit it to the terminal
+    let frame = if page.is_teletext_mode() {
+        page.compose_grid(&mut std::io::stdout())?
+    } else {
+        let ansi = page.compose_frame(&mut std::io::stdout(), width)?;
+        ScreenFrame::from_ansi(&ansi, width, height)
+    };
+
+    let mut rows: Vec<Vec<ScreenCell>> = frame.rows().map(<[ScreenCell]>::to_vec).collect();
+    while rows.last().is_some_and(|row| row.iter().all(is_blank)) {
+        rows.pop();
+    }
+    Ok(rows)
+}
+
+fn is_blank(cell: &ScreenCell) -> bool {
+    cell.ch == ' ' && cell.style.bg.is_none() && cell.style.link.is_none()
+}
+
+/// Splits a row into runs of equally styled cells: start column, text, style.
+fn runs(row: &[ScreenCell]) -> Vec<(usize, String, &CellStyle)> {
+    let mut runs: Vec<(usize, String, &CellStyle)> = Vec::new();
+    for (col, cell) in row.iter().enumerate() {
+        match runs.last_mut() {
+            Some((_, text, style)) if **style == cell.style => text.push(cell.ch),
+            _ => runs.push((col, cell.ch.to_string(), &cell.style)),
+        }
+    }
+    runs
+}
+
+/// Escapes text for HTML and SVG content and attribute values
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// The rows as plain text, trailing spaces trimmed.
+fn render_text(rows: &[Vec<ScreenCell>]) -> String {
+    let mut text = String::new();
+    for row in rows {
+        let line: String = row.iter().map(|cell| cell.ch).collect();
+        text.push_str(line.trim_end());
+        text.push('\n');
+    }
+    text
+}
+
+/// A standalone HTML document showing the rows in a `<pre>` block.
+fn render_html(rows: &[Vec<ScreenCell>], title: &str) -> String {
+    html_document(title, &html_rows(rows), "")
+}
+
+/// Link targets on the page with the text they are attached to, in order.
+fn links(rows: &[Vec<ScreenCell>]) -> Vec<(String, String)> {
+    let mut links: Vec<(String, String)> = Vec::new();
+    for row in rows {
+        for (_, text, style) in runs(row) {
+            if let Some(url) = &style.link {
+                links.push((text.trim().to_string(), url.clone()));
+            }
+        }
+    }
+    links
+}
+
+/// The rows as the HTML content of the page's `<pre>` block.
+fn html_rows(rows: &[Vec<ScreenCell>]) -> String {
+    let mut body = String::new();
+    for row in rows {
+        for (_, text, style) in runs(row) {
+            let mut css = Vec::new();
+            if let Some(fg) = style.fg {
+                css.push(format!("color:{}", ansi_hex(fg)));
+            }
+            if let Some(bg) = style.bg {
+                css.push(format!("background:{}", ansi_hex(bg)));
+            }
+            if style.bold {
+                css.push("font-weight:bold".to_string());
+            }
+            let mut run = escape(&text);
+            if !css.is_empty() {
+                run = format!("<span style=\"{}\">{run}</span>", css.join(";"));
+            }
+            if let Some(link) = &style.link {
+                run = format!("<a href=\"{}\">{run}</a>", escape(link));
+            }
+            body.push_str(&run);
+        }
+        body.push('\n');
+    }
+    body
+}
+
+/// Wraps `<pre>` content in a self-contained HTML document. `head` is
+/// appended to the document head as-is.
+pub fn html_document(title: &str, body: &str, head: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n\
+         <html lang=\"{lang}\">\n\
+         <head>\n\
+         <meta charset=\"utf-8\">\n\
+         <title>{title}</title>\n\
+         <style>\n\
+         body {{ margin: 0; background: {bg}; }}\n\
+         pre {{ display: inline-block; margin: 0; padding: 1em; \
+         font-family: {FONT_STACK}; font-size: 20px; line-height: 1.2; \
+         color: {fg}; background: {bg}; }}\n\
+         a {{ color: inherit; }}\n\
+         </style>\n\
+         {head}\
+         </head>\n\
+         <body>\n\
+         <pre>{body}</pre>\n\
+         </body>\n\
+         </html>\n",
+        lang = crate::i18n::language().code(),
+        title = escape(title),
+        fg = ansi_hex(DEFAULT_FG),
+        bg = ansi_hex(DEFAULT_BG),
+    )
+}
+
+/// A standalone SVG image of the rows on a fixed cell grid.
+fn render_svg(rows: &[Vec<ScreenCell>], title: &str) -> String {
+    let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let (width, height) = (columns * SVG_CELL_WIDTH, rows.len() * SVG_CELL_HEIGHT);
+
+    let mut backgrounds = String::new();
+    let mut texts = String::new();
+    for (index, row) in rows.iter().enumerate() {
+        let y = index * SVG_CELL_HEIGHT;
+        for (col, text, style) in runs(row) {
+            let x = col * SVG_CELL_WIDTH;
+            let run_width = text.chars().count() * SVG_CELL_WIDTH;
+            if let Some(bg) = style.bg {
+                backgrounds.push_str(&format!(
+                    "<rect x=\"{x}\" y=\"{y}\" width=\"{run_width}\" height=\"{SVG_CELL_HEIGHT}\" fill=\"{}\"/>\n",
+                    ansi_hex(bg)
+                ));
+            }
+            if text.trim().is_empty() {
+                continue;
+            }
+            let weight = if style.bold {
+                " font-weight=\"bold\""
+            } else {
+                ""
+            };
+            let mut element = format!(
+                "<text x=\"{x}\" y=\"{baseline}\" textLength=\"{run_width}\" \
+                 lengthAdjust=\"spacingAndGlyphs\" fill=\"{fill}\"{weight}>{text}</text>",
+                baseline = y + SVG_CELL_HEIGHT * 3 / 4,
+                fill = ansi_hex(style.fg.unwrap_or(DEFAULT_FG)),
+                text = escape(&text),
+            );
+            if let Some(link) = &style.link {
+                element = format!("<a href=\"{}\">{element}</a>", escape(link));
+            }
+            texts.push_str(&element);
+            texts.push('\n');
+        }
+    }
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\">\n\
+         <title>{title}</title>\n\
+         <rect width=\"100%\" height=\"100%\" fill=\"{bg}\"/>\n\
+         {backgrounds}\
+         <g font-family=\"{FONT_STACK}\" font-size=\"{SVG_FONT_SIZE}\" xml:space=\"preserve\">\n\
+         {texts}\
+         </g>\n\
+         </svg>\n",
+        title = escape(title),
+        bg = ansi_hex(DEFAULT_BG),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows_from_ansi(ansi: &str, width: u16, height: u16) -> Vec<Vec<ScreenCell>> {
+        ScreenFrame::from_ansi(ansi, width, height)
+            .rows()
+            .map(<[ScreenCell]>::to_vec)
+            .collect()
+    }
+
+    fn once_page() -> TeletextPage {
+        let mut page = TeletextPage::new(
+            221,
+            "JÄÄKIEKKO".to_string(),
+            "SM-LIIGA".to_string(),
+            false,
+            false,
+            true,
+            false,
+            false,
+        );
+        page.add_error_message("Ei otteluita <tänään> & huomenna");
+        page
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(
+            ExportFormat::from_path(Path::new("page.html")),
+            Some(ExportFormat::Html)
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("out/PAGE.HTM")),
+            Some(ExportFormat::Html)
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("page.svg")),
+            Some(ExportFormat::Svg)
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("page.txt")),
+            Some(ExportFormat::Text)
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("page.TTI")),
+            Some(ExportFormat::Tti)
+        );
+        assert_eq!(ExportFormat::from_path(Path::new("page.png")), None);
+        assert_eq!(ExportFormat::from_path(Path::new("page")), None);
+    }
+
+    #[test]
+    fn test_text_export_matches_page_and_drops_trailing_rows() {
+        let text = export_page(&mut once_page(), ExportFormat::Text).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines[0].starts_with("JÄÄKIEKKO"));
+        assert!(lines[0].contains("SM-LIIGA 221"));
+        assert!(text.contains("Ei otteluita <tänään> & huomenna"));
+        assert!(lines.len() < 20, "blank rows must be trimmed: {lines:?}");
+        assert!(lines.iter().all(|line| line == &line.trim_end()));
+    }
+
+    #[test]
+    fn test_html_export_is_escaped_and_uses_palette() {
+        let html = export_page(&mut once_page(), ExportFormat::Html).unwrap();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>SM-LIIGA 221</title>"));
+        assert!(html.contains("Ei otteluita &lt;tänään&gt; &amp; huomenna"));
+        // Header band: bright blue (21) and title green (46)
+        assert!(html.contains("background:#0000ff"));
+        assert!(html.contains("background:#00ff00"));
+        assert!(html.contains("monospace"));
+        assert!(!html.contains('\x1b'));
+    }
+
+    #[test]
+    fn test_svg_export_draws_header_band() {
+        let svg = export_page(&mut once_page(), ExportFormat::Svg).unwrap();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.contains("fill=\"#0000ff\""));
+        assert!(svg.contains("Ei otteluita &lt;tänään&gt; &amp; huomenna"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+
+    #[test]
+    fn test_links_stay_clickable() {
+        let ansi = "\x1b[1;1H\x1b[38;5;51mMaali \x1b]8;;https://example.com/v?a=1&b=2\x07\
+                    Selänne\x1b]8;;\x07 1-0\x1b[0m";
+        let rows = rows_from_ansi(ansi, 20, 1);
+
+        let html = render_html(&rows, "test");
+        assert!(html.contains(
+            "<a href=\"https://example.com/v?a=1&amp;b=2\"><span style=\"color:#00ffff\">Selänne</span></a>"
+        ));
+
+        let svg = render_svg(&rows, "test");
+        assert!(svg.contains("<a href=\"https://example.com/v?a=1&amp;b=2\"><text x=\"60\""));
+        assert_eq!(render_text(&rows), "Maali Selänne 1-0\n");
+    }
+
+    #[test]
+    fn test_palette_matches_xterm() {
+        use crate::ui::teletext::colors::ansi_rgb;
+        assert_eq!(ansi_rgb(0), (0, 0, 0));
+        assert_eq!(ansi_rgb(21), (0, 0, 255));
+        assert_eq!(ansi_rgb(46), (0, 255, 0));
+        assert_eq!(ansi_rgb(201), (255, 0, 255));
+        assert_eq!(ansi_rgb(226), (255, 255, 0));
+        assert_eq!(ansi_rgb(231), (255, 255, 255));
+        assert_eq!(ansi_rgb(232), (8, 8, 8));
+        assert_eq!(ansi_rgb(255), (238, 238, 238));
+        assert_eq!(ansi_hex(51), "#00ffff");
+    }
+}
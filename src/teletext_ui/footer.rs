@@ -8,6 +8,7 @@
 //! - Season countdown display
 
 use crate::error::AppError;
+use crate::i18n::t;
 use crate::teletext_ui::core::get_ansi_code;
 use crate::ui::teletext::colors::*;
 use crate::ui::teletext::loading_indicator::LoadingIndicator;
@@ -26,6 +27,8 @@ pub struct FooterContext<'a> {
     pub auto_refresh_disabled: bool,
     pub error_warning_active: bool,
     pub season_countdown: &'a Option<String>,
+    /// Shown in place of the season countdown while set
+    pub notice: &'a Option<String>,
     pub view_mode: Option<&'a crate::ui::interactive::state_manager::ViewMode>,
     pub show_today_shortcut: bool,
     pub has_bracket_data: bool,
@@ -69,64 +72,132 @@ const BLOCK_TEXT_LIGHT: u8 = 231; // white text for dark backgrounds
 fn build_footer_segments(ctx: &FooterContext<'_>) -> Vec<FooterSegment> {
     use crate::ui::interactive::state_manager::ViewMode;
 
-    let mut segments = vec![FooterSegment::plain("q=Lopeta")];
+    let mut segments = vec![FooterSegment::plain(t("footer.quit"))];
 
     match ctx.view_mode {
         Some(ViewMode::Standings { live_mode }) => {
             segments.push(FooterSegment::block(
-                "s=Ottelut",
+                t("footer.standings_games"),
                 FASTEXT_RED,
                 BLOCK_TEXT_LIGHT,
             ));
             segments.push(FooterSegment::block(
-                if *live_mode { "l=Live ✓" } else { "l=Live" },
+                if *live_mode {
+                    t("footer.live_on")
+                } else {
+                    t("footer.live")
+                },
                 FASTEXT_GREEN,
                 BLOCK_TEXT_DARK,
             ));
         }
         Some(ViewMode::Bracket) => {
             segments.push(FooterSegment::block(
-                "p=Ottelut",
+                t("footer.bracket_games"),
                 FASTEXT_RED,
                 BLOCK_TEXT_LIGHT,
             ));
             segments.push(FooterSegment::block(
-                "s=Taulukko",
+                t("footer.standings"),
                 FASTEXT_YELLOW,
                 BLOCK_TEXT_DARK,
             ));
         }
+        Some(ViewMode::GameDetail { .. } | ViewMode::GameLineup { .. }) => {
+            segments.push(FooterSegment::block(
+                t("footer.detail_games"),
+                FASTEXT_RED,
+                BLOCK_TEXT_LIGHT,
+            ));
+            segments.push(FooterSegment::block(
+                t("footer.detail_game"),
+                FASTEXT_GREEN,
+                BLOCK_TEXT_DARK,
+            ));
+            segments.push(FooterSegment::block(
+                if matches!(ctx.view_mode, Some(ViewMode::GameLineup { .. })) {
+                    t("footer.events")
+                } else {
+                    t("footer.lineups")
+                },
+                FASTEXT_YELLOW,
+                BLOCK_TEXT_DARK,
+            ));
+            segments.push(FooterSegment::plain(t("footer.videos")));
+        }
+        Some(ViewMode::GoalVideos { .. }) => {
+            segments.push(FooterSegment::block(
+                t("footer.videos_back"),
+                FASTEXT_RED,
+                BLOCK_TEXT_LIGHT,
+            ));
+            segments.push(FooterSegment::block(
+                t("footer.videos_select"),
+                FASTEXT_GREEN,
+                BLOCK_TEXT_DARK,
+            ));
+            segments.push(FooterSegment::block(
+                t("footer.videos_open"),
+                FASTEXT_YELLOW,
+                BLOCK_TEXT_DARK,
+            ));
+        }
+        Some(ViewMode::OffSeason) => {
+            segments.push(FooterSegment::block(
+                t("footer.detail_games"),
+                FASTEXT_RED,
+                BLOCK_TEXT_LIGHT,
+            ));
+            segments.push(FooterSegment::block(
+                t("footer.standings"),
+                FASTEXT_YELLOW,
+                BLOCK_TEXT_DARK,
+            ));
+        }
+        Some(ViewMode::Diagnostics) => {
+            segments.push(FooterSegment::block(
+                t("footer.detail_games"),
+                FASTEXT_RED,
+                BLOCK_TEXT_LIGHT,
+            ));
+            segments.push(FooterSegment::plain(t("footer.refresh")));
+        }
         Some(ViewMode::Games) | None => {
             segments.push(FooterSegment::block(
-                "⇧←Edellinen",
+                t("footer.previous"),
                 FASTEXT_RED,
                 BLOCK_TEXT_LIGHT,
             ));
             segments.push(FooterSegment::block(
-                "⇧→Seuraava",
+                t("footer.next"),
                 FASTEXT_GREEN,
                 BLOCK_TEXT_DARK,
             ));
             segments.push(FooterSegment::block(
-                "s=Taulukko",
+                t("footer.standings"),
                 FASTEXT_YELLOW,
                 BLOCK_TEXT_DARK,
             ));
             if ctx.has_bracket_data {
                 segments.push(FooterSegment::block(
-                    "p=Pudotuspelit",
+                    t("footer.playoffs"),
                     FASTEXT_BLUE,
                     BLOCK_TEXT_LIGHT,
                 ));
             }
             if ctx.show_today_shortcut {
-                segments.push(FooterSegment::plain("t=Tänään"));
+                segments.push(FooterSegment::plain(t("footer.today")));
+            }
+            segments.push(FooterSegment::plain(t("footer.game_page")));
+            // The countdown means the season hasn't started: point to page 224
+            if ctx.season_countdown.is_some() {
+                segments.push(FooterSegment::plain(t("footer.off_season")));
             }
         }
     }
 
     if ctx.auto_refresh_disabled {
-        segments.push(FooterSegment::plain("(Ei päivity)"));
+        segments.push(FooterSegment::plain(t("footer.not_updating")));
     }
 
     segments
@@ -138,8 +209,8 @@ pub fn render_footer_with_view(
     buffer: &mut String,
     ctx: &FooterContext<'_>,
 ) -> Result<(), AppError> {
-    // Add season countdown above the footer if available
-    if let Some(countdown) = ctx.season_countdown {
+    // Add a notice or the season countdown above the footer if available
+    if let Some(countdown) = ctx.notice.as_ref().or(ctx.season_countdown.as_ref()) {
         let countdown_y = ctx.footer_y.saturating_sub(1);
 
         // Use optimized ANSI code generation for countdown (requirement 4.3)
@@ -169,7 +240,7 @@ pub fn render_footer_with_view(
         // and the status hint as long as possible); fall back to the last block
         let drop_idx = segments
             .iter()
-            .position(|s| s.block.is_none() && s.text != "q=Lopeta")
+            .position(|s| s.block.is_none() && s.text != t("footer.quit"))
             .unwrap_or(segments.len() - 1);
         segments.remove(drop_idx);
     }
@@ -348,6 +419,7 @@ mod tests {
             auto_refresh_disabled: false,
             error_warning_active: false,
             season_countdown: &None,
+            notice: &None,
             view_mode: Some(&crate::ui::interactive::state_manager::ViewMode::Games),
             show_today_shortcut: false,
             has_bracket_data: true,
@@ -357,6 +429,27 @@ mod tests {
         assert!(buffer.contains("s=Taulukko"));
     }
 
+    #[test]
+    fn test_footer_notice_replaces_season_countdown() {
+        let mut buffer = String::new();
+        let mut stdout = std::io::stdout();
+        let ctx = FooterContext {
+            footer_y: 23,
+            width: 80,
+            auto_refresh_indicator: &None,
+            auto_refresh_disabled: false,
+            error_warning_active: false,
+            season_countdown: &Some("Runkosarjan alkuun 12 päivää".to_string()),
+            notice: &Some("LEVEÄ VAATII 128 MERKKIÄ, NYT 80 - NORMAALI TILA".to_string()),
+            view_mode: Some(&crate::ui::interactive::state_manager::ViewMode::Games),
+            show_today_shortcut: false,
+            has_bracket_data: false,
+        };
+        render_footer_with_view(&mut stdout, &mut buffer, &ctx).unwrap();
+        assert!(buffer.contains("LEVEÄ VAATII 128 MERKKIÄ, NYT 80 - NORMAALI TILA"));
+        assert!(!buffer.contains("Runkosarjan alkuun"));
+    }
+
     #[test]
     fn test_footer_games_view_without_bracket_data() {
         let mut buffer = String::new();
@@ -368,6 +461,7 @@ mod tests {
             auto_refresh_disabled: false,
             error_warning_active: false,
             season_countdown: &None,
+            notice: &None,
             view_mode: Some(&crate::ui::interactive::state_manager::ViewMode::Games),
             show_today_shortcut: false,
             has_bracket_data: false,
@@ -388,6 +482,7 @@ mod tests {
             auto_refresh_disabled: false,
             error_warning_active: false,
             season_countdown: &None,
+            notice: &None,
             view_mode: Some(&crate::ui::interactive::state_manager::ViewMode::Bracket),
             show_today_shortcut: false,
             has_bracket_data: true,
@@ -411,6 +506,7 @@ mod tests {
             auto_refresh_disabled: false,
             error_warning_active: false,
             season_countdown: &None,
+            notice: &None,
             view_mode: Some(&crate::ui::interactive::state_manager::ViewMode::Games),
             show_today_shortcut: false,
             has_bracket_data: true,
@@ -437,6 +533,7 @@ mod tests {
             auto_refresh_disabled: true,
             error_warning_active: false,
             season_countdown: &None,
+            notice: &None,
             view_mode: Some(&crate::ui::interactive::state_manager::ViewMode::Games),
             show_today_shortcut: true,
             has_bracket_data: true,
@@ -458,6 +555,7 @@ mod tests {
             auto_refresh_disabled: false,
             error_warning_active: false,
             season_countdown: &None,
+            notice: &None,
             view_mode: None,
             show_today_shortcut: false,
             has_bracket_data: false,
@@ -1,6 +1,7 @@
 // src/teletext_ui/formatting.rs - Formatting utilities for TeletextPage display and layout
 
 use super::core::{CompactDisplayConfig, TeletextPage, TeletextRow};
+use crate::i18n::t;
 use crate::teletext_ui::ScoreType;
 
 impl TeletextPage {
@@ -53,8 +54,8 @@ impl TeletextPage {
                     // Standings row: ~80 chars + ANSI sequences
                     size += 150;
                 }
-                TeletextRow::BracketLine(line) => {
-                    // Bracket line: pre-formatted ANSI content
+                TeletextRow::BracketLine(line) | TeletextRow::DetailLine(line) => {
+                    // Bracket/timeline line: pre-formatted ANSI content
                     size += line.len() + 30;
                 }
                 TeletextRow::BracketPageBreak => {}
@@ -210,9 +211,11 @@ impl TeletextPage {
                         // Ongoing games show score in white (like regular mode)
                         let mut score = result.clone();
                         if *is_shootout {
-                            score.push_str(" rl");
+                            score.push(' ');
+                            score.push_str(t("result.shootout"));
                         } else if *is_overtime {
-                            score.push_str(" ja");
+                            score.push(' ');
+                            score.push_str(t("result.overtime"));
                         }
                         format!(
                             "\x1b[38;5;{text_fg_code}m{:<width$}\x1b[0m",
@@ -224,9 +227,11 @@ impl TeletextPage {
                         // Final games show score in bright green (like regular mode)
                         let mut score = result.clone();
                         if *is_shootout {
-                            score.push_str(" rl");
+                            score.push(' ');
+                            score.push_str(t("result.shootout"));
                         } else if *is_overtime {
-                            score.push_str(" ja");
+                            score.push(' ');
+                            score.push_str(t("result.overtime"));
                         }
                         format!(
                             "\x1b[38;5;{result_fg_code}m{:<width$}\x1b[0m",
@@ -248,9 +253,10 @@ impl TeletextPage {
                 let subheader_fg_code = get_ansi_code(subheader_fg(), 46);
 
                 // Format future games header for compact mode - intelligently abbreviate to preserve date
-                let abbreviated_header = if header_text.starts_with("Seuraavat ottelut ") {
+                let next_games = format!("{} ", t("games.next_games"));
+                let abbreviated_header = if let Some(date) = header_text.strip_prefix(&next_games) {
                     // Special handling for "Seuraavat ottelut DD.MM." - abbreviate "Seuraavat" to preserve date
-                    header_text.replace("Seuraavat ottelut ", "Seur. ottelut ")
+                    format!("{} {date}", t("games.next_games_short"))
                 } else if header_text.len() > 30 {
                     // For other long headers, truncate at 30 characters (increased from 22)
                     format!("{}...", &header_text[..30])
@@ -312,7 +318,7 @@ pub fn format_playing_time(played_time: i32) -> String {
 
         if period > 3 {
             let ot_minutes = minutes - 60;
-            format!("JA {ot_minutes}:{seconds:02}")
+            format!("{} {ot_minutes}:{seconds:02}", t("clock.overtime"))
         } else {
             format!("{period}. {period_minutes}:{seconds:02}")
         }
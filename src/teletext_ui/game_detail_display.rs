@@ -0,0 +1,586 @@
+use crate::data_fetcher::models::goals::format_goal_types;
+use crate::data_fetcher::models::{GameData, GoalEventData};
+use crate::data_fetcher::processors::lineup::{GameLineups, SidelineReason, TeamLineup};
+use crate::data_fetcher::processors::timeline::{GameTimeline, TimelineEntry, TimelineEventKind};
+use crate::i18n::{t, tf};
+use crate::teletext_ui::ScoreType;
+use crate::teletext_ui::core::TeletextRow;
+use crate::teletext_ui::detail_style::{CYAN, GREEN, MAGENTA, RESET, WHITE, YELLOW, color, fit};
+use crate::ui::components::abbreviations::get_team_abbreviation;
+
+/// Visible width of one timeline row: the classic 40-column teletext grid.
+/// Wide mode places two of these side by side.
+pub const DETAIL_COLUMN_WIDTH: usize = 40;
+
+/// Marks rows inside a power-play window
+const POWER_PLAY_GUTTER: char = '┃';
+
+/// Width of the shared row prefix "MM:SS ┃ TAP " (time, gutter, team)
+const PREFIX_WIDTH: usize = 12;
+
+/// Formats game time in seconds as "MM:SS" (minutes keep counting across periods).
+fn format_event_time(game_time: i32) -> String {
+    let seconds = game_time.max(0);
+    format!("{:02}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// Heading for a period: "1. ERÄ" .. "3. ERÄ", then overtime or shootout.
+fn period_heading(period: i32, game: &GameData) -> String {
+    match period {
+        1..=3 => tf("detail.period", &[("period", &period)]),
+        // Regular season overtime is a single period; the shootout follows it
+        5.. if game.is_shootout && game.series_score.is_none() => t("detail.shootout").to_string(),
+        _ => t("detail.overtime").to_string(),
+    }
+}
+
+/// Builds the shared "MM:SS ┃ TAP " prefix for a timeline row.
+fn row_prefix(entry: &TimelineEntry, game: &GameData) -> String {
+    let team = if entry.is_home_team {
+        &game.home_team
+    } else {
+        &game.away_team
+    };
+    let gutter = if entry.in_power_play {
+        format!("{}{POWER_PLAY_GUTTER}", color(YELLOW))
+    } else {
+        " ".to_string()
+    };
+    format!(
+        "{}{} {gutter} {}{:<3} ",
+        color(WHITE),
+        format_event_time(entry.game_time),
+        color(CYAN),
+        fit(&get_team_abbreviation(team), 3),
+    )
+}
+
+/// Formats one timeline entry as a colored row of at most 40 visible columns.
+fn format_entry(entry: &TimelineEntry, game: &GameData) -> String {
+    let prefix = row_prefix(entry, game);
+    let available = DETAIL_COLUMN_WIDTH - PREFIX_WIDTH;
+
+    match &entry.kind {
+        TimelineEventKind::Goal {
+            scorer,
+            home_score,
+            away_score,
+            goal_types,
+            is_winning_goal,
+            lead,
+        } => {
+            let score = format!("{home_score}-{away_score}");
+            let lead = match lead {
+                0 => "±0".to_string(),
+                l => format!("{l:+}"),
+            };
+            let mut tail = format_goal_types(goal_types);
+            if *is_winning_goal {
+                if !tail.is_empty() {
+                    tail.push(' ');
+                }
+                tail.push_str(t("detail.winning_goal"));
+            }
+            // "3-2   +1 " before the name, " YV VM" after it
+            let fixed = 6 + 3 + usize::from(!tail.is_empty()) + tail.chars().count();
+            let name = fit(scorer, available.saturating_sub(fixed));
+            let name_color = if *is_winning_goal { MAGENTA } else { WHITE };
+            let mut row = format!(
+                "{prefix}{}{score:<5} {lead:>2} {}{name}",
+                color(GREEN),
+                color(name_color),
+            );
+            if !tail.is_empty() {
+                row.push_str(&format!(" {}{tail}", color(YELLOW)));
+            }
+            row.push_str(RESET);
+            row
+        }
+        TimelineEventKind::Penalty {
+            player,
+            minutes,
+            reason,
+        } => {
+            let minutes = format!("{minutes:>2} min");
+            let name = fit(player, 12);
+            let reason_width =
+                available.saturating_sub(minutes.len() + 1 + name.chars().count() + 1);
+            format!(
+                "{prefix}{}{minutes} {}{name} {}{RESET}",
+                color(YELLOW),
+                color(WHITE),
+                fit(reason, reason_width),
+            )
+        }
+        TimelineEventKind::PowerPlayStart { ends_at, skaters } => format!(
+            "{prefix}{}{} {}-{} → {}{RESET}",
+            color(YELLOW),
+            t("detail.power_play"),
+            skaters.0,
+            skaters.1,
+            format_event_time(*ends_at),
+        ),
+    }
+}
+
+/// Renders a game's event timeline as page rows.
+///
+/// The first row summarizes the game; each period starts with a heading row
+/// followed by its goals, penalties and power plays. Power-play windows are
+/// marked with a yellow gutter, the scoring team's running lead is shown after
+/// every score and the game-winning goal is tagged "VM". Every line fits the
+/// 40-column teletext grid.
+pub fn render_game_timeline(game: &GameData, timeline: &GameTimeline) -> Vec<TeletextRow> {
+    let mut rows = vec![TeletextRow::DetailLine(summary_line(game))];
+
+    if timeline.entries.is_empty() {
+        let message = match game.score_type {
+            ScoreType::Scheduled => tf("detail.starts_at", &[("time", &game.time)]),
+            _ => t("detail.no_events").to_string(),
+        };
+        rows.push(TeletextRow::DetailLine(format!(
+            "{}{message}{RESET}",
+            color(WHITE)
+        )));
+        return rows;
+    }
+
+    let mut current_period = None;
+    for entry in &timeline.entries {
+        if current_period != Some(entry.period) {
+            current_period = Some(entry.period);
+            rows.push(TeletextRow::SeriesHeader(period_heading(
+                entry.period,
+                game,
+            )));
+        }
+        rows.push(TeletextRow::DetailLine(format_entry(entry, game)));
+    }
+
+    rows
+}
+
+/// Width of the label column on lineup rows ("1.  ", "Poissa: ")
+const LINEUP_LABEL_WIDTH: usize = 8;
+
+/// Renders both teams' lineups grouped by line, each followed by the
+/// team's injured and suspended players.
+pub fn render_game_lineups(game: &GameData, lineups: &GameLineups) -> Vec<TeletextRow> {
+    let mut rows = vec![TeletextRow::DetailLine(summary_line(game))];
+    for (team, lineup) in [
+        (&game.home_team, &lineups.home),
+        (&game.away_team, &lineups.away),
+    ] {
+        rows.push(TeletextRow::SeriesHeader(team.to_uppercase()));
+        render_team_lineup(lineup, &mut rows);
+    }
+    rows
+}
+
+fn render_team_lineup(lineup: &TeamLineup, rows: &mut Vec<TeletextRow>) {
+    if lineup.lines.is_empty() {
+        rows.push(TeletextRow::DetailLine(format!(
+            "{}{}{RESET}",
+            color(WHITE),
+            t("lineup.unknown")
+        )));
+    }
+    for group in &lineup.lines {
+        push_labeled_list(rows, &format!("{}.", group.line), YELLOW, &group.players);
+    }
+    if !lineup.sidelined.is_empty() {
+        let players: Vec<String> = lineup
+            .sidelined
+            .iter()
+            .map(|p| {
+                let reason = match p.reason {
+                    SidelineReason::Injured => t("lineup.injured"),
+                    SidelineReason::Suspended => t("lineup.suspended"),
+                };
+                format!("{} ({reason})", p.name)
+            })
+            .collect();
+        push_labeled_list(rows, t("lineup.sidelined"), MAGENTA, &players);
+    }
+}
+
+/// Pushes a comma-separated list wrapped to the column width, with `label`
+/// on the first row and the continuation rows indented under the list.
+fn push_labeled_list(rows: &mut Vec<TeletextRow>, label: &str, label_color: u8, items: &[String]) {
+    let width = DETAIL_COLUMN_WIDTH - LINEUP_LABEL_WIDTH;
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for (i, item) in items.iter().enumerate() {
+        let mut entry = fit(item, width - 1);
+        if i + 1 < items.len() {
+            entry.push(',');
+        }
+        if !current.is_empty() && current.chars().count() + 1 + entry.chars().count() > width {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(&entry);
+    }
+    lines.push(current);
+    for (i, line) in lines.into_iter().enumerate() {
+        let label = if i == 0 { label } else { "" };
+        rows.push(TeletextRow::DetailLine(format!(
+            "{}{label:<LINEUP_LABEL_WIDTH$}{}{line}{RESET}",
+            color(label_color),
+            color(WHITE)
+        )));
+    }
+}
+
+/// Goals of `game` that have a video clip, in the order they were scored.
+pub fn goal_clips(game: &GameData) -> Vec<&GoalEventData> {
+    let mut clips: Vec<&GoalEventData> = game
+        .goal_events
+        .iter()
+        .filter(|e| {
+            e.video_clip_url
+                .as_deref()
+                .is_some_and(|url| !url.is_empty())
+        })
+        .collect();
+    // Events are stored home team first; the running score orders them
+    clips.sort_by_key(|e| e.home_team_score + e.away_team_score);
+    clips
+}
+
+/// Renders the goal video picker: one row per clip with the `selected` one
+/// marked, followed by the selected clip's URL split into grid-wide rows so
+/// it can be read and copied without hyperlink support.
+pub fn render_goal_videos(game: &GameData, selected: usize) -> Vec<TeletextRow> {
+    let mut rows = vec![TeletextRow::DetailLine(summary_line(game))];
+    let clips = goal_clips(game);
+    if clips.is_empty() {
+        rows.push(TeletextRow::DetailLine(format!(
+            "{}{}{RESET}",
+            color(WHITE),
+            t("videos.none")
+        )));
+        return rows;
+    }
+
+    for (i, clip) in clips.iter().enumerate() {
+        let team = if clip.is_home_team {
+            &game.home_team
+        } else {
+            &game.away_team
+        };
+        let (marker, marker_color) = if i == selected {
+            ('▶', YELLOW)
+        } else {
+            (' ', WHITE)
+        };
+        let score = format!("{}-{}", clip.home_team_score, clip.away_team_score);
+        // Marker, minute and team take 11 columns, the score the right edge
+        let scorer_width = DETAIL_COLUMN_WIDTH.saturating_sub(12 + score.chars().count());
+        rows.push(TeletextRow::DetailLine(format!(
+            "{}{marker} {}{:>2}' {}{:<3.3}  {}{:<scorer_width$} {}{score}{RESET}",
+            color(marker_color),
+            color(CYAN),
+            clip.minute,
+            color(WHITE),
+            get_team_abbreviation(team),
+            color(marker_color),
+            fit(&clip.scorer_name, scorer_width),
+            color(GREEN),
+        )));
+    }
+
+    if let Some(url) = clips
+        .get(selected)
+        .and_then(|clip| clip.video_clip_url.as_deref())
+    {
+        rows.push(TeletextRow::SeriesHeader(t("videos.url").to_string()));
+        let chars: Vec<char> = url.chars().collect();
+        for chunk in chars.chunks(DETAIL_COLUMN_WIDTH) {
+            rows.push(TeletextRow::DetailLine(format!(
+                "{}{}{RESET}",
+                color(CYAN),
+                chunk.iter().collect::<String>()
+            )));
+        }
+    }
+    rows
+}
+
+/// "Tappara - HIFK  3-2 ja" style summary of teams and result.
+fn summary_line(game: &GameData) -> String {
+    let result = match game.score_type {
+        ScoreType::Scheduled => game.time.clone(),
+        _ => {
+            let suffix = if game.is_shootout {
+                t("detail.shootout_win")
+            } else if game.is_overtime {
+                t("result.overtime")
+            } else {
+                ""
+            };
+            if suffix.is_empty() {
+                game.result.clone()
+            } else {
+                format!("{} {suffix}", game.result)
+            }
+        }
+    };
+    let teams_width = DETAIL_COLUMN_WIDTH.saturating_sub(result.chars().count() + 2);
+    let teams = fit(
+        &format!("{} - {}", game.home_team, game.away_team),
+        teams_width,
+    );
+    format!(
+        "{}{teams:<teams_width$}  {}{result}{RESET}",
+        color(WHITE),
+        color(GREEN)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_fetcher::processors::timeline::PowerPlayWindow;
+    use crate::teletext_ui::detail_style::visible;
+
+    fn game() -> GameData {
+        GameData {
+            home_team: "Tappara".to_string(),
+            away_team: "HIFK".to_string(),
+            time: "18:30".to_string(),
+            result: "3-2".to_string(),
+            score_type: ScoreType::Final,
+            is_overtime: false,
+            is_shootout: false,
+            serie: "RUNKOSARJA".to_string(),
+            goal_events: vec![],
+            played_time: 3600,
+            start: "2025-10-18T15:30:00Z".to_string(),
+            play_off_phase: None,
+            play_off_pair: None,
+            play_off_req_wins: None,
+            series_score: None,
+            game_id: 1,
+            season: 2026,
+            is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
+        }
+    }
+
+    fn goal_entry(time: i32, in_power_play: bool, is_winning_goal: bool) -> TimelineEntry {
+        TimelineEntry {
+            game_time: time,
+            period: time / 1200 + 1,
+            is_home_team: true,
+            in_power_play,
+            kind: TimelineEventKind::Goal {
+                scorer: "Meriläinen-Korhonen".to_string(),
+                home_score: 2,
+                away_score: 1,
+                goal_types: vec!["YV".to_string(), "TM".to_string()],
+                is_winning_goal,
+                lead: 1,
+            },
+        }
+    }
+
+    fn lines(rows: &[TeletextRow]) -> Vec<String> {
+        rows.iter()
+            .map(|row| match row {
+                TeletextRow::DetailLine(line) => visible(line),
+                TeletextRow::SeriesHeader(text) => text.clone(),
+                other => panic!("unexpected row {other:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rows_fit_teletext_grid() {
+        let timeline = GameTimeline {
+            entries: vec![
+                goal_entry(754, true, true),
+                TimelineEntry {
+                    game_time: 1800,
+                    period: 2,
+                    is_home_team: false,
+                    in_power_play: false,
+                    kind: TimelineEventKind::Penalty {
+                        player: "Virtanen".to_string(),
+                        minutes: 2,
+                        reason: "Estäminen mailalla hyvin pitkä syy".to_string(),
+                    },
+                },
+            ],
+            power_plays: vec![],
+        };
+        for line in lines(&render_game_timeline(&game(), &timeline)) {
+            assert!(
+                line.chars().count() <= DETAIL_COLUMN_WIDTH,
+                "line too wide: {line:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_goal_row_shows_lead_types_and_winner_flag() {
+        let timeline = GameTimeline {
+            entries: vec![goal_entry(754, true, true)],
+            power_plays: vec![],
+        };
+        let rendered = lines(&render_game_timeline(&game(), &timeline));
+        assert_eq!(rendered[1], "1. ERÄ");
+        let row = &rendered[2];
+        assert!(row.starts_with("12:34 ┃ TAP 2-1   +1 "), "{row:?}");
+        assert!(row.ends_with("YV TM VM"), "{row:?}");
+    }
+
+    #[test]
+    fn test_power_play_start_row() {
+        let window = PowerPlayWindow {
+            is_home_team: false,
+            start: 600,
+            end: 720,
+            skaters: (5, 4),
+        };
+        let timeline = GameTimeline {
+            entries: vec![TimelineEntry {
+                game_time: 600,
+                period: 1,
+                is_home_team: false,
+                in_power_play: true,
+                kind: TimelineEventKind::PowerPlayStart {
+                    ends_at: window.end,
+                    skaters: window.skaters,
+                },
+            }],
+            power_plays: vec![window],
+        };
+        let rendered = lines(&render_game_timeline(&game(), &timeline));
+        assert_eq!(rendered[2], "10:00 ┃ IFK YV 5-4 → 12:00");
+    }
+
+    #[test]
+    fn test_overtime_and_shootout_headings() {
+        let mut g = game();
+        assert_eq!(period_heading(4, &g), "JATKOAIKA");
+        g.is_shootout = true;
+        assert_eq!(period_heading(5, &g), "VOITTOLAUKAUKSET");
+        g.series_score = Some(crate::data_fetcher::models::PlayoffSeriesScore {
+            home_team_wins: 1,
+            away_team_wins: 1,
+            req_wins: 4,
+        });
+        assert_eq!(period_heading(5, &g), "JATKOAIKA");
+    }
+
+    #[test]
+    fn test_scheduled_game_without_events() {
+        let mut g = game();
+        g.score_type = ScoreType::Scheduled;
+        let rendered = lines(&render_game_timeline(&g, &GameTimeline::default()));
+        assert_eq!(rendered.len(), 2);
+        assert!(rendered[0].ends_with("18:30"));
+        assert_eq!(rendered[1], "Ottelu alkaa klo 18:30");
+    }
+
+    #[test]
+    fn test_lineup_rows_wrap_within_grid() {
+        use crate::data_fetcher::processors::lineup::{LineGroup, SidelinedPlayer};
+        let long_line = LineGroup {
+            line: 1,
+            players: [
+                "Meriläinen-Korhonen",
+                "Virtanen",
+                "Lahtinen",
+                "Koivu",
+                "Selänne",
+            ]
+            .iter()
+            .map(|n| n.to_string())
+            .collect(),
+        };
+        let lineups = GameLineups {
+            home: TeamLineup {
+                lines: vec![long_line],
+                sidelined: vec![SidelinedPlayer {
+                    name: "Granlund".to_string(),
+                    reason: SidelineReason::Suspended,
+                }],
+            },
+            away: TeamLineup::default(),
+        };
+        let rendered = lines(&render_game_lineups(&game(), &lineups));
+        for line in &rendered {
+            assert!(
+                line.chars().count() <= DETAIL_COLUMN_WIDTH,
+                "line too wide: {line:?}"
+            );
+        }
+        assert_eq!(rendered[1], "TAPPARA");
+        assert!(rendered[2].starts_with("1.      Meriläinen-Korhonen, Virtanen,"));
+        assert!(rendered[3].starts_with("        "));
+        assert!(rendered.contains(&"Poissa: Granlund (pelikielto)".to_string()));
+        assert!(rendered.contains(&"HIFK".to_string()));
+        assert_eq!(rendered.last().unwrap(), "Kokoonpano ei ole vielä tiedossa");
+    }
+
+    #[test]
+    fn test_goal_videos_list_clips_in_scoring_order() {
+        let clip = |scorer: &str, minute, score: (i32, i32), is_home_team, url: Option<&str>| {
+            GoalEventData {
+                scorer_player_id: 0,
+                scorer_name: scorer.to_string(),
+                minute,
+                home_team_score: score.0,
+                away_team_score: score.1,
+                is_winning_goal: false,
+                goal_types: vec![],
+                is_home_team,
+                video_clip_url: url.map(str::to_string),
+            }
+        };
+        let url = format!("https://video.example.com/{}.mp4", "a".repeat(40));
+        let mut game = game();
+        game.goal_events = vec![
+            clip(
+                "Aho",
+                12,
+                (1, 0),
+                true,
+                Some("https://video.example.com/1.mp4"),
+            ),
+            clip("Kivi", 55, (2, 1), true, Some(&url)),
+            clip("Lehto", 30, (1, 1), false, None),
+            clip("Ranta", 58, (2, 2), false, Some("")),
+        ];
+
+        let clips = goal_clips(&game);
+        assert_eq!(
+            clips.iter().map(|c| c.minute).collect::<Vec<_>>(),
+            vec![12, 55]
+        );
+
+        let rendered = lines(&render_goal_videos(&game, 1));
+        for line in &rendered {
+            assert!(
+                line.chars().count() <= DETAIL_COLUMN_WIDTH,
+                "line too wide: {line:?}"
+            );
+        }
+        assert!(rendered[1].starts_with("  12' TAP  Aho"));
+        assert!(rendered[2].starts_with("▶ 55' TAP  Kivi"));
+        assert!(rendered[2].ends_with(" 2-1"));
+        assert_eq!(rendered[3], "VIDEON OSOITE");
+        assert_eq!(rendered[4..].concat(), url);
+
+        game.goal_events.clear();
+        assert_eq!(
+            lines(&render_goal_videos(&game, 0))[1],
+            "Ottelusta ei ole maalivideoita"
+        );
+    }
+}
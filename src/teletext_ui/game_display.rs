@@ -3,10 +3,81 @@
 use super::core::get_ansi_code;
 use super::core::{TeletextPage, TeletextRow};
 use super::layout::{ColumnLayoutManager, IntelligentTruncator, LayoutConfig};
-use crate::data_fetcher::models::GameData;
+use super::live_clock::format_game_clock;
+use crate::data_fetcher::models::{GameAnnotation, GameData, PreGameInfo, TeamPreGame};
+use crate::i18n::{t, tf};
 use crate::teletext_ui::{CONTENT_MARGIN, ScoreType};
 use crate::ui::teletext::colors::*;
 
+/// Formats one team's standing and form before a game ("3. 45p VVHVH").
+pub fn format_team_pre_game(team: &TeamPreGame) -> String {
+    let mut parts = Vec::new();
+    if let Some(position) = team.position {
+        parts.push(format!("{position}."));
+    }
+    if let Some(points) = team.points {
+        parts.push(tf("pregame.points", &[("points", &points)]));
+    }
+    if !team.form.is_empty() {
+        let (win, loss) = (t("pregame.form_win"), t("pregame.form_loss"));
+        parts.push(
+            team.form
+                .iter()
+                .map(|&won| if won { win } else { loss })
+                .collect(),
+        );
+    }
+    parts.join(" ")
+}
+
+/// Formats the season head-to-head ("Kesk. 2–1"), or `None` before the
+/// teams have met.
+pub fn format_head_to_head((home_wins, away_wins): (u8, u8)) -> Option<String> {
+    (home_wins + away_wins > 0).then(|| {
+        tf(
+            "pregame.head_to_head",
+            &[("home", &home_wins), ("away", &away_wins)],
+        )
+    })
+}
+
+/// Formats all pre-game info on one line for the narrow wide mode columns
+/// ("3. 45p VVHVH - 7. 38p HHVVV  Kesk. 2–1").
+pub fn format_pre_game_line(info: &PreGameInfo) -> String {
+    let mut line = format!(
+        "{} - {}",
+        format_team_pre_game(&info.home),
+        format_team_pre_game(&info.away)
+    );
+    if let Some(head_to_head) = format_head_to_head(info.head_to_head) {
+        line.push_str("  ");
+        line.push_str(&head_to_head);
+    }
+    line
+}
+
+/// Formats an annotation line drawn under a finished game.
+pub fn format_annotation(annotation: &GameAnnotation) -> String {
+    match annotation {
+        GameAnnotation::HatTrick { scorer, goals } => tf(
+            "annotation.hat_trick",
+            &[("player", scorer), ("goals", goals)],
+        ),
+        GameAnnotation::Comeback { team, deficit } => tf(
+            "annotation.comeback",
+            &[("team", team), ("deficit", deficit)],
+        ),
+        GameAnnotation::Shutout { team } => tf("annotation.shutout", &[("team", team)]),
+        GameAnnotation::FirstSeasonGoal { scorer } => {
+            tf("annotation.first_season_goal", &[("player", scorer)])
+        }
+        GameAnnotation::EmptyNetGoal { scorer, minute } => tf(
+            "annotation.empty_net",
+            &[("player", scorer), ("minute", minute)],
+        ),
+    }
+}
+
 pub(crate) fn format_team_series_indicator(wins: u8, req_wins: u8) -> String {
     let filled = "●".repeat(wins as usize);
     let empty = "○".repeat(req_wins.saturating_sub(wins) as usize);
@@ -48,7 +119,11 @@ impl TeletextPage {
                         play_off_pair: None,
                         play_off_req_wins: None,
                         series_score: None,
+                        game_id: 0,
+                        season: 0,
                         is_placeholder: false,
+                        pre_game: None,
+                        annotations: vec![],
                     })
                 } else {
                     None
@@ -114,6 +189,8 @@ impl TeletextPage {
                     goal_events,
                     played_time,
                     series_score,
+                    pre_game,
+                    annotations,
                 } => {
                     self.render_game_result_row(
                         buffer,
@@ -127,6 +204,8 @@ impl TeletextPage {
                         goal_events,
                         *played_time,
                         series_score.as_ref(),
+                        pre_game.as_ref(),
+                        annotations,
                         current_line,
                         text_fg_code,
                         result_fg_code,
@@ -192,6 +271,13 @@ impl TeletextPage {
                     buffer.push_str(&line_code);
                     *current_line += 1;
                 }
+                TeletextRow::DetailLine(line) => {
+                    // Aligned with the period headers rendered between rows
+                    let line_code =
+                        format!("\x1b[{};{}H{}", *current_line, CONTENT_MARGIN + 1, line);
+                    buffer.push_str(&line_code);
+                    *current_line += 1;
+                }
                 TeletextRow::BracketPageBreak => {}
             }
         }
@@ -210,6 +296,9 @@ impl TeletextPage {
     /// * `is_shootout` - Whether game went to shootout
     /// * `goal_events` - List of goal events to display
     /// * `played_time` - Current game time in seconds (for ongoing games)
+    /// * `series_score` - Playoff series score, if any
+    /// * `pre_game` - Standings, form and head-to-head, shown under scheduled games
+    /// * `annotations` - Hat tricks, comebacks and other notes, shown under finished games
     /// * `current_line` - Current line position
     /// * `text_fg_code` - Text color code
     /// * `result_fg_code` - Result color code
@@ -228,6 +317,8 @@ impl TeletextPage {
         goal_events: &[crate::data_fetcher::GoalEventData],
         played_time: i32,
         series_score: Option<&crate::data_fetcher::models::PlayoffSeriesScore>,
+        pre_game: Option<&PreGameInfo>,
+        annotations: &[GameAnnotation],
         current_line: &mut usize,
         text_fg_code: u8,
         result_fg_code: u8,
@@ -235,9 +326,9 @@ impl TeletextPage {
     ) {
         // Format result with overtime/shootout indicator
         let result_text = if is_shootout {
-            format!("{result} rl")
+            format!("{result} {}", t("result.shootout"))
         } else if is_overtime {
-            format!("{result} ja")
+            format!("{result} {}", t("result.overtime"))
         } else {
             result.to_string()
         };
@@ -246,8 +337,8 @@ impl TeletextPage {
         let (time_display, score_display) = match score_type {
             ScoreType::Scheduled => (time.to_string(), String::new()),
             ScoreType::Ongoing => {
-                let formatted_time = format!("{:02}:{:02}", played_time / 60, played_time % 60);
-                (formatted_time, result_text.clone())
+                let shown_time = self.live_played_time(played_time, series_score.is_some());
+                (format_game_clock(shown_time), result_text.clone())
             }
             ScoreType::Final => (String::new(), result_text.clone()),
         };
@@ -298,6 +389,9 @@ impl TeletextPage {
                 text_fg_code,
                 &time_display,
             ));
+            if matches!(score_type, ScoreType::Ongoing) && !self.ignore_height_limit {
+                self.record_live_clock(played_time, series_score.is_some());
+            }
             game_line.push_str(&layout_manager.format_time_score(
                 *current_line,
                 score_pos,
@@ -355,6 +449,35 @@ impl TeletextPage {
 
         *current_line += 1;
 
+        // Standings and form under each team, head-to-head under the start time
+        if let Some(info) = pre_game
+            && matches!(score_type, ScoreType::Scheduled)
+        {
+            let home_pos = CONTENT_MARGIN + 1;
+            let away_pos = home_pos + layout_config.home_team_width + layout_config.separator_width;
+            buffer.push_str(&layout_manager.format_time_score(
+                *current_line,
+                home_pos,
+                text_fg_code,
+                &format_team_pre_game(&info.home),
+            ));
+            buffer.push_str(&layout_manager.format_time_score(
+                *current_line,
+                away_pos,
+                text_fg_code,
+                &format_team_pre_game(&info.away),
+            ));
+            if let Some(head_to_head) = format_head_to_head(info.head_to_head) {
+                buffer.push_str(&layout_manager.format_time_score(
+                    *current_line,
+                    layout_config.time_column,
+                    text_fg_code,
+                    &head_to_head,
+                ));
+            }
+            *current_line += 1;
+        }
+
         // Add goal events for finished/ongoing games
         if matches!(score_type, ScoreType::Ongoing | ScoreType::Final) && !goal_events.is_empty() {
             self.render_goal_events(
@@ -367,6 +490,20 @@ impl TeletextPage {
             );
         }
 
+        // One line per annotation under finished games
+        if matches!(score_type, ScoreType::Final) {
+            let annotation_fg_code = get_ansi_code(goal_type_fg(), 226);
+            for annotation in annotations {
+                buffer.push_str(&layout_manager.format_time_score(
+                    *current_line,
+                    CONTENT_MARGIN + 1,
+                    annotation_fg_code,
+                    &format_annotation(annotation),
+                ));
+                *current_line += 1;
+            }
+        }
+
         // Add spacing between games in interactive mode
         if !self.ignore_height_limit {
             *current_line += 1;
@@ -938,4 +1075,68 @@ mod tests {
             "goal type column misaligned: {single:?} vs {double:?}"
         );
     }
+
+    #[test]
+    fn test_format_annotation() {
+        assert_eq!(
+            format_annotation(&GameAnnotation::HatTrick {
+                scorer: "Aho".to_string(),
+                goals: 3
+            }),
+            "Hattutemppu: Aho (3 maalia)"
+        );
+        assert_eq!(
+            format_annotation(&GameAnnotation::Comeback {
+                team: "TPS".to_string(),
+                deficit: 2
+            }),
+            "Nousu: TPS 2 maalin takaa"
+        );
+        assert_eq!(
+            format_annotation(&GameAnnotation::EmptyNetGoal {
+                scorer: "Ranta".to_string(),
+                minute: 59
+            }),
+            "Tyhjään maaliin: Ranta 59."
+        );
+    }
+
+    #[test]
+    fn test_format_team_pre_game() {
+        let team = TeamPreGame {
+            position: Some(3),
+            points: Some(45),
+            form: vec![true, true, false, true, false],
+        };
+        assert_eq!(format_team_pre_game(&team), "3. 45p VVHVH");
+        assert_eq!(format_team_pre_game(&TeamPreGame::default()), "");
+        let form_only = TeamPreGame {
+            form: vec![false],
+            ..TeamPreGame::default()
+        };
+        assert_eq!(format_team_pre_game(&form_only), "H");
+    }
+
+    #[test]
+    fn test_format_pre_game_line_omits_unplayed_head_to_head() {
+        let mut info = PreGameInfo {
+            home: TeamPreGame {
+                position: Some(1),
+                points: Some(50),
+                form: vec![true],
+            },
+            away: TeamPreGame {
+                position: Some(12),
+                points: Some(21),
+                form: vec![false],
+            },
+            head_to_head: (0, 0),
+        };
+        assert_eq!(format_pre_game_line(&info), "1. 50p V - 12. 21p H");
+        info.head_to_head = (2, 1);
+        assert_eq!(
+            format_pre_game_line(&info),
+            "1. 50p V - 12. 21p H  Kesk. 2–1"
+        );
+    }
 }
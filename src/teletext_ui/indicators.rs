@@ -1,6 +1,7 @@
 // src/teletext_ui/indicators.rs - Loading indicators, error warnings, and state management utilities
 
 use super::core::TeletextPage;
+use crate::i18n::t;
 use crate::ui::teletext::loading_indicator::LoadingIndicator;
 
 impl TeletextPage {
@@ -24,7 +25,8 @@ impl TeletextPage {
 
     /// Shows a subtle auto-refresh indicator in the footer
     pub fn show_auto_refresh_indicator(&mut self) {
-        self.auto_refresh_indicator = Some(LoadingIndicator::new("Päivitetään...".to_string()));
+        self.auto_refresh_indicator =
+            Some(LoadingIndicator::new(t("loading.refreshing").to_string()));
     }
 
     /// Hides the auto-refresh indicator
@@ -59,6 +61,17 @@ impl TeletextPage {
         self.error_warning_active
     }
 
+    /// Sets the notice shown above the footer; `None` removes it.
+    pub fn set_notice(&mut self, notice: Option<String>) {
+        self.notice = notice;
+    }
+
+    /// Returns the notice shown above the footer, if any.
+    #[allow(dead_code)] // Used in tests
+    pub fn notice(&self) -> Option<&str> {
+        self.notice.as_deref()
+    }
+
     /// Sets whether auto-refresh should be disabled for this page.
     /// Useful for pages showing only future/scheduled games that don't need frequent updates.
     pub fn set_auto_refresh_disabled(&mut self, disabled: bool) {
@@ -76,14 +89,6 @@ impl TeletextPage {
         self.playoffs_lines = lines.to_vec();
     }
 
-    /// Requests that the next render skip the screen clear.
-    /// Used to avoid flicker when hiding the auto-refresh spinner with unchanged data.
-    /// The flag is automatically consumed (reset to false) by the next call to
-    /// `render_buffered` in interactive mode.
-    pub fn skip_next_screen_clear(&mut self) {
-        self.skip_screen_clear.set(true);
-    }
-
     /// Returns whether this page is a standings page.
     pub fn is_standings_page(&self) -> bool {
         self.is_standings_page
@@ -113,6 +118,48 @@ impl TeletextPage {
         self.is_bracket_page = is_bracket;
     }
 
+    /// Marks this page as the detail page of the game at `index` in the games list.
+    pub fn set_game_detail_index(&mut self, index: usize) {
+        self.game_detail_index = Some(index);
+    }
+
+    /// Returns true if this page shows a single game's timeline or lineups.
+    pub fn is_game_detail_page(&self) -> bool {
+        self.game_detail_index.is_some()
+    }
+
+    /// Marks this page as the lineup page of the game at `index` in the games list.
+    pub fn set_game_lineup_index(&mut self, index: usize) {
+        self.game_detail_index = Some(index);
+        self.is_game_lineup_page = true;
+    }
+
+    /// Marks this page as the goal video picker of the game at `index`, with
+    /// the `selected` clip highlighted.
+    pub fn set_goal_videos(&mut self, index: usize, selected: usize) {
+        self.goal_videos = Some((index, selected));
+    }
+
+    /// Marks this page as the off-season page.
+    pub fn set_off_season_page(&mut self) {
+        self.is_off_season_page = true;
+    }
+
+    /// Returns true if this is the off-season page.
+    pub fn is_off_season_page(&self) -> bool {
+        self.is_off_season_page
+    }
+
+    /// Marks this page as the hidden diagnostics page.
+    pub fn set_diagnostics_page(&mut self) {
+        self.is_diagnostics_page = true;
+    }
+
+    /// Returns true if this is the hidden diagnostics page.
+    pub fn is_diagnostics_page(&self) -> bool {
+        self.is_diagnostics_page
+    }
+
     /// Sets whether bracket data is available.
     pub fn set_has_bracket_data(&mut self, has_data: bool) {
         self.has_bracket_data = has_data;
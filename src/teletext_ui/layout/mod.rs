@@ -4,6 +4,7 @@ mod ansi_cache;
 mod columns;
 mod config;
 
+pub use ansi_cache::AnsiCodeCache;
 #[allow(unused_imports)] // Used by integration tests
 pub use columns::AlignmentCalculator;
 pub use config::{IntelligentTruncator, LayoutConfig};
@@ -11,7 +12,6 @@ pub use config::{IntelligentTruncator, LayoutConfig};
 use crate::data_fetcher::models::GameData;
 use std::collections::HashMap;
 
-use ansi_cache::AnsiCodeCache;
 use columns::generate_content_signature;
 use config::{
     CacheStats, ContentAnalysis, ContentCacheKey, GameDataValidator, LayoutCacheKey,
@@ -1346,7 +1346,11 @@ mod tests {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         }
     }
 
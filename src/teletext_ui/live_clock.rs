@@ -0,0 +1,236 @@
+// src/teletext_ui/live_clock.rs - Client-side ticking game clock for ongoing games
+//
+// The API reports `played_time` only as it was at the last poll, so without
+// interpolation the clock of a live game freezes between refreshes. The page
+// remembers when its data was last synced with the server and advances each
+// ongoing game's clock by the wall-clock time elapsed since then, stopping at
+// the end of the current period (intermission) until the server moves on.
+// Ticks go through the diffing renderer, so only the changed digits are sent.
+
+use super::core::TeletextPage;
+use crate::error::AppError;
+use std::io::Stdout;
+use std::time::{Duration, Instant};
+
+/// Length of a regulation period (and of a playoff overtime period) in seconds
+pub const PERIOD_SECONDS: i32 = 1200;
+
+/// Game time at the end of regulation (three periods) in seconds
+pub const REGULATION_SECONDS: i32 = 3 * PERIOD_SECONDS;
+
+/// Length of regular season 3-on-3 overtime in seconds. A shootout follows,
+/// during which the game clock does not run.
+pub const REGULAR_SEASON_OVERTIME_SECONDS: i32 = 300;
+
+/// Clock of one ongoing game, recorded while rendering so later ticks can tell
+/// whether the displayed value has changed.
+#[derive(Debug, Clone)]
+pub(super) struct LiveClockCell {
+    /// Server-reported played time the clock is interpolated from
+    pub played_time: i32,
+    /// Playoff games play full-length overtime periods
+    pub is_playoff: bool,
+    /// Clock text currently on screen
+    pub drawn: String,
+}
+
+/// Returns the game time at which the period containing `played_time` ends,
+/// or `None` when the clock is not running: before the opening faceoff, at a
+/// period boundary (intermission) or once regular season overtime is over.
+///
+/// # Arguments
+/// * `played_time` - Server-reported game time in seconds
+/// * `is_playoff` - Whether overtime is played in full 20 minute periods
+pub fn running_period_end(played_time: i32, is_playoff: bool) -> Option<i32> {
+    if played_time <= 0 || played_time % PERIOD_SECONDS == 0 {
+        return None;
+    }
+
+    if played_time >= REGULATION_SECONDS && !is_playoff {
+        let overtime_end = REGULATION_SECONDS + REGULAR_SEASON_OVERTIME_SECONDS;
+        return (played_time < overtime_end).then_some(overtime_end);
+    }
+
+    Some((played_time / PERIOD_SECONDS + 1) * PERIOD_SECONDS)
+}
+
+/// Advances a server-reported game clock by the wall-clock time elapsed since
+/// it was fetched, without running past the end of the current period.
+///
+/// # Example
+/// ```
+/// use liiga_teletext::teletext_ui::live_clock::interpolate_played_time;
+/// use std::time::Duration;
+///
+/// // 12:30 into the game, 10 seconds later
+/// assert_eq!(interpolate_played_time(750, Duration::from_secs(10), false), 760);
+/// // The clock stops at the first intermission
+/// assert_eq!(interpolate_played_time(1195, Duration::from_secs(30), false), 1200);
+/// ```
+pub fn interpolate_played_time(played_time: i32, elapsed: Duration, is_playoff: bool) -> i32 {
+    match running_period_end(played_time, is_playoff) {
+        Some(period_end) => {
+            let elapsed_secs = i32::try_from(elapsed.as_secs()).unwrap_or(i32::MAX);
+            played_time.saturating_add(elapsed_secs).min(period_end)
+        }
+        None => played_time,
+    }
+}
+
+/// Formats a game clock the way the results list shows it ("MM:SS").
+pub fn format_game_clock(played_time: i32) -> String {
+    format!("{:02}:{:02}", played_time / 60, played_time % 60)
+}
+
+impl TeletextPage {
+    /// Returns the game time to display for an ongoing game, interpolated from
+    /// the server value by the time elapsed since the page was last synced.
+    pub(super) fn live_played_time(&self, played_time: i32, is_playoff: bool) -> i32 {
+        interpolate_played_time(played_time, self.live_clock_synced_at.elapsed(), is_playoff)
+    }
+
+    /// Records an ongoing game's clock drawn during rendering.
+    pub(super) fn record_live_clock(&self, played_time: i32, is_playoff: bool) {
+        let drawn = format_game_clock(self.live_played_time(played_time, is_playoff));
+        self.live_clock_cells.borrow_mut().push(LiveClockCell {
+            played_time,
+            is_playoff,
+            drawn,
+        });
+    }
+
+    /// Snaps all clocks back to the server-reported values.
+    /// Call after each successful refresh: the rows hold the latest server
+    /// times, so interpolation restarts from them.
+    pub fn sync_live_clocks(&mut self) {
+        self.live_clock_synced_at = Instant::now();
+    }
+
+    /// Returns whether the last render drew any ticking game clocks.
+    pub fn has_live_clocks(&self) -> bool {
+        !self.live_clock_cells.borrow().is_empty()
+    }
+
+    /// Redraws the page if any clock has ticked to a new value since the last
+    /// render. The diffing renderer then only writes the changed digits.
+    ///
+    /// # Returns
+    /// * `Ok(true)` - At least one clock was redrawn
+    /// * `Ok(false)` - Nothing changed on screen
+    pub fn render_live_clock_updates(&self, stdout: &mut Stdout) -> Result<bool, AppError> {
+        let ticked = self.live_clock_cells.borrow().iter().any(|cell| {
+            format_game_clock(self.live_played_time(cell.played_time, cell.is_playoff))
+                != cell.drawn
+        });
+
+        if ticked {
+            self.render_buffered(stdout)?;
+        }
+        Ok(ticked)
+    }
+
+    /// Test hook: pretends the page was synced `elapsed` ago.
+    #[cfg(test)]
+    pub(crate) fn backdate_live_clock_sync(&mut self, elapsed: Duration) {
+        self.live_clock_synced_at = Instant::now()
+            .checked_sub(elapsed)
+            .unwrap_or_else(Instant::now);
+    }
+
+    /// Test hook: time since the clocks were last synced.
+    #[cfg(test)]
+    pub(crate) fn live_clock_sync_age(&self) -> Duration {
+        self.live_clock_synced_at.elapsed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clock_advances_within_period() {
+        assert_eq!(
+            interpolate_played_time(600, Duration::from_secs(15), false),
+            615
+        );
+        assert_eq!(
+            interpolate_played_time(2500, Duration::from_secs(15), false),
+            2515
+        );
+    }
+
+    #[test]
+    fn test_clock_stops_at_period_end() {
+        assert_eq!(
+            interpolate_played_time(1190, Duration::from_secs(15), false),
+            1200
+        );
+        assert_eq!(
+            interpolate_played_time(3590, Duration::from_secs(60), true),
+            3600
+        );
+    }
+
+    #[test]
+    fn test_clock_frozen_during_intermission_and_before_faceoff() {
+        for boundary in [0, 1200, 2400, 3600] {
+            assert_eq!(
+                interpolate_played_time(boundary, Duration::from_secs(30), false),
+                boundary,
+                "clock must not run at {boundary}s"
+            );
+        }
+    }
+
+    #[test]
+    fn test_regular_season_overtime_is_five_minutes() {
+        assert_eq!(running_period_end(3650, false), Some(3900));
+        assert_eq!(
+            interpolate_played_time(3890, Duration::from_secs(30), false),
+            3900
+        );
+        // Shootout: the clock stays at the end of overtime
+        assert_eq!(running_period_end(3900, false), None);
+    }
+
+    #[test]
+    fn test_playoff_overtime_runs_full_periods() {
+        assert_eq!(running_period_end(3650, true), Some(4800));
+        assert_eq!(
+            interpolate_played_time(4000, Duration::from_secs(30), true),
+            4030
+        );
+    }
+
+    #[test]
+    fn test_format_game_clock() {
+        assert_eq!(format_game_clock(0), "00:00");
+        assert_eq!(format_game_clock(754), "12:34");
+        assert_eq!(format_game_clock(3900), "65:00");
+    }
+
+    #[test]
+    fn test_recorded_cells_tick_only_while_clock_runs() {
+        let mut page = TeletextPage::new(
+            221,
+            "JÄÄKIEKKO".to_string(),
+            "SM-LIIGA".to_string(),
+            false,
+            true,
+            true,
+            false,
+            false,
+        );
+        page.record_live_clock(600, false);
+        page.record_live_clock(1200, false);
+        assert!(page.has_live_clocks());
+
+        page.backdate_live_clock_sync(Duration::from_secs(5));
+        let cells = page.live_clock_cells.borrow();
+        let running = format_game_clock(page.live_played_time(cells[0].played_time, false));
+        let paused = format_game_clock(page.live_played_time(cells[1].played_time, false));
+        assert_eq!(running, "10:05");
+        assert_eq!(paused, cells[1].drawn, "intermission clock must not change");
+    }
+}
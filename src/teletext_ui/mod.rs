@@ -4,15 +4,24 @@ pub mod bracket_display;
 pub mod compact_mode_rendering;
 pub mod content;
 pub mod core;
+mod detail_style;
+pub mod diagnostics_display;
+pub mod export;
 pub mod footer;
 pub mod formatting;
+pub mod game_detail_display;
 pub mod game_display;
 pub mod indicators;
 pub mod layout;
+pub mod live_clock;
+pub mod off_season_display;
 pub mod pagination;
 pub mod rendering;
+pub mod screen_diff;
 pub mod season_utils;
 pub mod standings_display;
+pub mod teletext_grid;
+pub mod tti;
 pub mod validation;
 pub mod wide_mode;
 
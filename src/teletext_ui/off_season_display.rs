@@ -0,0 +1,258 @@
+//! Off-season page (224): countdowns to the preseason and regular season,
+//! the opening fixtures, upcoming preseason games by day and the champion
+//! and final table of the season that ended.
+
+use crate::data_fetcher::models::ScheduleApiGame;
+use crate::data_fetcher::processors::format_time;
+use crate::data_fetcher::processors::off_season::{OffSeasonInfo, local_date};
+use crate::i18n::{t, tf};
+use crate::teletext_ui::core::TeletextRow;
+use crate::teletext_ui::detail_style::{CYAN, GREEN, RESET, WHITE, YELLOW, color, fit};
+
+/// Visible width of an off-season row (the 40-column teletext grid)
+const COLUMN_WIDTH: usize = 40;
+
+/// Width of the start time or result column of a fixture row
+const TIME_WIDTH: usize = 5;
+/// Width of the team name column of the final table
+const TEAM_WIDTH: usize = 20;
+
+/// "2025–2026" for the season ending in `season`.
+fn season_range(season: i32) -> String {
+    format!("{}–{season}", season - 1)
+}
+
+/// "16.8." style date of an API start time.
+fn day_label(start: &str) -> String {
+    local_date(start)
+        .map(|date| date.format("%-d.%-m.").to_string())
+        .unwrap_or_default()
+}
+
+fn text_line(text: &str, code: u8) -> TeletextRow {
+    TeletextRow::DetailLine(format!("{}{text}{RESET}", color(code)))
+}
+
+/// "18.30  Tappara - HIFK" for upcoming games, the result for ended ones.
+fn fixture_line(game: &ScheduleApiGame) -> TeletextRow {
+    let (time, time_color) = if game.ended {
+        (
+            format!("{}-{}", game.home_team_goals, game.away_team_goals),
+            GREEN,
+        )
+    } else {
+        (format_time(&game.start).unwrap_or_default(), CYAN)
+    };
+    let teams = fit(
+        &format!("{} - {}", game.home_team_name, game.away_team_name),
+        COLUMN_WIDTH - TIME_WIDTH - 2,
+    );
+    TeletextRow::DetailLine(format!(
+        "{}{time:<TIME_WIDTH$}  {}{teams}{RESET}",
+        color(time_color),
+        color(WHITE)
+    ))
+}
+
+/// Days until the preseason and the regular season, or that they are underway.
+fn countdown_lines(info: &OffSeasonInfo) -> Vec<TeletextRow> {
+    let mut rows = Vec::new();
+    match info.days_until_preseason {
+        Some(0) => rows.push(text_line(t("offseason.preseason_underway"), WHITE)),
+        Some(days) => rows.push(text_line(
+            &tf("offseason.preseason_countdown", &[("days", &days)]),
+            YELLOW,
+        )),
+        None => {}
+    }
+    match info.days_until_regular_season {
+        Some(0) => rows.push(text_line(t("offseason.season_underway"), WHITE)),
+        Some(days) => rows.push(text_line(
+            &tf("games.season_countdown", &[("days", &days)]),
+            YELLOW,
+        )),
+        None => rows.push(text_line(t("offseason.start_unknown"), WHITE)),
+    }
+    rows
+}
+
+/// Renders the off-season page as rows that fit the 40-column grid.
+pub fn render_off_season(info: &OffSeasonInfo) -> Vec<TeletextRow> {
+    let mut rows = vec![TeletextRow::SeriesHeader(tf(
+        "offseason.season",
+        &[("season", &season_range(info.finished_season + 1))],
+    ))];
+    rows.extend(countdown_lines(info));
+
+    if let Some(first) = info.opening_fixtures.first() {
+        rows.push(TeletextRow::SeriesHeader(tf(
+            "offseason.opening",
+            &[("date", &day_label(&first.start))],
+        )));
+        rows.extend(info.opening_fixtures.iter().map(fixture_line));
+    }
+
+    // One group per preseason game day
+    let mut current_day = None;
+    for game in &info.preseason_games {
+        let day = day_label(&game.start);
+        if current_day.as_ref() != Some(&day) {
+            rows.push(TeletextRow::SeriesHeader(tf(
+                "offseason.preseason",
+                &[("date", &day)],
+            )));
+            current_day = Some(day);
+        }
+        rows.push(fixture_line(game));
+    }
+
+    rows.push(TeletextRow::SeriesHeader(tf(
+        "offseason.season",
+        &[("season", &season_range(info.finished_season))],
+    )));
+    if let Some(champion) = &info.champion {
+        rows.push(text_line(
+            &tf("offseason.champion", &[("team", champion)]),
+            YELLOW,
+        ));
+    }
+    if info.final_standings.is_empty() {
+        rows.push(text_line(t("offseason.no_standings"), WHITE));
+    }
+    for (i, entry) in info.final_standings.iter().enumerate() {
+        rows.push(TeletextRow::DetailLine(format!(
+            "{}{:>2}. {}{:<TEAM_WIDTH$} {}{:>3} {:>4}{RESET}",
+            color(CYAN),
+            i + 1,
+            color(WHITE),
+            fit(&entry.team_name, TEAM_WIDTH),
+            color(GREEN),
+            entry.games_played,
+            tf("pregame.points", &[("points", &entry.points)]),
+        )));
+    }
+
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_fetcher::models::standings::StandingsEntry;
+    use crate::teletext_ui::detail_style::visible;
+
+    fn lines(rows: &[TeletextRow]) -> Vec<String> {
+        rows.iter()
+            .map(|row| match row {
+                TeletextRow::DetailLine(line) => visible(line),
+                TeletextRow::SeriesHeader(text) => text.clone(),
+                other => panic!("unexpected row {other:?}"),
+            })
+            .collect()
+    }
+
+    fn game(start: &str, home: &str, away: &str) -> ScheduleApiGame {
+        ScheduleApiGame {
+            id: 0,
+            season: 2027,
+            start: start.to_string(),
+            home_team_name: home.to_string(),
+            away_team_name: away.to_string(),
+            serie: 5,
+            finished_type: None,
+            started: false,
+            ended: false,
+            game_time: None,
+            play_off_phase: None,
+            play_off_pair: None,
+            play_off_req_wins: None,
+            home_team_goals: 0,
+            away_team_goals: 0,
+        }
+    }
+
+    fn standing(team: &str, games_played: u16, points: u16) -> StandingsEntry {
+        StandingsEntry {
+            team_name: team.to_string(),
+            team_id: String::new(),
+            games_played,
+            wins: 0,
+            ot_wins: 0,
+            ot_losses: 0,
+            losses: 0,
+            goals_for: 0,
+            goals_against: 0,
+            points,
+            live_goals_for: 0,
+            live_goals_against: 0,
+            live_points_delta: None,
+            live_position_change: None,
+            live_game_active: false,
+        }
+    }
+
+    #[test]
+    fn test_off_season_page_sections() {
+        let mut ended = game("2026-08-07T12:00:00Z", "Ässät", "TPS");
+        ended.ended = true;
+        ended.home_team_goals = 3;
+        ended.away_team_goals = 2;
+        let info = OffSeasonInfo {
+            finished_season: 2026,
+            days_until_preseason: Some(0),
+            days_until_regular_season: Some(41),
+            opening_fixtures: vec![game("2026-09-11T12:00:00Z", "Ilves", "Lukko")],
+            preseason_games: vec![
+                ended,
+                game("2026-08-07T12:00:00Z", "HIFK", "Jukurit"),
+                game("2026-08-14T12:00:00Z", "Tappara", "KalPa"),
+            ],
+            champion: Some("Tappara".to_string()),
+            final_standings: vec![standing("Tappara", 60, 112), standing("KalPa", 60, 104)],
+        };
+
+        // Start times are shown in local time
+        let noon = format_time("2026-08-07T12:00:00Z").unwrap();
+        let rendered = lines(&render_off_season(&info));
+        for line in &rendered {
+            assert!(
+                line.chars().count() <= COLUMN_WIDTH,
+                "line too wide: {line:?}"
+            );
+        }
+        let expected = [
+            "KAUSI 2026–2027",
+            "Harjoitusottelut ovat käynnissä",
+            "Runkosarjan alkuun 41 päivää",
+            "AVAUSKIERROS 11.9.",
+            &format!("{noon}  Ilves - Lukko"),
+            "HARJOITUSOTTELUT 7.8.",
+            "3-2    Ässät - TPS",
+            &format!("{noon}  HIFK - Jukurit"),
+            "HARJOITUSOTTELUT 14.8.",
+            &format!("{noon}  Tappara - KalPa"),
+            "KAUSI 2025–2026",
+            "Suomen mestari: Tappara",
+            " 1. Tappara               60 112p",
+            " 2. KalPa                 60 104p",
+        ];
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_unknown_start_and_missing_standings() {
+        let rendered = lines(&render_off_season(&OffSeasonInfo {
+            finished_season: 2026,
+            ..Default::default()
+        }));
+        assert_eq!(
+            rendered,
+            vec![
+                "KAUSI 2026–2027",
+                "Runkosarjan alkamispäivä ei ole vielä tiedossa",
+                "KAUSI 2025–2026",
+                "Sarjataulukko ei ole saatavilla",
+            ]
+        );
+    }
+}
@@ -13,13 +13,35 @@ impl TeletextPage {
     /// * `u16` - Height in terminal lines required for this row
     pub(super) fn calculate_game_height(&self, game: &TeletextRow) -> u16 {
         match game {
-            TeletextRow::GameResult { goal_events, .. } => {
+            TeletextRow::GameResult {
+                goal_events,
+                score_type,
+                pre_game,
+                annotations,
+                ..
+            } => {
                 let base_height = 1; // Game result line
                 let home_scorers = goal_events.iter().filter(|e| e.is_home_team).count();
                 let away_scorers = goal_events.iter().filter(|e| !e.is_home_team).count();
                 let scorer_lines = home_scorers.max(away_scorers);
+                // Pre-game line is drawn under scheduled games in normal and wide mode
+                let pre_game_line = u16::from(
+                    pre_game.is_some()
+                        && matches!(score_type, super::ScoreType::Scheduled)
+                        && !self.compact_mode
+                        && !self.teletext_mode,
+                );
+                // Annotation lines are drawn under finished games in normal and wide mode
+                let annotation_lines = if matches!(score_type, super::ScoreType::Final)
+                    && !self.compact_mode
+                    && !self.teletext_mode
+                {
+                    annotations.len() as u16
+                } else {
+                    0
+                };
                 let spacer = 1; // Space between games
-                base_height + scorer_lines as u16 + spacer
+                base_height + scorer_lines as u16 + pre_game_line + annotation_lines + spacer
             }
             TeletextRow::ErrorMessage(_) => 2u16, // Error message + spacer
             // Every header renders as a single line. Keeping one attached to
@@ -52,7 +74,7 @@ impl TeletextPage {
                     base
                 }
             }
-            TeletextRow::BracketLine(_) => 1u16,
+            TeletextRow::BracketLine(_) | TeletextRow::DetailLine(_) => 1u16,
             TeletextRow::BracketPageBreak => 0u16,
         }
     }
@@ -67,7 +89,11 @@ impl TeletextPage {
     /// * `u16` - Effective height in terminal lines considering layout mode
     pub(super) fn calculate_effective_game_height(&self, game: &TeletextRow) -> u16 {
         let base_height = self.calculate_game_height(game);
-        if self.wide_mode && self.can_fit_two_pages() {
+        if self.is_game_detail_page() {
+            // Timeline rows are packed line by line; wide mode doubles the
+            // page height instead (see paginate)
+            base_height
+        } else if self.wide_mode && self.can_fit_two_pages() {
             // In wide mode, we can fit two games in the same vertical space
             // Add spacing between games (1 extra line per game except the last)
             let height_with_spacing = base_height + 1; // Add space between games
@@ -82,10 +108,10 @@ impl TeletextPage {
     /// Spacing is used when the terminal is tall enough to fit all standings content
     /// with extra blank lines, making the table easier to read.
     pub(super) fn standings_use_spacing(&self) -> bool {
-        if !self.is_standings_page {
+        if !self.is_standings_page || self.teletext_mode {
             return false;
         }
-        let available_height = self.screen_height.saturating_sub(5);
+        let available_height = self.content_height();
         let standings_rows = self
             .content_rows
             .iter()
@@ -99,6 +125,16 @@ impl TeletextPage {
         total_with_spacing <= available_height
     }
 
+    /// Rows available for content on one page: the terminal minus the header
+    /// and footer, or the fixed content area of the teletext grid.
+    fn content_height(&self) -> u16 {
+        if self.teletext_mode {
+            super::teletext_grid::GRID_CONTENT_ROWS
+        } else {
+            self.screen_height.saturating_sub(5)
+        }
+    }
+
     /// Returns true for rows that only make sense directly above the content
     /// they introduce, and so must never end a page on their own.
     fn is_section_header(row: &TeletextRow) -> bool {
@@ -142,7 +178,11 @@ impl TeletextPage {
     /// if the two chunked separately they could disagree and navigation would
     /// run off the end of the real content.
     fn paginate(&self) -> Vec<Vec<&TeletextRow>> {
-        let available_height = self.screen_height.saturating_sub(5);
+        let mut available_height = self.content_height();
+        if self.is_game_detail_page() && self.wide_mode && self.can_fit_two_pages() {
+            // A wide timeline page continues from the left column into the right
+            available_height *= 2;
+        }
 
         let mut pages: Vec<Vec<&TeletextRow>> = Vec::new();
         let mut current_page_items: Vec<&TeletextRow> = Vec::new();
@@ -341,7 +381,11 @@ mod tests {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         }
     }
 
@@ -378,6 +422,46 @@ mod tests {
             .count()
     }
 
+    #[test]
+    fn test_pre_game_line_counts_toward_game_height() {
+        let mut page = page_with_height(15);
+        page.add_game_result(GameResultData::new(&scheduled_game(0)));
+        let mut with_pre_game = scheduled_game(1);
+        with_pre_game.pre_game = Some(crate::data_fetcher::models::PreGameInfo::default());
+        page.add_game_result(GameResultData::new(&with_pre_game));
+
+        let heights: Vec<u16> = page
+            .content_rows
+            .iter()
+            .map(|row| page.calculate_game_height(row))
+            .collect();
+        assert_eq!(heights, vec![2, 3]);
+
+        page.set_compact_mode(true).unwrap();
+        assert_eq!(page.calculate_game_height(&page.content_rows[1]), 2);
+    }
+
+    #[test]
+    fn test_annotations_count_toward_game_height() {
+        use crate::data_fetcher::models::GameAnnotation;
+
+        let mut page = page_with_height(15);
+        let mut finished = scheduled_game(0);
+        finished.score_type = ScoreType::Final;
+        finished.annotations = vec![
+            GameAnnotation::Shutout {
+                team: "Home 0".to_string(),
+            },
+            GameAnnotation::HatTrick {
+                scorer: "Aho".to_string(),
+                goals: 3,
+            },
+        ];
+        page.add_game_result(GameResultData::new(&finished));
+
+        assert_eq!(page.calculate_game_height(&page.content_rows[0]), 4);
+    }
+
     #[test]
     fn test_series_header_is_not_stranded_at_page_bottom() {
         // available = 10 lines. Four scheduled games fill 8, leaving exactly
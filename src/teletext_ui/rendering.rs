@@ -2,6 +2,8 @@
 
 use super::core::get_ansi_code;
 use super::core::{TeletextPage, TeletextRow};
+use super::live_clock::format_game_clock;
+use crate::i18n::t;
 use crate::teletext_ui::{CONTENT_MARGIN, ScoreType};
 use crate::ui::teletext::colors::*;
 
@@ -77,6 +79,17 @@ impl TeletextPage {
         // Distribute visible rows between left and right columns using the shared distribution logic
         let (left_games, right_games) = self.distribute_games_for_wide_display();
 
+        if self.is_game_detail_page() {
+            self.render_wide_detail_columns(
+                buffer,
+                &left_games,
+                &right_games,
+                current_line,
+                subheader_fg_code,
+            );
+            return;
+        }
+
         // Calculate column widths for wide mode using the layout system
         let left_column_start = 2;
         let gap_between_columns = 8; // Good separation between columns
@@ -100,6 +113,7 @@ impl TeletextPage {
         for (game_index, game) in left_games.iter().enumerate() {
             let formatted_game =
                 self.format_game_for_wide_column(game, column_width, &wide_layout_config);
+            self.record_wide_live_clock(game);
 
             let mut line_count = 0;
             for (line_index, line) in formatted_game.lines().enumerate() {
@@ -124,6 +138,7 @@ impl TeletextPage {
         for (game_index, game) in right_games.iter().enumerate() {
             let formatted_game =
                 self.format_game_for_wide_column(game, column_width, &wide_layout_config);
+            self.record_wide_live_clock(game);
 
             let mut line_count = 0;
             for (line_index, line) in formatted_game.lines().enumerate() {
@@ -177,6 +192,57 @@ impl TeletextPage {
         );
     }
 
+    /// Renders a game timeline as two 40-column teletext grids side by side.
+    /// Rows are packed line by line without the blank spacer used for games.
+    fn render_wide_detail_columns(
+        &self,
+        buffer: &mut String,
+        left_rows: &[&TeletextRow],
+        right_rows: &[&TeletextRow],
+        current_line: &mut usize,
+        subheader_fg_code: u8,
+    ) {
+        use super::game_detail_display::DETAIL_COLUMN_WIDTH;
+
+        let left_column_start = 2;
+        let right_column_start = left_column_start + DETAIL_COLUMN_WIDTH + 8;
+        let mut layout_manager = super::layout::ColumnLayoutManager::new(80, CONTENT_MARGIN);
+
+        let start_line = *current_line;
+        for (rows, column_start) in [
+            (left_rows, left_column_start),
+            (right_rows, right_column_start),
+        ] {
+            let mut line = start_line;
+            for row in rows {
+                let text = match row {
+                    TeletextRow::DetailLine(text) => text.clone(),
+                    TeletextRow::SeriesHeader(header) => {
+                        format!("\x1b[38;5;{subheader_fg_code}m{header}\x1b[0m")
+                    }
+                    _ => continue,
+                };
+                buffer.push_str(layout_manager.get_position_code(line, column_start));
+                buffer.push_str(&text);
+                line += 1;
+            }
+            *current_line = (*current_line).max(line);
+        }
+    }
+
+    /// Records the clock of an ongoing game drawn in a wide mode column.
+    fn record_wide_live_clock(&self, game: &TeletextRow) {
+        if let TeletextRow::GameResult {
+            score_type: ScoreType::Ongoing,
+            played_time,
+            series_score,
+            ..
+        } = game
+        {
+            self.record_live_clock(*played_time, series_score.is_some());
+        }
+    }
+
     /// Formats a game for display in a wide column with specified width constraints.
     /// Optimized for performance with pre-allocated buffers and reasonable goal limits.
     ///
@@ -205,6 +271,9 @@ impl TeletextPage {
                 goal_events,
                 played_time,
                 series_score,
+                pre_game,
+                annotations,
+                ..
             } => {
                 let text_fg_code = get_ansi_code(text_fg(), 231);
                 let result_fg_code = get_ansi_code(result_fg(), 46);
@@ -222,9 +291,9 @@ impl TeletextPage {
                 let team_score_line = {
                     // Format result with overtime/shootout indicator
                     let result_text = if *is_shootout {
-                        format!("{result} rl")
+                        format!("{result} {}", t("result.shootout"))
                     } else if *is_overtime {
-                        format!("{result} ja")
+                        format!("{result} {}", t("result.overtime"))
                     } else {
                         result.clone()
                     };
@@ -233,9 +302,9 @@ impl TeletextPage {
                     let (time_display, score_display) = match score_type {
                         ScoreType::Scheduled => (time.clone(), String::new()),
                         ScoreType::Ongoing => {
-                            let formatted_time =
-                                format!("{:02}:{:02}", played_time / 60, played_time % 60);
-                            (formatted_time, result_text)
+                            let shown_time =
+                                self.live_played_time(*played_time, series_score.is_some());
+                            (format_game_clock(shown_time), result_text)
                         }
                         ScoreType::Final => (String::new(), result_text),
                     };
@@ -306,6 +375,17 @@ impl TeletextPage {
                     lines.push(team_score_line);
                 }
 
+                // All pre-game info on one line to fit the narrow column
+                if let Some(info) = pre_game
+                    && matches!(score_type, ScoreType::Scheduled)
+                {
+                    use super::game_display::format_pre_game_line;
+                    lines.push(format!(
+                        "\x1b[38;5;{text_fg_code}m{}\x1b[0m",
+                        format_pre_game_line(info)
+                    ));
+                }
+
                 // Goal events - position scorers under their respective teams like normal mode
                 // Limit goal scorers for performance (max 15 per team to prevent excessive rendering)
                 if !goal_events.is_empty() {
@@ -461,6 +541,16 @@ impl TeletextPage {
                     }
                 }
 
+                if matches!(score_type, ScoreType::Final) {
+                    use super::game_display::format_annotation;
+                    lines.extend(annotations.iter().map(|annotation| {
+                        format!(
+                            "\x1b[38;5;{goal_type_fg_code}m{}\x1b[0m",
+                            format_annotation(annotation)
+                        )
+                    }));
+                }
+
                 lines.join("\n")
             }
             TeletextRow::ErrorMessage(message) => {
@@ -477,7 +567,7 @@ impl TeletextPage {
                 // Standings rows are rendered in normal mode only (not wide column mode)
                 String::new()
             }
-            TeletextRow::BracketLine(line) => line.clone(),
+            TeletextRow::BracketLine(line) | TeletextRow::DetailLine(line) => line.clone(),
             TeletextRow::BracketPageBreak => String::new(),
         }
     }
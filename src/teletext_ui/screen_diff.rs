@@ -0,0 +1,498 @@
+// src/teletext_ui/screen_diff.rs - Diffing renderer that only repaints changed screen cells
+//
+// `render_buffered` still composes a whole frame as an ANSI string, but in
+// interactive mode that string is no longer written to the terminal as-is.
+// It is replayed into a cell grid, compared with the frame currently on
+// screen, and only the cells that differ are emitted. A live clock tick or a
+// spinner frame then costs a few bytes instead of a full screen.
+//
+// The frame on screen outlives individual `TeletextPage`s (pages are rebuilt
+// whenever data changes), so it is kept process-wide like the other caches.
+
+use super::layout::AnsiCodeCache;
+use std::sync::{LazyLock, Mutex};
+
+/// Unchanged cells between two changed runs that are still rewritten instead
+/// of emitting a new cursor position (a position code costs ~8 bytes).
+const MAX_BRIDGED_GAP: usize = 4;
+
+/// DEC line size of a terminal row. A double-height row shows the top or
+/// bottom half of its characters, each two columns wide, so only the first
+/// half of the row's cells fit on screen.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(super) enum LineSize {
+    #[default]
+    Single,
+    DoubleTop,
+    DoubleBottom,
+}
+
+impl LineSize {
+    /// The escape sequence that gives the cursor's row this size
+    pub(super) fn escape(self) -> &'static str {
+        match self {
+            LineSize::Single => "\x1b#5",
+            LineSize::DoubleTop => "\x1b#3",
+            LineSize::DoubleBottom => "\x1b#4",
+        }
+    }
+}
+
+/// Terminal attributes of a single cell, as far as this app uses them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(super) struct CellStyle {
+    pub(super) fg: Option<u8>,
+    pub(super) bg: Option<u8>,
+    pub(super) bold: bool,
+    /// OSC 8 hyperlink target
+    pub(super) link: Option<String>,
+}
+
+impl CellStyle {
+    /// Applies an SGR parameter list (the part between `ESC[` and `m`).
+    fn apply_sgr(&mut self, params: &str) {
+        let mut codes = params.split(';').map(|p| p.parse::<u16>().unwrap_or(0));
+        while let Some(code) = codes.next() {
+            match code {
+                0 => {
+                    let link = self.link.take();
+                    *self = CellStyle {
+                        link,
+                        ..CellStyle::default()
+                    };
+                }
+                1 => self.bold = true,
+                22 => self.bold = false,
+                39 => self.fg = None,
+                49 => self.bg = None,
+                // Only the 256-color form (38;5;n / 48;5;n) is produced here
+                38 | 48 if codes.next() == Some(5) => {
+                    let color = codes.next().and_then(|c| u8::try_from(c).ok());
+                    if code == 38 {
+                        self.fg = color;
+                    } else {
+                        self.bg = color;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Writes the escape codes that switch the terminal from `from` to this style.
+    pub(super) fn push_transition(&self, from: &CellStyle, out: &mut String) {
+        if self.link != from.link {
+            match &self.link {
+                Some(url) => out.push_str(&format!("\x1b]8;;{url}\x07")),
+                None => out.push_str("\x1b]8;;\x07"),
+            }
+        }
+        if self.fg == from.fg && self.bg == from.bg && self.bold == from.bold {
+            return;
+        }
+        out.push_str("\x1b[0m");
+        if self.bold {
+            out.push_str("\x1b[1m");
+        }
+        if let Some(bg) = self.bg {
+            out.push_str(&format!("\x1b[48;5;{bg}m"));
+        }
+        if let Some(fg) = self.fg {
+            out.push_str(&format!("\x1b[38;5;{fg}m"));
+        }
+    }
+}
+
+/// One character cell of the terminal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct ScreenCell {
+    pub(super) ch: char,
+    pub(super) style: CellStyle,
+}
+
+impl Default for ScreenCell {
+    fn default() -> Self {
+        Self {
+            ch: ' ',
+            style: CellStyle::default(),
+        }
+    }
+}
+
+/// A full terminal frame as a grid of cells.
+///
+/// Built by replaying the escape sequences the renderers emit: absolute cursor
+/// positioning, 256-color SGR, erase-in-display/line, OSC 8 hyperlinks and
+/// DEC double-height lines.
+/// Every character is assumed to occupy one cell, which holds for the
+/// teletext character set used on the pages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScreenFrame {
+    width: usize,
+    height: usize,
+    cells: Vec<ScreenCell>,
+    line_sizes: Vec<LineSize>,
+}
+
+impl ScreenFrame {
+    /// Creates an empty (all blank) frame.
+    pub fn blank(width: u16, height: u16) -> Self {
+        let (width, height) = (width as usize, height as usize);
+        Self {
+            width,
+            height,
+            cells: vec![ScreenCell::default(); width * height],
+            line_sizes: vec![LineSize::Single; height],
+        }
+    }
+
+    /// Replays rendered ANSI output onto a blank frame of the given size.
+    /// Text written outside the frame is dropped, as the terminal would clip it.
+    pub fn from_ansi(ansi: &str, width: u16, height: u16) -> Self {
+        let mut frame = Self::blank(width, height);
+        let mut style = CellStyle::default();
+        let (mut row, mut col) = (0usize, 0usize);
+        let mut chars = ansi.chars().peekable();
+
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\x1b' => match chars.next() {
+                    Some('[') => {
+                        let mut params = String::new();
+                        let mut command = None;
+                        for c in chars.by_ref() {
+                            if ('\x40'..='\x7e').contains(&c) {
+                                command = Some(c);
+                                break;
+                            }
+                            params.push(c);
+                        }
+                        match command {
+                            Some('H') | Some('f') => {
+                                let mut parts = params.split(';');
+                                let mut next = || {
+                                    parts
+                                        .next()
+                                        .and_then(|p| p.parse::<usize>().ok())
+                                        .unwrap_or(1)
+                                        .max(1)
+                                };
+                                row = next() - 1;
+                                col = next() - 1;
+                            }
+                            Some('m') => style.apply_sgr(&params),
+                            Some('J') => frame.erase_display(row, col, &params),
+                            Some('K') => frame.erase_line(row, col),
+                            _ => {}
+                        }
+                    }
+                    Some(']') => {
+                        let mut payload = String::new();
+                        while let Some(c) = chars.next() {
+                            if c == '\x07' {
+                                break;
+                            }
+                            if c == '\x1b' {
+                                chars.next_if_eq(&'\\');
+                                break;
+                            }
+                            payload.push(c);
+                        }
+                        if let Some(rest) = payload.strip_prefix("8;") {
+                            let url = rest.split_once(';').map_or("", |(_, url)| url);
+                            style.link = (!url.is_empty()).then(|| url.to_string());
+                        }
+                    }
+                    Some('#') => {
+                        let size = match chars.next() {
+                            Some('3') => LineSize::DoubleTop,
+                            Some('4') => LineSize::DoubleBottom,
+                            _ => LineSize::Single,
+                        };
+                        if let Some(line_size) = frame.line_sizes.get_mut(row) {
+                            *line_size = size;
+                        }
+                    }
+                    _ => {}
+                },
+                '\n' => {
+                    row += 1;
+                    col = 0;
+                }
+                '\r' => col = 0,
+                c if c.is_control() => {}
+                c => {
+                    // Double-size rows only have room for half as many characters
+                    let clipped = frame
+                        .line_sizes
+                        .get(row)
+                        .is_some_and(|size| *size != LineSize::Single && col >= frame.width / 2);
+                    if !clipped && let Some(cell) = frame.cell_mut(row, col) {
+                        *cell = ScreenCell {
+                            ch: c,
+                            style: style.clone(),
+                        };
+                    }
+                    col += 1;
+                }
+            }
+        }
+
+        frame
+    }
+
+    /// The rows of the frame, top to bottom.
+    pub(super) fn rows(&self) -> impl Iterator<Item = &[ScreenCell]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    fn cell_mut(&mut self, row: usize, col: usize) -> Option<&mut ScreenCell> {
+        if row < self.height && col < self.width {
+            self.cells.get_mut(row * self.width + col)
+        } else {
+            None
+        }
+    }
+
+    fn erase_display(&mut self, row: usize, col: usize, params: &str) {
+        let start = match params {
+            "2" | "3" => 0,
+            "" | "0" => (row * self.width + col).min(self.cells.len()),
+            _ => return,
+        };
+        self.cells[start..].fill(ScreenCell::default());
+        // Rows erased completely lose their line size too
+        let first_full_row = start.div_ceil(self.width.max(1));
+        if let Some(sizes) = self.line_sizes.get_mut(first_full_row..) {
+            sizes.fill(LineSize::Single);
+        }
+    }
+
+    fn erase_line(&mut self, row: usize, col: usize) {
+        if row < self.height && col < self.width {
+            let start = row * self.width + col;
+            let end = (row + 1) * self.width;
+            self.cells[start..end].fill(ScreenCell::default());
+        }
+    }
+
+    /// The DEC line size of `row`.
+    pub(super) fn line_size(&self, row: usize) -> LineSize {
+        self.line_sizes.get(row).copied().unwrap_or_default()
+    }
+
+    /// Returns the escape sequences that turn `previous` into this frame,
+    /// touching only the cells that differ. Both frames must have the same size.
+    ///
+    /// A row whose line size changed is cleared and repainted whole, since
+    /// every character on it changes width.
+    pub fn diff(&self, previous: &ScreenFrame, positions: &mut AnsiCodeCache) -> String {
+        debug_assert_eq!((self.width, self.height), (previous.width, previous.height));
+
+        let blank = vec![ScreenCell::default(); self.width];
+        let mut out = String::new();
+        for row in 0..self.height {
+            let line = &self.cells[row * self.width..(row + 1) * self.width];
+            let old = if self.line_size(row) == previous.line_size(row) {
+                &previous.cells[row * self.width..(row + 1) * self.width]
+            } else {
+                out.push_str(positions.get_position_code(row + 1, 1));
+                out.push_str(self.line_size(row).escape());
+                out.push_str("\x1b[0m\x1b[2K");
+                &blank
+            };
+
+            let mut col = 0;
+            while col < self.width {
+                if line[col] == old[col] {
+                    col += 1;
+                    continue;
+                }
+
+                // Extend the run over changed cells and short unchanged gaps
+                let start = col;
+                let mut end = col + 1;
+                let mut scan = end;
+                while scan < self.width && scan - end <= MAX_BRIDGED_GAP {
+                    if line[scan] != old[scan] {
+                        end = scan + 1;
+                    }
+                    scan += 1;
+                }
+
+                out.push_str(positions.get_position_code(row + 1, start + 1));
+                let mut current = CellStyle::default();
+                out.push_str("\x1b[0m");
+                for cell in &line[start..end] {
+                    cell.style.push_transition(&current, &mut out);
+                    current = cell.style.clone();
+                    out.push(cell.ch);
+                }
+                CellStyle::default().push_transition(&current, &mut out);
+
+                col = end;
+            }
+        }
+        out
+    }
+}
+
+/// What is currently on the terminal, shared by every page render.
+struct PresentedScreen {
+    frame: Option<ScreenFrame>,
+    positions: AnsiCodeCache,
+}
+
+static PRESENTED_SCREEN: LazyLock<Mutex<PresentedScreen>> = LazyLock::new(|| {
+    Mutex::new(PresentedScreen {
+        frame: None,
+        positions: AnsiCodeCache::new(),
+    })
+});
+
+/// Turns a freshly rendered frame into the minimal terminal output and
+/// remembers it as the frame on screen.
+///
+/// The first frame, and any frame whose size differs from the one on screen
+/// (terminal resize), clears the screen and paints all non-blank cells.
+///
+/// # Arguments
+/// * `ansi` - The complete frame as rendered by `render_buffered`
+/// * `width` - Terminal width in columns
+/// * `height` - Terminal height in lines
+///
+/// # Returns
+/// * `String` - Escape sequences to write to the terminal (empty if nothing changed)
+pub fn present_frame(ansi: &str, width: u16, height: u16) -> String {
+    let frame = ScreenFrame::from_ansi(ansi, width, height);
+    let mut screen = PRESENTED_SCREEN
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let screen = &mut *screen;
+
+    let output = match screen.frame.as_ref() {
+        Some(previous) if previous.width == frame.width && previous.height == frame.height => {
+            frame.diff(previous, &mut screen.positions)
+        }
+        _ => {
+            // Position codes are cheap to regenerate; drop the ones for the old size
+            screen.positions.clear();
+            let blank = ScreenFrame::blank(width, height);
+            format!("\x1b[H\x1b[2J{}", frame.diff(&blank, &mut screen.positions))
+        }
+    };
+
+    tracing::trace!("Presented frame with {} bytes of output", output.len());
+    screen.frame = Some(frame);
+    output
+}
+
+/// Forgets the frame on screen so the next render repaints everything.
+/// Call after anything else has drawn on or cleared the terminal.
+pub fn invalidate_presented_frame() {
+    PRESENTED_SCREEN
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .frame = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text_at(frame: &ScreenFrame, row: usize, col: usize, len: usize) -> String {
+        frame.cells[row * frame.width + col..row * frame.width + col + len]
+            .iter()
+            .map(|c| c.ch)
+            .collect()
+    }
+
+    #[test]
+    fn test_replays_positioned_colored_text() {
+        let frame = ScreenFrame::from_ansi("\x1b[2;3H\x1b[38;5;46mHIFK\x1b[0m", 10, 3);
+        assert_eq!(text_at(&frame, 1, 2, 4), "HIFK");
+        assert_eq!(frame.cells[12].style.fg, Some(46));
+        assert_eq!(frame.cells[16].style, CellStyle::default());
+    }
+
+    #[test]
+    fn test_text_outside_frame_is_clipped() {
+        let frame = ScreenFrame::from_ansi("\x1b[1;8HTappara\x1b[9;1HX", 10, 3);
+        assert_eq!(text_at(&frame, 0, 7, 3), "Tap");
+    }
+
+    #[test]
+    fn test_hyperlinks_are_part_of_cell_style() {
+        let frame = ScreenFrame::from_ansi("\x1b]8;;https://x\x07AB\x1b]8;;\x07C", 5, 1);
+        assert_eq!(frame.cells[0].style.link.as_deref(), Some("https://x"));
+        assert_eq!(frame.cells[1].style.link.as_deref(), Some("https://x"));
+        assert_eq!(frame.cells[2].style.link, None);
+    }
+
+    #[test]
+    fn test_double_size_rows_hold_half_the_columns() {
+        let frame = ScreenFrame::from_ansi("\x1b[2;1H\x1b#3JÄÄKIEKKO\x1b[3;1HJÄÄKIEKKO", 10, 3);
+        assert_eq!(frame.line_size(1), LineSize::DoubleTop);
+        assert_eq!(text_at(&frame, 1, 0, 10), "JÄÄKI     ");
+        assert_eq!(frame.line_size(2), LineSize::Single);
+        assert_eq!(text_at(&frame, 2, 0, 9), "JÄÄKIEKKO");
+    }
+
+    #[test]
+    fn test_line_size_change_repaints_row() {
+        let before = ScreenFrame::from_ansi("\x1b[2;1HSM-LIIGA", 20, 3);
+        let after = ScreenFrame::from_ansi("\x1b[2;1H\x1b#4SM-LIIGA", 20, 3);
+        let output = after.diff(&before, &mut AnsiCodeCache::new());
+        assert!(output.contains("\x1b#4"), "got {output:?}");
+        assert!(output.contains("SM-LIIGA"), "got {output:?}");
+
+        let mut replay = String::from("\x1b[2;1HSM-LIIGA");
+        replay.push_str(&output);
+        assert_eq!(ScreenFrame::from_ansi(&replay, 20, 3), after);
+    }
+
+    #[test]
+    fn test_identical_frames_produce_no_output() {
+        let ansi = "\x1b[1;1H\x1b[48;5;21m\x1b[38;5;231mSM-LIIGA 221\x1b[0m";
+        let a = ScreenFrame::from_ansi(ansi, 40, 5);
+        let b = ScreenFrame::from_ansi(ansi, 40, 5);
+        assert!(a.diff(&b, &mut AnsiCodeCache::new()).is_empty());
+    }
+
+    #[test]
+    fn test_diff_rewrites_only_changed_cells() {
+        let before = ScreenFrame::from_ansi("\x1b[5;10H\x1b[38;5;231m12:34 2-1\x1b[0m", 80, 24);
+        let after = ScreenFrame::from_ansi("\x1b[5;10H\x1b[38;5;231m12:35 2-1\x1b[0m", 80, 24);
+
+        let output = after.diff(&before, &mut AnsiCodeCache::new());
+        assert!(output.starts_with("\x1b[5;14H"), "got {output:?}");
+        assert!(output.contains('5'));
+        assert!(
+            !output.contains("2-1"),
+            "unchanged score must not be rewritten"
+        );
+
+        // Applying the diff on top of the old frame yields the new one
+        let mut replay = String::from("\x1b[5;10H\x1b[38;5;231m12:34 2-1\x1b[0m");
+        replay.push_str(&output);
+        assert_eq!(ScreenFrame::from_ansi(&replay, 80, 24), after);
+    }
+
+    #[test]
+    fn test_diff_clears_removed_content() {
+        let before = ScreenFrame::from_ansi("\x1b[3;1HKärpät", 20, 4);
+        let after = ScreenFrame::blank(20, 4);
+
+        let mut replay = String::from("\x1b[3;1HKärpät");
+        replay.push_str(&after.diff(&before, &mut AnsiCodeCache::new()));
+        assert_eq!(ScreenFrame::from_ansi(&replay, 20, 4), after);
+    }
+
+    #[test]
+    fn test_style_only_change_is_detected() {
+        let before = ScreenFrame::from_ansi("\x1b[38;5;231m3-2", 10, 1);
+        let after = ScreenFrame::from_ansi("\x1b[38;5;46m3-2", 10, 1);
+        let output = after.diff(&before, &mut AnsiCodeCache::new());
+        assert!(output.contains("\x1b[38;5;46m3-2"), "got {output:?}");
+    }
+}
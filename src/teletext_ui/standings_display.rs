@@ -1,5 +1,6 @@
 use super::core::TeletextPage;
 use super::core::get_ansi_code;
+use crate::i18n::t;
 use crate::teletext_ui::CONTENT_MARGIN;
 use crate::ui::teletext::colors::*;
 
@@ -12,11 +13,25 @@ impl TeletextPage {
         text_fg_code: u8,
     ) {
         let header = if self.compact_mode {
-            format!(" {:>2}  {:<14} {:>4}", "#", "Joukkue", "P")
+            format!(
+                " {:>2}  {:<14} {:>4}",
+                "#",
+                t("standings.team"),
+                t("standings.points")
+            )
         } else {
             format!(
                 " {:>2}  {:<14} {:>2} {:>2} {:>2} {:>2} {:>2} {:>3} {:>3} {:>4}",
-                "#", "Joukkue", "O", "V", "JV", "JH", "H", "TM", "PM", "P"
+                "#",
+                t("standings.team"),
+                t("standings.games"),
+                t("standings.wins"),
+                t("standings.ot_wins"),
+                t("standings.ot_losses"),
+                t("standings.losses"),
+                t("standings.goals_for"),
+                t("standings.goals_against"),
+                t("standings.points")
             )
         };
 
@@ -103,35 +118,22 @@ impl TeletextPage {
         // Truncate team name to 14 chars
         let display_name: String = team_name.chars().take(14).collect();
 
-        // Compute projected stats: "if game ends like this"
-        let (display_gp, display_w, display_ow, display_ol, display_l) = if live_game_active {
-            let delta = live_points_delta.unwrap_or(0);
-            match delta {
-                3 => (games_played + 1, wins + 1, ot_wins, ot_losses, losses),
-                2 => (games_played + 1, wins, ot_wins + 1, ot_losses, losses),
-                1 => (games_played + 1, wins, ot_wins, ot_losses + 1, losses),
-                _ => (games_played + 1, wins, ot_wins, ot_losses, losses + 1),
-            }
-        } else {
-            (games_played, wins, ot_wins, ot_losses, losses)
-        };
-
-        let display_goals_for = if live_game_active {
-            live_goals_for
-        } else {
-            goals_for
-        };
-        let display_goals_against = if live_game_active {
-            live_goals_against
-        } else {
-            goals_against
-        };
-
-        // Show potential points (base + delta) when live game is active
-        let display_points = match live_points_delta {
-            Some(d) if *d != 0 => (points as i16 + d) as u16,
-            _ => points,
-        };
+        let ShownStandings {
+            games_played: display_gp,
+            wins: display_w,
+            ot_wins: display_ow,
+            ot_losses: display_ol,
+            losses: display_l,
+            goals_for: display_goals_for,
+            goals_against: display_goals_against,
+            points: display_points,
+        } = shown_standings(
+            [games_played, wins, ot_wins, ot_losses, losses],
+            (goals_for, goals_against, points),
+            (live_goals_for, live_goals_against),
+            *live_points_delta,
+            live_game_active,
+        );
 
         let row = if self.compact_mode {
             let live_suffix = match live_points_delta {
@@ -182,7 +184,73 @@ impl TeletextPage {
     }
 }
 
+/// Standings figures as a row shows them.
+pub(super) struct ShownStandings {
+    pub games_played: u16,
+    pub wins: u16,
+    pub ot_wins: u16,
+    pub ot_losses: u16,
+    pub losses: u16,
+    pub goals_for: u16,
+    pub goals_against: u16,
+    pub points: u16,
+}
+
+/// Projects a team's standings "if the game ends like this" while it is
+/// playing; otherwise returns the figures as they are.
+///
+/// # Arguments
+/// * `record` - Games played, wins, overtime wins, overtime losses and losses
+/// * `totals` - Goals for, goals against and points
+/// * `live_goals` - Goals for and against including the ongoing game
+/// * `live_points_delta` - Points the current score of the ongoing game gives
+/// * `live_game_active` - Whether the team is playing right now
+pub(super) fn shown_standings(
+    record: [u16; 5],
+    totals: (u16, u16, u16),
+    live_goals: (u16, u16),
+    live_points_delta: Option<i16>,
+    live_game_active: bool,
+) -> ShownStandings {
+    let [games_played, wins, ot_wins, ot_losses, losses] = record;
+    let (goals_for, goals_against, points) = totals;
+
+    // Compute projected stats: "if game ends like this"
+    let (games_played, wins, ot_wins, ot_losses, losses) = if live_game_active {
+        match live_points_delta.unwrap_or(0) {
+            3 => (games_played + 1, wins + 1, ot_wins, ot_losses, losses),
+            2 => (games_played + 1, wins, ot_wins + 1, ot_losses, losses),
+            1 => (games_played + 1, wins, ot_wins, ot_losses + 1, losses),
+            _ => (games_played + 1, wins, ot_wins, ot_losses, losses + 1),
+        }
+    } else {
+        (games_played, wins, ot_wins, ot_losses, losses)
+    };
+    let (goals_for, goals_against) = if live_game_active {
+        live_goals
+    } else {
+        (goals_for, goals_against)
+    };
+
+    // Show potential points (base + delta) when live game is active
+    let points = match live_points_delta {
+        Some(d) if d != 0 => (points as i16 + d) as u16,
+        _ => points,
+    };
+
+    ShownStandings {
+        games_played,
+        wins,
+        ot_wins,
+        ot_losses,
+        losses,
+        goals_for,
+        goals_against,
+        points,
+    }
+}
+
 /// Color for position numbers (yellow) — delegates to the shared teletext palette
-fn position_fg() -> crossterm::style::Color {
+pub(super) fn position_fg() -> crossterm::style::Color {
     goal_type_fg()
 }
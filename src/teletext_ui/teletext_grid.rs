@@ -0,0 +1,838 @@
+// src/teletext_ui/teletext_grid.rs - Strict 40x24 teletext grid rendering
+//
+// The other layouts follow the terminal width. In teletext mode every view
+// is laid out on the 40 column, 24 row grid of a real teletext page instead:
+// a header row, a double-height title on a block-mosaic band, the subheader,
+// 17 content rows, a notice row and the Fastext row. Game and standings rows
+// get 40 column formats of their own; the other rows already fit and are
+// drawn by the normal renderers. The grid is then placed on the terminal:
+// centred, and doubled in both directions with DEC double-size lines when
+// the terminal is large enough and draws them.
+
+use super::core::{TeletextPage, TeletextRow, get_ansi_code};
+use super::layout::IntelligentTruncator;
+use super::live_clock::format_game_clock;
+use super::rendering::truncate_team_name_gracefully;
+use super::screen_diff::{CellStyle, LineSize, ScreenCell, ScreenFrame};
+use super::standings_display::{ShownStandings, position_fg, shown_standings};
+use crate::data_fetcher::GoalEventData;
+use crate::error::AppError;
+use crate::i18n::t;
+use crate::teletext_ui::{CONTENT_MARGIN, ScoreType};
+use crate::ui::teletext::colors::*;
+use chrono::Local;
+use std::io::Stdout;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Columns of a teletext page
+pub const GRID_WIDTH: u16 = 40;
+
+/// Rows of a teletext page
+pub const GRID_HEIGHT: u16 = 24;
+
+/// Content rows between the subheader and the notice row
+pub const GRID_CONTENT_ROWS: u16 = 17;
+
+/// Grid row (0-based) of the title band's first row
+const TITLE_ROW: usize = 1;
+
+/// Grid rows above the content: header, two title rows, stripe and subheader
+const HEADER_ROWS: usize = 5;
+
+/// Team name width in game rows
+const TEAM_WIDTH: usize = 13;
+
+/// Width of the time/score column at the right edge of game rows
+const RESULT_WIDTH: usize = 9;
+
+/// Column (1-based) where the away team and its scorers start
+const AWAY_COLUMN: usize = CONTENT_MARGIN + 1 + TEAM_WIDTH + 3;
+
+/// Width of the team name in standings rows
+const STANDINGS_TEAM_WIDTH: usize = 9;
+
+/// Whether new pages start in teletext mode (`--teletext`, `display_mode = "teletext"`)
+static GRID_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Sets whether pages created from now on render into the teletext grid.
+pub fn set_grid_mode(enabled: bool) {
+    GRID_MODE.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether pages created now render into the teletext grid.
+pub fn grid_mode() -> bool {
+    GRID_MODE.load(Ordering::Relaxed)
+}
+
+/// The sextant block-mosaic character for a 2×3 pattern, as in the teletext
+/// G1 mosaic set: bit 0 is the top left block, bit 1 the top right one, then
+/// the middle and bottom rows. The four patterns that Unicode already had as
+/// block elements (blank, left half, right half, full) map to those.
+///
+/// # Example
+/// ```
+/// use liiga_teletext::teletext_ui::teletext_grid::sextant;
+///
+/// assert_eq!(sextant(0b000011), '🬂'); // top row
+/// assert_eq!(sextant(0b010101), '▌'); // left column
+/// ```
+pub fn sextant(bits: u8) -> char {
+    match bits & 0x3f {
+        0 => ' ',
+        21 => '▌',
+        42 => '▐',
+        63 => '█',
+        bits => {
+            let skipped = u32::from(bits > 21) + u32::from(bits > 42);
+            char::from_u32(0x1FB00 + u32::from(bits) - 1 - skipped).unwrap_or('█')
+        }
+    }
+}
+
+/// The 2×3 pattern of a character drawn by [`sextant`], or `None` for
+/// characters that aren't block mosaics.
+pub fn sextant_bits(ch: char) -> Option<u8> {
+    match ch {
+        '▌' => Some(21),
+        '▐' => Some(42),
+        '█' => Some(63),
+        '\u{1FB00}'..='\u{1FB3B}' => {
+            let index = u32::from(ch) - 0x1FB00 + 1;
+            let bits = index + u32::from(index >= 21) + u32::from(index >= 41);
+            u8::try_from(bits).ok()
+        }
+        _ => None,
+    }
+}
+
+/// Whether the terminal draws DEC double-height lines, judging by the
+/// environment. xterm, Konsole, Windows Terminal, WezTerm, mlterm and the
+/// macOS Terminal do; tmux and screen don't pass them through.
+pub fn supports_double_height() -> bool {
+    double_height_supported(|name| std::env::var(name).ok())
+}
+
+fn double_height_supported(env: impl Fn(&str) -> Option<String>) -> bool {
+    let term = env("TERM").unwrap_or_default();
+    if env("TMUX").is_some() || term.starts_with("screen") || term.starts_with("tmux") {
+        return false;
+    }
+    env("XTERM_VERSION").is_some()
+        || env("KONSOLE_VERSION").is_some()
+        || env("WT_SESSION").is_some()
+        || term.starts_with("mlterm")
+        || matches!(
+            env("TERM_PROGRAM").as_deref(),
+            Some("WezTerm" | "Apple_Terminal")
+        )
+}
+
+/// Where the grid goes on the terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Placement {
+    /// 2 when every grid row is drawn as a double-size line pair
+    scale: usize,
+    /// Terminal row and column (0-based) of the grid's top left corner
+    row: usize,
+    col: usize,
+}
+
+impl Placement {
+    /// Centres a grid of `grid_rows` rows on a `width`×`height` terminal
+    /// (height 0: no limit), at twice the size when it fits twice and the
+    /// terminal draws double-size lines.
+    fn fit(width: u16, height: u16, grid_rows: usize, double_height: bool) -> Self {
+        let (width, height) = (usize::from(width), usize::from(height));
+        let grid_width = usize::from(GRID_WIDTH);
+        let scale = if double_height && width >= 2 * grid_width && height >= 2 * grid_rows {
+            2
+        } else {
+            1
+        };
+        Self {
+            scale,
+            row: height.saturating_sub(grid_rows * scale) / 2,
+            // Double-width lines address every other column, so the grid
+            // starts on an even one for the title rows to line up
+            col: (width.saturating_sub(grid_width * scale) / 2) & !1,
+        }
+    }
+}
+
+/// The title band: the title on the title color, its right edge sloping into
+/// the header color in sextant mosaics. `rows` rows of `columns` cells, each
+/// cell 2×3 mosaic blocks; the title is on the first row.
+fn title_band(title: &str, columns: usize, rows: usize) -> Vec<Vec<ScreenCell>> {
+    let title_bg_code = get_ansi_code(title_bg(), 46);
+    let band_code = get_ansi_code(header_bg(), 21);
+    let title_fg_code = get_ansi_code(header_fg(), 21);
+
+    let title: Vec<char> = title.chars().take(columns.saturating_sub(4)).collect();
+    // Blocks covered by the title color on the top row; it reaches one
+    // block further right on every block row below
+    let title_blocks = (title.len() + 2) * 2;
+
+    (0..rows)
+        .map(|row| {
+            (0..columns)
+                .map(|col| {
+                    let bits = (0..6u8)
+                        .filter(|bit| {
+                            let x = col * 2 + usize::from(bit % 2);
+                            let y = row * 3 + usize::from(bit / 2);
+                            x < title_blocks + y
+                        })
+                        .fold(0u8, |bits, bit| bits | 1 << bit);
+                    let text = (row == 0 && col >= 1).then(|| title.get(col - 1)).flatten();
+                    match (text, bits) {
+                        (Some(&ch), _) => ScreenCell {
+                            ch,
+                            style: CellStyle {
+                                fg: Some(title_fg_code),
+                                bg: Some(title_bg_code),
+                                bold: true,
+                                link: None,
+                            },
+                        },
+                        (None, 63) => ScreenCell {
+                            ch: ' ',
+                            style: CellStyle {
+                                bg: Some(title_bg_code),
+                                ..CellStyle::default()
+                            },
+                        },
+                        (None, bits) => ScreenCell {
+                            ch: sextant(bits),
+                            style: CellStyle {
+                                fg: Some(title_bg_code),
+                                bg: Some(band_code),
+                                ..CellStyle::default()
+                            },
+                        },
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Writes `cells` at the cursor, then resets the style.
+fn push_cells(out: &mut String, cells: &[ScreenCell]) {
+    let mut current = CellStyle::default();
+    out.push_str("\x1b[0m");
+    for cell in cells {
+        cell.style.push_transition(&current, out);
+        current = cell.style.clone();
+        out.push(cell.ch);
+    }
+    CellStyle::default().push_transition(&current, out);
+}
+
+/// Writes `cells` on terminal row `row` from column `col` (both 0-based),
+/// giving the row line size `size` first.
+fn push_row(out: &mut String, row: usize, col: usize, size: LineSize, cells: &[ScreenCell]) {
+    if size != LineSize::Single {
+        out.push_str(&format!("\x1b[{};1H{}", row + 1, size.escape()));
+    }
+    out.push_str(&format!("\x1b[{};{}H", row + 1, col + 1));
+    push_cells(out, cells);
+}
+
+/// Color of a goal scorer: game-winning goals of overtime and shootout
+/// games stand out, like in the normal layout.
+fn scorer_fg_code(event: &GoalEventData, is_overtime: bool, is_shootout: bool) -> u8 {
+    if (event.is_winning_goal && (is_overtime || is_shootout))
+        || event.goal_types.iter().any(|goal_type| goal_type == "VL")
+    {
+        get_ansi_code(winning_goal_fg(), 201)
+    } else {
+        get_ansi_code(home_scorer_fg(), 51)
+    }
+}
+
+impl TeletextPage {
+    /// Places the teletext grid on a terminal `width` columns wide. Where the
+    /// terminal draws double-height lines the title is double height, and
+    /// the whole grid is doubled when the terminal has room for it twice.
+    pub(super) fn compose_grid_frame(
+        &self,
+        stdout: &mut Stdout,
+        width: u16,
+    ) -> Result<String, AppError> {
+        let grid = self.compose_grid(stdout)?;
+        let rows: Vec<&[ScreenCell]> = grid.rows().collect();
+        let height = if self.ignore_height_limit {
+            0
+        } else {
+            self.screen_height
+        };
+        let double_height = supports_double_height();
+        let placement = Placement::fit(width, height, rows.len(), double_height);
+
+        let mut out = String::with_capacity(rows.len() * usize::from(GRID_WIDTH) * 4);
+        if placement.scale == 2 {
+            for (y, cells) in rows.iter().enumerate() {
+                let row = placement.row + 2 * y;
+                push_row(&mut out, row, placement.col / 2, LineSize::DoubleTop, cells);
+                push_row(
+                    &mut out,
+                    row + 1,
+                    placement.col / 2,
+                    LineSize::DoubleBottom,
+                    cells,
+                );
+            }
+            return Ok(out);
+        }
+
+        for (y, cells) in rows.iter().enumerate() {
+            push_row(
+                &mut out,
+                placement.row + y,
+                placement.col,
+                LineSize::Single,
+                cells,
+            );
+        }
+        if double_height {
+            // Both title rows show the same double-width cells: the top
+            // halves of the characters on the first, the bottom halves on the second
+            let band = title_band(&self.title, usize::from(GRID_WIDTH) / 2, 1);
+            let row = placement.row + TITLE_ROW;
+            push_row(
+                &mut out,
+                row,
+                placement.col / 2,
+                LineSize::DoubleTop,
+                &band[0],
+            );
+            push_row(
+                &mut out,
+                row + 1,
+                placement.col / 2,
+                LineSize::DoubleBottom,
+                &band[0],
+            );
+        }
+        Ok(out)
+    }
+
+    /// Lays the page out on the teletext grid: 40 columns and 24 rows in
+    /// interactive mode, as many rows as the content needs in `--once` mode.
+    /// The title is single height here.
+    pub(crate) fn compose_grid(&self, stdout: &mut Stdout) -> Result<ScreenFrame, AppError> {
+        let mut buffer = String::with_capacity(4096);
+        self.render_grid_header(&mut buffer);
+
+        let (visible_rows, _) = self.get_page_content();
+        let mut current_line = HEADER_ROWS + 1;
+        for row in &visible_rows {
+            self.render_grid_row(&mut buffer, row, &mut current_line);
+        }
+
+        let rows = if self.ignore_height_limit {
+            current_line
+        } else {
+            usize::from(GRID_HEIGHT)
+        };
+        if self.show_footer {
+            self.render_footer(stdout, &mut buffer, rows - 1, usize::from(GRID_WIDTH))?;
+        }
+
+        Ok(ScreenFrame::from_ansi(
+            &buffer,
+            GRID_WIDTH,
+            u16::try_from(rows).unwrap_or(u16::MAX),
+        ))
+    }
+
+    /// Header row, title band, mosaic stripe and subheader.
+    fn render_grid_header(&self, buffer: &mut String) {
+        let width = usize::from(GRID_WIDTH);
+        let text_fg_code = get_ansi_code(text_fg(), 231);
+        let clock_fg_code = get_ansi_code(goal_type_fg(), 226);
+
+        let page = format!(" P{} SM-LIIGA", self.format_header_page_str());
+        let clock = Local::now().format("%H:%M").to_string();
+        let date = self
+            .header_date()
+            .map(|date| format!("{date} "))
+            .unwrap_or_default();
+        let gap = width.saturating_sub(page.chars().count() + date.chars().count() + 6);
+        buffer.push_str(&format!(
+            "\x1b[1;1H\x1b[38;5;{text_fg_code}m{page}{}{date}\x1b[38;5;{clock_fg_code}m{clock} \x1b[0m",
+            " ".repeat(gap)
+        ));
+
+        for (offset, cells) in title_band(&self.title, width, 2).iter().enumerate() {
+            buffer.push_str(&format!("\x1b[{};1H", TITLE_ROW + offset + 1));
+            push_cells(buffer, cells);
+        }
+
+        buffer.push_str(&format!(
+            "\x1b[{};1H\x1b[38;5;{}m{}\x1b[0m",
+            TITLE_ROW + 3,
+            get_ansi_code(header_bg(), 21),
+            sextant(0b000011).to_string().repeat(width)
+        ));
+
+        let total_pages = self.total_pages();
+        let page_info = if total_pages > 1 && !self.ignore_height_limit {
+            format!("{}/{}", self.current_page + 1, total_pages)
+        } else {
+            String::new()
+        };
+        let subheader: String = self
+            .subheader
+            .chars()
+            .take(width - CONTENT_MARGIN - page_info.len() - 1)
+            .collect();
+        buffer.push_str(&format!(
+            "\x1b[{HEADER_ROWS};{}H\x1b[38;5;{}m{subheader}\x1b[{HEADER_ROWS};{}H\x1b[38;5;{text_fg_code}m{page_info}\x1b[0m",
+            CONTENT_MARGIN + 1,
+            get_ansi_code(subheader_fg(), 46),
+            width + 1 - page_info.len(),
+        ));
+    }
+
+    /// Renders one content row at `current_line` (1-based) and moves past it.
+    fn render_grid_row(&self, buffer: &mut String, row: &TeletextRow, current_line: &mut usize) {
+        match row {
+            TeletextRow::GameResult {
+                home_team,
+                away_team,
+                time,
+                result,
+                score_type,
+                is_overtime,
+                is_shootout,
+                goal_events,
+                played_time,
+                series_score,
+                ..
+            } => {
+                let result_text = if *is_shootout {
+                    format!("{result} {}", t("result.shootout"))
+                } else if *is_overtime {
+                    format!("{result} {}", t("result.overtime"))
+                } else {
+                    result.clone()
+                };
+                let (right, right_fg_code) = match score_type {
+                    ScoreType::Scheduled => (time.clone(), get_ansi_code(scheduled_time_fg(), 51)),
+                    ScoreType::Ongoing => {
+                        let is_playoff = series_score.is_some();
+                        if !self.ignore_height_limit {
+                            self.record_live_clock(*played_time, is_playoff);
+                        }
+                        let clock =
+                            format_game_clock(self.live_played_time(*played_time, is_playoff));
+                        (format!("{clock} {result}"), get_ansi_code(text_fg(), 231))
+                    }
+                    ScoreType::Final => (result_text, get_ansi_code(result_fg(), 46)),
+                };
+
+                buffer.push_str(&format!(
+                    "\x1b[{line};{}H\x1b[38;5;{}m{:<TEAM_WIDTH$} - {:<TEAM_WIDTH$}\x1b[38;5;{right_fg_code}m{right:>RESULT_WIDTH$}\x1b[0m",
+                    CONTENT_MARGIN + 1,
+                    get_ansi_code(text_fg(), 231),
+                    truncate_team_name_gracefully(home_team, TEAM_WIDTH),
+                    truncate_team_name_gracefully(away_team, TEAM_WIDTH),
+                    line = *current_line,
+                ));
+                *current_line += 1;
+
+                if matches!(score_type, ScoreType::Ongoing | ScoreType::Final) {
+                    let (home, away): (Vec<_>, Vec<_>) =
+                        goal_events.iter().partition(|event| event.is_home_team);
+                    for i in 0..home.len().max(away.len()) {
+                        if let Some(event) = home.get(i) {
+                            let width = AWAY_COLUMN - CONTENT_MARGIN - 1;
+                            self.render_grid_goal(
+                                buffer,
+                                event,
+                                (*current_line, CONTENT_MARGIN + 1, width),
+                                *is_overtime,
+                                *is_shootout,
+                            );
+                        }
+                        if let Some(event) = away.get(i) {
+                            let width = usize::from(GRID_WIDTH) + 1 - AWAY_COLUMN;
+                            self.render_grid_goal(
+                                buffer,
+                                event,
+                                (*current_line, AWAY_COLUMN, width),
+                                *is_overtime,
+                                *is_shootout,
+                            );
+                        }
+                        *current_line += 1;
+                    }
+                }
+
+                // Space between games
+                *current_line += 1;
+            }
+            TeletextRow::StandingsHeader => {
+                buffer.push_str(&format!(
+                    "\x1b[{};1H\x1b[38;5;{}m {:>2} {:<STANDINGS_TEAM_WIDTH$}{:>3}{:>3}{:>3}{:>3}{:>3}{:>4} {:<3}{:>4}\x1b[0m",
+                    *current_line,
+                    get_ansi_code(text_fg(), 231),
+                    "#",
+                    t("standings.team"),
+                    t("standings.games"),
+                    t("standings.wins"),
+                    t("standings.ot_wins"),
+                    t("standings.ot_losses"),
+                    t("standings.losses"),
+                    t("standings.goals_for"),
+                    t("standings.goals_against"),
+                    t("standings.points"),
+                ));
+                *current_line += 1;
+            }
+            TeletextRow::StandingsRow {
+                position,
+                team_name,
+                games_played,
+                wins,
+                ot_wins,
+                ot_losses,
+                losses,
+                goals_for,
+                goals_against,
+                points,
+                live_goals_for,
+                live_goals_against,
+                live_points_delta,
+                live_position_change,
+                live_game_active,
+            } => {
+                if self
+                    .playoffs_lines
+                    .iter()
+                    .any(|&line| *position == line + 1)
+                {
+                    buffer.push_str(&format!(
+                        "\x1b[{};1H\x1b[38;5;{}m{}\x1b[0m",
+                        *current_line,
+                        get_ansi_code(text_fg(), 240),
+                        "\u{2500}".repeat(usize::from(GRID_WIDTH))
+                    ));
+                    *current_line += 1;
+                }
+
+                let ShownStandings {
+                    games_played,
+                    wins,
+                    ot_wins,
+                    ot_losses,
+                    losses,
+                    goals_for,
+                    goals_against,
+                    points,
+                } = shown_standings(
+                    [*games_played, *wins, *ot_wins, *ot_losses, *losses],
+                    (*goals_for, *goals_against, *points),
+                    (*live_goals_for, *live_goals_against),
+                    *live_points_delta,
+                    *live_game_active,
+                );
+                let indicator = match live_position_change {
+                    Some(change) if *change > 0 => '\u{2191}',
+                    Some(change) if *change < 0 => '\u{2193}',
+                    _ => ' ',
+                };
+                let (team_fg_code, stats_fg_code) = if *live_game_active {
+                    let cyan = get_ansi_code(home_scorer_fg(), 51);
+                    (cyan, cyan)
+                } else {
+                    let white = get_ansi_code(text_fg(), 231);
+                    (white, white)
+                };
+                let team: String = team_name.chars().take(STANDINGS_TEAM_WIDTH).collect();
+
+                buffer.push_str(&format!(
+                    "\x1b[{};1H\x1b[38;5;{}m{indicator}\x1b[38;5;{}m{position:>2} \x1b[38;5;{team_fg_code}m{team:<STANDINGS_TEAM_WIDTH$}\x1b[38;5;{stats_fg_code}m{games_played:>3}{wins:>3}{ot_wins:>3}{ot_losses:>3}{losses:>3}{goals_for:>4}-{goals_against:<3}\x1b[38;5;{}m{points:>4}\x1b[0m",
+                    *current_line,
+                    get_ansi_code(winning_goal_fg(), 201),
+                    get_ansi_code(position_fg(), 226),
+                    get_ansi_code(result_fg(), 46),
+                ));
+                *current_line += 1;
+            }
+            // Headers, messages, bracket and timeline rows fit in 40 columns
+            // as they are
+            _ => self.render_normal_mode_content(
+                buffer,
+                &[row],
+                current_line,
+                get_ansi_code(text_fg(), 231),
+                get_ansi_code(result_fg(), 46),
+                get_ansi_code(subheader_fg(), 46),
+            ),
+        }
+    }
+
+    /// Renders a scorer ("12 Mäenalanen YV") at `(line, column)`, at most
+    /// `width` columns wide. The name links to the goal video when shown.
+    fn render_grid_goal(
+        &self,
+        buffer: &mut String,
+        event: &GoalEventData,
+        (line, column, width): (usize, usize, usize),
+        is_overtime: bool,
+        is_shootout: bool,
+    ) {
+        let goal_types = event.get_goal_type_display();
+        let types_width = if goal_types.is_empty() {
+            0
+        } else {
+            goal_types.chars().count() + 1
+        };
+        // Minute (2) and a space before the name, a space after it
+        let name_width = width.saturating_sub(4 + types_width);
+        let name =
+            IntelligentTruncator::truncate_player_name(&event.scorer_name, name_width, Some(5));
+        let name = match &event.video_clip_url {
+            Some(url) if !self.disable_video_links && !url.trim().is_empty() => {
+                format!("\x1b]8;;{url}\x07{name}\x1b]8;;\x07")
+            }
+            _ => name,
+        };
+
+        buffer.push_str(&format!(
+            "\x1b[{line};{column}H\x1b[38;5;{}m{:>2} {name}",
+            scorer_fg_code(event, is_overtime, is_shootout),
+            event.minute.clamp(0, 200),
+        ));
+        if !goal_types.is_empty() {
+            buffer.push_str(&format!(
+                " \x1b[38;5;{}m{goal_types}",
+                get_ansi_code(goal_type_fg(), 226)
+            ));
+        }
+        buffer.push_str("\x1b[0m");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid_text(frame: &ScreenFrame) -> Vec<String> {
+        frame
+            .rows()
+            .map(|row| row.iter().map(|cell| cell.ch).collect::<String>())
+            .collect()
+    }
+
+    fn goal(minute: i32, scorer: &str, home: bool, goal_types: &[&str]) -> GoalEventData {
+        GoalEventData {
+            scorer_player_id: 0,
+            scorer_name: scorer.to_string(),
+            minute,
+            home_team_score: 0,
+            away_team_score: 0,
+            is_winning_goal: false,
+            goal_types: goal_types.iter().map(|s| s.to_string()).collect(),
+            is_home_team: home,
+            video_clip_url: None,
+        }
+    }
+
+    fn teletext_page() -> TeletextPage {
+        let mut page = TeletextPage::new(
+            221,
+            "JÄÄKIEKKO".to_string(),
+            "SM-LIIGA".to_string(),
+            true,
+            true,
+            false,
+            false,
+            false,
+        );
+        page.set_teletext_mode(true);
+        page
+    }
+
+    #[test]
+    fn test_sextant_maps_teletext_mosaics_to_unicode() {
+        assert_eq!(sextant(0), ' ');
+        assert_eq!(sextant(1), '\u{1FB00}');
+        assert_eq!(sextant(0b000011), '\u{1FB02}');
+        assert_eq!(sextant(20), '\u{1FB13}');
+        assert_eq!(sextant(22), '\u{1FB14}');
+        assert_eq!(sextant(62), '\u{1FB3B}');
+        assert_eq!(sextant(21), '▌');
+        assert_eq!(sextant(42), '▐');
+        assert_eq!(sextant(63), '█');
+    }
+
+    #[test]
+    fn test_sextant_bits_inverts_sextant() {
+        for bits in 1..64 {
+            assert_eq!(sextant_bits(sextant(bits)), Some(bits));
+        }
+        assert_eq!(sextant_bits('A'), None);
+    }
+
+    #[test]
+    fn test_double_height_detection() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        assert!(double_height_supported(env(&[
+            ("TERM", "xterm-256color"),
+            ("XTERM_VERSION", "XTerm(390)")
+        ])));
+        assert!(double_height_supported(env(&[("TERM_PROGRAM", "WezTerm")])));
+        assert!(!double_height_supported(env(&[("TERM", "xterm-kitty")])));
+        assert!(!double_height_supported(env(&[
+            ("TERM", "tmux-256color"),
+            ("KONSOLE_VERSION", "230804")
+        ])));
+    }
+
+    #[test]
+    fn test_placement_centres_and_doubles_when_possible() {
+        assert_eq!(
+            Placement::fit(100, 30, 24, true),
+            Placement {
+                scale: 1,
+                row: 3,
+                col: 30
+            }
+        );
+        assert_eq!(
+            Placement::fit(41, 24, 24, false),
+            Placement {
+                scale: 1,
+                row: 0,
+                col: 0
+            }
+        );
+        assert_eq!(
+            Placement::fit(120, 50, 24, true),
+            Placement {
+                scale: 2,
+                row: 1,
+                col: 20
+            }
+        );
+        // Without double-size lines the grid can only be centred
+        assert_eq!(Placement::fit(120, 50, 24, false).scale, 1);
+    }
+
+    #[test]
+    fn test_title_band_slopes_into_header_color() {
+        let band = title_band("ABC", 40, 2);
+        assert_eq!(band.len(), 2);
+        assert!(band.iter().all(|row| row.len() == 40));
+        let top: String = band[0].iter().map(|cell| cell.ch).collect();
+        assert!(top.starts_with(" ABC "), "got {top:?}");
+        // Title color covers 5 columns, then mosaics lead into the plain band
+        assert_ne!(band[0][5].ch, ' ');
+        assert_eq!(band[0][39].ch, ' ');
+        assert_eq!(band[0][39].style.bg, Some(21));
+        assert_eq!(band[0][1].style.bg, Some(46));
+    }
+
+    #[test]
+    fn test_grid_is_exactly_40_by_24() {
+        let mut page = teletext_page();
+        page.add_game_result(crate::teletext_ui::GameResultData::new(
+            &crate::data_fetcher::GameData {
+                home_team: "Tappara".to_string(),
+                away_team: "HIFK".to_string(),
+                time: "18:30".to_string(),
+                result: "3-2".to_string(),
+                score_type: ScoreType::Final,
+                is_overtime: true,
+                is_shootout: false,
+                serie: "RUNKOSARJA".to_string(),
+                goal_events: vec![
+                    goal(5, "Aho", true, &["YV"]),
+                    goal(22, "Lehtonen", false, &[]),
+                ],
+                played_time: 3900,
+                start: "2024-01-15T18:30:00Z".to_string(),
+                play_off_phase: None,
+                play_off_pair: None,
+                play_off_req_wins: None,
+                series_score: None,
+                game_id: 1,
+                season: 2024,
+                is_placeholder: false,
+                pre_game: None,
+                annotations: vec![],
+            },
+        ));
+
+        let frame = page.compose_grid(&mut std::io::stdout()).unwrap();
+        let rows = grid_text(&frame);
+        assert_eq!(rows.len(), 24);
+        assert!(rows.iter().all(|row| row.chars().count() == 40));
+
+        assert!(rows[0].starts_with(" P221 SM-LIIGA"), "got {:?}", rows[0]);
+        assert!(rows[1].contains("JÄÄKIEKKO"));
+        assert_eq!(rows[3], "🬂".repeat(40));
+        assert!(rows[4].starts_with("  SM-LIIGA"));
+        assert!(
+            rows[5].starts_with("  Tappara       - HIFK"),
+            "got {:?}",
+            rows[5]
+        );
+        assert!(rows[5].ends_with(&format!("3-2 {}", t("result.overtime"))));
+        assert!(rows[6].starts_with("   5 Aho YV"), "got {:?}", rows[6]);
+        let away: String = rows[6].chars().skip(AWAY_COLUMN - 1).collect();
+        assert_eq!(away.trim_end(), "22 Lehtonen");
+        assert!(rows[23].contains(t("footer.quit")));
+    }
+
+    #[test]
+    fn test_grid_paginates_into_content_rows() {
+        let mut page = teletext_page();
+        for _ in 0..40 {
+            page.add_error_message("Rivi");
+        }
+        // Every message takes two rows of the 17
+        assert_eq!(page.total_pages(), 40_usize.div_ceil(8));
+    }
+
+    #[test]
+    fn test_once_grid_grows_with_content() {
+        let mut page = TeletextPage::new(
+            221,
+            "JÄÄKIEKKO".to_string(),
+            "SM-LIIGA".to_string(),
+            true,
+            false,
+            true,
+            false,
+            false,
+        );
+        page.set_teletext_mode(true);
+        for _ in 0..20 {
+            page.add_error_message("Rivi");
+        }
+        let frame = page.compose_grid(&mut std::io::stdout()).unwrap();
+        assert_eq!(frame.rows().next().map(<[ScreenCell]>::len), Some(40));
+        assert!(frame.rows().count() > 24);
+    }
+
+    #[test]
+    fn test_compact_or_wide_mode_leaves_teletext_mode() {
+        let mut page = teletext_page();
+        page.set_compact_mode(true).unwrap();
+        assert!(!page.is_teletext_mode());
+        page.set_teletext_mode(true);
+        assert!(page.is_teletext_mode() && !page.is_compact_mode());
+        page.set_wide_mode(true).unwrap();
+        assert!(!page.is_teletext_mode());
+    }
+}
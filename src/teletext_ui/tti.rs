@@ -0,0 +1,465 @@
+// src/teletext_ui/tti.rs - Pages as TTI files for teletext inserters
+//
+// TTI is the page file format of the MRG Systems inserters that teletext
+// hobbyist tools (vbit2, wxTED, web viewers) read. A page is a set of
+// `OL,<row>,<text>` lines holding the 40 bytes of each teletext row, with
+// colours, mosaics and double height set by spacing control codes that take
+// up a character cell each.
+//
+// Every sub-page is laid out on the 40x24 grid of teletext mode and each
+// row of cells is turned into teletext bytes: a colour change becomes a
+// control code in the blank cell before it, block mosaics become G1 mosaic
+// characters and the text is written in the Swedish/Finnish national option
+// character set, which has the Å, Ä and Ö of the team and player names.
+
+use super::core::TeletextPage;
+use super::screen_diff::{ScreenCell, ScreenFrame};
+use super::teletext_grid::{GRID_WIDTH, sextant_bits};
+use crate::error::AppError;
+use crate::i18n::t;
+use crate::ui::teletext::colors::ansi_rgb;
+
+/// Teletext national option C12-C14 for the Swedish/Finnish character set
+const NATIONAL_OPTION_SWEDISH_FINNISH: u16 = 0b010;
+
+/// Page status: transmit the page (bit 15), national option in bits 7-9
+const PAGE_STATUS: u16 = 0x8000 | NATIONAL_OPTION_SWEDISH_FINNISH << 7;
+
+/// Seconds each sub-page is shown before the inserter moves to the next
+const CYCLE_SECONDS: u8 = 8;
+
+/// Fastext links: red, green and yellow go to the games, standings and
+/// bracket pages, cyan and the index link to the index page 100
+const FASTEXT_LINKS: &str = "221,222,223,100,8FF,100";
+
+/// Spacing attributes (ETS 300 706, 12.2)
+const ALPHA_COLOUR: u8 = 0x00;
+const DOUBLE_HEIGHT: u8 = 0x0D;
+const MOSAIC_COLOUR: u8 = 0x10;
+const BLACK_BACKGROUND: u8 = 0x1C;
+const NEW_BACKGROUND: u8 = 0x1D;
+
+/// Teletext colours, as numbered in the colour control codes
+const BLACK: u8 = 0;
+const RED: u8 = 1;
+const GREEN: u8 = 2;
+const YELLOW: u8 = 3;
+const BLUE: u8 = 4;
+const WHITE: u8 = 7;
+
+/// Renders every sub-page of `page` as one TTI file. Row 0, the page
+/// header with the page number and clock, is left to the inserter.
+pub fn render_tti(page: &mut TeletextPage) -> Result<String, AppError> {
+    let frames = grid_subpages(page)?;
+    let page_number = page.page_number();
+    let title = page.title.clone();
+
+    let mut out = String::new();
+    out.push_str(&format!("DE,SM-LIIGA {page_number}\r\n"));
+    if frames.len() > 1 {
+        out.push_str(&format!("CT,{CYCLE_SECONDS},T\r\n"));
+    }
+    for (index, frame) in frames.iter().enumerate() {
+        // A lone page is sub-page 00, a carousel counts from 01
+        let subpage = if frames.len() > 1 { index + 1 } else { 0 };
+        out.push_str(&format!("PN,{page_number}{subpage:02}\r\n"));
+        out.push_str(&format!("SC,{subpage:04}\r\n"));
+        out.push_str(&format!("PS,{PAGE_STATUS:04X}\r\n"));
+        out.push_str("RE,0\r\n");
+
+        push_row(&mut out, 1, &title_row(&title));
+        // Row 2 shows the lower half of the double-height title, and the
+        // grid is laid out without its footer: the Fastext row replaces it
+        for (row, cells) in frame.rows().enumerate().skip(3) {
+            push_row(&mut out, row, &encode_row(cells));
+        }
+        push_row(&mut out, 24, &fastext_row());
+        out.push_str(&format!("FL,{FASTEXT_LINKS}\r\n"));
+    }
+    Ok(out)
+}
+
+/// Lays out every sub-page of `page` on the teletext grid, without the
+/// footer. The page's own layout and position are restored afterwards.
+fn grid_subpages(page: &mut TeletextPage) -> Result<Vec<ScreenFrame>, AppError> {
+    let saved = (
+        page.teletext_mode,
+        page.compact_mode,
+        page.wide_mode,
+        page.ignore_height_limit,
+        page.show_footer,
+        page.current_page,
+    );
+    let live_clock_cells = page.live_clock_cells.take();
+
+    page.teletext_mode = true;
+    page.compact_mode = false;
+    page.wide_mode = false;
+    page.ignore_height_limit = false;
+    page.show_footer = false;
+    let frames = (0..page.total_pages())
+        .map(|index| {
+            page.current_page = index;
+            page.compose_grid(&mut std::io::stdout())
+        })
+        .collect();
+
+    (
+        page.teletext_mode,
+        page.compact_mode,
+        page.wide_mode,
+        page.ignore_height_limit,
+        page.show_footer,
+        page.current_page,
+    ) = saved;
+    page.live_clock_cells.replace(live_clock_cells);
+    frames
+}
+
+/// Writes an `OL` line unless the row is blank.
+fn push_row(out: &mut String, row: usize, bytes: &[u8]) {
+    if bytes.iter().all(|&byte| byte == b' ') {
+        return;
+    }
+    out.push_str(&format!("OL,{row},"));
+    for &byte in bytes {
+        // Control codes are written escaped, as ESC and the code + 0x40
+        if byte < 0x20 {
+            out.push('\x1b');
+            out.push(char::from(byte + 0x40));
+        } else {
+            out.push(char::from(byte));
+        }
+    }
+    out.push_str("\r\n");
+}
+
+/// The title row: the title in double height, blue on green, sloping into
+/// the blue of the header in mosaics.
+fn title_row(title: &str) -> Vec<u8> {
+    let mut row = vec![
+        ALPHA_COLOUR | GREEN,
+        NEW_BACKGROUND,
+        ALPHA_COLOUR | BLUE,
+        DOUBLE_HEIGHT,
+    ];
+    row.extend(title.chars().take(28).map(g0_byte));
+    row.extend([
+        b' ',
+        MOSAIC_COLOUR | BLUE,
+        NEW_BACKGROUND,
+        MOSAIC_COLOUR | GREEN,
+        mosaic_byte(0b111101),
+        mosaic_byte(0b010000),
+    ]);
+    row.resize(usize::from(GRID_WIDTH), b' ');
+    row
+}
+
+/// The Fastext prompt row, in the colours of the keys that follow the
+/// `FL` links.
+fn fastext_row() -> Vec<u8> {
+    let mut row = Vec::with_capacity(usize::from(GRID_WIDTH));
+    for (colour, label) in [
+        (RED, t("phase.games")),
+        (GREEN, t("page.standings")),
+        (YELLOW, t("page.playoffs")),
+    ] {
+        row.push(ALPHA_COLOUR | colour);
+        row.extend(label.chars().map(g0_byte));
+    }
+    row.resize(usize::from(GRID_WIDTH), b' ');
+    row
+}
+
+/// What a cell needs from the row's attributes: colour, mosaic or text,
+/// and background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Attributes {
+    fg: u8,
+    mosaic: bool,
+    bg: u8,
+}
+
+impl Default for Attributes {
+    /// Every row starts in white text on black
+    fn default() -> Self {
+        Self {
+            fg: WHITE,
+            mosaic: false,
+            bg: BLACK,
+        }
+    }
+}
+
+/// The teletext character for a cell, and whether it is a mosaic.
+fn cell_byte(cell: &ScreenCell) -> (u8, bool) {
+    match cell.ch {
+        // The separator lines of the standings, as a mosaic middle row
+        '─' => (mosaic_byte(0b001100), true),
+        ch => sextant_bits(ch).map_or((g0_byte(ch), false), |bits| (mosaic_byte(bits), true)),
+    }
+}
+
+/// Teletext bytes for one row of grid cells. Attribute changes are written
+/// into the blank cells before the cell that needs them; where there are
+/// too few, they take the place of the characters from that cell on.
+fn encode_row(cells: &[ScreenCell]) -> Vec<u8> {
+    let mut row: Vec<u8> = Vec::with_capacity(cells.len());
+    let mut current = Attributes::default();
+    // Blank cells at the end of `row` that a control code may replace
+    let mut free = 0;
+    // Control codes still waiting for a cell
+    let mut pending: Vec<u8> = Vec::new();
+
+    for cell in cells {
+        let (byte, mosaic) = cell_byte(cell);
+        let blank = byte == b' ';
+        let wanted = Attributes {
+            // A blank cell shows only its background
+            fg: if blank {
+                current.fg
+            } else {
+                teletext_colour(cell.style.fg, WHITE)
+            },
+            mosaic: if blank { current.mosaic } else { mosaic },
+            bg: teletext_colour(cell.style.bg, BLACK),
+        };
+
+        if wanted != current {
+            let codes = attribute_codes(current, wanted);
+            let placed = codes.len().min(free);
+            row.truncate(row.len() - placed);
+            row.extend(&codes[..placed]);
+            pending.extend(&codes[placed..]);
+            free = 0;
+            current = wanted;
+        }
+
+        if let Some(code) = (!pending.is_empty()).then(|| pending.remove(0)) {
+            row.push(code);
+        } else {
+            row.push(byte);
+            free = if blank { free + 1 } else { 0 };
+        }
+    }
+    row
+}
+
+/// Control codes that change `from` into `to`. A new background is the
+/// current foreground colour, so it is set first and the foreground after.
+fn attribute_codes(from: Attributes, to: Attributes) -> Vec<u8> {
+    let colour_code = |attributes: Attributes, colour: u8| {
+        colour
+            | if attributes.mosaic {
+                MOSAIC_COLOUR
+            } else {
+                ALPHA_COLOUR
+            }
+    };
+    let mut codes = Vec::new();
+    let mut current = from;
+    if to.bg != current.bg {
+        if to.bg == BLACK {
+            codes.push(BLACK_BACKGROUND);
+        } else {
+            current.fg = to.bg;
+            codes.extend([colour_code(current, to.bg), NEW_BACKGROUND]);
+        }
+        current.bg = to.bg;
+    }
+    if (to.fg, to.mosaic) != (current.fg, current.mosaic) {
+        codes.push(colour_code(to, to.fg));
+    }
+    codes
+}
+
+/// The teletext colour nearest to a 256-color code; every channel is on or off.
+fn teletext_colour(code: Option<u8>, default: u8) -> u8 {
+    code.map_or(default, |code| {
+        let (r, g, b) = ansi_rgb(code);
+        u8::from(r >= 0x40) | u8::from(g >= 0x40) << 1 | u8::from(b >= 0x40) << 2
+    })
+}
+
+/// The G1 mosaic character for a 2×3 pattern (bit 0 top left, bit 5
+/// bottom right). Bit 5 is at 0x40 so that mosaics avoid the capitals.
+fn mosaic_byte(bits: u8) -> u8 {
+    0x20 | (bits & 0x1f) | (bits & 0x20) << 1
+}
+
+/// The byte for `ch` in the Swedish/Finnish national option set. Its national
+/// letters replace some ASCII punctuation, which gets a stand-in, and other
+/// letters lose their accents.
+fn g0_byte(ch: char) -> u8 {
+    let ch = match ch {
+        '#' => return 0x23,
+        '¤' => return 0x24,
+        'É' => return 0x40,
+        'Ä' => return 0x5B,
+        'Ö' => return 0x5C,
+        'Å' => return 0x5D,
+        'Ü' => return 0x5E,
+        '_' => return 0x5F,
+        'é' => return 0x60,
+        'ä' => return 0x7B,
+        'ö' => return 0x7C,
+        'å' => return 0x7D,
+        'ü' => return 0x7E,
+        '[' | '{' => '(',
+        ']' | '}' => ')',
+        '|' => '!',
+        '\\' => '/',
+        '^' | '`' => '\'',
+        '~' | '–' | '—' | '↓' => '-',
+        '↑' => '+',
+        '←' => '<',
+        '→' => '>',
+        '$' => 'S',
+        '@' => 'a',
+        '✓' => '*',
+        'á' | 'à' | 'â' | 'ã' => 'a',
+        'Á' | 'À' | 'Â' | 'Ã' => 'A',
+        'è' | 'ê' | 'ë' => 'e',
+        'È' | 'Ê' | 'Ë' => 'E',
+        'í' | 'ì' | 'î' | 'ï' => 'i',
+        'ó' | 'ò' | 'ô' | 'õ' | 'ø' => 'o',
+        'Ó' | 'Ò' | 'Ô' | 'Õ' | 'Ø' => 'O',
+        'ú' | 'ù' | 'û' => 'u',
+        'ý' => 'y',
+        'š' => 's',
+        'Š' => 'S',
+        'ž' => 'z',
+        'Ž' => 'Z',
+        'č' | 'ć' => 'c',
+        'Č' | 'Ć' => 'C',
+        'ñ' | 'ń' => 'n',
+        ch => ch,
+    };
+    if ch.is_ascii() && !ch.is_ascii_control() {
+        ch as u8
+    } else {
+        b'?'
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::teletext_ui::screen_diff::CellStyle;
+
+    fn cells(text: &str, fg: Option<u8>, bg: Option<u8>) -> Vec<ScreenCell> {
+        text.chars()
+            .map(|ch| ScreenCell {
+                ch,
+                style: CellStyle {
+                    fg,
+                    bg,
+                    ..CellStyle::default()
+                },
+            })
+            .collect()
+    }
+
+    fn long_page() -> TeletextPage {
+        let mut page = TeletextPage::new(
+            222,
+            "JÄÄKIEKKO".to_string(),
+            "SARJATAULUKKO".to_string(),
+            false,
+            false,
+            true,
+            false,
+            false,
+        );
+        for i in 1..=15 {
+            page.add_error_message(&format!("Kärpät {i}"));
+        }
+        page
+    }
+
+    #[test]
+    fn test_national_characters_and_stand_ins() {
+        let bytes: Vec<u8> = "Kärpät ÅÖ [x]".chars().map(g0_byte).collect();
+        assert_eq!(bytes, b"K{rp{t ]\\ (x)");
+        assert_eq!(g0_byte('ł'), b'?');
+    }
+
+    #[test]
+    fn test_mosaic_bytes() {
+        assert_eq!(mosaic_byte(0), 0x20);
+        assert_eq!(mosaic_byte(0b000011), 0x23);
+        assert_eq!(mosaic_byte(0b111111), 0x7F);
+        assert_eq!(teletext_colour(Some(21), WHITE), BLUE);
+        assert_eq!(teletext_colour(Some(226), WHITE), YELLOW);
+        assert_eq!(teletext_colour(Some(240), BLACK), WHITE);
+        assert_eq!(teletext_colour(None, WHITE), WHITE);
+    }
+
+    #[test]
+    fn test_colour_change_goes_in_the_blank_before_it() {
+        let mut row = cells("  Tappara ", None, None);
+        row.extend(cells("3-2", Some(46), None));
+        assert_eq!(encode_row(&row), b"  Tappara\x023-2");
+    }
+
+    #[test]
+    fn test_background_needs_three_codes() {
+        let mut row = cells("   ", None, None);
+        row.extend(cells("AB", Some(21), Some(46)));
+        assert_eq!(encode_row(&row), [0x02, NEW_BACKGROUND, 0x04, b'A', b'B']);
+
+        // Without room the codes take the place of the first characters
+        let row = cells("ABCD", Some(21), Some(46));
+        assert_eq!(encode_row(&row), [0x02, NEW_BACKGROUND, 0x04, b'D']);
+    }
+
+    #[test]
+    fn test_mosaics_switch_to_graphics() {
+        let row = cells(" 🬂🬂", Some(21), None);
+        assert_eq!(encode_row(&row), [MOSAIC_COLOUR | BLUE, 0x23, 0x23]);
+    }
+
+    #[test]
+    fn test_tti_file_has_subpages_and_control_codes() {
+        let mut page = long_page();
+        let before = page.total_pages();
+        let tti = render_tti(&mut page).unwrap();
+
+        assert!(tti.starts_with("DE,SM-LIIGA 222\r\nCT,8,T\r\nPN,22201\r\nSC,0001\r\nPS,8100\r\n"));
+        assert!(tti.contains("PN,22202\r\nSC,0002\r\n"));
+        // Double-height title in blue on green
+        assert!(tti.contains("OL,1,\x1bB\x1b]\x1bD\x1bMJ[[KIEKKO"));
+        assert!(tti.contains("K{rp{t 1"));
+        assert!(tti.contains("FL,221,222,223,100,8FF,100\r\n"));
+        assert!(tti.lines().all(|line| line.is_ascii()));
+        assert!(tti.lines().all(|line| {
+            line.strip_prefix("OL,")
+                .and_then(|rest| rest.split_once(','))
+                .is_none_or(|(_, text)| text.replace('\x1b', "").len() == 40)
+        }));
+
+        // The page itself is left as it was
+        assert_eq!(page.total_pages(), before);
+        assert!(!page.is_teletext_mode());
+    }
+
+    #[test]
+    fn test_single_page_is_subpage_zero() {
+        let mut page = TeletextPage::new(
+            221,
+            "JÄÄKIEKKO".to_string(),
+            "SM-LIIGA".to_string(),
+            false,
+            true,
+            false,
+            false,
+            false,
+        );
+        page.add_error_message("Ei otteluita");
+        let tti = render_tti(&mut page).unwrap();
+        assert!(tti.contains("PN,22100\r\nSC,0000\r\n"));
+        assert!(!tti.contains("CT,"));
+        assert!(tti.contains("Ei otteluita"));
+    }
+}
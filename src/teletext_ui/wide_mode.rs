@@ -278,6 +278,8 @@ mod tests {
             goal_events: vec![],
             played_time: 3600,
             series_score: None,
+            pre_game: None,
+            annotations: Vec::new(),
         }
     }
 
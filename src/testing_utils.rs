@@ -23,7 +23,11 @@ impl TestDataBuilder {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         }
     }
 
@@ -51,7 +55,11 @@ impl TestDataBuilder {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         }
     }
 
@@ -73,7 +81,11 @@ impl TestDataBuilder {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         }
     }
 
@@ -95,7 +107,11 @@ impl TestDataBuilder {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         }
     }
 
@@ -117,7 +133,11 @@ impl TestDataBuilder {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         }
     }
 
@@ -139,7 +159,11 @@ impl TestDataBuilder {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: true,
+            pre_game: None,
+            annotations: vec![],
         }
     }
 
@@ -0,0 +1,51 @@
+//! Favourite teams, whose games are listed first within their group of the
+//! game list.
+
+use super::abbreviations::get_team_abbreviation;
+use crate::data_fetcher::models::GameData;
+use std::sync::OnceLock;
+
+static FAVOURITE_TEAMS: OnceLock<Vec<String>> = OnceLock::new();
+
+/// Sets the favourite teams for every page built from now on. Only the
+/// first call has an effect.
+pub fn set_favourite_teams(teams: &[String]) {
+    let _ = FAVOURITE_TEAMS.set(teams.to_vec());
+}
+
+/// Whether either team of `game` is one of the favourites set with
+/// [`set_favourite_teams`].
+pub fn is_favourite_game(game: &GameData) -> bool {
+    let favourites = FAVOURITE_TEAMS.get().map_or(&[][..], Vec::as_slice);
+    is_favourite_team(favourites, &game.home_team) || is_favourite_team(favourites, &game.away_team)
+}
+
+/// Whether `team_name` matches one of `favourites` by name or abbreviation,
+/// ignoring case.
+pub fn is_favourite_team(favourites: &[String], team_name: &str) -> bool {
+    let abbreviation = get_team_abbreviation(team_name);
+    favourites.iter().any(|favourite| {
+        let favourite = favourite.trim();
+        favourite.to_lowercase() == team_name.to_lowercase()
+            || favourite.to_uppercase() == abbreviation
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_name_or_abbreviation_ignoring_case() {
+        let favourites = vec![
+            "tappara".to_string(),
+            "IFK".to_string(),
+            "ässät".to_string(),
+        ];
+        assert!(is_favourite_team(&favourites, "Tappara"));
+        assert!(is_favourite_team(&favourites, "HIFK"));
+        assert!(is_favourite_team(&favourites, "Ässät"));
+        assert!(!is_favourite_team(&favourites, "TPS"));
+        assert!(!is_favourite_team(&[], "Tappara"));
+    }
+}
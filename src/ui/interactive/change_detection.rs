@@ -5,6 +5,8 @@
 
 use crate::data_fetcher::models::bracket::PlayoffBracket;
 use crate::data_fetcher::models::standings::StandingsEntry;
+use crate::data_fetcher::processors::lineup::GameLineups;
+use crate::data_fetcher::processors::timeline::GameTimeline;
 use crate::data_fetcher::{GameData, has_live_games_from_game_data};
 use crate::teletext_ui::ScoreType;
 use std::collections::hash_map::DefaultHasher;
@@ -35,6 +37,7 @@ pub(super) fn calculate_games_hash(games: &[GameData]) -> u64 {
             score.away_team_wins.hash(&mut hasher);
             score.req_wins.hash(&mut hasher);
         }
+        game.pre_game.hash(&mut hasher);
 
         // Hash only essential goal event fields for efficient change detection
         // These fields capture the most important changes: new goals, score updates, and timing
@@ -72,6 +75,22 @@ pub(super) fn calculate_bracket_hash(bracket: &PlayoffBracket) -> u64 {
     hasher.finish()
 }
 
+/// Calculates a hash of a game timeline for change detection.
+pub(super) fn calculate_game_detail_hash(index: usize, timeline: &GameTimeline) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    index.hash(&mut hasher);
+    timeline.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Calculates a hash of a game's lineups for change detection.
+pub(super) fn calculate_game_lineup_hash(index: usize, lineups: &GameLineups) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    index.hash(&mut hasher);
+    lineups.hash(&mut hasher);
+    hasher.finish()
+}
+
 /// Helper function to check if a game is in the future
 fn is_future_game(game: &GameData) -> bool {
     game.score_type == ScoreType::Scheduled
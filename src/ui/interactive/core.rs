@@ -3,34 +3,52 @@
 //! This module contains the main interactive UI loop and all UI-related helper functions.
 //! It handles user input, screen updates, page creation, and the main application flow.
 
+use crate::config::DisplayMode;
 use crate::error::AppError;
+use std::path::PathBuf;
 use std::time::Duration;
 
 // Import utilities from sibling modules
 use super::event_handler::{EventHandler, EventResult};
 use super::refresh_coordinator::{RefreshCoordinator, RefreshCycleConfig};
-use super::state_manager::InteractiveState;
+use super::session::SessionState;
+use super::state_manager::{InteractiveState, ViewMode};
 use super::terminal_manager::{TerminalConfig, TerminalManager};
 
 // Teletext page constants (removed unused constants)
 
-// UI timing constants (removed unused constants)
+// UI timing constants
+/// How long a notice stays above the footer
+const NOTICE_SECONDS: u64 = 4;
 
-/// Runs the interactive UI with adaptive polling and change detection
+/// Runs the interactive UI with adaptive polling and change detection.
+/// Opens the view and page of `session` when given, and returns the session
+/// to save when the user quits. Page snapshots are written into `export_dir`
+/// and goal clips are opened with `video_player`.
+#[allow(clippy::too_many_arguments)]
 pub async fn run_interactive_ui(
     date: Option<String>,
     disable_links: bool,
     debug_mode: bool,
     min_refresh_interval: Option<u64>,
-    compact_mode: bool,
-    wide_mode: bool,
-) -> Result<(), AppError> {
+    display_mode: DisplayMode,
+    session: Option<&SessionState>,
+    export_dir: PathBuf,
+    video_player: Option<String>,
+) -> Result<SessionState, AppError> {
     // Create terminal manager and setup terminal for interactive mode
     let terminal_manager = TerminalManager::with_config(TerminalConfig { debug_mode });
     let mut stdout = terminal_manager.setup_terminal()?;
 
     // Initialize all state through the state manager
     let mut state = InteractiveState::new(date);
+    state.ui.display_mode = display_mode;
+    state.ui.export_dir = export_dir;
+    state.ui.video_player = video_player;
+    if let Some(session) = session {
+        tracing::info!("Restoring last session: {session:?}");
+        session.restore(&mut state);
+    }
 
     // Create event handler with appropriate configuration
     let event_handler = if debug_mode {
@@ -43,11 +61,12 @@ pub async fn run_interactive_ui(
     let mut refresh_coordinator = RefreshCoordinator::new();
 
     // Create refresh cycle configuration
-    let refresh_config = RefreshCycleConfig {
+    let mut refresh_config = RefreshCycleConfig {
         min_refresh_interval,
         disable_links,
-        compact_mode,
-        wide_mode,
+        compact_mode: display_mode == DisplayMode::Compact,
+        wide_mode: display_mode == DisplayMode::Wide,
+        headless: false,
     };
 
     // Track date to detect date navigation and reset transient empty counter
@@ -84,6 +103,24 @@ pub async fn run_interactive_ui(
             state.request_render();
         }
 
+        // Clear a notice once it has been shown long enough
+        if state.ui.notice.is_some()
+            && state.timers.last_notice.elapsed() >= Duration::from_secs(NOTICE_SECONDS)
+        {
+            state.ui.notice = None;
+            if let Some(page) = state.current_page_mut() {
+                page.set_notice(None);
+            }
+            state.request_render();
+        }
+
+        // Pages built from here on use the display mode chosen at runtime
+        refresh_config.compact_mode = state.ui.display_mode == DisplayMode::Compact;
+        refresh_config.wide_mode = state.ui.display_mode == DisplayMode::Wide;
+        crate::teletext_ui::teletext_grid::set_grid_mode(
+            state.ui.display_mode == DisplayMode::Teletext,
+        );
+
         // Check if auto-refresh should be triggered
         if refresh_coordinator.should_trigger_refresh(&state, &refresh_config) {
             state.request_refresh();
@@ -121,12 +158,14 @@ pub async fn run_interactive_ui(
                 state.set_current_page(new_page);
             }
 
-            // Propagate bracket data availability and initial date to the current page for footer rendering
+            // Propagate bracket data availability, initial date and any notice to the current page for footer rendering
             let has_bracket = state.has_bracket_data();
             let initial_date = state.initial_fetched_date().clone();
+            let notice = state.ui.notice.clone();
             if let Some(page) = state.current_page_mut() {
                 page.set_has_bracket_data(has_bracket);
                 page.set_initial_fetched_date(initial_date);
+                page.set_notice(notice);
             }
 
             // Process refresh results and update state
@@ -156,6 +195,12 @@ pub async fn run_interactive_ui(
                 tracing::debug!("UI rendered with buffering");
             }
             state.clear_render_flag();
+        } else if matches!(state.current_view(), ViewMode::Games)
+            && let Some(page) = state.current_page()
+            && page.has_live_clocks()
+        {
+            // Tick ongoing game clocks between refreshes without a full redraw
+            page.render_live_clock_updates(&mut stdout)?;
         }
 
         // Process events using the event handler
@@ -182,5 +227,5 @@ pub async fn run_interactive_ui(
 
     // Cleanup terminal
     terminal_manager.cleanup_terminal(stdout)?;
-    Ok(())
+    Ok(SessionState::capture(&state, state.ui.display_mode))
 }
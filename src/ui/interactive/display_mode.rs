@@ -0,0 +1,322 @@
+//! Switching the game list layout at runtime
+//!
+//! The `v` key cycles normal → compact → wide → teletext. A mode the terminal cannot
+//! show is skipped, and the page shows a teletext-style notice naming the
+//! reason and the mode used instead.
+
+use crossterm::terminal;
+
+use crate::config::DisplayMode;
+use crate::i18n::{t, tf};
+use crate::teletext_ui::TeletextPage;
+use crate::teletext_ui::teletext_grid::{GRID_HEIGHT, GRID_WIDTH};
+use crate::teletext_ui::wide_mode::{WideModeConfig, WideModeManager, WideModeValidation};
+use crate::ui::teletext::compact_display::{
+    CompactDisplayConfig, CompactModeValidation, TerminalWidthValidation,
+};
+
+/// The mode after `mode` in the cycle normal → compact → wide → teletext → normal.
+fn next_in_cycle(mode: DisplayMode) -> DisplayMode {
+    match mode {
+        DisplayMode::Normal => DisplayMode::Compact,
+        DisplayMode::Compact => DisplayMode::Wide,
+        DisplayMode::Wide => DisplayMode::Teletext,
+        DisplayMode::Teletext => DisplayMode::Normal,
+    }
+}
+
+/// Teletext name of `mode` for notices.
+fn mode_name(mode: DisplayMode) -> &'static str {
+    match mode {
+        DisplayMode::Normal => t("display_mode.normal"),
+        DisplayMode::Compact => t("display_mode.compact"),
+        DisplayMode::Wide => t("display_mode.wide"),
+        DisplayMode::Teletext => t("display_mode.teletext"),
+    }
+}
+
+/// Why compact mode can't be used, given the terminal width check and the
+/// compatibility of the current page.
+fn compact_rejection(
+    width: &TerminalWidthValidation,
+    compatibility: Option<&CompactModeValidation>,
+) -> Option<String> {
+    if let TerminalWidthValidation::Insufficient {
+        current_width,
+        required_width,
+        ..
+    } = width
+    {
+        return Some(tf(
+            "display_mode.too_narrow",
+            &[
+                ("mode", &mode_name(DisplayMode::Compact)),
+                ("required", required_width),
+                ("current", current_width),
+            ],
+        ));
+    }
+    match compatibility {
+        Some(CompactModeValidation::Incompatible { issues }) => Some(tf(
+            "display_mode.incompatible",
+            &[
+                ("mode", &mode_name(DisplayMode::Compact)),
+                ("issue", &issues.first().map_or("", String::as_str)),
+            ],
+        )),
+        _ => None,
+    }
+}
+
+/// Why wide mode can't be used on a terminal with this validation result.
+fn wide_rejection(validation: &WideModeValidation) -> Option<String> {
+    match validation {
+        WideModeValidation::TooNarrow {
+            terminal_width,
+            required_width,
+            ..
+        } => Some(tf(
+            "display_mode.too_narrow",
+            &[
+                ("mode", &mode_name(DisplayMode::Wide)),
+                ("required", required_width),
+                ("current", terminal_width),
+            ],
+        )),
+        WideModeValidation::Disabled | WideModeValidation::Suitable { .. } => None,
+    }
+}
+
+/// Why a `width`×`height` terminal can't hold the teletext grid.
+fn teletext_rejection(width: u16, height: u16) -> Option<String> {
+    if width < GRID_WIDTH {
+        Some(tf(
+            "display_mode.too_narrow",
+            &[
+                ("mode", &mode_name(DisplayMode::Teletext)),
+                ("required", &GRID_WIDTH),
+                ("current", &width),
+            ],
+        ))
+    } else if height < GRID_HEIGHT {
+        Some(tf(
+            "display_mode.too_short",
+            &[
+                ("mode", &mode_name(DisplayMode::Teletext)),
+                ("required", &GRID_HEIGHT),
+                ("current", &height),
+            ],
+        ))
+    } else {
+        None
+    }
+}
+
+/// Why the current terminal (and `page`) can't show `mode`, if it can't.
+fn rejection(mode: DisplayMode, page: Option<&TeletextPage>) -> Option<String> {
+    match mode {
+        DisplayMode::Normal => None,
+        DisplayMode::Compact => {
+            let width = terminal::size().map_or(80, |(width, _)| usize::from(width));
+            compact_rejection(
+                &CompactDisplayConfig::default().validate_terminal_width(width),
+                page.map(TeletextPage::validate_compact_mode_compatibility)
+                    .as_ref(),
+            )
+        }
+        DisplayMode::Wide => {
+            let manager = WideModeManager::new(WideModeConfig {
+                enabled: true,
+                ..WideModeConfig::default()
+            });
+            wide_rejection(&manager.validate_terminal_for_wide_mode(false))
+        }
+        DisplayMode::Teletext => {
+            let (width, height) = terminal::size().unwrap_or((80, 24));
+            teletext_rejection(width, height)
+        }
+    }
+}
+
+/// The next mode after `current` that `rejection` accepts, and a notice
+/// if a mode had to be skipped. Normal mode is always accepted.
+fn select_next(
+    current: DisplayMode,
+    rejection: impl Fn(DisplayMode) -> Option<String>,
+) -> (DisplayMode, Option<String>) {
+    let mut reason = None;
+    let mut mode = next_in_cycle(current);
+    while mode != DisplayMode::Normal {
+        match rejection(mode) {
+            Some(rejected) => {
+                reason = Some(rejected);
+                mode = next_in_cycle(mode);
+            }
+            None => break,
+        }
+    }
+    let notice = reason.map(|reason| {
+        tf(
+            "display_mode.fallback",
+            &[("reason", &reason), ("mode", &mode_name(mode))],
+        )
+    });
+    (mode, notice)
+}
+
+/// Shows `page` in `mode`, keeping its page position (clamped to the new
+/// page count).
+pub fn apply_display_mode(page: &mut TeletextPage, mode: DisplayMode) {
+    let position = page.get_current_page();
+    let result = match mode {
+        DisplayMode::Normal => page
+            .set_compact_mode(false)
+            .and_then(|()| page.set_wide_mode(false))
+            .map(|()| page.set_teletext_mode(false)),
+        DisplayMode::Compact => page.set_compact_mode(true),
+        DisplayMode::Wide => page.set_wide_mode(true),
+        DisplayMode::Teletext => {
+            page.set_teletext_mode(true);
+            Ok(())
+        }
+    };
+    if let Err(e) = result {
+        tracing::warn!("Failed to switch display mode: {e}");
+    }
+    page.set_current_page(position);
+}
+
+/// Switches `display_mode` to the next mode the terminal can show and
+/// applies it to `page`.
+///
+/// # Returns
+/// * `Option<String>` - Notice to show when a mode was skipped
+pub fn cycle_display_mode(
+    display_mode: &mut DisplayMode,
+    page: Option<&mut TeletextPage>,
+) -> Option<String> {
+    let (mode, notice) = select_next(*display_mode, |mode| rejection(mode, page.as_deref()));
+    *display_mode = mode;
+    if let Some(page) = page {
+        apply_display_mode(page, mode);
+    }
+    notice
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle_order() {
+        let accept_all = |_| None;
+        assert_eq!(
+            select_next(DisplayMode::Normal, accept_all),
+            (DisplayMode::Compact, None)
+        );
+        assert_eq!(
+            select_next(DisplayMode::Compact, accept_all),
+            (DisplayMode::Wide, None)
+        );
+        assert_eq!(
+            select_next(DisplayMode::Wide, accept_all),
+            (DisplayMode::Teletext, None)
+        );
+        assert_eq!(
+            select_next(DisplayMode::Teletext, accept_all),
+            (DisplayMode::Normal, None)
+        );
+    }
+
+    #[test]
+    fn test_rejected_modes_fall_back_with_notice() {
+        let manager = WideModeManager::new(WideModeConfig {
+            enabled: true,
+            ..WideModeConfig::default()
+        });
+        let narrow = wide_rejection(&manager.validate_terminal_for_wide_mode_with_width(100));
+        assert_eq!(narrow.as_deref(), Some("LEVEÄ VAATII 128 MERKKIÄ, NYT 100"));
+
+        assert_eq!(
+            select_next(DisplayMode::Wide, |mode| (mode == DisplayMode::Teletext)
+                .then(|| teletext_rejection(80, 20))
+                .flatten()),
+            (
+                DisplayMode::Normal,
+                Some("TEKSTI-TV VAATII 24 RIVIÄ, NYT 20 - NORMAALI TILA".to_string())
+            )
+        );
+        assert_eq!(
+            teletext_rejection(30, 40).as_deref(),
+            Some("TEKSTI-TV VAATII 40 MERKKIÄ, NYT 30")
+        );
+        assert_eq!(teletext_rejection(40, 24), None);
+
+        assert_eq!(
+            select_next(DisplayMode::Compact, |mode| (mode != DisplayMode::Normal)
+                .then(|| narrow.clone().unwrap())),
+            (
+                DisplayMode::Normal,
+                Some("LEVEÄ VAATII 128 MERKKIÄ, NYT 100 - NORMAALI TILA".to_string())
+            )
+        );
+
+        // A terminal too narrow for compact mode skips straight to wide
+        let config = CompactDisplayConfig::default();
+        let too_narrow = config.validate_terminal_width(10);
+        let compact_rejected = |mode| {
+            (mode == DisplayMode::Compact)
+                .then(|| compact_rejection(&too_narrow, None))
+                .flatten()
+        };
+        let (mode, notice) = select_next(DisplayMode::Normal, compact_rejected);
+        assert_eq!(mode, DisplayMode::Wide);
+        assert!(notice.unwrap().ends_with("- LEVEÄ TILA"));
+    }
+
+    #[test]
+    fn test_incompatible_page_rejects_compact() {
+        let sufficient = CompactDisplayConfig::default().validate_terminal_width(200);
+        assert_eq!(
+            compact_rejection(&sufficient, Some(&CompactModeValidation::Compatible)),
+            None
+        );
+        let incompatible = CompactModeValidation::Incompatible {
+            issues: vec!["liikaa rivejä".to_string()],
+        };
+        assert_eq!(
+            compact_rejection(&sufficient, Some(&incompatible)).as_deref(),
+            Some("TIIVIS EI KÄY: liikaa rivejä")
+        );
+    }
+
+    #[test]
+    fn test_apply_display_mode_keeps_page_position() {
+        let mut page = TeletextPage::new(
+            221,
+            "JÄÄKIEKKO".to_string(),
+            "SM-LIIGA".to_string(),
+            false,
+            true,
+            false,
+            false,
+            false,
+        );
+        for i in 0..60 {
+            page.add_error_message(&format!("Rivi {i}"));
+        }
+        assert!(page.total_pages() > 1);
+        page.set_current_page(1);
+        let position = page.get_current_page();
+
+        apply_display_mode(&mut page, DisplayMode::Wide);
+        assert!(page.is_wide_mode() && !page.is_compact_mode());
+        apply_display_mode(&mut page, DisplayMode::Compact);
+        assert!(page.is_compact_mode() && !page.is_wide_mode());
+        apply_display_mode(&mut page, DisplayMode::Teletext);
+        assert!(page.is_teletext_mode() && !page.is_compact_mode());
+        apply_display_mode(&mut page, DisplayMode::Normal);
+        assert!(!page.is_compact_mode() && !page.is_wide_mode() && !page.is_teletext_mode());
+        assert_eq!(page.get_current_page(), position);
+    }
+}
@@ -145,8 +145,14 @@ impl EventHandler {
             preserved_games_page: &mut preserved_games_page,
             preserved_live_mode: &mut preserved_live_mode,
             has_bracket_data: state.navigation.has_bracket_data,
+            games: state.change_detection.last_games(),
             page_input: &mut state.navigation.page_input,
             last_page_input: &mut state.timers.last_page_input,
+            display_mode: &mut state.ui.display_mode,
+            notice: &mut state.ui.notice,
+            last_notice: &mut state.timers.last_notice,
+            export_dir: &state.ui.export_dir,
+            video_player: state.ui.video_player.as_deref(),
         })
         .await?;
 
@@ -167,6 +173,15 @@ impl EventHandler {
             state.change_detection.reset_bracket_hash();
         }
 
+        // Reset game detail hash when leaving the detail view or switching games
+        if matches!(
+            previous_view,
+            ViewMode::GameDetail { .. } | ViewMode::GameLineup { .. }
+        ) && current_view != previous_view
+        {
+            state.change_detection.reset_game_detail_hash();
+        }
+
         state.navigation.current_view = current_view;
         state.navigation.preserved_games_page = preserved_games_page;
         state.navigation.preserved_live_mode = preserved_live_mode;
@@ -0,0 +1,117 @@
+//! Opening goal clips from the goal video picker
+//!
+//! Clips open in the `video_player` command (e.g. `mpv` or `xdg-open`). The
+//! clip URL replaces `{url}` in the command or is appended to it. Without a
+//! player, or when it fails to start, the picker page already prints the URL
+//! and the notice says so.
+
+use std::process::{Command, Stdio};
+
+use crate::data_fetcher::models::GoalEventData;
+use crate::i18n::{t, tf};
+
+/// Splits the `template` command into the program and its arguments for
+/// opening `url`. Returns `None` for an empty command.
+fn player_command(template: &str, url: &str) -> Option<(String, Vec<String>)> {
+    let mut parts = template.split_whitespace();
+    let program = parts.next()?.to_string();
+    let mut args: Vec<String> = parts.map(str::to_string).collect();
+    if args.iter().any(|arg| arg.contains("{url}")) {
+        for arg in &mut args {
+            *arg = arg.replace("{url}", url);
+        }
+    } else {
+        args.push(url.to_string());
+    }
+    Some((program, args))
+}
+
+/// Starts `player` on `url` without waiting for it. The player gets no
+/// terminal streams so it can't draw over the page.
+fn spawn_player(player: &str, url: &str) -> std::io::Result<()> {
+    let Some((program, args)) = player_command(player, url) else {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "empty video player command",
+        ));
+    };
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    // Reap the player when it exits so it doesn't linger as a zombie
+    std::thread::spawn(move || child.wait());
+    Ok(())
+}
+
+/// Opens the clip of `goal` with `player` and returns the notice to show.
+pub(super) fn open_clip(player: Option<&str>, goal: &GoalEventData) -> String {
+    let Some(url) = goal.video_clip_url.as_deref() else {
+        return t("videos.none").to_string();
+    };
+    let Some(player) = player else {
+        tracing::info!("No video player configured, showing clip URL {url}");
+        return t("videos.no_player").to_string();
+    };
+    match spawn_player(player, url) {
+        Ok(()) => {
+            tracing::info!("Opened goal clip {url} with {player}");
+            tf(
+                "videos.opened",
+                &[("scorer", &goal.scorer_name), ("minute", &goal.minute)],
+            )
+        }
+        Err(e) => {
+            tracing::warn!("Failed to start video player {player:?}: {e}");
+            t("videos.failed").to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_player_command_appends_or_substitutes_url() {
+        let url = "https://video.example.com/1.mp4";
+        assert_eq!(
+            player_command("mpv --fs", url),
+            Some(("mpv".to_string(), vec!["--fs".to_string(), url.to_string()]))
+        );
+        assert_eq!(
+            player_command("vlc --play-and-exit {url} --fullscreen", url),
+            Some((
+                "vlc".to_string(),
+                vec![
+                    "--play-and-exit".to_string(),
+                    url.to_string(),
+                    "--fullscreen".to_string()
+                ]
+            ))
+        );
+        assert_eq!(player_command("  ", url), None);
+    }
+
+    #[test]
+    fn test_open_clip_without_player_points_to_url() {
+        let goal = GoalEventData {
+            scorer_player_id: 1,
+            scorer_name: "Aho".to_string(),
+            minute: 12,
+            home_team_score: 1,
+            away_team_score: 0,
+            is_winning_goal: false,
+            goal_types: vec![],
+            is_home_team: true,
+            video_clip_url: Some("https://video.example.com/1.mp4".to_string()),
+        };
+        assert_eq!(open_clip(None, &goal), t("videos.no_player"));
+        assert_eq!(
+            open_clip(Some("liiga-teletext-missing-player"), &goal),
+            t("videos.failed")
+        );
+    }
+}
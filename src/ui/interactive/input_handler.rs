@@ -6,14 +6,20 @@
 //! - Finding previous/next dates with games
 //! - Season boundary checking
 
-use crate::data_fetcher::{fetch_liiga_data, is_historical_date};
+use crate::config::DisplayMode;
+use crate::data_fetcher::{GameData, fetch_liiga_data, is_historical_date};
 use crate::error::AppError;
+use crate::i18n::{t, tf};
 use crate::teletext_ui::TeletextPage;
+use crate::teletext_ui::export::export_page_to_dir;
+use crate::teletext_ui::game_detail_display::goal_clips;
 use chrono::{Datelike, Local, NaiveDate, Utc};
 use crossterm::event::{self, KeyCode, KeyEventKind, KeyModifiers};
 use std::io::stdout;
+use std::path::Path;
 use std::time::{Duration, Instant};
 
+use super::display_mode::cycle_display_mode;
 use super::state_manager::ViewMode;
 
 /// Parameters for keyboard event handling
@@ -30,8 +36,17 @@ pub(super) struct KeyEventParams<'a> {
     pub preserved_games_page: &'a mut Option<usize>,
     pub preserved_live_mode: &'a mut bool,
     pub has_bracket_data: bool,
+    /// The current games list (for game detail and goal video pages)
+    pub games: &'a [GameData],
     pub page_input: &'a mut String,
     pub last_page_input: &'a mut Instant,
+    pub display_mode: &'a mut DisplayMode,
+    pub notice: &'a mut Option<String>,
+    pub last_notice: &'a mut Instant,
+    /// Directory the export key writes page snapshots into
+    pub export_dir: &'a Path,
+    /// Command goal clips are opened with
+    pub video_player: Option<&'a str>,
 }
 
 /// Checks if the given key event matches the date navigation shortcut.
@@ -296,6 +311,18 @@ async fn find_next_date_with_games(current_date: &str) -> Option<String> {
 const PAGE_GAMES: &str = "221";
 const PAGE_STANDINGS: &str = "222";
 const PAGE_BRACKET: &str = "223";
+const PAGE_OFF_SEASON: &str = "224";
+/// Game detail pages: 231 is the first game of the day, 232 the second, ...
+const PAGE_GAME_DETAIL_FIRST: u16 = 231;
+const PAGE_GAME_DETAIL_LAST: u16 = 239;
+/// Game lineup pages, numbered like the detail pages
+const PAGE_GAME_LINEUP_FIRST: u16 = 241;
+const PAGE_GAME_LINEUP_LAST: u16 = 249;
+/// Goal video pickers, numbered like the detail pages
+const PAGE_GOAL_VIDEOS_FIRST: u16 = 251;
+const PAGE_GOAL_VIDEOS_LAST: u16 = 259;
+/// Hidden diagnostics page, not listed in the footer
+const PAGE_DIAGNOSTICS: &str = "888";
 
 /// Handles a digit key press for teletext-style page number entry.
 /// Digits accumulate in the header (e.g. "22-"); after three digits the
@@ -359,8 +386,46 @@ fn handle_page_number_input(params: &mut KeyEventParams<'_>, digit: char) {
                 *params.needs_refresh = true;
             }
         }
+        PAGE_OFF_SEASON => {
+            if !matches!(*params.current_view, ViewMode::OffSeason) {
+                tracing::info!("Page entry: switching to off-season view");
+                preserve_games_page(params);
+                *params.current_view = ViewMode::OffSeason;
+                *params.needs_refresh = true;
+            }
+        }
+        PAGE_DIAGNOSTICS => {
+            if !matches!(*params.current_view, ViewMode::Diagnostics) {
+                tracing::info!("Page entry: switching to diagnostics view");
+                preserve_games_page(params);
+                *params.current_view = ViewMode::Diagnostics;
+                *params.needs_refresh = true;
+            }
+        }
         other => {
             let number = other.parse::<u16>().unwrap_or(0);
+            let target = if (PAGE_GAME_DETAIL_FIRST..=PAGE_GAME_DETAIL_LAST).contains(&number) {
+                let index = usize::from(number - PAGE_GAME_DETAIL_FIRST);
+                Some(ViewMode::GameDetail { index }).filter(|_| index < params.games.len())
+            } else if (PAGE_GAME_LINEUP_FIRST..=PAGE_GAME_LINEUP_LAST).contains(&number) {
+                let index = usize::from(number - PAGE_GAME_LINEUP_FIRST);
+                Some(ViewMode::GameLineup { index }).filter(|_| index < params.games.len())
+            } else if (PAGE_GOAL_VIDEOS_FIRST..=PAGE_GOAL_VIDEOS_LAST).contains(&number) {
+                let index = usize::from(number - PAGE_GOAL_VIDEOS_FIRST);
+                Some(ViewMode::GoalVideos { index, selected: 0 })
+                    .filter(|_| index < params.games.len())
+            } else {
+                None
+            };
+            if let Some(target) = target {
+                if *params.current_view != target {
+                    tracing::info!("Page entry: switching to {target:?}");
+                    preserve_games_page(params);
+                    *params.current_view = target;
+                    *params.needs_refresh = true;
+                }
+                return;
+            }
             tracing::info!("Page entry: page {number} not found");
             *params.current_page = Some(super::navigation_manager::create_page_not_found_page(
                 number,
@@ -369,6 +434,25 @@ fn handle_page_number_input(params: &mut KeyEventParams<'_>, digit: char) {
     }
 }
 
+/// Exports the page into `dir` and returns the notice reporting the result.
+async fn export_snapshot(page: &mut TeletextPage, dir: &Path) -> String {
+    match export_page_to_dir(page, dir).await {
+        Ok(files) => {
+            tracing::info!("Exported page snapshot: {files:?}");
+            let name = files
+                .first()
+                .and_then(|file| file.file_stem())
+                .map(|stem| format!("{}.*", stem.to_string_lossy()))
+                .unwrap_or_default();
+            tf("export.saved", &[("name", &name)])
+        }
+        Err(e) => {
+            tracing::error!("Failed to export page snapshot to {}: {e}", dir.display());
+            t("export.failed").to_string()
+        }
+    }
+}
+
 /// Handle keyboard events
 pub(super) async fn handle_key_event(mut params: KeyEventParams<'_>) -> Result<bool, AppError> {
     // Only handle key press events, ignore Release/Repeat to prevent double-toggling on Windows
@@ -393,10 +477,16 @@ pub(super) async fn handle_key_event(mut params: KeyEventParams<'_>) -> Result<b
         *params.needs_render = true;
     }
 
-    // Disable date navigation in standings and bracket views
+    // Disable date navigation in standings, bracket and game detail views
     let is_non_game_view = matches!(
         params.current_view,
-        ViewMode::Standings { .. } | ViewMode::Bracket
+        ViewMode::Standings { .. }
+            | ViewMode::Bracket
+            | ViewMode::GameDetail { .. }
+            | ViewMode::GameLineup { .. }
+            | ViewMode::GoalVideos { .. }
+            | ViewMode::OffSeason
+            | ViewMode::Diagnostics
     );
 
     // Check for date navigation first (Shift + Arrow keys)
@@ -409,7 +499,7 @@ pub(super) async fn handle_key_event(mut params: KeyEventParams<'_>) -> Result<b
 
             // Show loading indicator
             if let Some(page) = params.current_page.as_mut() {
-                page.show_loading("Etsitään edellisiä otteluita...".to_string());
+                page.show_loading(t("loading.previous_games").to_string());
                 // Force immediate render to show loading indicator
                 let mut stdout = stdout();
                 let _ = page.render_buffered(&mut stdout);
@@ -453,7 +543,7 @@ pub(super) async fn handle_key_event(mut params: KeyEventParams<'_>) -> Result<b
 
             // Show loading indicator
             if let Some(page) = params.current_page.as_mut() {
-                page.show_loading("Etsitään seuraavia otteluita...".to_string());
+                page.show_loading(t("loading.next_games").to_string());
                 // Force immediate render to show loading indicator
                 let mut stdout = stdout();
                 let _ = page.render_buffered(&mut stdout);
@@ -582,6 +672,130 @@ pub(super) async fn handle_key_event(mut params: KeyEventParams<'_>) -> Result<b
                     *params.needs_refresh = true;
                 }
             }
+            // Step through the clips on the goal video picker
+            KeyCode::Up | KeyCode::Down
+                if matches!(*params.current_view, ViewMode::GoalVideos { .. }) =>
+            {
+                let ViewMode::GoalVideos { index, selected } = *params.current_view else {
+                    return Ok(false);
+                };
+                let clip_count = params.games.get(index).map_or(0, |g| goal_clips(g).len());
+                let target = if params.key_event.code == KeyCode::Up {
+                    selected.checked_sub(1)
+                } else {
+                    Some(selected + 1).filter(|&i| i < clip_count)
+                };
+                if let Some(selected) = target {
+                    *params.current_view = ViewMode::GoalVideos { index, selected };
+                    *params.needs_refresh = true;
+                }
+            }
+            // Open the selected clip in the video player
+            KeyCode::Enter => {
+                if let ViewMode::GoalVideos { index, selected } = *params.current_view
+                    && let Some(game) = params.games.get(index)
+                    && let Some(goal) = goal_clips(game).get(selected)
+                {
+                    let notice = super::goal_videos::open_clip(params.video_player, goal);
+                    if let Some(page) = params.current_page.as_mut() {
+                        page.set_notice(Some(notice.clone()));
+                    }
+                    *params.notice = Some(notice);
+                    *params.last_notice = Instant::now();
+                    *params.needs_render = true;
+                }
+            }
+            // Step through the day's games on the game detail and lineup pages
+            KeyCode::Up | KeyCode::Down => {
+                let (index, lineup) = match *params.current_view {
+                    ViewMode::GameDetail { index } => (index, false),
+                    ViewMode::GameLineup { index } => (index, true),
+                    _ => return Ok(false),
+                };
+                let target = if params.key_event.code == KeyCode::Up {
+                    index.checked_sub(1)
+                } else {
+                    Some(index + 1).filter(|&i| i < params.games.len())
+                };
+                if let Some(target) = target {
+                    tracing::info!("Game detail: switching to game {target}");
+                    *params.current_view = if lineup {
+                        ViewMode::GameLineup { index: target }
+                    } else {
+                        ViewMode::GameDetail { index: target }
+                    };
+                    *params.needs_refresh = true;
+                }
+            }
+            // Cycle the game list layout: normal → compact → wide
+            KeyCode::Char('v') => {
+                let notice = cycle_display_mode(params.display_mode, params.current_page.as_mut());
+                tracing::info!("Display mode switched to {:?}", *params.display_mode);
+                if let Some(notice) = &notice {
+                    tracing::info!("Display mode fallback: {notice}");
+                }
+                if let Some(page) = params.current_page.as_mut() {
+                    page.set_notice(notice.clone());
+                }
+                *params.notice = notice;
+                *params.last_notice = Instant::now();
+                *params.needs_render = true;
+            }
+            // Save the page as HTML, SVG, text and TTI for sharing
+            KeyCode::Char('x') => {
+                if let Some(page) = params.current_page.as_mut() {
+                    let notice = export_snapshot(page, params.export_dir).await;
+                    page.set_notice(Some(notice.clone()));
+                    *params.notice = Some(notice);
+                    *params.last_notice = Instant::now();
+                    *params.needs_render = true;
+                }
+            }
+            // Switch between a game's events and its lineups
+            KeyCode::Char('k') => match *params.current_view {
+                ViewMode::GameDetail { index } => {
+                    tracing::info!("Game detail: showing lineups");
+                    *params.current_view = ViewMode::GameLineup { index };
+                    *params.needs_refresh = true;
+                }
+                ViewMode::GameLineup { index } => {
+                    tracing::info!("Game detail: showing events");
+                    *params.current_view = ViewMode::GameDetail { index };
+                    *params.needs_refresh = true;
+                }
+                _ => {}
+            },
+            // Open the goal video picker of the game, or return to its events
+            KeyCode::Char('g') => match *params.current_view {
+                ViewMode::GameDetail { index } | ViewMode::GameLineup { index } => {
+                    tracing::info!("Game detail: showing goal videos");
+                    *params.current_view = ViewMode::GoalVideos { index, selected: 0 };
+                    *params.needs_refresh = true;
+                }
+                ViewMode::GoalVideos { index, .. } => {
+                    tracing::info!("Game detail: showing events");
+                    *params.current_view = ViewMode::GameDetail { index };
+                    *params.needs_refresh = true;
+                }
+                _ => {}
+            },
+            KeyCode::Esc | KeyCode::Backspace => {
+                if let ViewMode::GoalVideos { index, .. } = *params.current_view {
+                    tracing::info!("Returning to game events");
+                    *params.current_view = ViewMode::GameDetail { index };
+                    *params.needs_refresh = true;
+                } else if matches!(
+                    *params.current_view,
+                    ViewMode::GameDetail { .. }
+                        | ViewMode::GameLineup { .. }
+                        | ViewMode::OffSeason
+                        | ViewMode::Diagnostics
+                ) {
+                    tracing::info!("Returning to games view");
+                    *params.current_view = ViewMode::Games;
+                    *params.needs_refresh = true;
+                }
+            }
             KeyCode::Char('t') => {
                 tracing::info!("Today's view requested");
                 *params.current_date = None;
@@ -672,6 +886,11 @@ mod tests {
         preserved_live_mode: bool,
         page_input: String,
         last_page_input: Instant,
+        display_mode: DisplayMode,
+        notice: Option<String>,
+        last_notice: Instant,
+        export_dir: std::path::PathBuf,
+        games: Vec<GameData>,
     }
 
     impl KeyEventState {
@@ -689,6 +908,11 @@ mod tests {
                 preserved_live_mode: false,
                 page_input: String::new(),
                 last_page_input: Instant::now(),
+                display_mode: DisplayMode::Normal,
+                notice: None,
+                last_notice: Instant::now(),
+                export_dir: std::path::PathBuf::from("."),
+                games: Vec::new(),
             }
         }
 
@@ -706,8 +930,14 @@ mod tests {
                 preserved_games_page: &mut self.preserved_games_page,
                 preserved_live_mode: &mut self.preserved_live_mode,
                 has_bracket_data: false,
+                games: &self.games,
                 page_input: &mut self.page_input,
                 last_page_input: &mut self.last_page_input,
+                display_mode: &mut self.display_mode,
+                notice: &mut self.notice,
+                last_notice: &mut self.last_notice,
+                export_dir: &self.export_dir,
+                video_player: None,
             }
         }
     }
@@ -738,4 +968,132 @@ mod tests {
 
         assert_eq!(state.page_input, "22");
     }
+
+    #[tokio::test]
+    async fn test_k_switches_between_game_events_and_lineups() {
+        let mut state = KeyEventState::new();
+        state.current_view = ViewMode::GameDetail { index: 2 };
+
+        let key_event = event::KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE);
+        handle_key_event(state.params(&key_event)).await.unwrap();
+        assert_eq!(state.current_view, ViewMode::GameLineup { index: 2 });
+        assert!(state.needs_refresh);
+
+        handle_key_event(state.params(&key_event)).await.unwrap();
+        assert_eq!(state.current_view, ViewMode::GameDetail { index: 2 });
+    }
+
+    #[tokio::test]
+    async fn test_goal_video_picker_keys() {
+        let mut state = KeyEventState::new();
+        let mut game = crate::testing_utils::TestDataBuilder::create_custom_game(
+            1,
+            "TPS",
+            "HIFK",
+            "2-0",
+            "runkosarja",
+        );
+        for (score, url) in [(1, Some("https://video.example.com/1.mp4")), (2, None)] {
+            let mut goal =
+                crate::testing_utils::TestDataBuilder::create_goal_event("Aho", 10, score, 0, true);
+            goal.video_clip_url = url.map(str::to_string);
+            game.goal_events.push(goal);
+        }
+        state.games = vec![game];
+        state.current_view = ViewMode::GameDetail { index: 0 };
+
+        let g = event::KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE);
+        handle_key_event(state.params(&g)).await.unwrap();
+        assert_eq!(
+            state.current_view,
+            ViewMode::GoalVideos {
+                index: 0,
+                selected: 0
+            }
+        );
+
+        // Only one goal has a clip, so there is nothing below it
+        let down = event::KeyEvent::new(KeyCode::Down, KeyModifiers::NONE);
+        state.needs_refresh = false;
+        handle_key_event(state.params(&down)).await.unwrap();
+        assert!(!state.needs_refresh);
+
+        let enter = event::KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        handle_key_event(state.params(&enter)).await.unwrap();
+        assert_eq!(state.notice.as_deref(), Some(t("videos.no_player")));
+
+        let esc = event::KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+        handle_key_event(state.params(&esc)).await.unwrap();
+        assert_eq!(state.current_view, ViewMode::GameDetail { index: 0 });
+    }
+
+    #[tokio::test]
+    async fn test_page_888_opens_diagnostics_and_esc_returns() {
+        let mut state = KeyEventState::new();
+
+        for digit in ['8', '8', '8'] {
+            let key_event = event::KeyEvent::new(KeyCode::Char(digit), KeyModifiers::NONE);
+            handle_key_event(state.params(&key_event)).await.unwrap();
+        }
+        assert_eq!(state.current_view, ViewMode::Diagnostics);
+        assert!(state.needs_refresh);
+
+        let esc = event::KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+        handle_key_event(state.params(&esc)).await.unwrap();
+        assert_eq!(state.current_view, ViewMode::Games);
+    }
+
+    #[tokio::test]
+    async fn test_page_224_opens_off_season_and_esc_returns() {
+        let mut state = KeyEventState::new();
+
+        for digit in ['2', '2', '4'] {
+            let key_event = event::KeyEvent::new(KeyCode::Char(digit), KeyModifiers::NONE);
+            handle_key_event(state.params(&key_event)).await.unwrap();
+        }
+        assert_eq!(state.current_view, ViewMode::OffSeason);
+        assert!(state.needs_refresh);
+
+        let esc = event::KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+        handle_key_event(state.params(&esc)).await.unwrap();
+        assert_eq!(state.current_view, ViewMode::Games);
+    }
+
+    #[tokio::test]
+    async fn test_x_exports_page_into_export_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut state = KeyEventState::new();
+        state.export_dir = dir.path().join("snapshots");
+        state.current_page = Some(TeletextPage::new(
+            221,
+            "JÄÄKIEKKO".to_string(),
+            "SM-LIIGA".to_string(),
+            false,
+            true,
+            true,
+            false,
+            false,
+        ));
+
+        let key_event = event::KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE);
+        handle_key_event(state.params(&key_event)).await.unwrap();
+
+        let mut files: Vec<String> = std::fs::read_dir(&state.export_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        files.sort();
+        assert_eq!(files.len(), 4, "{files:?}");
+        assert!(files[0].starts_with("liiga-221-") && files[0].ends_with(".html"));
+        assert!(files[1].ends_with(".svg"));
+        assert!(files[2].ends_with(".tti"));
+        assert!(files[3].ends_with(".txt"));
+        assert!(
+            state
+                .notice
+                .as_deref()
+                .is_some_and(|n| n.starts_with("TALLENNETTU"))
+        );
+        assert!(state.needs_render);
+    }
 }
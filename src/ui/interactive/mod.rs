@@ -6,22 +6,30 @@
 //! - `indicators`: Loading and auto-refresh indicator management
 //! - `refresh_manager`: Auto-refresh timing and logic
 //! - `input_handler`: Keyboard input and date navigation
+//! - `goal_videos`: Opening goal clips with the configured video player
+//! - `display_mode`: Switching between normal, compact and wide layouts at runtime
 //! - `state_manager`: State management and organization
 //! - `event_handler`: Event processing and coordination
 //! - `navigation_manager`: Page navigation and creation management
+//! - `page_feed`: Headless refresh loop for one page, used by the web server
 //! - `refresh_coordinator`: Auto-refresh operations and data fetching coordination
 //! - `terminal_manager`: Terminal setup and cleanup operations
+//! - `session`: Last view and display mode, saved on exit and restored on start
 //! - `core`: Main interactive UI loop and orchestration
 
 mod change_detection;
 mod core;
+mod display_mode;
 mod event_handler;
+mod goal_videos;
 mod indicators;
 mod input_handler;
 pub mod navigation_manager;
+pub mod page_feed;
 mod refresh_coordinator;
 mod refresh_manager;
 pub(crate) mod series_utils;
+pub mod session;
 pub mod state_manager;
 mod terminal_manager;
 
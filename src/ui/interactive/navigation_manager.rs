@@ -9,9 +9,19 @@
 
 use super::series_utils::{get_subheader, is_playoff_type, playoff_phase_name, series_group_label};
 use crate::data_fetcher::models::bracket::PlayoffBracket;
+use crate::data_fetcher::processors::lineup::GameLineups;
+use crate::data_fetcher::processors::off_season::OffSeasonInfo;
+use crate::data_fetcher::processors::timeline::GameTimeline;
 use crate::data_fetcher::{GameData, is_historical_date};
+use crate::i18n::{t, tf};
 use crate::teletext_ui::bracket_display::render_bracket;
+use crate::teletext_ui::diagnostics_display::{DiagnosticsSnapshot, render_diagnostics};
+use crate::teletext_ui::game_detail_display::{
+    render_game_lineups, render_game_timeline, render_goal_videos,
+};
+use crate::teletext_ui::off_season_display::render_off_season;
 use crate::teletext_ui::{GameResultData, TeletextPage, TeletextRow};
+use crate::ui::components::favourites::is_favourite_game;
 use chrono::NaiveDate;
 use std::collections::{HashMap, HashSet};
 
@@ -218,7 +228,7 @@ async fn create_base_page(
     let subheader = get_subheader(games);
     let mut page = TeletextPage::new(
         221,
-        "JÄÄKIEKKO".to_string(),
+        t("header.ice_hockey").to_string(),
         subheader,
         disable_video_links,
         show_footer,
@@ -282,6 +292,8 @@ async fn create_base_page(
                 .to_string(),
             serie_key,
             phase,
+            // Favourite teams' games lead their group
+            !is_favourite_game(g),
             g.start.clone(),
             g.play_off_pair.unwrap_or(i32::MAX),
             g.home_team.clone(),
@@ -395,7 +407,7 @@ pub async fn create_future_games_page(
         );
 
         let future_games_header = if show_future_header {
-            Some(format!("Seuraavat ottelut {formatted_date}"))
+            Some(format!("{} {formatted_date}", t("games.next_games")))
         } else {
             None
         };
@@ -431,7 +443,7 @@ pub fn create_loading_page(
 ) -> TeletextPage {
     let mut loading_page = TeletextPage::new(
         221,
-        "JÄÄKIEKKO".to_string(),
+        t("header.ice_hockey").to_string(),
         "SM-LIIGA".to_string(),
         disable_links,
         true,
@@ -444,19 +456,19 @@ pub fn create_loading_page(
 
     if let Some(date) = current_date {
         if is_historical_date(date) {
-            loading_page.add_error_message(&format!(
-                "Haetaan historiallista dataa päivälle {}...",
-                format_date_for_display(date)
+            loading_page.add_error_message(&tf(
+                "loading.historical",
+                &[("date", &format_date_for_display(date))],
             ));
-            loading_page.add_error_message("Tämä voi kestää hetken, odotathan...");
+            loading_page.add_error_message(t("loading.historical_wait"));
         } else {
-            loading_page.add_error_message(&format!(
-                "Haetaan otteluita päivälle {}...",
-                format_date_for_display(date)
+            loading_page.add_error_message(&tf(
+                "loading.games_on_date",
+                &[("date", &format_date_for_display(date))],
             ));
         }
     } else {
-        loading_page.add_error_message("Haetaan päivän otteluita...");
+        loading_page.add_error_message(t("loading.todays_games"));
     }
 
     loading_page
@@ -471,7 +483,7 @@ pub fn create_error_page(
 ) -> TeletextPage {
     let mut error_page = TeletextPage::new(
         221,
-        "JÄÄKIEKKO".to_string(),
+        t("header.ice_hockey").to_string(),
         "SM-LIIGA".to_string(),
         disable_links,
         true,
@@ -483,22 +495,23 @@ pub fn create_error_page(
     let formatted_date = format_date_for_display(fetched_date);
 
     if is_historical_date(fetched_date) {
-        error_page.add_error_message(&format!("Ei otteluita päivälle {}", formatted_date));
+        error_page.add_error_message(&tf("games.no_games_on_date", &[("date", &formatted_date)]));
         error_page.add_error_message("");
-        error_page.add_error_message("Käytä Shift + nuolia siirtyäksesi toiselle päivälle");
-        error_page.add_error_message("tai käynnistä sovellus uudelleen (-d parametrilla)");
-        error_page.add_error_message("nähdäksesi päivän ottelut.");
+        error_page.add_error_message(t("games.navigate_hint"));
+        error_page.add_error_message(t("games.restart_hint"));
+        error_page.add_error_message(t("games.restart_hint_end"));
     } else {
-        error_page.add_error_message(&format!("Ei otteluita päivälle {}", formatted_date));
+        error_page.add_error_message(&tf("games.no_games_on_date", &[("date", &formatted_date)]));
         error_page.add_error_message("");
-        error_page.add_error_message("Käytä Shift + nuolia siirtyäksesi toiselle päivälle");
-        error_page.add_error_message("tai paina 'r' päivittääksesi tiedot.");
+        error_page.add_error_message(t("games.navigate_hint"));
+        error_page.add_error_message(t("games.refresh_hint"));
     }
 
     error_page
 }
 
-/// 3x5 block-graphics glyphs for the "SIVUA EI LÖYDY" page art.
+/// 3x5 block-graphics glyphs for the "SIVUA EI LÖYDY" page art and its
+/// translations.
 /// Returns the five rows of a glyph, or None for unsupported characters.
 fn block_glyph(c: char) -> Option<[&'static str; 5]> {
     match c {
@@ -513,6 +526,13 @@ fn block_glyph(c: char) -> Option<[&'static str; 5]> {
         'Ö' => Some(["█ █", "███", "█ █", "█ █", "███"]),
         'Y' => Some(["█ █", "█ █", " █ ", " █ ", " █ "]),
         'D' => Some(["██ ", "█ █", "█ █", "█ █", "██ "]),
+        'N' => Some(["██ ", "█ █", "█ █", "█ █", "█ █"]),
+        'O' => Some(["███", "█ █", "█ █", "█ █", "███"]),
+        'P' => Some(["██ ", "█ █", "██ ", "█  ", "█  "]),
+        'G' => Some(["███", "█  ", "█ █", "█ █", "███"]),
+        'T' => Some(["███", " █ ", " █ ", " █ ", " █ "]),
+        'F' => Some(["███", "█  ", "██ ", "█  ", "█  "]),
+        'K' => Some(["█ █", "█ █", "██ ", "█ █", "█ █"]),
         ' ' => Some(["   ", "   ", "   ", "   ", "   "]),
         _ => None,
     }
@@ -532,7 +552,7 @@ fn render_block_text(text: &str) -> Vec<String> {
 pub fn create_page_not_found_page(page_number: u16) -> TeletextPage {
     let mut page = TeletextPage::new(
         page_number,
-        "JÄÄKIEKKO".to_string(),
+        t("header.ice_hockey").to_string(),
         "SM-LIIGA".to_string(),
         false,
         true,
@@ -543,16 +563,16 @@ pub fn create_page_not_found_page(page_number: u16) -> TeletextPage {
 
     // Block art centered within the classic 40-column teletext area
     page.add_banner_line(" ".to_string());
-    for line in render_block_text("SIVUA") {
+    for line in render_block_text(t("not_found.art_top")) {
         page.add_banner_line(format!("{:^40}", line));
     }
     page.add_banner_line(" ".to_string());
-    for line in render_block_text("EI LÖYDY") {
+    for line in render_block_text(t("not_found.art_bottom")) {
         page.add_banner_line(format!("{:^40}", line));
     }
     page.add_banner_line(" ".to_string());
-    page.add_error_message(&format!("Sivu {page_number} ei ole käytössä"));
-    page.add_error_message("221 Ottelut  222 Taulukko  223 Pudotuspelit");
+    page.add_error_message(&tf("not_found.message", &[("page", &page_number)]));
+    page.add_error_message(t("not_found.pages"));
 
     page
 }
@@ -610,15 +630,15 @@ pub fn create_standings_page(
     _wide_mode: bool,
 ) -> TeletextPage {
     let subheader = if live_mode {
-        "SARJATAULUKKO (LIVE)".to_string()
+        t("page.standings_live").to_string()
     } else {
-        "SARJATAULUKKO".to_string()
+        t("page.standings").to_string()
     };
 
     // Force normal mode for standings - compact/wide renderers don't support standings rows
     let mut page = TeletextPage::new(
         222,
-        "JÄÄKIEKKO".to_string(),
+        t("header.ice_hockey").to_string(),
         subheader,
         disable_links,
         true,
@@ -645,12 +665,12 @@ pub fn create_bracket_page(
     terminal_width: u16,
     terminal_height: u16,
 ) -> TeletextPage {
-    let subheader = format!("PUDOTUSPELIT {}", bracket.season);
+    let subheader = format!("{} {}", t("page.playoffs"), bracket.season);
 
     // Force normal mode (no compact/wide), same as standings
     let mut page = TeletextPage::new(
         223,
-        "JÄÄKIEKKO".to_string(),
+        t("header.ice_hockey").to_string(),
         subheader,
         disable_links,
         true,
@@ -673,6 +693,165 @@ pub fn create_bracket_page(
     page
 }
 
+/// Creates the timeline page for the game at `index` in the games list.
+/// Pages are numbered from 231 upwards in games list order.
+pub fn create_game_detail_page(
+    game: &GameData,
+    index: usize,
+    timeline: &GameTimeline,
+    disable_links: bool,
+    wide_mode: bool,
+) -> TeletextPage {
+    let mut page = TeletextPage::new(
+        231 + index as u16,
+        t("header.ice_hockey").to_string(),
+        t("page.game_events").to_string(),
+        disable_links,
+        true,
+        false,
+        false,
+        wide_mode,
+    );
+
+    page.set_game_detail_index(index);
+
+    for row in render_game_timeline(game, timeline) {
+        match row {
+            TeletextRow::DetailLine(line) => page.add_detail_line(line),
+            TeletextRow::SeriesHeader(header) => page.add_series_header(header),
+            _ => {}
+        }
+    }
+
+    page
+}
+
+/// Creates the lineup page for the game at `index` in the games list.
+/// Pages are numbered from 241 upwards in games list order.
+pub fn create_game_lineup_page(
+    game: &GameData,
+    index: usize,
+    lineups: &GameLineups,
+    disable_links: bool,
+    wide_mode: bool,
+) -> TeletextPage {
+    let mut page = TeletextPage::new(
+        241 + index as u16,
+        t("header.ice_hockey").to_string(),
+        t("page.lineups").to_string(),
+        disable_links,
+        true,
+        false,
+        false,
+        wide_mode,
+    );
+
+    page.set_game_lineup_index(index);
+
+    for row in render_game_lineups(game, lineups) {
+        match row {
+            TeletextRow::DetailLine(line) => page.add_detail_line(line),
+            TeletextRow::SeriesHeader(header) => page.add_series_header(header),
+            _ => {}
+        }
+    }
+
+    page
+}
+
+/// Creates the goal video picker for the game at `index` in the games list,
+/// with the `selected` clip highlighted. Pages are numbered from 251 upwards
+/// in games list order.
+pub fn create_goal_videos_page(
+    game: &GameData,
+    index: usize,
+    selected: usize,
+    disable_links: bool,
+    wide_mode: bool,
+) -> TeletextPage {
+    let mut page = TeletextPage::new(
+        251 + index as u16,
+        t("header.ice_hockey").to_string(),
+        t("page.goal_videos").to_string(),
+        disable_links,
+        true,
+        false,
+        false,
+        wide_mode,
+    );
+
+    page.set_goal_videos(index, selected);
+
+    for row in render_goal_videos(game, selected) {
+        match row {
+            TeletextRow::DetailLine(line) => page.add_detail_line(line),
+            TeletextRow::SeriesHeader(header) => page.add_series_header(header),
+            _ => {}
+        }
+    }
+
+    page
+}
+
+/// Creates the off-season page (224).
+pub fn create_off_season_page(
+    info: &OffSeasonInfo,
+    disable_links: bool,
+    wide_mode: bool,
+) -> TeletextPage {
+    let mut page = TeletextPage::new(
+        224,
+        t("header.ice_hockey").to_string(),
+        t("page.off_season").to_string(),
+        disable_links,
+        true,
+        false,
+        false,
+        wide_mode,
+    );
+
+    page.set_off_season_page();
+
+    for row in render_off_season(info) {
+        match row {
+            TeletextRow::DetailLine(line) => page.add_detail_line(line),
+            TeletextRow::SeriesHeader(header) => page.add_series_header(header),
+            _ => {}
+        }
+    }
+
+    page
+}
+
+/// Creates the hidden diagnostics page (888).
+pub fn create_diagnostics_page(
+    snapshot: &DiagnosticsSnapshot,
+    disable_links: bool,
+) -> TeletextPage {
+    let mut page = TeletextPage::new(
+        888,
+        t("header.ice_hockey").to_string(),
+        t("page.diagnostics").to_string(),
+        disable_links,
+        true,
+        false,
+        false,
+        false,
+    );
+
+    page.set_diagnostics_page();
+
+    for row in render_diagnostics(snapshot) {
+        match row {
+            TeletextRow::DetailLine(line) => page.add_detail_line(line),
+            TeletextRow::SeriesHeader(header) => page.add_series_header(header),
+            _ => {}
+        }
+    }
+
+    page
+}
+
 /// Formats a date string for display in Finnish format (DD.MM.)
 pub fn format_date_for_display(date_str: &str) -> String {
     // Parse the date using chrono for better error handling
@@ -708,6 +887,20 @@ mod tests {
         assert!(ei_loydy.iter().all(|row| row.chars().count() == 31));
     }
 
+    #[test]
+    fn test_page_not_found_art_has_glyphs_in_every_language() {
+        for language in crate::i18n::Language::ALL {
+            for key in ["not_found.art_top", "not_found.art_bottom"] {
+                let text = language.text(key);
+                assert!(
+                    text.chars().all(|c| block_glyph(c).is_some()),
+                    "{language}: no glyph for a letter of {text:?}"
+                );
+                assert!(render_block_text(text)[0].chars().count() <= 40);
+            }
+        }
+    }
+
     #[test]
     fn test_render_block_text_skips_unsupported_chars() {
         let with_unsupported = render_block_text("S?I");
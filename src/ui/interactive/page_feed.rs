@@ -0,0 +1,112 @@
+//! Headless refresh loop for one teletext page.
+//!
+//! The web server (`--serve`) has no terminal and no keyboard, but its pages
+//! should follow live games exactly like the interactive viewer. A `PageFeed`
+//! runs the same refresh steps as the interactive loop in `core` — timing,
+//! backoff, change detection and page creation through `RefreshCoordinator` —
+//! for a fixed view, without drawing anything.
+
+use crate::data_fetcher::GameData;
+use crate::data_fetcher::models::bracket::PlayoffBracket;
+use crate::data_fetcher::models::standings::StandingsEntry;
+use crate::error::AppError;
+use crate::teletext_ui::TeletextPage;
+
+use super::refresh_coordinator::{RefreshCoordinator, RefreshCycleConfig};
+use super::state_manager::{InteractiveState, ViewMode};
+
+/// Keeps one view (games, standings or bracket) up to date.
+pub struct PageFeed {
+    state: InteractiveState,
+    coordinator: RefreshCoordinator,
+    config: RefreshCycleConfig,
+    last_refresh_date: Option<String>,
+}
+
+impl PageFeed {
+    /// Creates a feed for `view`, showing today's games or standings.
+    pub fn new(view: ViewMode, disable_links: bool, min_refresh_interval: Option<u64>) -> Self {
+        let mut state = InteractiveState::new(None);
+        state.navigation.current_view = view;
+        Self {
+            state,
+            coordinator: RefreshCoordinator::new(),
+            config: RefreshCycleConfig {
+                min_refresh_interval,
+                disable_links,
+                compact_mode: false,
+                wide_mode: false,
+                headless: true,
+            },
+            last_refresh_date: None,
+        }
+    }
+
+    /// Refreshes the page when the refresh logic says it is due.
+    /// Returns true when the page changed and should be republished.
+    pub async fn tick(&mut self) -> Result<bool, AppError> {
+        let state = &mut self.state;
+        if self.coordinator.should_trigger_refresh(state, &self.config) {
+            state.request_refresh();
+        }
+
+        if state.needs_refresh() {
+            let is_date_change = state.current_date() != &self.last_refresh_date;
+            if is_date_change {
+                self.coordinator.reset_transient_empty_counter();
+                self.last_refresh_date = state.current_date().clone();
+            }
+
+            let mut result = self
+                .coordinator
+                .perform_refresh_cycle(state, &self.config, is_date_change)
+                .await?;
+
+            if state.navigation.initial_fetched_date.is_none() && !result.fetched_date.is_empty() {
+                state.navigation.initial_fetched_date = Some(result.fetched_date.clone());
+            }
+            if let Some(new_page) = result.new_page.take() {
+                state.set_current_page(new_page);
+            }
+
+            self.coordinator.process_refresh_results(state, &result);
+            self.coordinator
+                .update_refresh_timing(state, result.should_retry);
+
+            // Every page is shown whole, whatever terminal the server runs in
+            if let Some(page) = state.current_page_mut() {
+                page.set_full_page();
+            }
+        }
+
+        let changed = state.needs_render();
+        state.clear_render_flag();
+        Ok(changed)
+    }
+
+    /// The page as last refreshed, if any
+    pub fn page(&self) -> Option<&TeletextPage> {
+        self.state.current_page()
+    }
+
+    /// Games behind the games page (empty for the other views)
+    pub fn games(&self) -> &[GameData] {
+        self.state.change_detection.last_games()
+    }
+
+    /// Standings and playoff line positions behind the standings page
+    /// (empty for the other views)
+    pub fn standings(&self) -> (&[StandingsEntry], &[u16]) {
+        self.state.change_detection.last_standings()
+    }
+
+    /// Bracket behind the bracket page (None for the other views)
+    pub fn bracket(&self) -> Option<&PlayoffBracket> {
+        self.state.change_detection.last_bracket()
+    }
+
+    /// Date of the games shown, once known
+    pub fn date(&self) -> Option<&str> {
+        self.state.current_date().as_deref()
+    }
+}
@@ -8,9 +8,12 @@
 //! - Backoff and retry logic coordination
 
 use crate::data_fetcher::api::standings_api::fetch_standings;
+use crate::data_fetcher::processors::lineup::build_game_lineups;
+use crate::data_fetcher::processors::timeline::build_game_timeline;
 use crate::data_fetcher::{GameData, fetch_liiga_data, has_live_games_from_game_data};
 use crate::error::AppError;
-use crate::teletext_ui::{ScoreType, TeletextPage};
+use crate::i18n::t;
+use crate::teletext_ui::{ScoreType, TeletextPage, teletext_grid};
 use std::time::Duration;
 use tracing;
 
@@ -71,6 +74,9 @@ pub struct RefreshCycleConfig {
     pub disable_links: bool,
     pub compact_mode: bool,
     pub wide_mode: bool,
+    /// No terminal to draw on (the web server): loading screens and
+    /// spinners shown during a fetch are skipped
+    pub headless: bool,
 }
 
 /// Cache monitoring configuration
@@ -178,6 +184,7 @@ type FetchResult = (Vec<GameData>, bool, String, bool);
 async fn animate_during_fetch(
     state: &mut InteractiveState,
     mut handle: tokio::task::JoinHandle<FetchResult>,
+    headless: bool,
 ) -> FetchResult {
     let mut stdout = std::io::stdout();
 
@@ -193,7 +200,8 @@ async fn animate_during_fetch(
                 }
             }
             _ = tokio::time::sleep(Duration::from_millis(100)) => {
-                if let Some(page) = state.current_page_mut()
+                if !headless
+                    && let Some(page) = state.current_page_mut()
                     && page.is_auto_refresh_indicator_active()
                 {
                     page.update_auto_refresh_animation();
@@ -254,6 +262,20 @@ impl RefreshCoordinator {
         state: &InteractiveState,
         config: &RefreshCycleConfig,
     ) -> bool {
+        if matches!(state.current_view(), ViewMode::Diagnostics) {
+            // Only in-memory metrics: no game-state or backoff gating needed
+            return state.needs_refresh()
+                || state.timers.last_auto_refresh.elapsed()
+                    >= Duration::from_secs(
+                        crate::constants::refresh::DIAGNOSTICS_INTERVAL_SECONDS,
+                    );
+        }
+
+        if matches!(state.current_view(), ViewMode::GoalVideos { .. }) {
+            // Built from the games already loaded; only redrawn on input
+            return state.needs_refresh();
+        }
+
         if !state.needs_refresh() {
             // Calculate refresh intervals
             let is_standings_live = matches!(
@@ -261,19 +283,38 @@ impl RefreshCoordinator {
                 ViewMode::Standings { live_mode: true }
             );
             let is_bracket = matches!(state.current_view(), ViewMode::Bracket);
-            let (auto_refresh_interval, game_count_for_min_interval) = if is_standings_live {
-                (
-                    Duration::from_secs(crate::constants::refresh::LIVE_GAMES_INTERVAL_SECONDS),
-                    0,
-                )
-            } else if is_bracket {
-                (Duration::from_secs(60), 0)
-            } else {
-                (
-                    calculate_auto_refresh_interval(state.change_detection.last_games()),
-                    state.change_detection.last_games().len(),
-                )
+            let is_live_game_detail = match state.current_view() {
+                ViewMode::GameDetail { index } => state
+                    .change_detection
+                    .last_games()
+                    .get(index)
+                    .is_some_and(|g| g.score_type == ScoreType::Ongoing),
+                _ => false,
             };
+            let (auto_refresh_interval, game_count_for_min_interval) =
+                if is_standings_live || is_live_game_detail {
+                    (
+                        Duration::from_secs(crate::constants::refresh::LIVE_GAMES_INTERVAL_SECONDS),
+                        0,
+                    )
+                } else if matches!(state.current_view(), ViewMode::OffSeason) {
+                    (
+                        Duration::from_secs(crate::constants::refresh::OFF_SEASON_INTERVAL_SECONDS),
+                        0,
+                    )
+                } else if is_bracket
+                    || matches!(
+                        state.current_view(),
+                        ViewMode::GameDetail { .. } | ViewMode::GameLineup { .. }
+                    )
+                {
+                    (Duration::from_secs(60), 0)
+                } else {
+                    (
+                        calculate_auto_refresh_interval(state.change_detection.last_games()),
+                        state.change_detection.last_games().len(),
+                    )
+                };
             let min_interval_between_refreshes = calculate_min_refresh_interval(
                 game_count_for_min_interval,
                 config.min_refresh_interval,
@@ -486,6 +527,30 @@ impl RefreshCoordinator {
                 .await;
         }
 
+        if matches!(state.current_view(), ViewMode::OffSeason) {
+            return self.perform_off_season_refresh(state, config).await;
+        }
+
+        if matches!(state.current_view(), ViewMode::Diagnostics) {
+            return Ok(self.perform_diagnostics_refresh(state, config).await);
+        }
+
+        if let ViewMode::GoalVideos { index, selected } = state.current_view() {
+            return Ok(self.perform_goal_videos_refresh(state, config, index, selected));
+        }
+
+        if let ViewMode::GameDetail { index } = state.current_view() {
+            return self
+                .perform_game_detail_refresh(state, config, index, false)
+                .await;
+        }
+
+        if let ViewMode::GameLineup { index } = state.current_view() {
+            return self
+                .perform_game_detail_refresh(state, config, index, true)
+                .await;
+        }
+
         if let ViewMode::Standings { live_mode } = state.current_view() {
             let preserved_page = state.preserved_page();
             let last_games = state.change_detection.last_games().to_vec();
@@ -548,7 +613,9 @@ impl RefreshCoordinator {
 
         // Render immediately so spinner is visible during fetch
         if state.needs_render() {
-            if let Some(page) = state.current_page() {
+            if !config.headless
+                && let Some(page) = state.current_page()
+            {
                 let mut stdout = std::io::stdout();
                 let _ = page.render_buffered(&mut stdout);
             }
@@ -565,7 +632,7 @@ impl RefreshCoordinator {
 
         // Animate the spinner while waiting for the fetch to complete
         let (games, had_error, raw_fetched_date, should_retry) =
-            animate_during_fetch(state, fetch_handle).await;
+            animate_during_fetch(state, fetch_handle, config.headless).await;
         // Normalize the fetched date at the boundary to prevent whitespace
         // from poisoning current_date or causing spurious date-mismatch discards.
         let fetched_date = raw_fetched_date.trim().to_string();
@@ -651,7 +718,9 @@ impl RefreshCoordinator {
                 state.request_render();
             }
             // Render spinner immediately
-            if let Some(page) = state.current_page() {
+            if !config.headless
+                && let Some(page) = state.current_page()
+            {
                 let mut stdout = std::io::stdout();
                 if let Err(e) = page.render_buffered(&mut stdout) {
                     tracing::warn!("Failed to render auto-refresh spinner for standings: {e}");
@@ -662,17 +731,19 @@ impl RefreshCoordinator {
             // Show loading indicator immediately so the UI feels responsive
             let mut loading_page = TeletextPage::new(
                 222,
-                "JÄÄKIEKKO".to_string(),
-                "SARJATAULUKKO".to_string(),
+                t("header.ice_hockey").to_string(),
+                t("page.standings").to_string(),
                 config.disable_links,
                 true,
                 false,
                 false,
                 false,
             );
-            loading_page.add_error_message("Haetaan sarjataulukkoa...");
+            loading_page.add_error_message(t("loading.standings"));
             let mut stdout = std::io::stdout();
-            if let Err(e) = loading_page.render_buffered(&mut stdout) {
+            if !config.headless
+                && let Err(e) = loading_page.render_buffered(&mut stdout)
+            {
                 tracing::warn!("Failed to render standings loading page: {e}");
             }
         }
@@ -743,6 +814,9 @@ impl RefreshCoordinator {
 
         let data_changed = if !had_error {
             let new_hash = calculate_standings_hash(&standings, &playoffs_lines, live_mode);
+            state
+                .change_detection
+                .record_standings(&standings, &playoffs_lines);
             state.change_detection.update_standings_hash(new_hash)
         } else {
             true // errors always count as "changed" to show the error page
@@ -751,9 +825,6 @@ impl RefreshCoordinator {
         // Hide auto-refresh spinner for auto-refresh case
         if is_auto_refresh && let Some(page) = state.current_page_mut() {
             page.hide_auto_refresh_indicator();
-            if !data_changed {
-                page.skip_next_screen_clear();
-            }
             state.request_render();
         }
 
@@ -786,16 +857,16 @@ impl RefreshCoordinator {
         } else {
             let mut error_page = TeletextPage::new(
                 222,
-                "JÄÄKIEKKO".to_string(),
-                "SARJATAULUKKO".to_string(),
+                t("header.ice_hockey").to_string(),
+                t("page.standings").to_string(),
                 config.disable_links,
                 true,
                 false,
                 config.compact_mode,
                 config.wide_mode,
             );
-            error_page.add_error_message("Sarjataulukon lataus epäonnistui.");
-            error_page.add_error_message("Paina 's' palataksesi otteluihin.");
+            error_page.add_error_message(t("error.standings"));
+            error_page.add_error_message(t("error.standings_back"));
             Some(error_page)
         };
 
@@ -834,7 +905,9 @@ impl RefreshCoordinator {
                 page.show_auto_refresh_indicator();
                 state.request_render();
             }
-            if let Some(page) = state.current_page() {
+            if !config.headless
+                && let Some(page) = state.current_page()
+            {
                 let mut stdout = std::io::stdout();
                 if let Err(e) = page.render_buffered(&mut stdout) {
                     tracing::warn!("Failed to render auto-refresh spinner for bracket: {e}");
@@ -844,17 +917,19 @@ impl RefreshCoordinator {
         } else {
             let mut loading_page = TeletextPage::new(
                 223,
-                "JÄÄKIEKKO".to_string(),
-                "PUDOTUSPELIT".to_string(),
+                t("header.ice_hockey").to_string(),
+                t("page.playoffs").to_string(),
                 config.disable_links,
                 true,
                 false,
                 false,
                 false,
             );
-            loading_page.add_error_message("Haetaan pudotuspelejä...");
+            loading_page.add_error_message(t("loading.playoffs"));
             let mut stdout = std::io::stdout();
-            if let Err(e) = loading_page.render_buffered(&mut stdout) {
+            if !config.headless
+                && let Err(e) = loading_page.render_buffered(&mut stdout)
+            {
                 tracing::warn!("Failed to render bracket loading page: {e}");
             }
         }
@@ -897,6 +972,7 @@ impl RefreshCoordinator {
 
         let data_changed = if let Some(ref b) = bracket {
             let new_hash = super::change_detection::calculate_bracket_hash(b);
+            state.change_detection.record_bracket(b);
             state.change_detection.update_bracket_hash(new_hash)
         } else {
             true
@@ -904,9 +980,6 @@ impl RefreshCoordinator {
 
         if is_auto_refresh && let Some(page) = state.current_page_mut() {
             page.hide_auto_refresh_indicator();
-            if !data_changed {
-                page.skip_next_screen_clear();
-            }
             state.request_render();
         }
 
@@ -923,7 +996,12 @@ impl RefreshCoordinator {
             });
         }
 
-        let (terminal_width, terminal_height) = crossterm::terminal::size().unwrap_or((80, 24));
+        // The teletext grid has room for the bracket's 40 column layout only
+        let (terminal_width, terminal_height) = if teletext_grid::grid_mode() {
+            (teletext_grid::GRID_WIDTH, teletext_grid::GRID_HEIGHT)
+        } else {
+            crossterm::terminal::size().unwrap_or((80, 24))
+        };
 
         let new_page = if let Some(bracket) = bracket {
             let mut page = navigation_manager::create_bracket_page(
@@ -939,16 +1017,231 @@ impl RefreshCoordinator {
         } else {
             let mut error_page = TeletextPage::new(
                 223,
-                "JÄÄKIEKKO".to_string(),
-                "PUDOTUSPELIT".to_string(),
+                t("header.ice_hockey").to_string(),
+                t("page.playoffs").to_string(),
                 config.disable_links,
                 true,
                 false,
                 false,
                 false,
             );
-            error_page.add_error_message("Pudotuspelien lataus epäonnistui.");
-            error_page.add_error_message("Paina 'p' palataksesi.");
+            error_page.add_error_message(t("error.playoffs"));
+            error_page.add_error_message(t("error.playoffs_back"));
+            Some(error_page)
+        };
+
+        Ok(RefreshResult {
+            games: vec![],
+            had_error,
+            fetched_date: String::new(),
+            should_retry: had_error,
+            new_page,
+            needs_render: true,
+            skip_change_detection: true,
+        })
+    }
+
+    /// Perform game detail refresh cycle.
+    ///
+    /// Fetches the detailed data of the selected game from the current games
+    /// list and rebuilds its timeline page, or its lineup page when `lineup`
+    /// is set. Like the bracket view, unchanged data (by hash) skips the UI
+    /// rebuild.
+    async fn perform_game_detail_refresh(
+        &self,
+        state: &mut InteractiveState,
+        config: &RefreshCycleConfig,
+        index: usize,
+        lineup: bool,
+    ) -> Result<RefreshResult, AppError> {
+        let Some(game) = state.change_detection.last_games().get(index).cloned() else {
+            tracing::warn!("Game detail requested for missing game index {index}");
+            state.navigation.current_view = ViewMode::Games;
+            return Ok(RefreshResult {
+                games: vec![],
+                had_error: false,
+                fetched_date: String::new(),
+                should_retry: false,
+                new_page: None,
+                needs_render: false,
+                skip_change_detection: true,
+            });
+        };
+        let (page_number, subheader, loading_text, error_text) = if lineup {
+            (
+                241 + index as u16,
+                t("page.lineups"),
+                t("loading.lineups"),
+                t("error.lineups"),
+            )
+        } else {
+            (
+                231 + index as u16,
+                t("page.game_events"),
+                t("loading.game_events"),
+                t("error.game_events"),
+            )
+        };
+        tracing::info!(
+            "Fetching game detail for {} - {} (game {})",
+            game.home_team,
+            game.away_team,
+            game.game_id
+        );
+
+        let is_auto_refresh = state
+            .current_page()
+            .is_some_and(|p| p.is_game_detail_page())
+            && state.change_detection.last_game_detail_hash().is_some();
+
+        if is_auto_refresh {
+            if let Some(page) = state.current_page_mut() {
+                page.show_auto_refresh_indicator();
+                state.request_render();
+            }
+            if !config.headless
+                && let Some(page) = state.current_page()
+            {
+                let mut stdout = std::io::stdout();
+                if let Err(e) = page.render_buffered(&mut stdout) {
+                    tracing::warn!("Failed to render auto-refresh spinner for game detail: {e}");
+                }
+            }
+            state.clear_render_flag();
+        } else {
+            let mut loading_page = TeletextPage::new(
+                page_number,
+                t("header.ice_hockey").to_string(),
+                subheader.to_string(),
+                config.disable_links,
+                true,
+                false,
+                false,
+                false,
+            );
+            loading_page.add_error_message(loading_text);
+            let mut stdout = std::io::stdout();
+            if !config.headless
+                && let Err(e) = loading_page.render_buffered(&mut stdout)
+            {
+                tracing::warn!("Failed to render game detail loading page: {e}");
+            }
+        }
+
+        // Placeholders have no API id. Scheduled games have no events yet,
+        // but their lineups may already be published.
+        let skip_fetch = game.game_id == 0 || (!lineup && game.score_type == ScoreType::Scheduled);
+        let details = if skip_fetch {
+            Some(None)
+        } else {
+            let app_config = match crate::config::Config::load().await {
+                Ok(config) => config,
+                Err(e) => {
+                    if is_auto_refresh && let Some(page) = state.current_page_mut() {
+                        page.hide_auto_refresh_indicator();
+                        state.request_render();
+                    }
+                    return Err(e);
+                }
+            };
+            let timeout_duration =
+                std::time::Duration::from_secs(app_config.http_timeout_seconds + 5);
+
+            match tokio::time::timeout(
+                timeout_duration,
+                crate::data_fetcher::api::game_detail_api::fetch_game_details(
+                    &app_config,
+                    game.season,
+                    game.game_id,
+                ),
+            )
+            .await
+            {
+                Ok(Ok(details)) => Some(Some(details)),
+                Ok(Err(e)) => {
+                    tracing::error!("Failed to fetch game detail: {e}");
+                    None
+                }
+                Err(_) => {
+                    tracing::error!("Game detail fetch timed out");
+                    None
+                }
+            }
+        };
+        let had_error = details.is_none();
+
+        // Build the page and its change-detection hash from the fetched data
+        let built = details.map(|details| {
+            if lineup {
+                let lineups = details.as_ref().map(build_game_lineups).unwrap_or_default();
+                let hash = super::change_detection::calculate_game_lineup_hash(index, &lineups);
+                let page = navigation_manager::create_game_lineup_page(
+                    &game,
+                    index,
+                    &lineups,
+                    config.disable_links,
+                    config.wide_mode,
+                );
+                (hash, page)
+            } else {
+                let timeline = details
+                    .as_ref()
+                    .map(build_game_timeline)
+                    .unwrap_or_default();
+                let hash = super::change_detection::calculate_game_detail_hash(index, &timeline);
+                let page = navigation_manager::create_game_detail_page(
+                    &game,
+                    index,
+                    &timeline,
+                    config.disable_links,
+                    config.wide_mode,
+                );
+                (hash, page)
+            }
+        });
+
+        let data_changed = match built {
+            Some((hash, _)) => state.change_detection.update_game_detail_hash(hash),
+            None => true,
+        };
+
+        if is_auto_refresh && let Some(page) = state.current_page_mut() {
+            page.hide_auto_refresh_indicator();
+            state.request_render();
+        }
+
+        if !data_changed {
+            tracing::debug!("Game detail unchanged, skipping UI update");
+            return Ok(RefreshResult {
+                games: vec![],
+                had_error: false,
+                fetched_date: String::new(),
+                should_retry: false,
+                new_page: None,
+                needs_render: true,
+                skip_change_detection: true,
+            });
+        }
+
+        let new_page = if let Some((_, mut page)) = built {
+            // Keep the reader's page position across auto-refreshes
+            if is_auto_refresh && let Some(current) = state.current_page() {
+                page.set_current_page(current.get_current_page());
+            }
+            Some(page)
+        } else {
+            let mut error_page = TeletextPage::new(
+                page_number,
+                t("header.ice_hockey").to_string(),
+                subheader.to_string(),
+                config.disable_links,
+                true,
+                false,
+                false,
+                false,
+            );
+            error_page.add_error_message(error_text);
+            error_page.add_error_message(t("error.detail_back"));
             Some(error_page)
         };
 
@@ -983,7 +1276,6 @@ impl RefreshCoordinator {
                 && page.is_auto_refresh_indicator_active()
             {
                 page.hide_auto_refresh_indicator();
-                page.skip_next_screen_clear();
                 state.request_render();
                 needs_state_render = true;
             }
@@ -1029,12 +1321,15 @@ impl RefreshCoordinator {
 
         // Update change detection variables only on successful fetch
         if !result.had_error {
-            if let Some(page) = state.current_page_mut()
-                && page.is_error_warning_active()
-            {
-                page.hide_error_warning();
-                state.request_render();
-                needs_state_render = true;
+            if let Some(page) = state.current_page_mut() {
+                // Fresh server times, changed or not (the clock stands still
+                // during stoppages): restart live clock interpolation from them
+                page.sync_live_clocks();
+                if page.is_error_warning_active() {
+                    page.hide_error_warning();
+                    state.request_render();
+                    needs_state_render = true;
+                }
             }
             state
                 .change_detection
@@ -1050,9 +1345,6 @@ impl RefreshCoordinator {
             && page.is_auto_refresh_indicator_active()
         {
             page.hide_auto_refresh_indicator();
-            if !data_changed {
-                page.skip_next_screen_clear();
-            }
             state.request_render();
             needs_state_render = true;
         }
@@ -1064,6 +1356,16 @@ impl RefreshCoordinator {
     pub fn update_refresh_timing(&self, state: &mut InteractiveState, should_retry: bool) {
         state.clear_refresh_flag();
 
+        // The diagnostics and goal video pages make no requests; leave the
+        // backoff untouched
+        if matches!(
+            state.current_view(),
+            ViewMode::Diagnostics | ViewMode::GoalVideos { .. }
+        ) {
+            state.timers.update_auto_refresh();
+            return;
+        }
+
         // Only update last_auto_refresh if we shouldn't retry
         // This ensures that failed auto-refresh attempts will be retried on the next cycle
         if !should_retry {
@@ -1081,6 +1383,176 @@ impl RefreshCoordinator {
         }
     }
 
+    /// Builds the goal video picker of the game at `index` from the current
+    /// games list. Makes no API requests.
+    fn perform_goal_videos_refresh(
+        &self,
+        state: &mut InteractiveState,
+        config: &RefreshCycleConfig,
+        index: usize,
+        selected: usize,
+    ) -> RefreshResult {
+        let new_page = match state.change_detection.last_games().get(index) {
+            Some(game) => Some(navigation_manager::create_goal_videos_page(
+                game,
+                index,
+                selected,
+                config.disable_links,
+                config.wide_mode,
+            )),
+            None => {
+                tracing::warn!("Goal videos requested for missing game index {index}");
+                state.navigation.current_view = ViewMode::Games;
+                None
+            }
+        };
+
+        RefreshResult {
+            games: vec![],
+            had_error: false,
+            fetched_date: String::new(),
+            should_retry: false,
+            needs_render: new_page.is_some(),
+            new_page,
+            skip_change_detection: true,
+        }
+    }
+
+    /// Fetches the coming season's schedules and the finished season's
+    /// playoffs and table for the off-season page.
+    async fn perform_off_season_refresh(
+        &self,
+        state: &InteractiveState,
+        config: &RefreshCycleConfig,
+    ) -> Result<RefreshResult, AppError> {
+        tracing::info!("Fetching off-season data");
+
+        let off_season_page = || {
+            TeletextPage::new(
+                224,
+                t("header.ice_hockey").to_string(),
+                t("page.off_season").to_string(),
+                config.disable_links,
+                true,
+                false,
+                false,
+                config.wide_mode,
+            )
+        };
+
+        let off_season_error_page = || {
+            let mut page = off_season_page();
+            page.add_error_message(t("error.off_season"));
+            page.add_error_message(t("error.detail_back"));
+            page
+        };
+
+        // Hourly refreshes redraw in place; only the first load shows a notice
+        let is_auto_refresh = state
+            .current_page()
+            .is_some_and(|page| page.is_off_season_page());
+        if !is_auto_refresh && !config.headless {
+            let mut loading_page = off_season_page();
+            loading_page.add_error_message(t("loading.off_season"));
+            if let Err(e) = loading_page.render_buffered(&mut std::io::stdout()) {
+                tracing::warn!("Failed to render off-season loading page: {e}");
+            }
+        }
+
+        let app_config = crate::config::Config::load().await?;
+        let timeout_duration = std::time::Duration::from_secs(app_config.http_timeout_seconds + 5);
+        let result = tokio::time::timeout(
+            timeout_duration,
+            crate::data_fetcher::api::off_season_api::fetch_off_season_info(&app_config),
+        )
+        .await;
+
+        let (new_page, had_error) = match result {
+            Ok(Ok(info)) => {
+                let mut page = navigation_manager::create_off_season_page(
+                    &info,
+                    config.disable_links,
+                    config.wide_mode,
+                );
+                // Keep the reader's page position across refreshes
+                if let Some(current) = state.current_page()
+                    && current.is_off_season_page()
+                {
+                    page.set_current_page(current.get_current_page());
+                }
+                (page, false)
+            }
+            Ok(Err(e)) => {
+                tracing::error!("Failed to fetch off-season data: {e}");
+                (off_season_error_page(), true)
+            }
+            Err(_) => {
+                tracing::error!("Off-season data fetch timed out");
+                (off_season_error_page(), true)
+            }
+        };
+
+        Ok(RefreshResult {
+            games: vec![],
+            had_error,
+            fetched_date: String::new(),
+            should_retry: had_error,
+            new_page: Some(new_page),
+            needs_render: true,
+            skip_change_detection: true,
+        })
+    }
+
+    /// Rebuilds the diagnostics page from the current metrics. Makes no API
+    /// requests, so it never fails.
+    async fn perform_diagnostics_refresh(
+        &self,
+        state: &InteractiveState,
+        config: &RefreshCycleConfig,
+    ) -> RefreshResult {
+        use crate::data_fetcher::api::endpoint_health::endpoint_health;
+        use crate::data_fetcher::api::metrics::request_metrics;
+        use crate::data_fetcher::api::rate_limit::in_flight_requests;
+        use crate::data_fetcher::api::tournament_logic::unavailable_tournaments;
+        use crate::data_fetcher::cache::get_all_cache_stats;
+        use crate::teletext_ui::diagnostics_display::DiagnosticsSnapshot;
+
+        let stats = get_all_cache_stats().await;
+        let snapshot = DiagnosticsSnapshot {
+            in_flight: in_flight_requests(),
+            endpoints: request_metrics().snapshot(),
+            api_endpoints: endpoint_health().statuses(std::time::Instant::now()),
+            caches: vec![
+                (t("diagnostics.cache_http"), stats.http_response_cache),
+                (t("diagnostics.cache_games"), stats.detailed_game_cache),
+                (t("diagnostics.cache_tournament"), stats.tournament_cache),
+                (t("diagnostics.cache_goals"), stats.goal_events_cache),
+                (t("diagnostics.cache_players"), stats.player_cache),
+            ],
+            retry_backoff: state.adaptive_polling.retry_backoff(),
+            backoff_remaining: state.adaptive_polling.backoff_remaining(),
+            unavailable_tournaments: unavailable_tournaments().await,
+        };
+
+        let mut page = navigation_manager::create_diagnostics_page(&snapshot, config.disable_links);
+        // Keep the reader's page position across refreshes
+        if let Some(current) = state.current_page()
+            && current.is_diagnostics_page()
+        {
+            page.set_current_page(current.get_current_page());
+        }
+
+        RefreshResult {
+            games: vec![],
+            had_error: false,
+            fetched_date: String::new(),
+            should_retry: false,
+            new_page: Some(page),
+            needs_render: true,
+            skip_change_detection: true,
+        }
+    }
+
     /// Monitor cache usage and log statistics for long-running sessions
     pub async fn monitor_cache_usage(&self) {
         // The LRU cache automatically manages memory by evicting least recently used entries
@@ -1287,6 +1759,7 @@ mod tests {
             disable_links: true,
             compact_mode: false,
             wide_mode: true,
+            headless: false,
         };
 
         assert_eq!(config.min_refresh_interval, Some(10));
@@ -1347,6 +1820,7 @@ mod tests {
             disable_links: false,
             compact_mode: false,
             wide_mode: false,
+            headless: false,
         };
 
         let coordinator = RefreshCoordinator::new();
@@ -1397,6 +1871,7 @@ mod tests {
             disable_links: false,
             compact_mode: false,
             wide_mode: false,
+            headless: false,
         };
         let coordinator = RefreshCoordinator::new();
 
@@ -1419,6 +1894,7 @@ mod tests {
             disable_links: false,
             compact_mode: false,
             wide_mode: false,
+            headless: false,
         };
 
         let coordinator = RefreshCoordinator::new();
@@ -1484,6 +1960,47 @@ mod tests {
         assert_eq!(state.change_detection.last_games()[0].home_team, "TPS");
     }
 
+    #[test]
+    fn test_unchanged_refresh_resyncs_live_clocks() {
+        let coordinator = RefreshCoordinator::new();
+        let mut state = InteractiveState::new(Some("2025-03-13".to_string()));
+
+        // The same live game twice, as during a stoppage
+        let mut game = crate::testing_utils::TestDataBuilder::create_basic_game("TPS", "HIFK");
+        game.score_type = ScoreType::Ongoing;
+        game.played_time = 600;
+        let result = RefreshResult {
+            games: vec![game],
+            had_error: false,
+            fetched_date: "2025-03-13".to_string(),
+            should_retry: false,
+            new_page: None,
+            needs_render: false,
+            skip_change_detection: false,
+        };
+        coordinator.process_refresh_results(&mut state, &result);
+
+        let mut page = TeletextPage::new(
+            221,
+            "JÄÄKIEKKO".to_string(),
+            "SM-LIIGA".to_string(),
+            false,
+            true,
+            true,
+            false,
+            false,
+        );
+        page.backdate_live_clock_sync(Duration::from_secs(30));
+        state.set_current_page(page);
+
+        coordinator.process_refresh_results(&mut state, &result);
+        let age = state.current_page_mut().unwrap().live_clock_sync_age();
+        assert!(
+            age < Duration::from_secs(30),
+            "clocks must snap back to the server time even when the data is unchanged"
+        );
+    }
+
     #[test]
     fn test_should_discard_for_date_mismatch_whitespace_in_fetched_date() {
         // Trailing/leading whitespace in the fetched date should be treated
@@ -4,6 +4,7 @@
 //! for different types of Liiga games (playoffs, regular season, etc.).
 
 use crate::data_fetcher::GameData;
+use crate::i18n::t;
 
 /// Represents different tournament series types with explicit priority ordering
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -37,11 +38,11 @@ impl std::fmt::Display for SeriesType {
     /// Returns the display text for the teletext UI subheader
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let display_text = match self {
-            SeriesType::Playoffs => "PLAYOFFS",
-            SeriesType::Playout => "PLAYOUT-OTTELUT",
-            SeriesType::Qualifications => "LIIGAKARSINTA",
-            SeriesType::Practice => "HARJOITUSOTTELUT",
-            SeriesType::RegularSeason => "RUNKOSARJA",
+            SeriesType::Playoffs => t("series.playoffs"),
+            SeriesType::Playout => t("series.playout"),
+            SeriesType::Qualifications => t("series.qualifications"),
+            SeriesType::Practice => t("series.practice"),
+            SeriesType::RegularSeason => t("series.regular_season"),
         };
         f.write_str(display_text)
     }
@@ -59,7 +60,7 @@ pub(super) fn is_playoff_type(serie: &str) -> bool {
 
 /// Display label for a single serie value.
 ///
-/// Known series map to their established Finnish name. Anything else is a
+/// Known series map to their established name. Anything else is a
 /// tournament the API names directly (e.g. the preseason `PITSITURNAUS`) and is
 /// shown verbatim in upper case rather than falling back to "RUNKOSARJA".
 pub(super) fn series_group_label(serie: &str) -> String {
@@ -100,21 +101,21 @@ pub(super) fn get_subheader(games: &[GameData]) -> String {
         .to_string()
 }
 
-/// Returns the Finnish name for a playoff phase based on phase number and serie type.
+/// Returns the name for a playoff phase based on phase number and serie type.
 pub fn playoff_phase_name(phase: i32, serie: &str) -> &'static str {
-    match serie.to_ascii_lowercase().as_str() {
+    t(match serie.to_ascii_lowercase().as_str() {
         "playoffs" => match phase {
-            1 => "1. KIERROS",
-            2 => "PUOLIVÄLIERÄT",
-            3 => "VÄLIERÄT",
-            4 => "PRONSSIOTTELU",
-            5 => "FINAALI",
-            _ => "PLAYOFFS",
+            1 => "phase.first_round",
+            2 => "phase.quarter_finals",
+            3 => "phase.semi_finals",
+            4 => "phase.bronze_game",
+            5 => "phase.final",
+            _ => "series.playoffs",
         },
-        "playout" => "PLAYOUT",
-        "qualifications" => "LIIGAKARSINTA",
-        _ => "OTTELUT",
-    }
+        "playout" => "phase.playout",
+        "qualifications" => "series.qualifications",
+        _ => "phase.games",
+    })
 }
 
 #[cfg(test)]
@@ -0,0 +1,176 @@
+//! Last session state, saved on exit and restored on the next start
+//!
+//! Only the views that make sense without context are restored: the games
+//! list, the standings (with their live mode) and the playoff bracket. A game
+//! detail, lineup or diagnostics page is saved as the games page it was
+//! opened from, since game indexes belong to one day's list.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+use super::state_manager::{InteractiveState, ViewMode};
+use crate::config::DisplayMode;
+use crate::error::AppError;
+
+/// View restored on start.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SessionView {
+    #[default]
+    Games,
+    Standings,
+    Bracket,
+}
+
+/// What the user was looking at when the interactive UI last closed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionState {
+    pub view: SessionView,
+    /// Standings live mode, kept even when the standings were not open
+    pub live_mode: bool,
+    pub display_mode: DisplayMode,
+    /// Page of the restored view (0-based)
+    pub page: usize,
+}
+
+impl SessionState {
+    /// Captures the session of `state`, shown in `display_mode`.
+    pub fn capture(state: &InteractiveState, display_mode: DisplayMode) -> Self {
+        let current_page = state
+            .current_page()
+            .map_or(0, |page| page.get_current_page());
+        let games_page = state.navigation.preserved_games_page.unwrap_or(0);
+        let (view, live_mode, page) = match state.current_view() {
+            ViewMode::Games => (
+                SessionView::Games,
+                state.navigation.preserved_live_mode,
+                current_page,
+            ),
+            ViewMode::Standings { live_mode } => (SessionView::Standings, live_mode, current_page),
+            ViewMode::Bracket => (
+                SessionView::Bracket,
+                state.navigation.preserved_live_mode,
+                current_page,
+            ),
+            ViewMode::GameDetail { .. }
+            | ViewMode::GameLineup { .. }
+            | ViewMode::GoalVideos { .. }
+            | ViewMode::OffSeason
+            | ViewMode::Diagnostics => (
+                SessionView::Games,
+                state.navigation.preserved_live_mode,
+                games_page,
+            ),
+        };
+        Self {
+            view,
+            live_mode,
+            display_mode,
+            page,
+        }
+    }
+
+    /// Opens the saved view and page in a freshly created `state`.
+    pub fn restore(&self, state: &mut InteractiveState) {
+        state.navigation.current_view = match self.view {
+            SessionView::Games => ViewMode::Games,
+            SessionView::Standings => ViewMode::Standings {
+                live_mode: self.live_mode,
+            },
+            SessionView::Bracket => ViewMode::Bracket,
+        };
+        state.navigation.preserved_live_mode = self.live_mode;
+        state.preserve_page(self.page);
+    }
+
+    /// Reads the saved session. A missing or unreadable file means there is
+    /// nothing to restore; the latter is logged.
+    pub async fn load(path: &Path) -> Option<Self> {
+        let content = match fs::read_to_string(path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return None,
+            Err(e) => {
+                tracing::warn!("Cannot read session file {}: {e}", path.display());
+                return None;
+            }
+        };
+        match toml::from_str(&content) {
+            Ok(session) => Some(session),
+            Err(e) => {
+                tracing::warn!("Ignoring invalid session file {}: {e}", path.display());
+                None
+            }
+        }
+    }
+
+    /// Writes the session to `path`, creating its directory if needed.
+    pub async fn save(&self, path: &Path) -> Result<(), AppError> {
+        if let Some(dir) = path.parent()
+            && !dir.exists()
+        {
+            fs::create_dir_all(dir).await?;
+        }
+        fs::write(path, toml::to_string_pretty(self)?).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_capture_and_restore_standings() {
+        let mut state = InteractiveState::new(None);
+        state.navigation.current_view = ViewMode::Standings { live_mode: true };
+        let session = SessionState::capture(&state, DisplayMode::Wide);
+        assert_eq!(session.view, SessionView::Standings);
+        assert!(session.live_mode);
+        assert_eq!(session.display_mode, DisplayMode::Wide);
+
+        let mut restored = InteractiveState::new(None);
+        SessionState { page: 2, ..session }.restore(&mut restored);
+        assert_eq!(
+            restored.current_view(),
+            ViewMode::Standings { live_mode: true }
+        );
+        assert!(restored.navigation.preserved_live_mode);
+        assert_eq!(restored.preserved_page(), Some(2));
+    }
+
+    #[test]
+    fn test_detail_pages_are_saved_as_games_page() {
+        let mut state = InteractiveState::new(None);
+        state.navigation.current_view = ViewMode::GameDetail { index: 4 };
+        state.navigation.preserved_games_page = Some(1);
+        state.navigation.preserved_live_mode = true;
+
+        let session = SessionState::capture(&state, DisplayMode::Normal);
+        assert_eq!(session.view, SessionView::Games);
+        assert_eq!(session.page, 1);
+        assert!(session.live_mode);
+    }
+
+    #[tokio::test]
+    async fn test_save_and_load() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("nested").join("session.toml");
+        assert_eq!(SessionState::load(&path).await, None);
+
+        let session = SessionState {
+            view: SessionView::Bracket,
+            live_mode: true,
+            display_mode: DisplayMode::Compact,
+            page: 3,
+        };
+        session.save(&path).await.unwrap();
+        assert_eq!(SessionState::load(&path).await, Some(session));
+
+        std::fs::write(&path, "view = \"teletext\"\n").unwrap();
+        assert_eq!(SessionState::load(&path).await, None);
+    }
+}
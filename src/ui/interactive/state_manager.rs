@@ -4,16 +4,39 @@
 //! organizing different types of state into logical groupings and providing
 //! clean interfaces for state operations.
 
+use crate::config::DisplayMode;
 use crate::data_fetcher::GameData;
+use crate::data_fetcher::models::bracket::PlayoffBracket;
+use crate::data_fetcher::models::standings::StandingsEntry;
 use crate::teletext_ui::TeletextPage;
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
 /// Which view mode is active in the interactive UI
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ViewMode {
     Games,
-    Standings { live_mode: bool },
+    Standings {
+        live_mode: bool,
+    },
     Bracket,
+    /// Event timeline of the game at `index` in the games list
+    GameDetail {
+        index: usize,
+    },
+    /// Lineups and sidelined players of the game at `index` in the games list
+    GameLineup {
+        index: usize,
+    },
+    /// Goal video picker of the game at `index`, with the `selected` clip
+    GoalVideos {
+        index: usize,
+        selected: usize,
+    },
+    /// Coming season's countdowns and fixtures and last season's outcome
+    OffSeason,
+    /// Hidden request, cache and backoff diagnostics page
+    Diagnostics,
 }
 
 /// Timer state for various interactive UI operations
@@ -28,6 +51,8 @@ pub struct TimerState {
     pub cache_monitor_timer: Instant,
     /// Last digit typed for teletext-style page number entry
     pub last_page_input: Instant,
+    /// When the current notice was shown
+    pub last_notice: Instant,
 }
 
 impl TimerState {
@@ -43,6 +68,7 @@ impl TimerState {
             last_activity: now,
             cache_monitor_timer: now,
             last_page_input: now,
+            last_notice: now,
         }
     }
 
@@ -86,6 +112,14 @@ pub struct UIState {
     pub current_page: Option<TeletextPage>,
     pub pending_resize: bool,
     pub resize_timer: Instant,
+    /// Layout of the game list, switchable at runtime
+    pub display_mode: DisplayMode,
+    /// Notice shown above the footer until it times out
+    pub notice: Option<String>,
+    /// Directory page snapshots are exported into
+    pub export_dir: PathBuf,
+    /// Command goal clips are opened with; `None` prints the URL instead
+    pub video_player: Option<String>,
 }
 
 impl UIState {
@@ -97,6 +131,10 @@ impl UIState {
             current_page: None,
             pending_resize: false,
             resize_timer: Instant::now(),
+            display_mode: DisplayMode::Normal,
+            notice: None,
+            export_dir: PathBuf::from("."),
+            video_player: None,
         }
     }
 
@@ -233,7 +271,11 @@ pub struct ChangeDetectionState {
     pub last_games_hash: u64,
     pub last_games: Vec<GameData>,
     last_standings_hash: Option<u64>,
+    last_standings: Vec<StandingsEntry>,
+    last_playoff_lines: Vec<u16>,
     last_bracket_hash: Option<u64>,
+    last_bracket: Option<PlayoffBracket>,
+    last_game_detail_hash: Option<u64>,
 }
 
 impl ChangeDetectionState {
@@ -243,7 +285,11 @@ impl ChangeDetectionState {
             last_games_hash: 0,
             last_games: Vec::new(),
             last_standings_hash: None,
+            last_standings: Vec::new(),
+            last_playoff_lines: Vec::new(),
             last_bracket_hash: None,
+            last_bracket: None,
+            last_game_detail_hash: None,
         }
     }
 
@@ -282,6 +328,17 @@ impl ChangeDetectionState {
         changed
     }
 
+    /// Keeps the standings and playoff line positions last fetched
+    pub fn record_standings(&mut self, standings: &[StandingsEntry], playoff_lines: &[u16]) {
+        self.last_standings = standings.to_vec();
+        self.last_playoff_lines = playoff_lines.to_vec();
+    }
+
+    /// Get last fetched standings and playoff line positions
+    pub fn last_standings(&self) -> (&[StandingsEntry], &[u16]) {
+        (&self.last_standings, &self.last_playoff_lines)
+    }
+
     /// Reset standings hash (e.g., when switching away from standings view)
     pub fn reset_standings_hash(&mut self) {
         self.last_standings_hash = None;
@@ -301,10 +358,38 @@ impl ChangeDetectionState {
         changed
     }
 
+    /// Keeps the bracket last fetched
+    pub fn record_bracket(&mut self, bracket: &PlayoffBracket) {
+        self.last_bracket = Some(bracket.clone());
+    }
+
+    /// Get last fetched bracket, if any
+    pub fn last_bracket(&self) -> Option<&PlayoffBracket> {
+        self.last_bracket.as_ref()
+    }
+
     /// Reset bracket hash (e.g., when switching away from bracket view)
     pub fn reset_bracket_hash(&mut self) {
         self.last_bracket_hash = None;
     }
+
+    /// Get last game detail hash (None means never fetched)
+    pub fn last_game_detail_hash(&self) -> Option<u64> {
+        self.last_game_detail_hash
+    }
+
+    /// Update game detail hash after a successful fetch.
+    /// Returns true if the hash differs from the previously stored value.
+    pub fn update_game_detail_hash(&mut self, new_hash: u64) -> bool {
+        let changed = self.last_game_detail_hash != Some(new_hash);
+        self.last_game_detail_hash = Some(new_hash);
+        changed
+    }
+
+    /// Reset game detail hash (e.g., when leaving or switching the detail view)
+    pub fn reset_game_detail_hash(&mut self) {
+        self.last_game_detail_hash = None;
+    }
 }
 
 impl Default for ChangeDetectionState {
@@ -516,7 +601,12 @@ impl NavigationState {
         self.current_view = match self.current_view {
             ViewMode::Games => ViewMode::Standings { live_mode: false },
             ViewMode::Standings { .. } => ViewMode::Games,
-            ViewMode::Bracket => ViewMode::Games,
+            ViewMode::Bracket
+            | ViewMode::GameDetail { .. }
+            | ViewMode::GameLineup { .. }
+            | ViewMode::GoalVideos { .. }
+            | ViewMode::OffSeason
+            | ViewMode::Diagnostics => ViewMode::Games,
         };
     }
 
@@ -609,6 +699,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_records_last_standings_and_bracket() {
+        let mut cd = ChangeDetectionState::new();
+        assert!(cd.last_standings().0.is_empty());
+        assert!(cd.last_bracket().is_none());
+
+        let entry = StandingsEntry {
+            team_name: "Tappara".to_string(),
+            team_id: "TAP".to_string(),
+            games_played: 10,
+            wins: 7,
+            ot_wins: 1,
+            ot_losses: 0,
+            losses: 2,
+            goals_for: 30,
+            goals_against: 18,
+            points: 23,
+            live_goals_for: 0,
+            live_goals_against: 0,
+            live_points_delta: None,
+            live_position_change: None,
+            live_game_active: false,
+        };
+        cd.record_standings(std::slice::from_ref(&entry), &[6, 10]);
+        assert_eq!(cd.last_standings(), (&[entry][..], &[6, 10][..]));
+
+        cd.record_bracket(&PlayoffBracket {
+            season: "2025-2026".to_string(),
+            phases: vec![],
+            has_data: false,
+        });
+        assert_eq!(cd.last_bracket().unwrap().season, "2025-2026");
+    }
+
     #[test]
     fn test_update_standings_hash_first_call_returns_true() {
         let mut cd = ChangeDetectionState::new();
@@ -50,6 +50,9 @@ impl TerminalManager {
             execute!(stdout, EnterAlternateScreen)?;
         }
 
+        // Whatever was drawn before is gone; the first render paints everything
+        crate::teletext_ui::screen_diff::invalidate_presented_frame();
+
         Ok(stdout)
     }
 
@@ -1,36 +1,85 @@
 use crossterm::style::Color;
 
 // Constants for teletext appearance
-pub fn header_bg() -> Color {
+pub const fn header_bg() -> Color {
     Color::AnsiValue(21)
 } // Bright blue
-pub fn header_fg() -> Color {
+pub const fn header_fg() -> Color {
     Color::AnsiValue(21)
 } // Bright blue
-pub fn subheader_fg() -> Color {
+pub const fn subheader_fg() -> Color {
     Color::AnsiValue(46)
 } // Bright green
-pub fn result_fg() -> Color {
+pub const fn result_fg() -> Color {
     Color::AnsiValue(46)
 } // Bright green
-pub fn text_fg() -> Color {
+pub const fn text_fg() -> Color {
     Color::AnsiValue(231)
 } // Pure white
-pub fn home_scorer_fg() -> Color {
+pub const fn home_scorer_fg() -> Color {
     Color::AnsiValue(51)
 } // Bright cyan
-pub fn away_scorer_fg() -> Color {
+pub const fn away_scorer_fg() -> Color {
     Color::AnsiValue(51)
 } // Bright cyan
-pub fn winning_goal_fg() -> Color {
+pub const fn winning_goal_fg() -> Color {
     Color::AnsiValue(201)
 } // Bright magenta
-pub fn goal_type_fg() -> Color {
+pub const fn goal_type_fg() -> Color {
     Color::AnsiValue(226)
 } // Bright yellow
-pub fn scheduled_time_fg() -> Color {
+pub const fn scheduled_time_fg() -> Color {
     Color::AnsiValue(51)
 } // Bright cyan
-pub fn title_bg() -> Color {
+pub const fn title_bg() -> Color {
     Color::AnsiValue(46)
 } // Bright green
+
+/// The 16 system colors of the xterm palette
+const SYSTEM_COLORS: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Channel levels of the 6×6×6 color cube (codes 16-231)
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// RGB value of a 256-color ANSI code, as xterm draws it. Used where the
+/// page is drawn without a terminal, e.g. exported snapshots.
+pub fn ansi_rgb(code: u8) -> (u8, u8, u8) {
+    match code {
+        0..=15 => SYSTEM_COLORS[usize::from(code)],
+        16..=231 => {
+            let index = usize::from(code - 16);
+            (
+                CUBE_LEVELS[index / 36],
+                CUBE_LEVELS[(index / 6) % 6],
+                CUBE_LEVELS[index % 6],
+            )
+        }
+        _ => {
+            let level = 8 + (code - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+/// Hex color (`#rrggbb`) of a 256-color ANSI code
+pub fn ansi_hex(code: u8) -> String {
+    let (r, g, b) = ansi_rgb(code);
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
@@ -1,9 +1,11 @@
 //! Game result data structures and types
 
 use crate::data_fetcher::GoalEventData;
-use crate::data_fetcher::models::PlayoffSeriesScore;
+use crate::data_fetcher::models::{GameAnnotation, PlayoffSeriesScore, PreGameInfo};
+use serde::Serialize;
 
-#[derive(Debug, Clone, Hash, PartialEq)]
+#[derive(Debug, Clone, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ScoreType {
     Final,     // Final score
     Ongoing,   // Ongoing game with current score
@@ -24,6 +26,8 @@ pub struct GameResultData {
     pub goal_events: Vec<GoalEventData>,
     pub played_time: i32,
     pub series_score: Option<PlayoffSeriesScore>,
+    pub pre_game: Option<PreGameInfo>,
+    pub annotations: Vec<GameAnnotation>,
 }
 
 impl GameResultData {
@@ -54,7 +58,11 @@ impl GameResultData {
     ///     play_off_pair: None,
     ///     play_off_req_wins: None,
     ///     series_score: None,
+    ///     game_id: 0,
+    ///     season: 0,
     ///     is_placeholder: false,
+    ///     pre_game: None,
+    ///     annotations: vec![],
     /// };
     /// let result = GameResultData::new(&game_data);
     /// ```
@@ -70,6 +78,8 @@ impl GameResultData {
             goal_events: game_data.goal_events.clone(),
             played_time: game_data.played_time,
             series_score: game_data.series_score.clone(),
+            pre_game: game_data.pre_game.clone(),
+            annotations: game_data.annotations.clone(),
         }
     }
 }
@@ -5,29 +5,37 @@ use crossterm::{
 use semver::Version;
 use std::io::stdout;
 
+use crate::config::Config;
+use crate::data_fetcher::api::http_client::{HttpClientSettings, shared_http_client};
+
 const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const CRATE_NAME: &str = env!("CARGO_PKG_NAME");
 
+/// Timeout for the update check, shorter than for API requests
+const UPDATE_CHECK_TIMEOUT_SECONDS: u64 = 10;
+
 /// Checks for the latest version of this crate on crates.io.
 ///
+/// Uses the proxy, CA certificates, User-Agent and extra headers of `config`.
+///
 /// Returns `Some(version_string)` if a newer version is available,
 /// or `None` if there was an error checking or if the current version is up to date.
-pub async fn check_latest_version() -> Option<String> {
+pub async fn check_latest_version(config: Config) -> Option<String> {
     let crates_io_url = format!("https://crates.io/api/v1/crates/{CRATE_NAME}");
 
-    // Create a properly configured HTTP client with timeout handling
-    let client = reqwest::Client::builder()
-        .timeout(std::time::Duration::from_secs(10)) // Shorter timeout for update checks
-        .build()
-        .unwrap_or_else(|_| reqwest::Client::new()); // Fallback to default client if builder fails
+    let settings = HttpClientSettings {
+        timeout_seconds: UPDATE_CHECK_TIMEOUT_SECONDS,
+        ..HttpClientSettings::from_config(&config)
+    };
+    let client = match shared_http_client(&settings) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to check for updates: {e}");
+            return None;
+        }
+    };
 
-    let user_agent = format!("{CRATE_NAME}/{CURRENT_VERSION}");
-    let response = match client
-        .get(&crates_io_url)
-        .header("User-Agent", user_agent)
-        .send()
-        .await
-    {
+    let response = match client.get(&crates_io_url).send().await {
         Ok(resp) => resp,
         Err(e) => {
             eprintln!("Failed to check for updates: {e}");
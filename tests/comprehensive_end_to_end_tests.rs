@@ -551,7 +551,11 @@ async fn test_error_handling_comprehensive_scenarios() {
         play_off_pair: None,
         play_off_req_wins: None,
         series_score: None,
+        game_id: 0,
+        season: 0,
         is_placeholder: false,
+        pre_game: None,
+        annotations: vec![],
     }];
 
     // Test layout system with malformed data
@@ -0,0 +1,91 @@
+//! End-to-end fetches through the pluggable data sources, without HTTP or
+//! `LIIGA_API_DOMAIN`.
+
+use std::sync::Arc;
+
+use liiga_teletext::data_fetcher::api::data_source::{
+    ApiResource, FixtureDirSource, InMemorySource, reset_data_source, set_data_source,
+};
+use liiga_teletext::fetch_liiga_data;
+use liiga_teletext::teletext_ui::ScoreType;
+use serial_test::serial;
+
+fn today() -> String {
+    chrono::Local::now().format("%Y-%m-%d").to_string()
+}
+
+/// One scheduled regular season game later today
+fn schedule_document(date: &str) -> serde_json::Value {
+    let team = |id: &str, name: &str| {
+        serde_json::json!({
+            "teamId": id,
+            "teamName": name,
+            "goals": 0,
+            "powerplayInstances": 0,
+            "powerplayGoals": 0,
+            "shortHandedInstances": 0,
+            "shortHandedGoals": 0,
+            "gameStartDateTime": format!("{date}T16:30:00Z"),
+            "goalEvents": []
+        })
+    };
+    serde_json::json!({
+        "games": [{
+            "id": 1,
+            "season": 2026,
+            "start": format!("{date}T16:30:00Z"),
+            "homeTeam": team("tappara", "Tappara"),
+            "awayTeam": team("hifk", "HIFK"),
+            "started": false,
+            "ended": false,
+            "gameTime": 0,
+            "serie": "RUNKOSARJA"
+        }]
+    })
+}
+
+fn games_resource(date: &str) -> ApiResource {
+    ApiResource::Games {
+        tournament: "runkosarja".to_string(),
+        date: date.to_string(),
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn test_fetch_from_in_memory_source() {
+    let date = today();
+    let source = InMemorySource::new();
+    source
+        .insert(games_resource(&date), &schedule_document(&date))
+        .unwrap();
+    set_data_source(Arc::new(source)).await;
+
+    let result = fetch_liiga_data(Some(date.clone())).await;
+    reset_data_source().await;
+
+    let (games, fetched_date) = result.expect("in-memory fetch should succeed");
+    assert_eq!(fetched_date, date);
+    assert_eq!(games.len(), 1);
+    assert_eq!(games[0].home_team, "Tappara");
+    assert_eq!(games[0].away_team, "HIFK");
+    assert_eq!(games[0].score_type, ScoreType::Scheduled);
+}
+
+#[tokio::test]
+#[serial]
+async fn test_fetch_from_fixture_directory() {
+    let date = today();
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join(games_resource(&date).fixture_path());
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, schedule_document(&date).to_string()).unwrap();
+    set_data_source(Arc::new(FixtureDirSource::new(dir.path()))).await;
+
+    let result = fetch_liiga_data(Some(date)).await;
+    reset_data_source().await;
+
+    let (games, _) = result.expect("fixture fetch should succeed");
+    assert_eq!(games.len(), 1);
+    assert_eq!(games[0].away_team, "HIFK");
+}
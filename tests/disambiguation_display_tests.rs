@@ -223,6 +223,8 @@ fn test_normal_mode_displays_disambiguated_names_correctly() {
         goal_events: disambiguated_goal_events,
         played_time: 60,
         series_score: None,
+        pre_game: None,
+        annotations: Vec::new(),
     };
 
     let mut page = create_test_page(false, false); // normal mode
@@ -325,6 +327,8 @@ fn test_compact_mode_handles_disambiguated_names_within_space_constraints() {
         ],
         played_time: 60,
         series_score: None,
+        pre_game: None,
+        annotations: Vec::new(),
     };
 
     let mut page = create_test_page(true, false); // compact mode
@@ -387,6 +391,8 @@ fn test_wide_mode_maintains_consistent_disambiguation_logic() {
         goal_events: disambiguated_events1,
         played_time: 60,
         series_score: None,
+        pre_game: None,
+        annotations: Vec::new(),
     };
 
     // Test disambiguation context for second game
@@ -427,6 +433,8 @@ fn test_wide_mode_maintains_consistent_disambiguation_logic() {
         }],
         played_time: 60,
         series_score: None,
+        pre_game: None,
+        annotations: Vec::new(),
     };
 
     let mut page = create_test_page(false, true); // wide mode
@@ -665,6 +673,8 @@ fn test_name_truncation_works_properly_with_disambiguated_names() {
         ],
         played_time: 60,
         series_score: None,
+        pre_game: None,
+        annotations: Vec::new(),
     };
 
     let mut page = create_test_page(false, false); // normal mode
@@ -768,6 +778,8 @@ fn test_all_modes_handle_unicode_disambiguated_names() {
         goal_events,
         played_time: 60,
         series_score: None,
+        pre_game: None,
+        annotations: Vec::new(),
     };
 
     // Test normal mode
@@ -858,6 +870,8 @@ fn test_disambiguation_error_scenarios_in_display() {
         }],
         played_time: 60,
         series_score: None,
+        pre_game: None,
+        annotations: Vec::new(),
     };
 
     // Test that all UI modes can handle error scenarios without crashing
@@ -984,6 +998,8 @@ fn test_disambiguation_performance_with_many_players() {
         ],
         played_time: 60,
         series_score: None,
+        pre_game: None,
+        annotations: Vec::new(),
     };
 
     // Test that UI can handle large player sets
@@ -321,6 +321,8 @@ async fn test_complete_data_flow_api_to_display() {
         ],
         played_time: 3600,
         series_score: None,
+        pre_game: None,
+        annotations: Vec::new(),
     };
 
     // Step 4: Test display in all UI modes
@@ -1703,7 +1703,11 @@ async fn test_game_display_error_handling() {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         },
         // Game with very long team names
         GameData {
@@ -1741,7 +1745,11 @@ async fn test_game_display_error_handling() {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         },
     ];
 
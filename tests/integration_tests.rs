@@ -94,7 +94,11 @@ async fn test_page_navigation() {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         };
         page.add_game_result(GameResultData::new(&game));
     }
@@ -116,11 +120,13 @@ async fn test_config_validation() {
             api_domain: "https://api.example.com".to_string(),
             log_file_path: None,
             http_timeout_seconds: liiga_teletext::constants::DEFAULT_HTTP_TIMEOUT_SECONDS,
+            ..Default::default()
         },
         Config {
             api_domain: "http://api.example.com".to_string(),
             log_file_path: Some("/custom/log/path".to_string()),
             http_timeout_seconds: liiga_teletext::constants::DEFAULT_HTTP_TIMEOUT_SECONDS,
+            ..Default::default()
         },
     ];
 
@@ -158,7 +164,11 @@ async fn test_game_result_data_creation() {
         play_off_pair: None,
         play_off_req_wins: None,
         series_score: None,
+        game_id: 0,
+        season: 0,
         is_placeholder: false,
+        pre_game: None,
+        annotations: vec![],
     };
 
     // Create game result data
@@ -194,7 +204,11 @@ async fn test_teletext_ui_generation() {
         play_off_pair: None,
         play_off_req_wins: None,
         series_score: None,
+        game_id: 0,
+        season: 0,
         is_placeholder: false,
+        pre_game: None,
+        annotations: vec![],
     };
 
     // Create teletext page
@@ -229,6 +243,7 @@ async fn test_config_integration() {
         api_domain: "https://api.test.com".to_string(),
         log_file_path: Some("/test/log/path".to_string()),
         http_timeout_seconds: liiga_teletext::constants::DEFAULT_HTTP_TIMEOUT_SECONDS,
+        ..Default::default()
     };
 
     // Save config
@@ -274,7 +289,11 @@ async fn test_end_to_end_multiple_games() {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         },
         GameData {
             home_team: "Kärpät".to_string(),
@@ -292,7 +311,11 @@ async fn test_end_to_end_multiple_games() {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         },
     ];
 
@@ -338,7 +361,11 @@ async fn test_different_tournament_types() {
         play_off_pair: None,
         play_off_req_wins: None,
         series_score: None,
+        game_id: 0,
+        season: 0,
         is_placeholder: false,
+        pre_game: None,
+        annotations: vec![],
     };
 
     // Create teletext page
@@ -381,7 +408,11 @@ async fn test_ongoing_games() {
         play_off_pair: None,
         play_off_req_wins: None,
         series_score: None,
+        game_id: 0,
+        season: 0,
         is_placeholder: false,
+        pre_game: None,
+        annotations: vec![],
     };
 
     // Verify game data
@@ -410,7 +441,11 @@ async fn test_special_situations() {
         play_off_pair: None,
         play_off_req_wins: None,
         series_score: None,
+        game_id: 0,
+        season: 0,
         is_placeholder: false,
+        pre_game: None,
+        annotations: vec![],
     };
 
     // Verify game data
@@ -439,7 +474,11 @@ async fn test_compact_mode_non_interactive() {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         },
         GameData {
             home_team: "Kärpät".to_string(),
@@ -457,7 +496,11 @@ async fn test_compact_mode_non_interactive() {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         },
         GameData {
             home_team: "Ilves".to_string(),
@@ -475,7 +518,11 @@ async fn test_compact_mode_non_interactive() {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         },
     ];
 
@@ -536,7 +583,11 @@ async fn test_compact_mode_with_dates() {
         play_off_pair: None,
         play_off_req_wins: None,
         series_score: None,
+        game_id: 0,
+        season: 0,
         is_placeholder: false,
+        pre_game: None,
+        annotations: vec![],
     };
 
     let future_game = GameData {
@@ -555,7 +606,11 @@ async fn test_compact_mode_with_dates() {
         play_off_pair: None,
         play_off_req_wins: None,
         series_score: None,
+        game_id: 0,
+        season: 0,
         is_placeholder: false,
+        pre_game: None,
+        annotations: vec![],
     };
 
     // Test with past game (compact mode should work)
@@ -680,7 +735,11 @@ async fn test_compact_mode_preserves_styling() {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         },
         GameData {
             home_team: "Kärpät".to_string(),
@@ -698,7 +757,11 @@ async fn test_compact_mode_preserves_styling() {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         },
         GameData {
             home_team: "Ilves".to_string(),
@@ -716,7 +779,11 @@ async fn test_compact_mode_preserves_styling() {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         },
     ];
 
@@ -850,7 +917,11 @@ async fn test_compact_mode_basic_functionality() {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         },
         GameData {
             home_team: "Kärpät".to_string(),
@@ -868,7 +939,11 @@ async fn test_compact_mode_basic_functionality() {
             play_off_pair: None,
             play_off_req_wins: None,
             series_score: None,
+            game_id: 0,
+            season: 0,
             is_placeholder: false,
+            pre_game: None,
+            annotations: vec![],
         },
     ];
 
@@ -103,6 +103,8 @@ fn test_ui_modes_with_verified_disambiguation() {
         goal_events: goal_events.clone(),
         played_time: 60,
         series_score: None,
+        pre_game: None,
+        annotations: Vec::new(),
     };
 
     // Test normal mode (Requirement 3.1)
@@ -226,6 +228,8 @@ fn test_disambiguation_error_scenarios() {
         goal_events: vec![goal_with_missing_id],
         played_time: 60,
         series_score: None,
+        pre_game: None,
+        annotations: Vec::new(),
     };
 
     let mut error_page = TeletextPage::new(
@@ -360,6 +364,8 @@ fn test_name_truncation_with_disambiguation() {
         goal_events: long_disambiguated_names,
         played_time: 60,
         series_score: None,
+        pre_game: None,
+        annotations: Vec::new(),
     };
 
     let mut truncation_page = TeletextPage::new(
@@ -427,6 +433,8 @@ fn test_disambiguation_consistency_across_modes() {
         goal_events,
         played_time: 60,
         series_score: None,
+        pre_game: None,
+        annotations: Vec::new(),
     };
 
     // Test all modes store the same disambiguation results